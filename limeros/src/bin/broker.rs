@@ -1,80 +1,116 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use dashmap::DashMap;
-use log::{debug, info, warn};
-use tokio::{signal, sync::mpsc::Sender};
-use limeros::{logger, msgs::UdpMessage, scout::Subscription, UdpMessageHandler, UdpNode};
+use clap::Parser;
+use limeros::{
+    broker::{CacheConfig, CachePolicy, Forwarder, LastValueCache},
+    logger,
+    metrics::{self, BrokerMetrics},
+    msgs::{BrokerRatesRep, BrokerRatesReq},
+    security::Security,
+    topology::FlowRate,
+    NodeConfig, ScoutConfig, UdpNode,
+};
+use log::info;
+use tokio::signal;
 
-struct Forwarder {
-    sender: Sender<UdpMessage>,
-    subscriptions: Arc<DashMap<String, Vec<Subscription>>>,
-}
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// multicast group, IPv4 or IPv6 e.g. [ff02::1:4c]:50000
+    #[arg(short = 'm', long, default_value = "239.0.0.1:50000")]
+    multicast_addr: String,
 
-#[async_trait::async_trait]
-impl UdpMessageHandler for Forwarder {
-    async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
-        debug!(
-            "Forwarder received message of type {:?} from {:?}",
-            udp_message.msg_type, udp_message.src
-        );
-        
-        if let Some(msg_type) = &udp_message.msg_type {
-            if let Some(dsts) = self.subscriptions.get(msg_type) {
-                for dst in dsts.iter() {
-                    self.sender
-                        .send(UdpMessage {
-                            dst: Some(dst.destination.clone()),
-                            ..udp_message.clone()
-                        })
-                        .await?;
-                    debug!(
-                        "Forwarded message of type {:?} from {:?} to {:?}",
-                        udp_message.msg_type,
-                        udp_message.src,
-                        dst.destination.clone()
-                    );
-                }
-            } ;
-            if let Some(dsts) = self.subscriptions.get("*")  {
-                for dst in dsts.iter() {
-                    self.sender
-                        .send(UdpMessage {
-                            dst: Some(dst.destination.clone()),
-                            ..udp_message.clone()
-                        })
-                        .await?;
-                    debug!(
-                        "(*)Forwarded message of type {:?} from {:?} to {:?}",
-                        udp_message.msg_type,
-                        udp_message.src,
-                        dst.destination.clone()
-                    );
-                }
-            };
-        } else {
-            warn!(
-                "Forwarder received message with no type from {:?}",
-                udp_message.src
-            );
-        }
+    /// interface name or address to discover on, repeat for several interfaces
+    #[arg(short = 'i', long = "interface")]
+    interfaces: Vec<String>,
 
-        Ok(())
-    }
+    /// pre-shared key config file, encrypts all traffic
+    #[arg(long)]
+    psk: Option<String>,
+
+    /// last values kept per source and type for late joiners, 0 disables the cache
+    #[arg(long, default_value_t = 1)]
+    cache_depth: usize,
+
+    /// seconds a cached value is replayed
+    #[arg(long)]
+    cache_ttl: Option<f64>,
+
+    /// per type cache policy msg_type=depth[:ttl_secs], e.g. LogEvent=20:300 or HoverboardEvent=0
+    #[arg(long = "cache")]
+    cache_rules: Vec<String>,
+
+    /// address of the HTTP server with the Prometheus metrics at /metrics, e.g. 127.0.0.1:9464,
+    /// without it no server is started
+    #[arg(long)]
+    metrics_addr: Option<SocketAddr>,
+
+    /// seconds between BrokerMetricsEvent messages on the bus, 0 disables them
+    #[arg(long, default_value_t = 10)]
+    metrics_interval: u64,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
     logger::init();
     info!("Starting BROKER...");
     // Bind Unicast to 8080. Multicast listens on 5000 automatically via socket2 logic in lib.
-    let node = UdpNode::new("broker", "239.0.0.1:50000").await?;
-    let sender = node.sender();
-    let subscriptions = node.get_subscriptions().await;
-    let forwarder = Forwarder {
-        sender,
-        subscriptions,
+    let security = args.psk.as_ref().map(Security::load).transpose()?;
+    let config = NodeConfig {
+        scout: ScoutConfig {
+            interfaces: args.interfaces.clone(),
+            ..Default::default()
+        },
+        security,
+        ..Default::default()
     };
-    node.add_generic_handler(forwarder).await;
+    let node = UdpNode::with_config("broker", &args.multicast_addr, config).await?;
+    let cache_config = CacheConfig {
+        default: CachePolicy {
+            depth: args.cache_depth,
+            ttl: args.cache_ttl.map(Duration::from_secs_f64),
+        },
+        types: args
+            .cache_rules
+            .iter()
+            .map(|rule| CachePolicy::parse_rule(rule))
+            .collect::<anyhow::Result<_>>()?,
+    };
+    let cache = Arc::new(LastValueCache::new(cache_config));
+    cache.clone().start_replay(node.scout(), node.sender());
+    let forwarder = Arc::new(Forwarder::new(node.sender(), node.scout()).with_cache(cache));
+    let rates = forwarder.rates();
+    let counters = forwarder.counters();
+    node.add_generic_handler(forwarder.clone()).await;
+    node.serve::<BrokerRatesReq, BrokerRatesRep, _, _>(move |_src, _req| {
+        let rates = rates.clone();
+        async move { FlowRate::to_reply(&rates.rates()) }
+    });
+
+    if let Some(addr) = args.metrics_addr {
+        let node = node.clone();
+        let counters = counters.clone();
+        metrics::serve_http(addr, move || {
+            BrokerMetrics::collect(&counters, &node).to_prometheus()
+        })
+        .await?;
+    }
+    if args.metrics_interval > 0 {
+        let node = node.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(args.metrics_interval));
+            loop {
+                interval.tick().await;
+                let event = BrokerMetrics::collect(&counters, &node).to_event();
+                if let Err(e) = forwarder.publish(node.codec(), &event).await {
+                    info!("Failed to publish metrics: {}", e);
+                }
+            }
+        });
+    }
 
     tokio::spawn(async move {
         loop {
@@ -1,6 +1,6 @@
 use clap::Parser;
 use limeros::{
-    TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, logger, msgs::{PingRep, PingReq, SysEvent, TypedMessage}
+    Codec, NodeConfig, ScoutConfig, TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, logger, msgs::{PingRep, PingReq, SysEvent, TypedMessage}, security::Security
 };
 use log::info;
 use std::{sync::Arc, time::Duration};
@@ -23,6 +23,18 @@ struct Args {
 
     #[arg(short = 'c', long, default_value_t = 1)]
     client_count: u64,
+
+    /// send payloads as CBOR instead of JSON
+    #[arg(long, default_value_t = false)]
+    cbor: bool,
+
+    /// pre-shared key config file, encrypts all traffic
+    #[arg(long)]
+    psk: Option<String>,
+
+    /// interface name or address to discover on, repeat for several interfaces
+    #[arg(short = 'i', long = "interface")]
+    interfaces: Vec<String>,
 }       
 
 struct Handler {
@@ -46,7 +58,7 @@ impl UdpMessageHandler for Handler {
                         number: typed_msg.payload.as_ref().and_then(|p| p.number).map(|n| n + 1),
                         ..Default::default()
                     },
-                ).await;
+                ).await?;
             },
             Some(other) => {
                 info!("Generic Handler received unknown message type: {} ", other);
@@ -64,11 +76,24 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     logger::init();
     info!("Starting CLIENT...");
-    let node = UdpNode::new(
+    let security = args.psk.as_ref().map(Security::load).transpose()?;
+    let config = NodeConfig {
+        scout: ScoutConfig {
+            interfaces: args.interfaces.clone(),
+            ..Default::default()
+        },
+        security,
+        ..Default::default()
+    };
+    let node = UdpNode::with_config(
         args.node_name.as_str(),
         args.multicast_addr.clone().as_str(),
+        config,
     )
     .await?;
+    if args.cbor {
+        node.set_codec(Codec::Cbor);
+    }
     node.add_subscription(SysEvent::MSG_TYPE).await;
     let handler = Handler { node: node.clone() };      
     node.add_generic_handler(handler).await;
@@ -104,7 +129,7 @@ async fn main() -> anyhow::Result<()> {
             cpu_board: Some("linux-x86".to_string()),
             ..Default::default()
         })
-        .await;
+        .await?;
         node.send_msg_to(
             "esp1",
             PingReq {
@@ -112,7 +137,7 @@ async fn main() -> anyhow::Result<()> {
                 ..Default::default()
             },
         )
-        .await;
+        .await?;
     info!("{} sent SysEvent and PingReq", args.node_name);
         sleep(Duration::from_secs(args.frequency)).await;
     }
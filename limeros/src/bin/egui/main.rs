@@ -1,7 +1,7 @@
 use dashmap::DashMap;
 use eframe::egui::{self};
 use limeros::{
-    Alive, Endpoint, Msg, TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, eventbus::{Bus, as_message}, logger, msgs::{HoverboardCmd, HoverboardEvent, SysEvent, TypedMessage, WifiEvent}
+    Alive, Endpoint, Msg, TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, eventbus::{Bus, as_message}, logger, schema::Registry, msgs::{HoverboardCmd, HoverboardEvent, SysEvent, TypedMessage, WifiEvent}
 };
 use log::info;
 use socket2::Socket;
@@ -128,6 +128,7 @@ impl UdpMonitorApp {}
 // --- 4. Main Entry Point ---
 
 struct GuiHandler {
+    registry: Registry,
     cache: Arc<DashMap<String, Record>>,
     graph_data: Arc<DashMap<String, MetricData>>,
     start_time: std::time::Instant,
@@ -136,20 +137,17 @@ struct GuiHandler {
 #[async_trait::async_trait]
 impl UdpMessageHandler for GuiHandler {
     async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
-        // Parse the payload (assuming JSON as per your UdpNode implementation)
-        let fields = if let Some(payload) = &udp_message.payload {
-            let v: serde_json::Value = serde_json::from_slice(payload).unwrap_or_default();
-            if let serde_json::Value::Object(map) = v {
-                map.into_iter().map(|(k, v)| (k, v.to_string())).collect()
-            } else {
-                vec![(
-                    "raw".to_string(),
-                    String::from_utf8_lossy(payload).into_owned(),
-                )]
-            }
-        } else {
-            vec![]
-        };
+        // any payload, the registry names the fields of the known message types
+        let fields: Vec<(String, limeros::schema::Value)> = self
+            .registry
+            .decode_message(udp_message)
+            .flatten()
+            .into_iter()
+            .map(|(field_name, value)| match field_name.is_empty() {
+                true => ("raw".to_string(), value),
+                false => (field_name, value),
+            })
+            .collect();
 
         for (field_name, value) in &fields {
             let key = format!(
@@ -163,7 +161,7 @@ impl UdpMessageHandler for GuiHandler {
                 src: udp_message.src.clone().unwrap_or_default(),
                 msg_type: udp_message.msg_type.clone().unwrap_or_default(),
                 field_name: field_name.clone(),
-                value: value.clone(),
+                value: value.to_string(),
                 counter : 1,
             };
             self.cache
@@ -179,8 +177,8 @@ impl UdpMessageHandler for GuiHandler {
             .as_secs_f64();
 
         for (field_name, value) in &fields {
-            // Try to parse value as a number for graphing
-            if let Ok(num) = value.parse::<f64>() {
+            // graph the numeric fields
+            if let Some(num) = value.as_f64() {
                 let key = format!(
                     "{}:{}:{}",
                     udp_message.src.as_deref().unwrap_or("?"),
@@ -259,6 +257,7 @@ async fn main() -> anyhow::Result<()> {
     n.add_subscription(HoverboardEvent::MSG_TYPE).await;*/
     n.add_subscription("*").await;
     n.add_generic_handler(GuiHandler {
+        registry: Registry::builtin(),
         cache: cache.clone(),
         graph_data: graph_data.clone(),
         start_time: std::time::Instant::now(),
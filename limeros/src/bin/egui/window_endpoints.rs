@@ -6,7 +6,7 @@ use limeros::{
 };
 use anyhow::Result;
 use log::info;
-use std::{sync::Arc, time::Instant};
+use std::sync::Arc;
 
 pub struct WindowEndpoints {
    node : Arc<UdpNode>  ,
@@ -44,7 +44,7 @@ impl MyWindow for WindowEndpoints {
                         for entry in self.endpoints.iter() {
                             let ep = entry.value();
                             let secs_ago =
-                                Instant::now().duration_since(ep.last_seen).as_millis() / 1000;
+                                ep.last_seen.elapsed().as_millis() / 1000;
 
                             ui.label(entry.key());
                             ui.label(ep.addr.to_string());
@@ -5,7 +5,7 @@ use eframe::egui::{self, Slider};
 use ehmi::{Bar, Gauge};
 use futures::executor::block_on;
 use limeros::{
-    Msg, TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, eventbus::Bus, msgs::{HoverboardCmd, HoverboardEvent, TypedMessage}
+    Codec, Msg, TypedUdpMessage, UdpMessage, UdpMessageHandler, UdpNode, eventbus::Bus, msgs::{HoverboardCmd, HoverboardEvent, TypedMessage}
 };
 use log::info;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -64,7 +64,9 @@ impl HoverboardWindow {
         if udp_msg.msg_type.as_deref() != Some(HoverboardEvent::MSG_TYPE) {
             return Ok(());
         }
-        let event = serde_json::from_slice::<HoverboardEvent>(&udp_msg.payload.unwrap())?;
+        let event = TypedUdpMessage::<HoverboardEvent>::from(udp_msg)?
+            .payload
+            .ok_or(anyhow::anyhow!("Invalid HoverboardEvent payload"))?;
         self.last_update = Instant::now();
         event.spdl.map(|v| self.speed_left = v as f32);
         event.spdr.map(|v| self.speed_right = v as f32);
@@ -90,6 +92,8 @@ impl HoverboardWindow {
                 })
                 .unwrap(),
             ),
+            codec: Some(Codec::Json),
+            ..Default::default()
         });
         info!(
             "Sent HoverboardCmd: speed={} steer={}",
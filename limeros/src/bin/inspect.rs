@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use limeros::msgs::{BrokerRatesRep, BrokerRatesReq};
+use limeros::topology::{FlowRate, Topology};
+use limeros::{logger, security::Security, NodeConfig, UdpNode};
+use log::{info, warn};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Inspect a running limeros network", long_about = None)]
+struct Args {
+    #[arg(short = 'm', long, default_value = "239.0.0.1:50000")]
+    multicast_addr: String,
+
+    /// pre-shared key config file, encrypts all traffic
+    #[arg(long)]
+    psk: Option<String>,
+
+    #[arg(short = 'n', long, default_value = "inspect")]
+    node_name: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Graph of the endpoints and the messages they exchange
+    Topology {
+        #[arg(short = 'f', long, value_enum, default_value_t = Format::Dot)]
+        format: Format,
+        /// annotate the edges with the message rates of the broker
+        #[arg(long, default_value_t = false)]
+        rates: bool,
+        /// seconds to listen for Alive messages
+        #[arg(long, default_value_t = 3)]
+        wait: u64,
+        /// write to a file instead of stdout
+        #[arg(short = 'o', long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    logger::init();
+    let security = args.psk.as_ref().map(Security::load).transpose()?;
+    let config = NodeConfig {
+        security,
+        ..Default::default()
+    };
+    let node = UdpNode::with_config(&args.node_name, &args.multicast_addr, config).await?;
+
+    match args.command {
+        Command::Topology {
+            format,
+            rates,
+            wait,
+            output,
+        } => {
+            info!("Listening {} seconds for endpoints", wait);
+            tokio::time::sleep(Duration::from_secs(wait)).await;
+            let mut topology = Topology::from_scout(&node.scout());
+            topology.remove_endpoint(&args.node_name);
+            if rates {
+                match node
+                    .request::<BrokerRatesReq, BrokerRatesRep>(
+                        "broker",
+                        BrokerRatesReq {},
+                        Duration::from_secs(2),
+                    )
+                    .await
+                {
+                    Ok(reply) => topology = topology.with_rates(&FlowRate::from_reply(&reply)),
+                    Err(e) => warn!("No rates from the broker: {}", e),
+                }
+            }
+            let text = match format {
+                Format::Dot => topology.to_dot(),
+                Format::Mermaid => topology.to_mermaid(),
+                Format::Json => topology.to_json()?,
+            };
+            match output {
+                Some(path) => std::fs::write(path, text)?,
+                None => println!("{}", text),
+            }
+        }
+    }
+    Ok(())
+}
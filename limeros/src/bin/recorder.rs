@@ -0,0 +1,191 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::{Parser, Subcommand};
+use limeros::recording::{RecordFilter, RecordReader, RecordWriter, ReplaySpeed};
+use limeros::{logger, msgs::UdpMessage, security::Security, NodeConfig, UdpNode};
+use log::{error, info};
+use tokio::{signal, sync::mpsc, time::Instant};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Record limeros traffic to a log file and replay it", long_about = None)]
+struct Args {
+    #[arg(short = 'm', long, default_value = "239.0.0.1:50000")]
+    multicast_addr: String,
+
+    /// pre-shared key config file, encrypts all traffic
+    #[arg(long)]
+    psk: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Subscribe through the broker and write every message received
+    Record {
+        /// log file to write
+        file: String,
+        /// subscription patterns, everything by default
+        #[arg(short = 's', long = "subscribe", default_value = "**")]
+        subscriptions: Vec<String>,
+        #[arg(short = 'n', long, default_value = "recorder")]
+        node_name: String,
+    },
+    /// Send the recorded messages to the broker again
+    Replay {
+        /// log file to read
+        file: String,
+        /// only messages from these sources
+        #[arg(long = "src")]
+        sources: Vec<String>,
+        /// only messages of these types
+        #[arg(long = "type")]
+        msg_types: Vec<String>,
+        /// replay speed factor, 2.0 is twice as fast
+        #[arg(long, default_value_t = 1.0)]
+        speed: f64,
+        /// wait for Enter before each message
+        #[arg(long, default_value_t = false)]
+        step: bool,
+        /// start at this many seconds into the recording
+        #[arg(long, default_value_t = 0.0)]
+        from: f64,
+        #[arg(short = 'n', long, default_value = "replayer")]
+        node_name: String,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    logger::init();
+    let security = args.psk.as_ref().map(Security::load).transpose()?;
+    let config = NodeConfig {
+        security,
+        ..Default::default()
+    };
+    match args.command {
+        Command::Record {
+            file,
+            subscriptions,
+            node_name,
+        } => {
+            let node = UdpNode::with_config(&node_name, &args.multicast_addr, config).await?;
+            record(node, &file, &subscriptions).await
+        }
+        Command::Replay {
+            file,
+            sources,
+            msg_types,
+            speed,
+            step,
+            from,
+            node_name,
+        } => {
+            let node = UdpNode::with_config(&node_name, &args.multicast_addr, config).await?;
+            let filter = RecordFilter { sources, msg_types };
+            let speed = if step {
+                ReplaySpeed::Stepped
+            } else if speed == 1.0 {
+                ReplaySpeed::Original
+            } else {
+                ReplaySpeed::Scaled(speed)
+            };
+            replay(node, &file, filter, speed, (from * 1_000_000.0) as u64).await
+        }
+    }
+}
+
+async fn record(
+    node: std::sync::Arc<UdpNode>,
+    file: &str,
+    subscriptions: &[String],
+) -> anyhow::Result<()> {
+    let start_us = SystemTime::now().duration_since(UNIX_EPOCH)?.as_micros() as u64;
+    let start = Instant::now();
+    let mut writer = RecordWriter::create(file, start_us)?;
+    let (tx, mut rx) = mpsc::channel::<UdpMessage>(1000);
+    node.add_sender(tx);
+    for subscription in subscriptions {
+        node.add_subscription(subscription).await;
+    }
+    info!("Recording {:?} to {}", subscriptions, file);
+
+    loop {
+        tokio::select! {
+            Some(message) = rx.recv() => {
+                writer.write(start.elapsed().as_micros() as u64, &message)?;
+                if writer.len() % 100 == 0 {
+                    writer.flush()?;
+                    info!("Recorded {} messages", writer.len());
+                }
+            }
+            _ = signal::ctrl_c() => break,
+        }
+    }
+    info!("Recorded {} messages to {}", writer.len(), file);
+    writer.finish()?;
+    Ok(())
+}
+
+async fn replay(
+    node: std::sync::Arc<UdpNode>,
+    file: &str,
+    filter: RecordFilter,
+    speed: ReplaySpeed,
+    from_us: u64,
+) -> anyhow::Result<()> {
+    let mut reader = RecordReader::open(file)?;
+    info!(
+        "Replaying {} messages, {:?} long, from {}",
+        reader.len(),
+        reader.duration(),
+        file
+    );
+    // let Scout discover the broker before sending
+    tokio::time::sleep(node.scout().config().heartbeat_interval * 2).await;
+
+    let sender = node.sender();
+    let position = reader.seek_time(from_us);
+    let mut previous_us = from_us;
+    let mut replayed = 0;
+    for record in reader.records(position) {
+        let record = record?;
+        if !filter.matches(&record.message) {
+            continue;
+        }
+        match speed.delay(previous_us, record.offset_us) {
+            Some(delay) => tokio::time::sleep(delay).await,
+            None => {
+                println!(
+                    "{:>10.3}s {:?} from {:?}, Enter to send",
+                    record.offset_us as f64 / 1_000_000.0,
+                    record.message.msg_type,
+                    record.message.src
+                );
+                let mut line = String::new();
+                tokio::task::spawn_blocking(move || std::io::stdin().read_line(&mut line))
+                    .await??;
+            }
+        }
+        previous_us = record.offset_us;
+        // the broker forwards it again as coming from the original source
+        let message = UdpMessage {
+            dst: Some("broker".to_string()),
+            seq: None,
+            nonce: None,
+            correlation_id: None,
+            ..record.message
+        };
+        if let Err(e) = sender.send(message).await {
+            error!("Replay stopped: {}", e);
+            break;
+        }
+        replayed += 1;
+    }
+    info!("Replayed {} messages", replayed);
+    // give the unicast sender time to empty its queue
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crossterm::{
 };
 use dashmap::DashMap;
 use limeros::{
-    Endpoint, UdpMessage, UdpMessageHandler, UdpNode, msgs::{ HoverboardEvent, SysEvent, TypedMessage, WifiEvent}
+    Endpoint, UdpMessage, UdpMessageHandler, UdpNode, schema::Registry
 }; // Assuming the code you provided is in the same crate
 use ratatui::{
     backend::{Backend, CrosstermBackend},
@@ -101,6 +101,7 @@ impl App {
 
 struct TuiHandler {
     state: Arc<Mutex<App>>,
+    registry: Registry,
 }
 
 #[async_trait::async_trait]
@@ -108,20 +109,17 @@ impl UdpMessageHandler for TuiHandler {
     async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
         let  app = self.state.lock().await;
 
-        // Parse the payload (assuming JSON as per your UdpNode implementation)
-        let fields = if let Some(payload) = &udp_message.payload {
-            let v: serde_json::Value = serde_json::from_slice(payload).unwrap_or_default();
-            if let serde_json::Value::Object(map) = v {
-                map.into_iter().map(|(k, v)| (k, v.to_string())).collect()
-            } else {
-                vec![(
-                    "raw".to_string(),
-                    String::from_utf8_lossy(payload).into_owned(),
-                )]
-            }
-        } else {
-            vec![]
-        };
+        // any payload, the registry names the fields of the known message types
+        let fields: Vec<(String, String)> = self
+            .registry
+            .decode_message(udp_message)
+            .flatten()
+            .into_iter()
+            .map(|(field_name, value)| match field_name.is_empty() {
+                true => ("raw".to_string(), value.to_string()),
+                false => (field_name, value.to_string()),
+            })
+            .collect();
 
         for (field_name, value) in &fields {
             let key = format!(
@@ -162,14 +160,13 @@ async fn main() -> anyhow::Result<()> {
 
     // Initialize UdpNode
     let node = UdpNode::new("tui-monitor", "239.0.0.1:50000").await?;
-    node.add_subscription(SysEvent::MSG_TYPE).await;
-    node.add_subscription(WifiEvent::MSG_TYPE).await;
-    node.add_subscription(HoverboardEvent::MSG_TYPE).await;
+    node.add_subscription("*").await;
     let app_state = Arc::new(Mutex::new(App::new(node.clone())));
 
     // Register our TUI handler to the node
     node.add_generic_handler(TuiHandler {
         state: app_state.clone(),
+        registry: Registry::builtin(),
     })
     .await;
 
@@ -284,7 +281,7 @@ fn render_endpoints_tab(f: &mut Frame, area: Rect, endpoints: &Vec<Endpoint>) {
                 Cell::from(ep.name.clone()),
                 Cell::from(ep.addr.to_string()),
                 Cell::from(format!("{:?}", ep.subscribe)),
-                Cell::from(format!("{} sec ago", Instant::now().duration_since(ep.last_seen).as_secs())),
+                Cell::from(format!("{} sec ago", ep.last_seen.elapsed().as_secs())),
             ])
         })
         .collect();
@@ -1,6 +1,6 @@
 use anyhow::Ok;
 use dashmap::DashMap;
-use limeros::{UdpMessage, UdpMessageHandler, UdpNode};
+use limeros::{schema::Registry, UdpMessage, UdpMessageHandler, UdpNode};
 use std::sync::Arc;
 use winit::{
     application::ApplicationHandler,
@@ -91,38 +91,41 @@ impl ApplicationHandler for App {
 }
 
 struct AppHandler {
+    registry: Registry,
     cache: Arc<DashMap<String, Record>>,
 }
 #[async_trait::async_trait]
 impl UdpMessageHandler for AppHandler {
     async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
-        let payload = udp_message
-            .payload
-            .as_ref()
-            .ok_or(anyhow::anyhow!("No payload"))?;
-        let v: serde_json::Value = serde_json::from_slice(payload).unwrap_or_default();
+        if udp_message.payload.is_none() {
+            return Err(anyhow::anyhow!("No payload"));
+        }
+        let value = self.registry.decode_message(udp_message);
 
-        if let serde_json::Value::Object(map) = v {
-            for (field_name, value) in map {
-                let key = format!(
-                    "{}:{}:{}",
-                    udp_message.src.as_deref().unwrap_or("?"),
-                    udp_message.msg_type.as_deref().unwrap_or("?"),
-                    field_name
-                );
-                let record = Record {
-                    src: udp_message.src.clone().unwrap_or_default(),
-                    msg_type: udp_message.msg_type.clone().unwrap_or_default(),
-                    field_name: field_name.clone(),
-                    value: value.to_string(),
-                };
-                self.cache
-                    .entry(key)
-                    .and_modify(|e| {
-                        *e = record.clone();
-                    })
-                    .or_insert(record);
-            }
+        for (field_name, value) in value.flatten() {
+            let field_name = if field_name.is_empty() {
+                "raw".to_string()
+            } else {
+                field_name
+            };
+            let key = format!(
+                "{}:{}:{}",
+                udp_message.src.as_deref().unwrap_or("?"),
+                udp_message.msg_type.as_deref().unwrap_or("?"),
+                field_name
+            );
+            let record = Record {
+                src: udp_message.src.clone().unwrap_or_default(),
+                msg_type: udp_message.msg_type.clone().unwrap_or_default(),
+                field_name: field_name.clone(),
+                value: value.to_string(),
+            };
+            self.cache
+                .entry(key)
+                .and_modify(|e| {
+                    *e = record.clone();
+                })
+                .or_insert(record);
         }
         Ok(())
     }
@@ -146,6 +149,7 @@ fn main() -> anyhow::Result<()> {
             .await
             .unwrap();
         node.add_generic_handler(AppHandler {
+            registry: Registry::builtin(),
             cache: cache.clone(),
         })
         .await;
@@ -0,0 +1,390 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::metrics::MessageCounters;
+use crate::msgs::{Msg, TypedMessage, UdpMessage};
+use crate::pattern::{KeyPattern, SubscriptionIndex};
+use crate::scout::EndpointEvent;
+use crate::topology::FlowRate;
+use crate::txqueue::TxSender;
+use crate::{Codec, Scout, UdpMessageHandler};
+
+// --- CONSTANTS ---
+/// Rates are averaged over this window
+pub const RATE_WINDOW: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Flow {
+    src: String,
+    dst: Option<String>,
+    msg_type: String,
+}
+
+/// Messages counted per flow in one second buckets over a sliding window
+pub struct FlowRates {
+    started: Instant,
+    window_secs: u64,
+    counts: Mutex<HashMap<Flow, VecDeque<(u64, u64)>>>,
+}
+
+impl Default for FlowRates {
+    fn default() -> Self {
+        Self::new(RATE_WINDOW)
+    }
+}
+
+impl FlowRates {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            window_secs: window.as_secs().max(1),
+            counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count a message, received by the broker when `dst` is None
+    pub fn record(&self, src: &str, dst: Option<&str>, msg_type: &str) {
+        let second = self.started.elapsed().as_secs();
+        let flow = Flow {
+            src: src.to_string(),
+            dst: dst.map(str::to_string),
+            msg_type: msg_type.to_string(),
+        };
+        let mut counts = self.counts.lock().unwrap();
+        let buckets = counts.entry(flow).or_default();
+        match buckets.back_mut() {
+            Some((bucket, count)) if *bucket == second => *count += 1,
+            _ => buckets.push_back((second, 1)),
+        }
+        while buckets
+            .front()
+            .is_some_and(|(bucket, _)| bucket + self.window_secs <= second)
+        {
+            buckets.pop_front();
+        }
+    }
+
+    /// Messages per second of each flow seen within the window
+    pub fn rates(&self) -> Vec<FlowRate> {
+        let elapsed = self.started.elapsed();
+        let second = elapsed.as_secs();
+        // the window is shorter until it has been running that long
+        let span = elapsed.as_secs_f64().clamp(1.0, self.window_secs as f64);
+        let mut counts = self.counts.lock().unwrap();
+        counts.retain(|_, buckets| {
+            buckets.retain(|(bucket, _)| bucket + self.window_secs > second);
+            !buckets.is_empty()
+        });
+        let mut rates: Vec<FlowRate> = counts
+            .iter()
+            .map(|(flow, buckets)| FlowRate {
+                src: flow.src.clone(),
+                dst: flow.dst.clone(),
+                msg_type: flow.msg_type.clone(),
+                rate: buckets.iter().map(|(_, count)| *count).sum::<u64>() as f64 / span,
+            })
+            .collect();
+        rates.sort_by(|a, b| (&a.src, &a.dst, &a.msg_type).cmp(&(&b.src, &b.dst, &b.msg_type)));
+        rates
+    }
+}
+
+/// How many values of a msg_type are kept per source, and for how long
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// values kept per (src, msg_type), 0 disables the cache for the type
+    pub depth: usize,
+    /// older values are not replayed, None keeps them until replaced
+    pub ttl: Option<Duration>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            depth: 1,
+            ttl: None,
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Parse "msg_type=depth" or "msg_type=depth:ttl_secs", e.g. "LogEvent=20:300"
+    pub fn parse_rule(rule: &str) -> anyhow::Result<(String, CachePolicy)> {
+        let (msg_type, policy) = rule
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Cache rule {} is not msg_type=depth[:ttl]", rule))?;
+        let (depth, ttl) = match policy.split_once(':') {
+            Some((depth, ttl)) => (depth, Some(Duration::from_secs_f64(ttl.parse()?))),
+            None => (policy, None),
+        };
+        Ok((
+            msg_type.to_string(),
+            CachePolicy {
+                depth: depth.parse()?,
+                ttl,
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CacheConfig {
+    /// policy of the types not listed
+    pub default: CachePolicy,
+    pub types: HashMap<String, CachePolicy>,
+}
+
+impl CacheConfig {
+    pub fn policy(&self, msg_type: &str) -> CachePolicy {
+        self.types.get(msg_type).copied().unwrap_or(self.default)
+    }
+}
+
+// messages of one (src, msg_type) with the time they were stored, oldest first
+type CachedValues = VecDeque<(Instant, UdpMessage)>;
+
+/// Latest messages per (src, msg_type), replayed to endpoints when they subscribe
+pub struct LastValueCache {
+    config: CacheConfig,
+    values: Mutex<HashMap<(String, String), CachedValues>>,
+}
+
+impl LastValueCache {
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Keep an event, requests and replies are not cached
+    pub fn store(&self, udp_message: &UdpMessage) {
+        let (Some(src), Some(msg_type)) = (&udp_message.src, &udp_message.msg_type) else {
+            return;
+        };
+        if udp_message.correlation_id.is_some() {
+            return;
+        }
+        let policy = self.config.policy(msg_type);
+        if policy.depth == 0 {
+            return;
+        }
+        let mut values = self.values.lock().unwrap();
+        let kept = values.entry((src.clone(), msg_type.clone())).or_default();
+        kept.push_back((Instant::now(), udp_message.clone()));
+        while kept.len() > policy.depth {
+            kept.pop_front();
+        }
+    }
+
+    /// Values matching any of the patterns as seen by the broker, oldest first.
+    /// Expired values are dropped.
+    pub fn matching(&self, patterns: &[String]) -> Vec<UdpMessage> {
+        let patterns: Vec<KeyPattern> = patterns.iter().map(|p| KeyPattern::parse(p)).collect();
+        let mut values = self.values.lock().unwrap();
+        values.retain(|(_, msg_type), kept| {
+            if let Some(ttl) = self.config.policy(msg_type).ttl {
+                kept.retain(|(stored, _)| stored.elapsed() < ttl);
+            }
+            !kept.is_empty()
+        });
+        let mut matching: Vec<&(Instant, UdpMessage)> = values
+            .iter()
+            .filter(|((src, msg_type), _)| {
+                patterns.iter().any(|p| p.matches(src, "broker", msg_type))
+            })
+            .flat_map(|(_, kept)| kept.iter())
+            .collect();
+        matching.sort_by_key(|(stored, _)| *stored);
+        matching.into_iter().map(|(_, m)| m.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values
+            .lock()
+            .unwrap()
+            .values()
+            .map(VecDeque::len)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Send the cached values to each endpoint that joins or adds subscriptions
+    pub fn start_replay(self: Arc<Self>, scout: Arc<Scout>, sender: TxSender) -> JoinHandle<()> {
+        let mut events = scout.events();
+        tokio::spawn(async move {
+            loop {
+                let (endpoint, patterns) = match events.recv().await {
+                    Ok(EndpointEvent::EndpointJoined(endpoint)) => {
+                        let patterns = endpoint.subscribe.clone();
+                        (endpoint, patterns)
+                    }
+                    Ok(EndpointEvent::EndpointChanged { old, new }) => {
+                        let patterns = new
+                            .subscribe
+                            .iter()
+                            .filter(|p| !old.subscribe.contains(p))
+                            .cloned()
+                            .collect();
+                        (new, patterns)
+                    }
+                    Ok(EndpointEvent::EndpointLeft(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                        warn!("Cache replay missed {} endpoint events", missed);
+                        continue;
+                    }
+                    Err(_) => break,
+                };
+                if patterns.is_empty() {
+                    continue;
+                }
+                let replay = self.matching(&patterns);
+                if !replay.is_empty() {
+                    info!(
+                        "Replaying {} cached values to {}",
+                        replay.len(),
+                        endpoint.name
+                    );
+                }
+                for udp_message in replay {
+                    let udp_message = UdpMessage {
+                        dst: Some(endpoint.name.clone()),
+                        seq: None,
+                        ..udp_message
+                    };
+                    if let Err(e) = sender.send(udp_message).await {
+                        warn!("Cache replay to {} stopped: {}", endpoint.name, e);
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Forwards every message it receives to the endpoints subscribed to it
+pub struct Forwarder {
+    sender: TxSender,
+    scout: Arc<Scout>,
+    // index compiled from the scout subscriptions, with the version it was built from
+    index: RwLock<(u64, Arc<SubscriptionIndex>)>,
+    rates: Arc<FlowRates>,
+    counters: Arc<MessageCounters>,
+    cache: Option<Arc<LastValueCache>>,
+}
+
+impl Forwarder {
+    pub fn new(sender: TxSender, scout: Arc<Scout>) -> Self {
+        Forwarder {
+            sender,
+            scout,
+            index: RwLock::new((u64::MAX, Arc::new(SubscriptionIndex::new()))),
+            rates: Arc::new(FlowRates::default()),
+            counters: Arc::new(MessageCounters::new()),
+            cache: None,
+        }
+    }
+
+    /// Keep the messages forwarded in `cache`
+    pub fn with_cache(mut self, cache: Arc<LastValueCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Rates of the messages received and forwarded
+    pub fn rates(&self) -> Arc<FlowRates> {
+        self.rates.clone()
+    }
+
+    /// Messages received and forwarded since the start
+    pub fn counters(&self) -> Arc<MessageCounters> {
+        self.counters.clone()
+    }
+
+    /// Forward an event of the broker itself to its subscribers
+    pub async fn publish<T>(&self, codec: Codec, event: &T) -> anyhow::Result<()>
+    where
+        T: TypedMessage + Msg,
+    {
+        self.handle(&UdpMessage {
+            src: Some("broker".to_string()),
+            dst: Some("broker".to_string()),
+            msg_type: Some(T::MSG_TYPE.to_string()),
+            payload: Some(codec.encode(event)?),
+            codec: Some(codec),
+            ..Default::default()
+        })
+        .await
+    }
+
+    fn index(&self) -> Arc<SubscriptionIndex> {
+        let version = self.scout.subscriptions_version();
+        {
+            let index = self.index.read().unwrap();
+            if index.0 == version {
+                return index.1.clone();
+            }
+        }
+        let rebuilt = Arc::new(SubscriptionIndex::from_subscriptions(
+            &self.scout.subscriptions,
+        ));
+        debug!("Rebuilt subscription index with {} patterns", rebuilt.len());
+        *self.index.write().unwrap() = (version, rebuilt.clone());
+        rebuilt
+    }
+}
+
+#[async_trait::async_trait]
+impl UdpMessageHandler for Forwarder {
+    async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
+        debug!(
+            "Forwarder received message of type {:?} from {:?}",
+            udp_message.msg_type, udp_message.src
+        );
+
+        if let Some(msg_type) = &udp_message.msg_type {
+            let src = udp_message.src.as_deref().unwrap_or("unknown");
+            self.rates.record(src, None, msg_type);
+            self.counters.received(src, msg_type);
+            if let Some(cache) = &self.cache {
+                cache.store(udp_message);
+            }
+            let destinations = self.index().destinations(
+                src,
+                udp_message.dst.as_deref().unwrap_or("broker"),
+                msg_type,
+            );
+            for destination in destinations {
+                self.rates.record(src, Some(&destination), msg_type);
+                self.counters.forwarded(&destination, msg_type);
+                debug!(
+                    "Forwarded message of type {:?} from {:?} to {:?}",
+                    udp_message.msg_type, udp_message.src, destination
+                );
+                self.sender
+                    .send(UdpMessage {
+                        dst: Some(destination),
+                        // forwarding is best effort, the sender got its ack from the broker
+                        seq: None,
+                        ..udp_message.clone()
+                    })
+                    .await?;
+            }
+        } else {
+            warn!(
+                "Forwarder received message with no type from {:?}",
+                udp_message.src
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+pub use crate::msgs::Codec;
+use crate::msgs::{Msg, UdpMessage};
+
+impl Codec {
+    /// Codec of the payload of a message, from the envelope or guessed for
+    /// senders that don't fill in the codec field.
+    pub fn of(udp_message: &UdpMessage) -> Codec {
+        match udp_message.codec {
+            Some(codec) => codec,
+            None => Codec::detect(udp_message.payload.as_deref().unwrap_or_default()),
+        }
+    }
+
+    /// Guess the codec from the first byte of the payload.
+    /// JSON payloads are objects, CBOR payloads are maps ( major type 5 ).
+    pub fn detect(payload: &[u8]) -> Codec {
+        match payload.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{') => Codec::Json,
+            Some(b) if (0xA0..=0xBF).contains(b) => Codec::Cbor,
+            _ => Codec::Json,
+        }
+    }
+
+    pub fn encode<T: Msg>(&self, msg: &T) -> Result<Vec<u8>> {
+        match self {
+            Codec::Json => msg.json_serialize(),
+            Codec::Cbor => msg.cbor_serialize(),
+        }
+    }
+
+    pub fn decode<T: Msg>(&self, payload: &[u8]) -> Result<T> {
+        let v = payload.to_vec();
+        match self {
+            Codec::Json => T::json_deserialize(&v),
+            Codec::Cbor => T::cbor_deserialize(&v),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use log::info;
+use tokio::time::Instant;
+
+use crate::msgs::{Msg, UdpMessage};
+
+// --- CONSTANTS ---
+/// Largest datagram sent by default, fits an ethernet frame without IP fragmentation
+pub const DEFAULT_MTU: usize = 1400;
+pub const REASSEMBLY_TIMEOUT: Duration = Duration::from_secs(2);
+/// Bytes of incomplete messages kept per source
+pub const REASSEMBLY_MAX_BYTES: usize = 4 * 1024 * 1024;
+/// Bytes of incomplete messages kept over all sources
+pub const REASSEMBLY_MAX_TOTAL_BYTES: usize = 16 * 1024 * 1024;
+/// Smallest payload chunk of a fragment, bounds the `frag_count` a receiver accepts
+pub const MIN_CHUNK: usize = 64;
+// room for the CBOR key and length header of the payload bytes
+const PAYLOAD_HEADER: usize = 8;
+
+/// Split a message whose CBOR envelope exceeds `mtu` into fragments that each fit.
+/// The fragments repeat the envelope and carry `frag_id`, `frag_index` and `frag_count`.
+pub fn fragment(message: &UdpMessage, mtu: usize, frag_id: u32) -> Result<Vec<UdpMessage>> {
+    let payload = message.payload.clone().unwrap_or_default();
+    let header = UdpMessage {
+        payload: None,
+        frag_id: Some(u32::MAX),
+        frag_index: Some(u32::MAX),
+        frag_count: Some(u32::MAX),
+        ..message.clone()
+    }
+    .cbor_serialize()?;
+    let chunk_size = mtu.saturating_sub(header.len() + PAYLOAD_HEADER);
+    if chunk_size < MIN_CHUNK {
+        return Err(anyhow::anyhow!("MTU {} too small for envelope", mtu));
+    }
+    if payload.is_empty() {
+        return Err(anyhow::anyhow!("Envelope exceeds MTU {} without payload", mtu));
+    }
+    let frag_count = payload.len().div_ceil(chunk_size) as u32;
+    Ok(payload
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(index, chunk)| UdpMessage {
+            payload: Some(chunk.to_vec()),
+            frag_id: Some(frag_id),
+            frag_index: Some(index as u32),
+            frag_count: Some(frag_count),
+            ..message.clone()
+        })
+        .collect())
+}
+
+/// Counters of the reassembly, partial messages dropped are lost messages
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FragmentStats {
+    pub reassembled: u64,
+    pub dropped_timeout: u64,
+    pub dropped_memory: u64,
+    pub dropped_invalid: u64,
+}
+
+struct Partial {
+    chunks: Vec<Option<Vec<u8>>>,
+    received: usize,
+    bytes: usize,
+    first_seen: Instant,
+    envelope: UdpMessage,
+}
+
+pub struct Reassembler {
+    timeout: Duration,
+    max_bytes: usize,
+    max_total_bytes: usize,
+    total_bytes: usize,
+    partials: HashMap<(String, u32), Partial>,
+    bytes_per_source: HashMap<String, usize>,
+    stats: FragmentStats,
+}
+
+impl Default for Reassembler {
+    fn default() -> Self {
+        Self::new(REASSEMBLY_TIMEOUT, REASSEMBLY_MAX_BYTES)
+    }
+}
+
+impl Reassembler {
+    pub fn new(timeout: Duration, max_bytes: usize) -> Self {
+        Self {
+            timeout,
+            max_bytes,
+            max_total_bytes: REASSEMBLY_MAX_TOTAL_BYTES,
+            total_bytes: 0,
+            partials: HashMap::new(),
+            bytes_per_source: HashMap::new(),
+            stats: FragmentStats::default(),
+        }
+    }
+
+    /// Limit the bytes kept over all sources, so many sources can't add up to more
+    pub fn with_total_limit(mut self, max_total_bytes: usize) -> Self {
+        self.max_total_bytes = max_total_bytes;
+        self
+    }
+
+    /// Returns the complete message once its last missing fragment arrives
+    pub fn push(&mut self, fragment: UdpMessage) -> Option<UdpMessage> {
+        let (Some(frag_id), Some(index), Some(count)) =
+            (fragment.frag_id, fragment.frag_index, fragment.frag_count)
+        else {
+            return Some(fragment);
+        };
+        let src = fragment.src.clone().unwrap_or_else(|| "unknown".to_string());
+        let key = (src.clone(), frag_id);
+        if count == 0 || index >= count {
+            self.stats.dropped_invalid += 1;
+            return None;
+        }
+        // a message of more fragments could never fit, refuse it before allocating its slots
+        if count as usize > self.max_bytes / MIN_CHUNK {
+            info!("Reassembly of {} fragments from {} exceeds the memory limit", count, src);
+            self.stats.dropped_memory += 1;
+            return None;
+        }
+        let chunk = fragment.payload.clone().unwrap_or_default();
+        let used = self.bytes_per_source.get(&src).copied().unwrap_or(0);
+        if used + chunk.len() > self.max_bytes
+            || self.total_bytes + chunk.len() > self.max_total_bytes
+        {
+            info!("Reassembly memory limit reached for {}", src);
+            self.stats.dropped_memory += 1;
+            self.remove(&key);
+            return None;
+        }
+
+        let partial = self.partials.entry(key.clone()).or_insert_with(|| Partial {
+            chunks: vec![None; count as usize],
+            received: 0,
+            bytes: 0,
+            first_seen: Instant::now(),
+            envelope: UdpMessage {
+                payload: None,
+                frag_id: None,
+                frag_index: None,
+                frag_count: None,
+                ..fragment.clone()
+            },
+        });
+        if partial.chunks.len() != count as usize {
+            self.stats.dropped_invalid += 1;
+            self.remove(&key);
+            return None;
+        }
+        let slot = &mut partial.chunks[index as usize];
+        if slot.is_some() {
+            return None; // duplicate fragment
+        }
+        partial.received += 1;
+        partial.bytes += chunk.len();
+        *slot = Some(chunk.clone());
+        *self.bytes_per_source.entry(src).or_insert(0) += chunk.len();
+        self.total_bytes += chunk.len();
+
+        if partial.received < partial.chunks.len() {
+            return None;
+        }
+        let partial = self.remove(&key)?;
+        self.stats.reassembled += 1;
+        Some(UdpMessage {
+            payload: Some(partial.chunks.into_iter().flatten().flatten().collect()),
+            ..partial.envelope
+        })
+    }
+
+    /// Drop the partial messages older than the timeout
+    pub fn expire(&mut self) {
+        let expired: Vec<(String, u32)> = self
+            .partials
+            .iter()
+            .filter(|(_, partial)| partial.first_seen.elapsed() >= self.timeout)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            info!("Reassembly of {} from {} timed out", key.1, key.0);
+            self.stats.dropped_timeout += 1;
+            self.remove(&key);
+        }
+    }
+
+    pub fn stats(&self) -> FragmentStats {
+        self.stats
+    }
+
+    fn remove(&mut self, key: &(String, u32)) -> Option<Partial> {
+        let partial = self.partials.remove(key)?;
+        self.total_bytes = self.total_bytes.saturating_sub(partial.bytes);
+        if let Some(used) = self.bytes_per_source.get_mut(&key.0) {
+            *used = used.saturating_sub(partial.bytes);
+            if *used == 0 {
+                self.bytes_per_source.remove(&key.0);
+            }
+        }
+        Some(partial)
+    }
+}
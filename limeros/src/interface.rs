@@ -0,0 +1,194 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+use anyhow::Result;
+use socket2::{Domain, Protocol, Socket, Type};
+
+/*
+   Network interfaces used for discovery and unicast traffic. Interfaces are selected
+   by name ( "eth0", "wlan0" ) or by address ( "192.168.1.10", "fe80::1" ). Without
+   selection the OS picks the interface, as before.
+*/
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetInterface {
+    pub name: String,
+    pub addr: IpAddr,
+    pub netmask: IpAddr,
+    pub index: u32,
+}
+
+impl NetInterface {
+    /// True when `ip` is in the subnet of this interface
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, self.netmask, ip) {
+            (IpAddr::V4(addr), IpAddr::V4(mask), IpAddr::V4(ip)) => {
+                u32::from(addr) & u32::from(mask) == u32::from(*ip) & u32::from(mask)
+            }
+            (IpAddr::V6(addr), IpAddr::V6(mask), IpAddr::V6(ip)) => {
+                u128::from(addr) & u128::from(mask) == u128::from(*ip) & u128::from(mask)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// All addresses of the interfaces of this host
+pub fn list() -> Result<Vec<NetInterface>> {
+    Ok(if_addrs::get_if_addrs()?
+        .into_iter()
+        .map(|interface| {
+            let (addr, netmask) = match &interface.addr {
+                if_addrs::IfAddr::V4(v4) => (IpAddr::V4(v4.ip), IpAddr::V4(v4.netmask)),
+                if_addrs::IfAddr::V6(v6) => (IpAddr::V6(v6.ip), IpAddr::V6(v6.netmask)),
+            };
+            NetInterface {
+                name: interface.name,
+                addr,
+                netmask,
+                index: interface.index.unwrap_or(0),
+            }
+        })
+        .collect())
+}
+
+/// The `available` interfaces matching the selectors, only the addresses of the
+/// family of the multicast group are kept. An interface is selected once, with
+/// its first matching address, multicast membership and sockets are per interface.
+pub fn select(
+    available: &[NetInterface],
+    selectors: &[String],
+    ipv6: bool,
+) -> Result<Vec<NetInterface>> {
+    let mut selected: Vec<NetInterface> = vec![];
+    for selector in selectors {
+        let by_addr = selector.parse::<IpAddr>().ok();
+        let matching: Vec<&NetInterface> = available
+            .iter()
+            .filter(|i| i.addr.is_ipv6() == ipv6)
+            .filter(|i| match by_addr {
+                Some(addr) => i.addr == addr,
+                None => &i.name == selector,
+            })
+            .collect();
+        if matching.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No {} interface {}",
+                if ipv6 { "IPv6" } else { "IPv4" },
+                selector
+            ));
+        }
+        for interface in matching {
+            if !selected.iter().any(|s| same_interface(s, interface)) {
+                selected.push(interface.clone());
+            }
+        }
+    }
+    Ok(selected)
+}
+
+// without an index the OS didn't tell, then only the same address is the same interface
+fn same_interface(a: &NetInterface, b: &NetInterface) -> bool {
+    if a.index == 0 || b.index == 0 {
+        a == b
+    } else {
+        a.index == b.index
+    }
+}
+
+pub fn resolve(selectors: &[String], ipv6: bool) -> Result<Vec<NetInterface>> {
+    if selectors.is_empty() {
+        return Ok(vec![]);
+    }
+    select(&list()?, selectors, ipv6)
+}
+
+/// Interface through which `peer` was seen, by the scope of an IPv6 link-local
+/// address or else by subnet.
+pub fn interface_of<'a>(
+    interfaces: &'a [NetInterface],
+    peer: &SocketAddr,
+) -> Option<&'a NetInterface> {
+    if let SocketAddr::V6(v6) = peer {
+        if v6.scope_id() != 0 {
+            return interfaces.iter().find(|i| i.index == v6.scope_id());
+        }
+    }
+    interfaces.iter().find(|i| i.contains(&peer.ip()))
+}
+
+/// Unicast socket on a random port, with multicast going out through `interface`
+pub fn bind_unicast(interface: Option<&NetInterface>, ipv6: bool) -> Result<std::net::UdpSocket> {
+    let domain = if ipv6 { Domain::IPV6 } else { Domain::IPV4 };
+    let socket = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+    let bind_addr = match interface {
+        Some(interface) => match interface.addr {
+            IpAddr::V4(ip) => {
+                socket.set_multicast_if_v4(&ip)?;
+                SocketAddr::new(IpAddr::V4(ip), 0)
+            }
+            IpAddr::V6(ip) => {
+                socket.set_multicast_if_v6(interface.index)?;
+                SocketAddr::V6(SocketAddrV6::new(ip, 0, 0, interface.index))
+            }
+        },
+        None if ipv6 => SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0),
+        None => SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0),
+    };
+    if ipv6 {
+        socket.set_only_v6(true)?;
+    }
+    socket.bind(&bind_addr.into())?;
+    socket.set_nonblocking(true)?;
+    Ok(socket.into())
+}
+
+/// Socket bound to the port of the multicast `group` and joined on each interface,
+/// without interfaces the OS chooses.
+pub fn bind_multicast(
+    group: SocketAddr,
+    interfaces: &[NetInterface],
+) -> Result<std::net::UdpSocket> {
+    let ipv6 = group.is_ipv6();
+    let domain = if ipv6 { Domain::IPV6 } else { Domain::IPV4 };
+    let socket2 = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+    socket2.set_reuse_address(true)?;
+    #[cfg(not(windows))]
+    socket2.set_reuse_port(true)?; // Unix specific, helps with load balancing/binding
+
+    // Bind to 0.0.0.0:5000 or [::]:5000 (or multicast IP directly on Windows sometimes)
+    let any = if ipv6 {
+        socket2.set_only_v6(true)?;
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    } else {
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    };
+    socket2.bind(&SocketAddr::new(any, group.port()).into())?;
+
+    // Join the group once per interface, a second join on the same one fails
+    let mut joined: Vec<&NetInterface> = vec![];
+    for interface in interfaces.iter().filter(|i| i.addr.is_ipv6() == ipv6) {
+        if !joined.iter().any(|j| same_interface(j, interface)) {
+            joined.push(interface);
+        }
+    }
+    match group.ip() {
+        IpAddr::V4(group) if interfaces.is_empty() => {
+            socket2.join_multicast_v4(&group, &Ipv4Addr::UNSPECIFIED)?
+        }
+        IpAddr::V6(group) if interfaces.is_empty() => socket2.join_multicast_v6(&group, 0)?,
+        IpAddr::V4(group) => {
+            for interface in joined {
+                if let IpAddr::V4(addr) = interface.addr {
+                    socket2.join_multicast_v4(&group, &addr)?;
+                }
+            }
+        }
+        IpAddr::V6(group) => {
+            for interface in joined {
+                socket2.join_multicast_v6(&group, interface.index)?;
+            }
+        }
+    }
+    socket2.set_nonblocking(true)?;
+    Ok(socket2.into())
+}
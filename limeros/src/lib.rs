@@ -1,26 +1,85 @@
 use anyhow::Result;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use log::{debug, error, info};
 use serde::Serialize;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration};
-use tokio::net::UdpSocket;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, oneshot, Mutex};
 use tokio::task::JoinHandle;
 
+pub mod broker;
+pub mod codec;
 pub mod eventbus;
+pub mod fragment;
+pub mod interface;
 pub mod logger;
+pub mod metrics;
 pub mod msgs;
+pub mod pattern;
+pub mod recording;
+pub mod reliable;
+pub mod rpc;
+pub mod schema;
+pub mod security;
+pub mod topology;
+pub mod transport;
+pub mod txqueue;
 use msgs::TypedMessage;
+use fragment::{FragmentStats, Reassembler};
+use reliable::ReliableState;
+use rpc::{PendingRequest, RpcError, ServiceWrapper};
+use security::Security;
+use transport::{Network, Transport, UdpNetwork};
+use txqueue::{Priority, RateLimit, TxConfig, TxReceiver, TxSender, TxStats};
 
 pub use crate::msgs::{Alive, Msg, UdpMessage};
+pub use codec::Codec;
 
 pub mod scout;
 pub use scout::Scout;
 pub use scout::Endpoint;
+pub use scout::{EndpointEvent, ScoutConfig};
+
+/// Settings of a node, the defaults match `UdpNode::new`
+pub struct NodeConfig {
+    pub scout: ScoutConfig,
+    pub security: Option<Security>,
+    /// where sockets are opened, `MemoryNetwork` in tests
+    pub network: Arc<dyn Network>,
+    /// priorities, coalescing and rate limits of the messages sent
+    pub tx: TxConfig,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            scout: ScoutConfig::default(),
+            security: None,
+            network: Arc::new(UdpNetwork),
+            tx: TxConfig::default(),
+        }
+    }
+}
 
 
+/// Counters of a node, see `UdpNode::stats`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeStats {
+    /// datagrams received that are not a UdpMessage
+    pub decode_errors: u64,
+    /// payloads that failed to decode, were rejected by their typed handler or found its queue full
+    pub handler_errors: u64,
+    /// messages dropped because their destination was never discovered, per destination
+    pub unknown_endpoint_drops: Vec<(String, u64)>,
+    /// messages waiting for their destination to be discovered
+    pub undelivered: usize,
+    /// messages waiting in the transmit queue
+    pub tx_queue: usize,
+    pub tx: TxStats,
+}
+
 #[derive(Debug, Clone)]
 pub struct TypedUdpMessage<T> where T: TypedMessage {
     pub src: Option<String>,
@@ -34,19 +93,23 @@ impl<T> TypedUdpMessage<T> where T: TypedMessage+Msg {
         if T::MSG_TYPE != udp_message.msg_type.as_deref().unwrap_or("") {
             return Err(anyhow::anyhow!("Message type mismatch"));
         }
+        let payload = udp_message
+            .payload
+            .as_ref()
+            .and_then(|p| Codec::of(&udp_message).decode(p).ok());
         Ok(
         TypedUdpMessage {
             src: udp_message.src,
             dst: udp_message.dst,
             msg_type: udp_message.msg_type,
-            payload: udp_message.payload.map(|p| T::json_deserialize(&p).ok()).flatten(),
+            payload,
         })  
     }
 }
 
 #[async_trait::async_trait]
 pub trait MessageHandler: Send + Sync {
-    async fn handle(&self, source: &str, payload: &[u8]) -> anyhow::Result<()>;
+    async fn handle(&self, source: &str, codec: Codec, payload: &[u8]) -> anyhow::Result<()>;
 }
 
 pub struct HandlerWrapper<T, F> {
@@ -67,6 +130,13 @@ impl<T, F> HandlerWrapper<T, F> {
 pub trait UdpMessageHandler: Send + Sync {
     async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()>;
 }
+
+#[async_trait::async_trait]
+impl<H: UdpMessageHandler + ?Sized> UdpMessageHandler for Arc<H> {
+    async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
+        self.as_ref().handle(udp_message).await
+    }
+}
 /* 
 pub struct UdpHandlerWrapper<T, F> {
     callback: F,
@@ -102,23 +172,88 @@ where
     F: Fn(String, T) -> Fut + Send + Sync + 'static,
     Fut: std::future::Future<Output = ()> + Send,
 {
-    async fn handle(&self, source: &str, payload: &[u8]) -> anyhow::Result<()> {
-        let msg: T = T::json_deserialize(&payload.to_vec())?;
+    async fn handle(&self, source: &str, codec: Codec, payload: &[u8]) -> anyhow::Result<()> {
+        let msg: T = codec.decode(payload)?;
         (self.callback)(source.to_string(), msg).await;
         Ok(())
     }
 }
 
+/// Payloads waiting for a typed handler, more are dropped and counted as handler errors
+pub const HANDLER_QUEUE_CAPACITY: usize = 64;
+
+/// Payloads for one typed handler, a single task runs them in the order they arrived
+struct TypedSubscription {
+    msg_type: String,
+    tx: mpsc::Sender<(String, Codec, Vec<u8>)>,
+    handler_errors: Arc<DashMap<String, u64>>,
+}
+
+impl TypedSubscription {
+    fn start(
+        msg_type: &str,
+        handler: Arc<dyn MessageHandler>,
+        handler_errors: Arc<DashMap<String, u64>>,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::channel::<(String, Codec, Vec<u8>)>(HANDLER_QUEUE_CAPACITY);
+        let errors = handler_errors.clone();
+        let task_msg_type = msg_type.to_string();
+        // ends when the subscription is removed or replaced and the queue is drained
+        tokio::spawn(async move {
+            while let Some((source, codec, payload)) = rx.recv().await {
+                if let Err(e) = handler.handle(&source, codec, &payload).await {
+                    error!("Handler error for {} from {}: {}", task_msg_type, source, e);
+                    *errors.entry(task_msg_type.clone()).or_insert(0) += 1;
+                }
+            }
+        });
+        Self {
+            msg_type: msg_type.to_string(),
+            tx,
+            handler_errors,
+        }
+    }
+
+    /// queue the payload, a slow handler loses the newest instead of growing the queue
+    fn push(&self, source: String, codec: Codec, payload: Vec<u8>) {
+        if let Err(mpsc::error::TrySendError::Full((source, _, _))) = self.tx.try_send((source, codec, payload)) {
+            debug!("Handler queue of {} full, dropped payload from {}", self.msg_type, source);
+            *self.handler_errors.entry(self.msg_type.clone()).or_insert(0) += 1;
+        }
+    }
+}
+
+/// Unicast socket on one interface, without interface the OS routes
+struct UnicastSocket {
+    interface: Option<String>,
+    socket: Arc<dyn Transport>,
+}
+
 pub struct UdpNode {
     multicast_addr: SocketAddr,
     multicast_scout: Arc<Scout>,
-    unicast_socket: Arc<UdpSocket>,
+    unicast_sockets: Vec<UnicastSocket>,
     my_id: Arc<Mutex<String>>,
     my_subscriptions: Arc<Mutex<Vec<String>>>,
-    handlers: Arc<DashMap<String, Box<dyn MessageHandler>>>,
-    /// Maps Peer ID -> (Data Address, Last Seen)
-    tx_queue_sender: mpsc::Sender<UdpMessage>,
-    tx_queue_receiver: Arc<Mutex<mpsc::Receiver<UdpMessage>>>,
+    // msg_types sent with send_event, advertised in Alive.publish
+    published: DashSet<String>,
+    codec: std::sync::Mutex<Codec>,
+    handlers: Arc<DashMap<String, TypedSubscription>>,
+    handler_errors: Arc<DashMap<String, u64>>,
+    services: Arc<DashMap<String, Arc<dyn UdpMessageHandler>>>,
+    pending_requests: Arc<DashMap<u32, PendingRequest>>,
+    next_correlation_id: AtomicU32,
+    reliable: ReliableState,
+    mtu: AtomicUsize,
+    next_frag_id: AtomicU32,
+    reassembler: std::sync::Mutex<Reassembler>,
+    security: Option<Arc<Security>>,
+    // probability to drop an outgoing packet, as f64 bits, to simulate lossy links
+    tx_loss: AtomicU64,
+    // datagrams received that are not a UdpMessage
+    decode_errors: AtomicU64,
+    tx_queue_sender: TxSender,
+    tx_queue_receiver: Arc<Mutex<TxReceiver>>,
     generic_handlers: Arc<Mutex<Vec<Box<dyn UdpMessageHandler>>>>,
     generic_senders : Arc<Mutex<Vec<mpsc::Sender<UdpMessage>>>>,
     tasks: Mutex<Vec<JoinHandle<()>>>,
@@ -126,22 +261,84 @@ pub struct UdpNode {
 
 impl UdpNode {
     pub async fn new(id: &str, multicast_addr: &str) -> Result<Arc<Self>> {
+        UdpNode::with_config(id, multicast_addr, NodeConfig::default()).await
+    }
+
+    /// With security all traffic is encrypted with the network key, and packets
+    /// that are not sealed with it are dropped.
+    pub async fn with_security(
+        id: &str,
+        multicast_addr: &str,
+        security: Option<Security>,
+    ) -> Result<Arc<Self>> {
+        let config = NodeConfig {
+            security,
+            ..Default::default()
+        };
+        UdpNode::with_config(id, multicast_addr, config).await
+    }
+
+    pub async fn with_config(
+        id: &str,
+        multicast_addr: &str,
+        config: NodeConfig,
+    ) -> Result<Arc<Self>> {
         let multicast_addr = multicast_addr
             .parse::<SocketAddr>()
             .map_err(|e| anyhow::anyhow!("Invalid multicast address: {}", e))?;
-        let multicast_scout = Scout::new(multicast_addr.to_string()).await?;
-        let unicast_socket = Arc::new(UdpSocket::bind("0.0.0.0:0").await?); // give random port
-        info!("Unicast socket bound to {}", unicast_socket.local_addr()?);
-        let (tx_queue_sender, tx_queue_receiver) = mpsc::channel::<UdpMessage>(100);
+        let security = config.security.map(Arc::new);
+        let network = config.network;
+        let multicast_scout = Scout::with_config(
+            multicast_addr.to_string(),
+            config.scout,
+            security.clone(),
+            network.clone(),
+        )
+        .await?;
+        // one socket per interface so Alive goes out on each, and replies come back the same way
+        let interfaces: Vec<Option<&interface::NetInterface>> =
+            if multicast_scout.interfaces().is_empty() {
+                vec![None]
+            } else {
+                multicast_scout.interfaces().iter().map(Some).collect()
+            };
+        let mut unicast_sockets = vec![];
+        for net_interface in interfaces {
+            // give random port
+            let socket = network.bind_unicast(net_interface, multicast_addr.is_ipv6())?;
+            info!(
+                "Unicast socket bound to {} on {:?}",
+                socket.local_addr()?,
+                net_interface.map(|i| i.name.clone())
+            );
+            unicast_sockets.push(UnicastSocket {
+                interface: net_interface.map(|i| i.name.clone()),
+                socket,
+            });
+        }
+        let (tx_queue_sender, tx_queue_receiver) = txqueue::tx_queue(config.tx);
 
         let node = Arc::new(Self {
             multicast_addr,
             multicast_scout,
-            unicast_socket: unicast_socket.clone(),
+            unicast_sockets,
             my_id: Arc::new(Mutex::new(id.to_string())),
             my_subscriptions: Arc::new(Mutex::new(vec![])),
+            published: DashSet::new(),
+            codec: std::sync::Mutex::new(Codec::Json),
 
             handlers: Arc::new(DashMap::new()),
+            handler_errors: Arc::new(DashMap::new()),
+            services: Arc::new(DashMap::new()),
+            pending_requests: Arc::new(DashMap::new()),
+            next_correlation_id: AtomicU32::new(rand::random()),
+            reliable: ReliableState::new(),
+            mtu: AtomicUsize::new(fragment::DEFAULT_MTU),
+            next_frag_id: AtomicU32::new(0),
+            reassembler: std::sync::Mutex::new(Reassembler::default()),
+            security,
+            tx_loss: AtomicU64::new(0f64.to_bits()),
+            decode_errors: AtomicU64::new(0),
             tx_queue_sender,
             tx_queue_receiver: Arc::new(Mutex::new(tx_queue_receiver)),
             generic_handlers: Arc::new(Mutex::new(Vec::new())),
@@ -155,91 +352,200 @@ impl UdpNode {
     }
 
     fn start_tasks(node: Arc<Self>) -> Vec<JoinHandle<()>> {
-        vec![
+        let mut tasks = vec![
             UdpNode::start_unicast_sender(node.clone()),
-            UdpNode::start_unicast_receiver(node.clone()),
             Scout::start(node.multicast_scout.clone()),
             UdpNode::start_multicast_sender(node.clone()),
-        ]
+            UdpNode::start_reliable_timer(node.clone()),
+        ];
+        for unicast in &node.unicast_sockets {
+            tasks.push(UdpNode::start_unicast_receiver(
+                node.clone(),
+                unicast.socket.clone(),
+            ));
+        }
+        tasks
     }
 
     /*
        send my Alive periodically via multicast
     */
     fn start_multicast_sender(node: Arc<Self>) -> JoinHandle<()> {
-        // To be implemented if needed
         let my_id = node.my_id.clone();
-        let my_subscriptions = node.my_subscriptions.clone();
-        let unicast_socket = node.unicast_socket.clone();
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            let heartbeat_interval = node.multicast_scout.config().heartbeat_interval;
+            let mut interval = tokio::time::interval(heartbeat_interval);
             // Target address for multicast
 
             loop {
                 interval.tick().await;
 
                 // 1. Send Heartbeat
-                let mut alive = Alive::default();
+                let alive = node.alive().await;
                 let my_id = my_id.lock().await;
-                let my_subscriptions = my_subscriptions.lock().await;
-                alive.subscribe = Some(my_subscriptions.clone());
 
-                if let Ok(payload) = alive.json_serialize() {
+                let codec = node.codec();
+                if let Ok(payload) = codec.encode(&alive) {
                     let packet = UdpMessage {
                         src: Some(my_id.clone()),
                         dst: None,
                         msg_type: Some(Alive::MSG_TYPE.to_string()),
                         payload: Some(payload),
+                        codec: Some(codec),
+                        ..Default::default()
                     };
                     // We send this via our standard TX queue, aiming at the Multicast Addr
                     debug!("MC Send Alive {:?}", alive);
+                    let packet = match &node.security {
+                        Some(security) => match security.seal(packet) {
+                            Ok(packet) => packet,
+                            Err(e) => {
+                                error!("Failed to seal Alive: {}", e);
+                                continue;
+                            }
+                        },
+                        None => packet,
+                    };
                     let data = UdpMessage::cbor_serialize(&packet).unwrap();
-                    let _ = unicast_socket.send_to(&data, &node.multicast_addr).await;
+                    for unicast in &node.unicast_sockets {
+                        let _ = unicast.socket.send_to(&data, node.multicast_addr).await;
+                    }
                 }
             }
         })
     }
 
     fn start_unicast_sender(node: Arc<Self>) -> JoinHandle<()> {
-        let send_socket = node.unicast_socket.clone();
         let tx_queue_receiver = node.tx_queue_receiver.clone();
         tokio::spawn(async move {
-            while let Some(udp_message) = tx_queue_receiver.lock().await.recv().await {
-                let target = if let Some(dst_endpoint) = &udp_message.dst {
-                    if let Some(addr) = node.multicast_scout.endpoint_to_addr(dst_endpoint) {
-                        debug!(
-                            "UC Send {:?} to {:?} @ {:?}",
-                            udp_message.msg_type, dst_endpoint, addr
-                        );
-
-                        addr
-                    } else {
-                        info!("Unknown endpoint: {}", dst_endpoint);
-                        continue;
-                    }
-                } else {
-                    info!("No destination endpoint specified");
-                    continue;
-                };
-                if let Ok(data) = udp_message.cbor_serialize() {
-                    if let Err(e) = send_socket.send_to(&data, target).await {
-                        error!("Send error: {}", e);
-                    }
+            let mut tx_queue_receiver = tx_queue_receiver.lock().await;
+            loop {
+                let udp_message = tx_queue_receiver.recv().await;
+                node.transmit(udp_message).await;
+            }
+        })
+    }
+
+    /*
+       retransmit unacked reliable messages, deliver queued messages to newly discovered endpoints
+       and expire incomplete fragmented messages
+    */
+    fn start_reliable_timer(node: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(reliable::RETRANSMIT_TIMEOUT / 3);
+            loop {
+                interval.tick().await;
+                let scout = node.multicast_scout.clone();
+                let deliverable = node
+                    .reliable
+                    .take_deliverable(|dst| scout.endpoint_to_addr(dst).is_some());
+                for udp_message in deliverable.into_iter().chain(node.reliable.due_retransmits()) {
+                    node.transmit(udp_message).await;
                 }
+                node.reassembler.lock().unwrap().expire();
             }
         })
     }
 
-    fn start_unicast_receiver(node: Arc<Self>) -> JoinHandle<()> {
+    async fn transmit(&self, udp_message: UdpMessage) {
+        let Some(dst_endpoint) = udp_message.dst.clone() else {
+            info!("No destination endpoint specified");
+            return;
+        };
+        let Some(target) = self.multicast_scout.endpoint_to_addr(&dst_endpoint) else {
+            debug!("Unknown endpoint: {}, queued", dst_endpoint);
+            self.reliable.queue_undelivered(udp_message);
+            return;
+        };
+        debug!(
+            "UC Send {:?} to {:?} @ {:?}",
+            udp_message.msg_type, dst_endpoint, target
+        );
+        if udp_message.msg_type.as_deref() != Some(reliable::ACK_MSG_TYPE) {
+            self.reliable.track(&udp_message);
+        }
+        let udp_message = match &self.security {
+            Some(security) => match security.seal(udp_message) {
+                Ok(sealed) => sealed,
+                Err(e) => {
+                    error!("Failed to seal message to {}: {}", dst_endpoint, e);
+                    return;
+                }
+            },
+            None => udp_message,
+        };
+        let Ok(data) = udp_message.cbor_serialize() else {
+            error!("Failed to encode {:?} to {}", udp_message.msg_type, dst_endpoint);
+            return;
+        };
+        let mtu = self.mtu.load(Ordering::Relaxed);
+        let datagrams = if data.len() > mtu {
+            let frag_id = self.next_frag_id.fetch_add(1, Ordering::Relaxed);
+            match fragment::fragment(&udp_message, mtu, frag_id) {
+                Ok(fragments) => fragments
+                    .iter()
+                    .filter_map(|f| f.cbor_serialize().ok())
+                    .collect(),
+                Err(e) => {
+                    error!("Cannot fragment {:?}: {}", udp_message.msg_type, e);
+                    return;
+                }
+            }
+        } else {
+            vec![data]
+        };
+        let loss = f64::from_bits(self.tx_loss.load(Ordering::Relaxed));
+        for data in datagrams {
+            if loss > 0.0 && rand::random::<f64>() < loss {
+                debug!("Simulated loss of {:?} to {}", udp_message.msg_type, dst_endpoint);
+                continue;
+            }
+            if let Err(e) = self.socket_for(&dst_endpoint).send_to(&data, target).await {
+                error!("Send error: {}", e);
+            }
+        }
+    }
+
+    /*
+       socket of the interface the endpoint was seen on
+    */
+    fn socket_for(&self, endpoint: &str) -> &dyn Transport {
+        let interface = self.multicast_scout.endpoint_interface(endpoint);
+        let unicast = self
+            .unicast_sockets
+            .iter()
+            .find(|u| interface.is_some() && u.interface == interface)
+            .unwrap_or(&self.unicast_sockets[0]);
+        unicast.socket.as_ref()
+    }
+
+    fn start_unicast_receiver(node: Arc<Self>, recv_socket: Arc<dyn Transport>) -> JoinHandle<()> {
         let generic_handlers = node.generic_handlers.clone();
-        let recv_socket = node.unicast_socket.clone();
-        let handlers = node.handlers.clone();
         tokio::spawn(async move {
             let mut buf = [0u8; 65535];
             loop {
                 if let Ok((len, addr)) = recv_socket.recv_from(&mut buf).await {
                     let data: Vec<u8> = buf[..len].to_vec();
                     if let Ok(packet) = UdpMessage::cbor_deserialize(&data) {
+                        let Some(packet) = node.reassembler.lock().unwrap().push(packet) else {
+                            continue;
+                        };
+                        let packet = match &node.security {
+                            Some(security) => match security.open(packet) {
+                                Ok(packet) => packet,
+                                Err(e) => {
+                                    info!("Rejected packet from {}: {}", addr, e);
+                                    continue;
+                                }
+                            },
+                            None => packet,
+                        };
+                        if !node.accept_reliable(&packet).await {
+                            continue;
+                        }
+                        node.resolve_pending(&packet);
+                        node.dispatch_typed(&packet);
+                        node.dispatch_service(&packet);
                         let gen_handlers = generic_handlers.clone();
                         let handlers_guard = gen_handlers.lock().await;
                         for i in 0..handlers_guard.len() {
@@ -260,6 +566,7 @@ impl UdpNode {
                         }
                         
                     } else {
+                        node.decode_errors.fetch_add(1, Ordering::Relaxed);
                         info!("Failed to decode CBOR packet from {}", addr);
                     }
                 }
@@ -267,38 +574,230 @@ impl UdpNode {
         })
     }
 
-    pub async fn send_event<T>(&self, event: T)
+    /*
+       run the typed handler registered for the msg_type of the packet, if any
+    */
+    fn dispatch_typed(&self, packet: &UdpMessage) {
+        let Some(msg_type) = packet.msg_type.clone() else {
+            return;
+        };
+        let Some(subscription) = self.handlers.get(&msg_type) else {
+            return;
+        };
+        let source = packet.src.clone().unwrap_or_else(|| "unknown".to_string());
+        let payload = packet.payload.clone().unwrap_or_default();
+        subscription.push(source, Codec::of(packet), payload);
+    }
+
+    /*
+       ack reliable messages, returns false for acks and duplicates which need no further handling
+    */
+    async fn accept_reliable(&self, packet: &UdpMessage) -> bool {
+        let Some(seq) = packet.seq else {
+            return true;
+        };
+        let src = packet.src.clone().unwrap_or_else(|| "unknown".to_string());
+        if packet.msg_type.as_deref() == Some(reliable::ACK_MSG_TYPE) {
+            self.reliable.ack(&src, seq);
+            return false;
+        }
+        let ack = UdpMessage {
+            src: Some(self.my_id.lock().await.clone()),
+            dst: Some(src.clone()),
+            msg_type: Some(reliable::ACK_MSG_TYPE.to_string()),
+            payload: None,
+            seq: Some(seq),
+            ..Default::default()
+        };
+        let _ = self.tx_queue_sender.send(ack).await;
+        if !self.reliable.accept(&src, seq) {
+            debug!("Duplicate {:?} seq {} from {}", packet.msg_type, seq, src);
+            return false;
+        }
+        true
+    }
+
+    /*
+       hand a reply to the request() waiting for its correlation id
+    */
+    fn resolve_pending(&self, packet: &UdpMessage) {
+        let Some(correlation_id) = packet.correlation_id else {
+            return;
+        };
+        let is_reply = self
+            .pending_requests
+            .get(&correlation_id)
+            .map(|pending| Some(pending.msg_type) == packet.msg_type.as_deref())
+            .unwrap_or(false);
+        if is_reply {
+            if let Some((_, pending)) = self.pending_requests.remove(&correlation_id) {
+                let _ = pending.reply.send(packet.clone());
+            }
+        }
+    }
+
+    /*
+       run the service registered with serve() for the request type of the packet, if any
+    */
+    fn dispatch_service(&self, packet: &UdpMessage) {
+        let Some(msg_type) = packet.msg_type.clone() else {
+            return;
+        };
+        let Some(service) = self.services.get(&msg_type).map(|s| s.value().clone()) else {
+            return;
+        };
+        let packet = packet.clone();
+        let handler_errors = self.handler_errors.clone();
+        tokio::spawn(async move {
+            if let Err(e) = service.handle(&packet).await {
+                error!("Service error for {} from {:?}: {}", msg_type, packet.src, e);
+                *handler_errors.entry(msg_type).or_insert(0) += 1;
+            }
+        });
+    }
+
+    /// Publish `event` through the broker, `T` is advertised in Alive.publish.
+    pub async fn send_event<T>(&self, event: T) -> anyhow::Result<()>
     where
         T: TypedMessage + Msg + Serialize,
     {
+        if !self.published.contains(T::MSG_TYPE) {
+            self.published.insert(T::MSG_TYPE.to_string());
+        }
+        let codec = self.codec();
         let udp_message = UdpMessage {
             src: Some(self.my_id.lock().await.clone()),
             dst: Some("broker".to_string()),
             msg_type: Some(T::MSG_TYPE.to_string()),
-            payload: Some(event.json_serialize().unwrap()),
+            payload: Some(codec.encode(&event)?),
+            codec: Some(codec),
+            ..Default::default()
         };
-        self.tx_queue_sender.send(udp_message).await.unwrap()
+        Ok(self.tx_queue_sender.send(udp_message).await?)
     }
 
-    pub async fn send_msg_to<T>(&self, dst: &str, msg: T)
+    pub async fn send_msg_to<T>(&self, dst: &str, msg: T) -> anyhow::Result<()>
     where
         T: TypedMessage + Msg + Serialize,
     {
+        let codec = self.codec();
         let udp_message = UdpMessage {
             src: Some(self.my_id.lock().await.clone()),
             dst: Some(dst.to_string()),
             msg_type: Some(T::MSG_TYPE.to_string()),
-            payload: Some(msg.json_serialize().unwrap()),
+            payload: Some(codec.encode(&msg)?),
+            codec: Some(codec),
+            ..Default::default()
         };
-        self.tx_queue_sender.send(udp_message).await.unwrap()
+        Ok(self.tx_queue_sender.send(udp_message).await?)
+    }
+
+    /// Send with at-least-once delivery : the message is retransmitted until `dst` acks it,
+    /// duplicates are dropped by the receiver.
+    pub async fn send_reliable_to<T>(&self, dst: &str, msg: T) -> anyhow::Result<()>
+    where
+        T: TypedMessage + Msg + Serialize,
+    {
+        let codec = self.codec();
+        let udp_message = UdpMessage {
+            src: Some(self.my_id.lock().await.clone()),
+            dst: Some(dst.to_string()),
+            msg_type: Some(T::MSG_TYPE.to_string()),
+            payload: Some(codec.encode(&msg)?),
+            codec: Some(codec),
+            seq: Some(self.reliable.next_seq(dst)),
+            ..Default::default()
+        };
+        Ok(self.tx_queue_sender.send(udp_message).await?)
+    }
+
+    /// Number of reliable messages sent and not yet acked
+    pub fn unacked_count(&self) -> usize {
+        self.reliable.unacked_count()
+    }
+
+    /// Codec used for the payloads this node sends, JSON by default.
+    /// Received payloads are decoded with the codec of their envelope.
+    pub fn set_codec(&self, codec: Codec) {
+        *self.codec.lock().unwrap() = codec;
+    }
+
+    pub fn codec(&self) -> Codec {
+        *self.codec.lock().unwrap()
+    }
+
+    /// Largest datagram sent, larger messages are fragmented
+    pub fn set_mtu(&self, mtu: usize) {
+        self.mtu.store(mtu, Ordering::Relaxed);
+    }
+
+    /// Reassembly counters, including the partial messages dropped
+    pub fn fragment_stats(&self) -> FragmentStats {
+        self.reassembler.lock().unwrap().stats()
+    }
+
+    /// Messages of `msg_type` go out before those of a lower priority
+    pub fn set_priority(&self, msg_type: &str, priority: Priority) {
+        self.tx_queue_sender.set_priority(msg_type, priority);
+    }
+
+    /// Latest value wins : a queued `msg_type` is replaced by a newer one to the same destination
+    pub fn set_coalescing(&self, msg_type: &str, coalesce: bool) {
+        self.tx_queue_sender.set_coalescing(msg_type, coalesce);
+    }
+
+    /// Limit the messages per second to `dst`, the broker for events. None removes the limit.
+    pub fn set_rate_limit(&self, dst: &str, limit: Option<RateLimit>) {
+        self.tx_queue_sender.set_rate_limit(dst, limit);
+    }
+
+    pub fn tx_stats(&self) -> TxStats {
+        self.tx_queue_sender.stats()
+    }
+
+    /// Queue depths and the messages lost on the way in or out
+    pub fn stats(&self) -> NodeStats {
+        NodeStats {
+            decode_errors: self.decode_errors.load(Ordering::Relaxed),
+            handler_errors: self.handler_errors.iter().map(|e| *e.value()).sum(),
+            unknown_endpoint_drops: self.reliable.undelivered_dropped(),
+            undelivered: self.reliable.undelivered_count(),
+            tx_queue: self.tx_queue_sender.len(),
+            tx: self.tx_queue_sender.stats(),
+        }
+    }
+
+    /// Drop outgoing packets with the given probability, to test on a lossy link over loopback
+    pub fn simulate_loss(&self, probability: f64) {
+        self.tx_loss
+            .store(probability.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
     }
 // add a generic U
     pub fn add_handler<H>(&self, msg_type: &str, handler: H)
     where
         H: MessageHandler + 'static,
     {
-        self.handlers
-            .insert(msg_type.to_string(), Box::new(handler));
+        self.insert_handler(msg_type, Arc::new(handler));
+    }
+
+    fn insert_handler(&self, msg_type: &str, handler: Arc<dyn MessageHandler>) {
+        let subscription = TypedSubscription::start(msg_type, handler, self.handler_errors.clone());
+        self.handlers.insert(msg_type.to_string(), subscription);
+    }
+
+    /// Unregister the typed handler for `msg_type`, returns false if none was registered.
+    /// The msg_type is no longer advertised as subscribed from the next Alive.
+    pub fn remove_handler(&self, msg_type: &str) -> bool {
+        self.handlers.remove(msg_type).is_some()
+    }
+
+    /// Number of payloads of `msg_type` that failed to decode, were rejected by their handler
+    /// or were dropped because the handler queue was full.
+    pub fn handler_error_count(&self, msg_type: &str) -> u64 {
+        self.handler_errors
+            .get(msg_type)
+            .map(|count| *count)
+            .unwrap_or(0)
     }
 
     pub fn add_sender(&self, sender: mpsc::Sender<UdpMessage>) {
@@ -308,16 +807,14 @@ impl UdpNode {
         }
     }
 
+    /// Handle every `T` received, `T` is advertised as subscribed in Alive.
     pub fn on<T, F, Fut>(&self, callback: F)
     where
         T: TypedMessage + Msg,
         F: Fn(String, T) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = ()> + Send,
     {
-        self.handlers.insert(
-            T::MSG_TYPE.to_string(),
-            Box::new(HandlerWrapper::new(callback)),
-        );
+        self.insert_handler(T::MSG_TYPE, Arc::new(HandlerWrapper::new(callback)));
     }
 
     pub async fn send_typed<T: TypedMessage + Msg + Serialize>(
@@ -326,23 +823,106 @@ impl UdpNode {
         dest_id: &str,
         msg: T,
     ) -> anyhow::Result<()> {
-        let payload = msg.json_serialize()?;
+        let codec = self.codec();
+        let payload = codec.encode(&msg)?;
 
         let packet = UdpMessage {
             src: Some(src.to_string()),
             dst: Some(dest_id.to_string()),
             msg_type: Some(T::MSG_TYPE.to_string()),
             payload: Some(payload),
+            codec: Some(codec),
+            ..Default::default()
         };
 
-        self.tx_queue_sender
-            .send(packet)
-            .await
-            .map_err(|_| anyhow::anyhow!("Queue Closed"))?;
-        Ok(())
+        Ok(self.tx_queue_sender.send(packet).await?)
+    }
+
+    /// Send `req` to `dst` and wait for the matching `Rep`. The request is retried
+    /// with a doubling wait per attempt until `timeout` is spent.
+    pub async fn request<Req, Rep>(
+        &self,
+        dst: &str,
+        req: Req,
+        timeout: Duration,
+    ) -> Result<Rep, RpcError>
+    where
+        Req: TypedMessage + Msg,
+        Rep: TypedMessage + Msg,
+    {
+        let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+        let codec = self.codec();
+        let packet = UdpMessage {
+            src: Some(self.my_id.lock().await.clone()),
+            dst: Some(dst.to_string()),
+            msg_type: Some(Req::MSG_TYPE.to_string()),
+            payload: Some(codec.encode(&req).map_err(RpcError::Encode)?),
+            codec: Some(codec),
+            correlation_id: Some(correlation_id),
+            ..Default::default()
+        };
+        let (reply_sender, mut reply_receiver) = oneshot::channel();
+        self.pending_requests.insert(
+            correlation_id,
+            PendingRequest {
+                msg_type: Rep::MSG_TYPE,
+                reply: reply_sender,
+            },
+        );
+
+        for (attempt, wait) in rpc::attempt_timeouts(timeout, rpc::RPC_ATTEMPTS)
+            .into_iter()
+            .enumerate()
+        {
+            if self.tx_queue_sender.send(packet.clone()).await.is_err() {
+                self.pending_requests.remove(&correlation_id);
+                return Err(RpcError::QueueClosed);
+            }
+            match tokio::time::timeout(wait, &mut reply_receiver).await {
+                Ok(Ok(reply)) => {
+                    let payload = reply.payload.clone().unwrap_or_default();
+                    return Codec::of(&reply)
+                        .decode(&payload)
+                        .map_err(RpcError::Decode);
+                }
+                Ok(Err(_)) => return Err(RpcError::QueueClosed),
+                Err(_) => debug!(
+                    "{} to {} attempt {} timed out after {:?}",
+                    Req::MSG_TYPE,
+                    dst,
+                    attempt + 1,
+                    wait
+                ),
+            }
+        }
+        self.pending_requests.remove(&correlation_id);
+        Err(RpcError::Timeout {
+            dst: dst.to_string(),
+            msg_type: Rep::MSG_TYPE,
+            attempts: rpc::RPC_ATTEMPTS,
+        })
     }
 
-    pub fn sender(&self) -> mpsc::Sender<UdpMessage> {
+    /// Answer every `Req` received with the `Rep` returned by `handler`.
+    /// `Req` is advertised in Alive.services.
+    pub fn serve<Req, Rep, F, Fut>(&self, handler: F)
+    where
+        Req: TypedMessage + Msg,
+        Rep: TypedMessage + Msg,
+        F: Fn(String, Req) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Rep> + Send,
+    {
+        self.services.insert(
+            Req::MSG_TYPE.to_string(),
+            Arc::new(ServiceWrapper::<Req, Rep, F>::new(
+                handler,
+                self.my_id.clone(),
+                self.tx_queue_sender.clone(),
+            )),
+        );
+    }
+
+    pub fn sender(&self) -> TxSender {
         self.tx_queue_sender.clone()
     }
 
@@ -357,10 +937,47 @@ impl UdpNode {
         self.multicast_scout.subscriptions.clone()
     }
 
+    pub fn scout(&self) -> Arc<Scout> {
+        self.multicast_scout.clone()
+    }
+
+    /// Addresses of the unicast sockets, one per interface
+    pub fn local_addrs(&self) -> Vec<SocketAddr> {
+        self.unicast_sockets
+            .iter()
+            .filter_map(|u| u.socket.local_addr().ok())
+            .collect()
+    }
+
+    /// Endpoints joining, leaving or changing their advertised lists
+    pub fn endpoint_events(&self) -> tokio::sync::broadcast::Receiver<EndpointEvent> {
+        self.multicast_scout.events()
+    }
+
     pub async fn get_endpoints(&self) -> Arc<DashMap<String, Endpoint>> {
         self.multicast_scout.endpoints.clone()
     }
 
+    /*
+       what this node advertises : subscriptions added by hand and the msg_types with a handler,
+       the events it sent and the services it serves. Sorted, so Scout only sees a change when
+       there is one.
+    */
+    pub async fn alive(&self) -> Alive {
+        let sorted = |mut list: Vec<String>| {
+            list.sort();
+            list.dedup();
+            list
+        };
+        let mut subscribe = self.my_subscriptions.lock().await.clone();
+        subscribe.extend(self.handlers.iter().map(|h| h.key().clone()));
+        Alive {
+            subscribe: Some(sorted(subscribe)),
+            publish: Some(sorted(self.published.iter().map(|p| p.key().clone()).collect())),
+            services: Some(sorted(self.services.iter().map(|s| s.key().clone()).collect())),
+        }
+    }
+
     pub async fn add_subscription(&self, subscription: &str) {
         let mut my_subscriptions = self.my_subscriptions.lock().await;
         if !my_subscriptions.contains(&subscription.to_string()) {
@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::msgs::BrokerMetricsEvent;
+use crate::{NodeStats, UdpNode};
+
+/*
+   Health of the broker, for dashboards that alarm on dead devices :
+   - messages in per source and type, out per destination and type, counted by the Forwarder
+   - messages lost : unknown destinations, datagrams that don't decode, queue depths from UdpNode
+   - time since the last Alive of each endpoint, from Scout
+   Served as Prometheus text over HTTP and published on the bus as BrokerMetricsEvent.
+*/
+
+// --- CONSTANTS ---
+/// Largest HTTP request read, only the request line is used
+const MAX_REQUEST: usize = 4096;
+/// Pause after a failed accept
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Messages counted per (endpoint, msg_type), since the start
+#[derive(Default)]
+pub struct MessageCounters {
+    received: Mutex<HashMap<(String, String), u64>>,
+    forwarded: Mutex<HashMap<(String, String), u64>>,
+}
+
+impl MessageCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn received(&self, src: &str, msg_type: &str) {
+        Self::count(&self.received, src, msg_type);
+    }
+
+    pub fn forwarded(&self, dst: &str, msg_type: &str) {
+        Self::count(&self.forwarded, dst, msg_type);
+    }
+
+    /// (src, msg_type, count) sorted
+    pub fn received_counts(&self) -> Vec<(String, String, u64)> {
+        Self::counts(&self.received)
+    }
+
+    /// (dst, msg_type, count) sorted
+    pub fn forwarded_counts(&self) -> Vec<(String, String, u64)> {
+        Self::counts(&self.forwarded)
+    }
+
+    fn count(counters: &Mutex<HashMap<(String, String), u64>>, endpoint: &str, msg_type: &str) {
+        *counters
+            .lock()
+            .unwrap()
+            .entry((endpoint.to_string(), msg_type.to_string()))
+            .or_insert(0) += 1;
+    }
+
+    fn counts(counters: &Mutex<HashMap<(String, String), u64>>) -> Vec<(String, String, u64)> {
+        let mut counts: Vec<(String, String, u64)> = counters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|((endpoint, msg_type), count)| (endpoint.clone(), msg_type.clone(), *count))
+            .collect();
+        counts.sort();
+        counts
+    }
+}
+
+/// Everything the broker reports, taken at one moment
+#[derive(Debug, Clone, Default)]
+pub struct BrokerMetrics {
+    /// (src, msg_type, count)
+    pub received: Vec<(String, String, u64)>,
+    /// (dst, msg_type, count)
+    pub forwarded: Vec<(String, String, u64)>,
+    pub node: NodeStats,
+    /// endpoints known to Scout with the time since their last Alive, sorted by name
+    pub endpoints: Vec<(String, Duration)>,
+}
+
+impl BrokerMetrics {
+    pub fn collect(counters: &MessageCounters, node: &UdpNode) -> Self {
+        let mut endpoints: Vec<(String, Duration)> = node
+            .scout()
+            .endpoints
+            .iter()
+            .map(|e| (e.key().clone(), e.value().last_seen.elapsed()))
+            .collect();
+        endpoints.sort();
+        BrokerMetrics {
+            received: counters.received_counts(),
+            forwarded: counters.forwarded_counts(),
+            node: node.stats(),
+            endpoints,
+        }
+    }
+
+    /// Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut text = String::new();
+        header(
+            &mut text,
+            "limeros_messages_received_total",
+            "counter",
+            "Messages received by the broker",
+        );
+        for (src, msg_type, count) in &self.received {
+            sample(
+                &mut text,
+                "limeros_messages_received_total",
+                &[("src", src), ("msg_type", msg_type)],
+                *count as f64,
+            );
+        }
+        header(
+            &mut text,
+            "limeros_messages_forwarded_total",
+            "counter",
+            "Messages forwarded to subscribers",
+        );
+        for (dst, msg_type, count) in &self.forwarded {
+            sample(
+                &mut text,
+                "limeros_messages_forwarded_total",
+                &[("dst", dst), ("msg_type", msg_type)],
+                *count as f64,
+            );
+        }
+        header(
+            &mut text,
+            "limeros_dropped_unknown_endpoint_total",
+            "counter",
+            "Messages dropped because their destination was never discovered",
+        );
+        for (dst, count) in &self.node.unknown_endpoint_drops {
+            sample(
+                &mut text,
+                "limeros_dropped_unknown_endpoint_total",
+                &[("dst", dst)],
+                *count as f64,
+            );
+        }
+        let totals = [
+            (
+                "limeros_decode_errors_total",
+                "counter",
+                "Datagrams received that are not a UdpMessage",
+                self.node.decode_errors as f64,
+            ),
+            (
+                "limeros_handler_errors_total",
+                "counter",
+                "Payloads that failed to decode or were rejected by their handler",
+                self.node.handler_errors as f64,
+            ),
+            (
+                "limeros_tx_queue_depth",
+                "gauge",
+                "Messages waiting in the transmit queue",
+                self.node.tx_queue as f64,
+            ),
+            (
+                "limeros_undelivered_queue_depth",
+                "gauge",
+                "Messages waiting for their destination to be discovered",
+                self.node.undelivered as f64,
+            ),
+            (
+                "limeros_tx_rate_limited_total",
+                "counter",
+                "Messages held back by a rate limit",
+                self.node.tx.rate_limited as f64,
+            ),
+            (
+                "limeros_endpoints",
+                "gauge",
+                "Endpoints known to the broker",
+                self.endpoints.len() as f64,
+            ),
+        ];
+        for (name, kind, help, value) in totals {
+            header(&mut text, name, kind, help);
+            sample(&mut text, name, &[], value);
+        }
+        header(
+            &mut text,
+            "limeros_endpoint_last_seen_seconds",
+            "gauge",
+            "Seconds since the last Alive of the endpoint",
+        );
+        for (endpoint, last_seen) in &self.endpoints {
+            sample(
+                &mut text,
+                "limeros_endpoint_last_seen_seconds",
+                &[("endpoint", endpoint)],
+                last_seen.as_secs_f64(),
+            );
+        }
+        text
+    }
+
+    /// Summary per endpoint for the bus
+    pub fn to_event(&self) -> BrokerMetricsEvent {
+        let total = |counts: &[(String, String, u64)], endpoint: &str| -> u64 {
+            counts
+                .iter()
+                .filter(|(e, _, _)| e == endpoint)
+                .map(|(_, _, count)| count)
+                .sum()
+        };
+        BrokerMetricsEvent {
+            endpoint: Some(self.endpoints.iter().map(|(e, _)| e.clone()).collect()),
+            last_seen_ms: Some(
+                self.endpoints
+                    .iter()
+                    .map(|(_, last_seen)| last_seen.as_millis().min(u32::MAX as u128) as u32)
+                    .collect(),
+            ),
+            received: Some(
+                self.endpoints
+                    .iter()
+                    .map(|(e, _)| total(&self.received, e))
+                    .collect(),
+            ),
+            forwarded: Some(
+                self.endpoints
+                    .iter()
+                    .map(|(e, _)| total(&self.forwarded, e))
+                    .collect(),
+            ),
+            dropped_unknown: Some(
+                self.node
+                    .unknown_endpoint_drops
+                    .iter()
+                    .map(|(_, count)| count)
+                    .sum(),
+            ),
+            decode_errors: Some(self.node.decode_errors),
+            queue_depth: Some(self.node.tx_queue as u32),
+        }
+    }
+}
+
+fn header(text: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, kind);
+}
+
+fn sample(text: &mut String, name: &str, labels: &[(&str, &str)], value: f64) {
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|(label, value)| format!("{}=\"{}\"", label, escape(value)))
+        .collect();
+    if labels.is_empty() {
+        let _ = writeln!(text, "{} {}", name, value);
+    } else {
+        let _ = writeln!(text, "{}{{{}}} {}", name, labels.join(","), value);
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Serve `GET /metrics` with the text of `metrics` and `GET /health` with ok
+pub async fn serve_http<F>(addr: SocketAddr, metrics: F) -> anyhow::Result<JoinHandle<()>>
+where
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind(addr).await?;
+    info!("Metrics on http://{}/metrics", listener.local_addr()?);
+    Ok(serve_listener(listener, metrics))
+}
+
+/// As `serve_http` on a bound listener, port 0 in tests
+pub fn serve_listener<F>(listener: TcpListener, metrics: F) -> JoinHandle<()>
+where
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    let metrics = Arc::new(metrics);
+    tokio::spawn(async move {
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                // like EMFILE, retrying at once would spin until a descriptor is free
+                Err(e) => {
+                    warn!("Metrics accept failed: {}", e);
+                    tokio::time::sleep(ACCEPT_BACKOFF).await;
+                    continue;
+                }
+            };
+            let metrics = metrics.clone();
+            tokio::spawn(async move {
+                if let Err(e) = respond(stream, metrics.as_ref()).await {
+                    debug!("Metrics request from {} failed: {}", peer, e);
+                }
+            });
+        }
+    })
+}
+
+async fn respond<F>(mut stream: TcpStream, metrics: &F) -> anyhow::Result<()>
+where
+    F: Fn() -> String,
+{
+    let mut request = vec![];
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let (method, path) = (request_line.next(), request_line.next());
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", "text/plain; version=0.0.4", metrics()),
+        (Some("GET"), Some("/health")) => ("200 OK", "text/plain", "ok\n".to_string()),
+        (Some("GET"), _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
@@ -6,6 +6,7 @@ use minicbor::{Encode, Decode};
 pub trait TypedMessage : DeserializeOwned + Send + Sync +'static{
     const ID: u32;
     const MSG_TYPE: &'static str;
+    const FIELDS: &'static [FieldDescriptor];
 }
 pub trait Msg  : Send + Sync {
     fn type_name(&self) -> &'static str ;
@@ -16,8 +17,40 @@ pub trait Msg  : Send + Sync {
     fn json_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
 }
 
+/// Field as declared in the .proto, `type_name` is the Rust type of one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub index: u32,
+    pub type_name: &'static str,
+    pub repeated: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub fields: &'static [FieldDescriptor],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumDescriptor {
+    pub name: &'static str,
+    pub values: &'static [(&'static str, u32)],
+}
+
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+/// Encoding of the payload carried inside a `UdpMessage` envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
+pub enum Codec {
+    #[n(0)]
+    Json,
+    #[n(1)]
+    Cbor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum LogLevel {
     #[n(1)]
     Debug,
@@ -33,7 +66,7 @@ pub enum LogLevel {
     Alert,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum MessageType {
     #[n(1)]
     SysCmd,
@@ -47,7 +80,7 @@ pub enum MessageType {
     MotorCmd,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum Toggle {
     #[n(0)]
     Off,
@@ -55,7 +88,7 @@ pub enum Toggle {
     On,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum CtrlMod {
     #[n(1)]
     Voltage,
@@ -65,7 +98,7 @@ pub enum CtrlMod {
     Torque,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum CtrlTyp {
     #[n(0)]
     Commutation,
@@ -75,7 +108,7 @@ pub enum CtrlTyp {
     Foc,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum InTyp {
     #[n(0)]
     Disabled,
@@ -87,7 +120,7 @@ pub enum InTyp {
     AutoDetect,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize,Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
 pub enum LawnmowerMode {
     #[n(0)]
     Manual,
@@ -118,6 +151,11 @@ pub struct Alive {
 impl TypedMessage for Alive {
     const ID: u32 = 57419;
     const MSG_TYPE: &'static str = "Alive";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "subscribe", index: 3, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "publish", index: 4, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "services", index: 5, type_name: "String", repeated: true, optional: false },
+    ];
 }
 
 impl Msg for Alive {
@@ -145,11 +183,45 @@ pub struct UdpMessage {
     #[cbor(n(4), with = "minicbor::bytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payload: Option<Vec<u8>>,
+    #[n(5)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<u32>,
+    #[n(6)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+    #[n(7)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frag_id: Option<u32>,
+    #[n(8)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frag_index: Option<u32>,
+    #[n(9)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frag_count: Option<u32>,
+    #[n(10)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codec: Option<Codec>,
+    #[cbor(n(11), with = "minicbor::bytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Vec<u8>>,
 }
 
 impl TypedMessage for UdpMessage {
     const ID: u32 = 61718;
     const MSG_TYPE: &'static str = "UdpMessage";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "dst", index: 1, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "src", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "msg_type", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "payload", index: 4, type_name: "Vec<u8>", repeated: false, optional: true },
+        FieldDescriptor { name: "correlation_id", index: 5, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "seq", index: 6, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "frag_id", index: 7, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "frag_index", index: 8, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "frag_count", index: 9, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "codec", index: 10, type_name: "Codec", repeated: false, optional: true },
+        FieldDescriptor { name: "nonce", index: 11, type_name: "Vec<u8>", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for UdpMessage {
@@ -182,6 +254,12 @@ pub struct UdpMessageCbor {
 impl TypedMessage for UdpMessageCbor {
     const ID: u32 = 65322;
     const MSG_TYPE: &'static str = "UdpMessageCbor";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "dst", index: 1, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "src", index: 2, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "msg_type", index: 3, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "payload", index: 4, type_name: "Vec<u8>", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for UdpMessageCbor {
@@ -223,6 +301,15 @@ pub struct ZenohEvent {
 impl TypedMessage for ZenohEvent {
     const ID: u32 = 48902;
     const MSG_TYPE: &'static str = "ZenohEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "zid", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "what_am_i", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "peers", index: 4, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "prefix", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "routers", index: 6, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "connect", index: 7, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "listen", index: 8, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for ZenohEvent {
@@ -261,6 +348,14 @@ pub struct LogEvent {
 impl TypedMessage for LogEvent {
     const ID: u32 = 29204;
     const MSG_TYPE: &'static str = "LogEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "level", index: 2, type_name: "LogLevel", repeated: false, optional: true },
+        FieldDescriptor { name: "message", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "error_code", index: 4, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "file", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "line", index: 6, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "timestamp", index: 7, type_name: "u64", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LogEvent {
@@ -293,6 +388,12 @@ pub struct SysCmd {
 impl TypedMessage for SysCmd {
     const ID: u32 = 51983;
     const MSG_TYPE: &'static str = "SysCmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "src", index: 2, type_name: "String", repeated: false, optional: false },
+        FieldDescriptor { name: "set_time", index: 3, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "reboot", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "console", index: 5, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for SysCmd {
@@ -331,6 +432,14 @@ pub struct SysEvent {
 impl TypedMessage for SysEvent {
     const ID: u32 = 23049;
     const MSG_TYPE: &'static str = "SysEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "utc", index: 1, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "uptime", index: 2, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "free_heap", index: 3, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "flash", index: 4, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "cpu_board", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "build_date", index: 6, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for SysEvent {
@@ -375,6 +484,16 @@ pub struct WifiEvent {
 impl TypedMessage for WifiEvent {
     const ID: u32 = 54881;
     const MSG_TYPE: &'static str = "WifiEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "ssid", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "bssid", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "rssi", index: 4, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "ip", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "mac", index: 6, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "channel", index: 7, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "gateway", index: 8, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "netmask", index: 9, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for WifiEvent {
@@ -404,6 +523,11 @@ pub struct MulticastEvent {
 impl TypedMessage for MulticastEvent {
     const ID: u32 = 53788;
     const MSG_TYPE: &'static str = "MulticastEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "group", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "port", index: 3, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "mtu", index: 4, type_name: "u32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for MulticastEvent {
@@ -427,6 +551,9 @@ pub struct PingReq {
 impl TypedMessage for PingReq {
     const ID: u32 = 27754;
     const MSG_TYPE: &'static str = "PingReq";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "number", index: 1, type_name: "u32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for PingReq {
@@ -450,6 +577,9 @@ pub struct PingRep {
 impl TypedMessage for PingRep {
     const ID: u32 = 28011;
     const MSG_TYPE: &'static str = "PingRep";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "number", index: 1, type_name: "u32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for PingRep {
@@ -608,6 +738,54 @@ pub struct HoverboardEventRaw {
 impl TypedMessage for HoverboardEventRaw {
     const ID: u32 = 16168;
     const MSG_TYPE: &'static str = "HoverboardEventRaw";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "ctrl_mod", index: 0, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "ctrl_typ", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "cur_mot_max", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "rpm_mot_max", index: 3, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_ena", index: 4, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_hi", index: 5, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_lo", index: 6, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_max", index: 7, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "phase_adv_max_deg", index: 8, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_raw", index: 9, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_typ", index: 10, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_min", index: 11, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_mid", index: 12, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_max", index: 13, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_cmd", index: 14, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_raw", index: 15, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_typ", index: 16, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_min", index: 17, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_mid", index: 18, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_max", index: 19, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_cmd", index: 20, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_raw", index: 21, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_typ", index: 22, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_min", index: 23, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_mid", index: 24, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_max", index: 25, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_cmd", index: 26, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_raw", index: 27, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_typ", index: 28, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_min", index: 29, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_mid", index: 30, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_max", index: 31, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_cmd", index: 32, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "dc_curr", index: 33, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "rdc_curr", index: 34, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "ldc_curr", index: 35, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "cmdl", index: 36, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "cmdr", index: 37, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spd_avg", index: 38, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spdl", index: 39, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spdr", index: 40, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "filter_rate", index: 41, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spd_coef", index: 42, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "str_coef", index: 43, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "batv", index: 44, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "temp", index: 45, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for HoverboardEventRaw {
@@ -766,6 +944,54 @@ pub struct HoverboardEvent {
 impl TypedMessage for HoverboardEvent {
     const ID: u32 = 31340;
     const MSG_TYPE: &'static str = "HoverboardEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "ctrl_mod", index: 0, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "ctrl_typ", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "cur_mot_max", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "rpm_mot_max", index: 3, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_ena", index: 4, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_hi", index: 5, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_lo", index: 6, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "fi_weak_max", index: 7, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "phase_adv_max_deg", index: 8, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_raw", index: 9, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_typ", index: 10, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_min", index: 11, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_mid", index: 12, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_max", index: 13, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input1_cmd", index: 14, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_raw", index: 15, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_typ", index: 16, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_min", index: 17, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_mid", index: 18, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_max", index: 19, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "input2_cmd", index: 20, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_raw", index: 21, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_typ", index: 22, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_min", index: 23, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_mid", index: 24, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_max", index: 25, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input1_cmd", index: 26, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_raw", index: 27, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_typ", index: 28, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_min", index: 29, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_mid", index: 30, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_max", index: 31, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "aux_input2_cmd", index: 32, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "dc_curr", index: 33, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "rdc_curr", index: 34, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "ldc_curr", index: 35, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "cmdl", index: 36, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "cmdr", index: 37, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spd_avg", index: 38, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spdl", index: 39, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spdr", index: 40, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "filter_rate", index: 41, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "spd_coef", index: 42, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "str_coef", index: 43, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "batv", index: 44, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "temp", index: 45, type_name: "f32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for HoverboardEvent {
@@ -792,6 +1018,10 @@ pub struct HoverboardCmd {
 impl TypedMessage for HoverboardCmd {
     const ID: u32 = 58218;
     const MSG_TYPE: &'static str = "HoverboardCmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "speed", index: 0, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "steer", index: 1, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for HoverboardCmd {
@@ -818,6 +1048,10 @@ pub struct HoverboardReply {
 impl TypedMessage for HoverboardReply {
     const ID: u32 = 30066;
     const MSG_TYPE: &'static str = "HoverboardReply";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "error_code", index: 0, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "message", index: 1, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for HoverboardReply {
@@ -850,6 +1084,12 @@ pub struct TouchPoint {
 impl TypedMessage for TouchPoint {
     const ID: u32 = 49173;
     const MSG_TYPE: &'static str = "TouchPoint";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "active", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "id", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "x", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "y", index: 3, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for TouchPoint {
@@ -969,6 +1209,41 @@ pub struct Ps4Event {
 impl TypedMessage for Ps4Event {
     const ID: u32 = 29767;
     const MSG_TYPE: &'static str = "Ps4Event";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "button_left", index: 1, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_right", index: 2, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_up", index: 3, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_down", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_square", index: 5, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_cross", index: 6, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_circle", index: 7, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_triangle", index: 8, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_left_shoulder", index: 9, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_right_shoulder", index: 10, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_left_trigger", index: 11, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_right_trigger", index: 12, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_left_joystick", index: 13, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_right_joystick", index: 14, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_share", index: 15, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_options", index: 16, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_touchpad", index: 33, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "button_ps", index: 34, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "axis_lx", index: 17, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "axis_ly", index: 18, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "axis_rx", index: 19, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "axis_ry", index: 20, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "gyro_x", index: 21, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "gyro_y", index: 22, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "gyro_z", index: 23, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "accel_x", index: 24, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "accel_y", index: 25, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "accel_z", index: 26, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "connected", index: 27, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "battery_level", index: 28, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "bluetooth", index: 30, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "debug", index: 31, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "temp", index: 32, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for Ps4Event {
@@ -1010,6 +1285,15 @@ pub struct Ps4Cmd {
 impl TypedMessage for Ps4Cmd {
     const ID: u32 = 50497;
     const MSG_TYPE: &'static str = "Ps4Cmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "rumble_small", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "rumble_large", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "led_red", index: 3, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "led_green", index: 4, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "led_blue", index: 5, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "led_flash_on", index: 6, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "led_flash_off", index: 7, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for Ps4Cmd {
@@ -1048,6 +1332,14 @@ pub struct CameraEvent {
 impl TypedMessage for CameraEvent {
     const ID: u32 = 32617;
     const MSG_TYPE: &'static str = "CameraEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "width", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "height", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "format", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "data", index: 4, type_name: "Vec<u8>", repeated: false, optional: true },
+        FieldDescriptor { name: "led", index: 5, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "quality", index: 6, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for CameraEvent {
@@ -1077,6 +1369,11 @@ pub struct CameraCmd {
 impl TypedMessage for CameraCmd {
     const ID: u32 = 61551;
     const MSG_TYPE: &'static str = "CameraCmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "led", index: 1, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "capture_tcp_destination", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "quality", index: 4, type_name: "i32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for CameraCmd {
@@ -1106,6 +1403,11 @@ pub struct CameraReply {
 impl TypedMessage for CameraReply {
     const ID: u32 = 32887;
     const MSG_TYPE: &'static str = "CameraReply";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "error_code", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "message", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "data", index: 3, type_name: "Vec<u8>", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for CameraReply {
@@ -1135,6 +1437,11 @@ pub struct LawnmowerManualEvent {
 impl TypedMessage for LawnmowerManualEvent {
     const ID: u32 = 24124;
     const MSG_TYPE: &'static str = "LawnmowerManualEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "speed", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "steering", index: 2, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "blade", index: 3, type_name: "bool", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerManualEvent {
@@ -1179,6 +1486,16 @@ pub struct LawnmowerManualCmd {
 impl TypedMessage for LawnmowerManualCmd {
     const ID: u32 = 1850;
     const MSG_TYPE: &'static str = "LawnmowerManualCmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "speed", index: 1, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "steer", index: 2, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "blade", index: 3, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "start_manual_control", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "stop_manual_control", index: 5, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "emergency_stop", index: 6, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "start_auto_mode", index: 7, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "stop_auto_mode", index: 8, type_name: "bool", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerManualCmd {
@@ -1205,6 +1522,10 @@ pub struct LawnmowerManualReply {
 impl TypedMessage for LawnmowerManualReply {
     const ID: u32 = 22818;
     const MSG_TYPE: &'static str = "LawnmowerManualReply";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "error_code", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "message", index: 2, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerManualReply {
@@ -1243,6 +1564,14 @@ pub struct LawnmowerAutoEvent {
 impl TypedMessage for LawnmowerAutoEvent {
     const ID: u32 = 58665;
     const MSG_TYPE: &'static str = "LawnmowerAutoEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "started", index: 1, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "stopped", index: 2, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "paused", index: 3, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "resumed", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "mode", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "path", index: 6, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerAutoEvent {
@@ -1281,6 +1610,14 @@ pub struct LawnmowerAutoCmd {
 impl TypedMessage for LawnmowerAutoCmd {
     const ID: u32 = 22063;
     const MSG_TYPE: &'static str = "LawnmowerAutoCmd";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "start", index: 1, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "stop", index: 2, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "pause", index: 3, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "resume", index: 4, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "mode", index: 5, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "path", index: 6, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerAutoCmd {
@@ -1313,6 +1650,12 @@ pub struct LawnmowerStatus {
 impl TypedMessage for LawnmowerStatus {
     const ID: u32 = 21374;
     const MSG_TYPE: &'static str = "LawnmowerStatus";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "battery_level", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "blade_status", index: 2, type_name: "bool", repeated: false, optional: true },
+        FieldDescriptor { name: "current_mode", index: 3, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "error_message", index: 4, type_name: "String", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for LawnmowerStatus {
@@ -1351,6 +1694,14 @@ pub struct MotorEvent {
 impl TypedMessage for MotorEvent {
     const ID: u32 = 55067;
     const MSG_TYPE: &'static str = "MotorEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "motor_id", index: 1, type_name: "i32", repeated: false, optional: true },
+        FieldDescriptor { name: "temperature", index: 2, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "voltage", index: 3, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "current", index: 4, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "speed", index: 5, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "position", index: 6, type_name: "f32", repeated: false, optional: true },
+    ];
 }
 
 impl Msg for MotorEvent {
@@ -1363,3 +1714,158 @@ impl Msg for MotorEvent {
 }
     
 
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct BrokerRatesReq {
+}
+
+impl TypedMessage for BrokerRatesReq {
+    const ID: u32 = 15144;
+    const MSG_TYPE: &'static str = "BrokerRatesReq";
+    const FIELDS: &'static [FieldDescriptor] = &[
+    ];
+}
+
+impl Msg for BrokerRatesReq {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct BrokerRatesRep {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<Vec<String>>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst: Option<Vec<String>>,
+    #[n(3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg_type: Option<Vec<String>>,
+    #[n(4)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<Vec<f32>>,
+}
+
+impl TypedMessage for BrokerRatesRep {
+    const ID: u32 = 15401;
+    const MSG_TYPE: &'static str = "BrokerRatesRep";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "src", index: 1, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "dst", index: 2, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "msg_type", index: 3, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "rate", index: 4, type_name: "f32", repeated: true, optional: false },
+    ];
+}
+
+impl Msg for BrokerRatesRep {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct BrokerMetricsEvent {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<Vec<String>>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen_ms: Option<Vec<u32>>,
+    #[n(3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received: Option<Vec<u64>>,
+    #[n(4)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarded: Option<Vec<u64>>,
+    #[n(5)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropped_unknown: Option<u64>,
+    #[n(6)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decode_errors: Option<u64>,
+    #[n(7)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_depth: Option<u32>,
+}
+
+impl TypedMessage for BrokerMetricsEvent {
+    const ID: u32 = 13348;
+    const MSG_TYPE: &'static str = "BrokerMetricsEvent";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "endpoint", index: 1, type_name: "String", repeated: true, optional: false },
+        FieldDescriptor { name: "last_seen_ms", index: 2, type_name: "u32", repeated: true, optional: false },
+        FieldDescriptor { name: "received", index: 3, type_name: "u64", repeated: true, optional: false },
+        FieldDescriptor { name: "forwarded", index: 4, type_name: "u64", repeated: true, optional: false },
+        FieldDescriptor { name: "dropped_unknown", index: 5, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "decode_errors", index: 6, type_name: "u64", repeated: false, optional: true },
+        FieldDescriptor { name: "queue_depth", index: 7, type_name: "u32", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for BrokerMetricsEvent {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+pub const MESSAGES: &[MessageDescriptor] = &[
+    MessageDescriptor { name: Alive::MSG_TYPE, id: Alive::ID, fields: Alive::FIELDS },
+    MessageDescriptor { name: UdpMessage::MSG_TYPE, id: UdpMessage::ID, fields: UdpMessage::FIELDS },
+    MessageDescriptor { name: UdpMessageCbor::MSG_TYPE, id: UdpMessageCbor::ID, fields: UdpMessageCbor::FIELDS },
+    MessageDescriptor { name: ZenohEvent::MSG_TYPE, id: ZenohEvent::ID, fields: ZenohEvent::FIELDS },
+    MessageDescriptor { name: LogEvent::MSG_TYPE, id: LogEvent::ID, fields: LogEvent::FIELDS },
+    MessageDescriptor { name: SysCmd::MSG_TYPE, id: SysCmd::ID, fields: SysCmd::FIELDS },
+    MessageDescriptor { name: SysEvent::MSG_TYPE, id: SysEvent::ID, fields: SysEvent::FIELDS },
+    MessageDescriptor { name: WifiEvent::MSG_TYPE, id: WifiEvent::ID, fields: WifiEvent::FIELDS },
+    MessageDescriptor { name: MulticastEvent::MSG_TYPE, id: MulticastEvent::ID, fields: MulticastEvent::FIELDS },
+    MessageDescriptor { name: PingReq::MSG_TYPE, id: PingReq::ID, fields: PingReq::FIELDS },
+    MessageDescriptor { name: PingRep::MSG_TYPE, id: PingRep::ID, fields: PingRep::FIELDS },
+    MessageDescriptor { name: HoverboardEventRaw::MSG_TYPE, id: HoverboardEventRaw::ID, fields: HoverboardEventRaw::FIELDS },
+    MessageDescriptor { name: HoverboardEvent::MSG_TYPE, id: HoverboardEvent::ID, fields: HoverboardEvent::FIELDS },
+    MessageDescriptor { name: HoverboardCmd::MSG_TYPE, id: HoverboardCmd::ID, fields: HoverboardCmd::FIELDS },
+    MessageDescriptor { name: HoverboardReply::MSG_TYPE, id: HoverboardReply::ID, fields: HoverboardReply::FIELDS },
+    MessageDescriptor { name: TouchPoint::MSG_TYPE, id: TouchPoint::ID, fields: TouchPoint::FIELDS },
+    MessageDescriptor { name: Ps4Event::MSG_TYPE, id: Ps4Event::ID, fields: Ps4Event::FIELDS },
+    MessageDescriptor { name: Ps4Cmd::MSG_TYPE, id: Ps4Cmd::ID, fields: Ps4Cmd::FIELDS },
+    MessageDescriptor { name: CameraEvent::MSG_TYPE, id: CameraEvent::ID, fields: CameraEvent::FIELDS },
+    MessageDescriptor { name: CameraCmd::MSG_TYPE, id: CameraCmd::ID, fields: CameraCmd::FIELDS },
+    MessageDescriptor { name: CameraReply::MSG_TYPE, id: CameraReply::ID, fields: CameraReply::FIELDS },
+    MessageDescriptor { name: LawnmowerManualEvent::MSG_TYPE, id: LawnmowerManualEvent::ID, fields: LawnmowerManualEvent::FIELDS },
+    MessageDescriptor { name: LawnmowerManualCmd::MSG_TYPE, id: LawnmowerManualCmd::ID, fields: LawnmowerManualCmd::FIELDS },
+    MessageDescriptor { name: LawnmowerManualReply::MSG_TYPE, id: LawnmowerManualReply::ID, fields: LawnmowerManualReply::FIELDS },
+    MessageDescriptor { name: LawnmowerAutoEvent::MSG_TYPE, id: LawnmowerAutoEvent::ID, fields: LawnmowerAutoEvent::FIELDS },
+    MessageDescriptor { name: LawnmowerAutoCmd::MSG_TYPE, id: LawnmowerAutoCmd::ID, fields: LawnmowerAutoCmd::FIELDS },
+    MessageDescriptor { name: LawnmowerStatus::MSG_TYPE, id: LawnmowerStatus::ID, fields: LawnmowerStatus::FIELDS },
+    MessageDescriptor { name: MotorEvent::MSG_TYPE, id: MotorEvent::ID, fields: MotorEvent::FIELDS },
+    MessageDescriptor { name: BrokerRatesReq::MSG_TYPE, id: BrokerRatesReq::ID, fields: BrokerRatesReq::FIELDS },
+    MessageDescriptor { name: BrokerRatesRep::MSG_TYPE, id: BrokerRatesRep::ID, fields: BrokerRatesRep::FIELDS },
+    MessageDescriptor { name: BrokerMetricsEvent::MSG_TYPE, id: BrokerMetricsEvent::ID, fields: BrokerMetricsEvent::FIELDS },
+];
+
+pub const ENUMS: &[EnumDescriptor] = &[
+    EnumDescriptor { name: "Codec", values: &[("Json", 0), ("Cbor", 1)] },
+    EnumDescriptor { name: "LogLevel", values: &[("Debug", 1), ("Info", 2), ("Warn", 3), ("Error", 4), ("Fatal", 5), ("Alert", 6)] },
+    EnumDescriptor { name: "MessageType", values: &[("SysCmd", 1), ("SysInfo", 2), ("WifiInfo", 3), ("MotorInfo", 4), ("MotorCmd", 5)] },
+    EnumDescriptor { name: "Toggle", values: &[("Off", 0), ("On", 1)] },
+    EnumDescriptor { name: "CtrlMod", values: &[("Voltage", 1), ("Speed", 2), ("Torque", 3)] },
+    EnumDescriptor { name: "CtrlTyp", values: &[("Commutation", 0), ("Sinusoidal", 1), ("Foc", 2)] },
+    EnumDescriptor { name: "InTyp", values: &[("Disabled", 0), ("NormalPot", 1), ("MiddleRestingPot", 2), ("AutoDetect", 3)] },
+    EnumDescriptor { name: "LawnmowerMode", values: &[("Manual", 0), ("Auto", 1), ("Paused", 2), ("EmergencyStop", 3)] },
+];
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+
+use crate::scout::Subscription;
+
+// Subscription patterns, compatible with zenoh key expressions :
+// - a pattern without '/' matches the msg_type : "HoverboardEvent", "Hoverboard*", "*"
+// - a pattern with '/' matches the keys "src/<src>/<msg_type>" and "dst/<dst>/<msg_type>"
+//   e.g. "src/esp1/*", "src/esp1/**", "src/*/HoverboardEvent", "**"
+// - '*' matches one chunk, '**' zero or more chunks, a '*' inside a chunk matches any characters
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Chunk {
+    Literal(String),
+    Glob(String),
+    Wild,
+    Rest,
+}
+
+impl Chunk {
+    fn parse(s: &str) -> Chunk {
+        match s {
+            "**" => Chunk::Rest,
+            "*" => Chunk::Wild,
+            s if s.contains('*') => Chunk::Glob(s.to_string()),
+            s => Chunk::Literal(s.to_string()),
+        }
+    }
+
+    fn matches(&self, s: &str) -> bool {
+        match self {
+            Chunk::Literal(l) => l == s,
+            Chunk::Glob(g) => glob_match(g.as_bytes(), s.as_bytes()),
+            Chunk::Wild | Chunk::Rest => true,
+        }
+    }
+
+    fn literal(&self) -> Option<&str> {
+        match self {
+            Chunk::Literal(l) => Some(l),
+            _ => None,
+        }
+    }
+}
+
+// Iterative, on a mismatch only the last '*' takes one more character, so patterns from the
+// network like "a*a*a*a*b" cost O(pattern * text) instead of exponential time
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // index of the last '*' in the pattern and of the text it matches up to
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if pattern.get(p) == Some(&b'*') {
+            star = Some((p, t));
+            p += 1;
+        } else if pattern.get(p) == Some(&text[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = star {
+            star = Some((star_p, star_t + 1));
+            p = star_p + 1;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+// Same walk with '**' as the star and chunks as the characters
+fn chunks_match(pattern: &[Chunk], key: &[&str]) -> bool {
+    let (mut p, mut k) = (0, 0);
+    let mut rest: Option<(usize, usize)> = None;
+    while k < key.len() {
+        match pattern.get(p) {
+            Some(Chunk::Rest) => {
+                rest = Some((p, k));
+                p += 1;
+            }
+            Some(chunk) if chunk.matches(key[k]) => {
+                p += 1;
+                k += 1;
+            }
+            _ => match rest {
+                Some((rest_p, rest_k)) => {
+                    rest = Some((rest_p, rest_k + 1));
+                    p = rest_p + 1;
+                    k = rest_k + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|chunk| *chunk == Chunk::Rest)
+}
+
+/// A compiled subscription pattern
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyPattern {
+    MsgType(Chunk),
+    KeyExpr(Vec<Chunk>),
+}
+
+impl KeyPattern {
+    pub fn parse(pattern: &str) -> KeyPattern {
+        if pattern.contains('/') {
+            KeyPattern::KeyExpr(pattern.split('/').map(Chunk::parse).collect())
+        } else {
+            KeyPattern::MsgType(Chunk::parse(pattern))
+        }
+    }
+
+    pub fn matches(&self, src: &str, dst: &str, msg_type: &str) -> bool {
+        match self {
+            KeyPattern::MsgType(chunk) => chunk.matches(msg_type),
+            KeyPattern::KeyExpr(chunks) => {
+                chunks_match(chunks, &["src", src, msg_type])
+                    || chunks_match(chunks, &["dst", dst, msg_type])
+            }
+        }
+    }
+}
+
+/// Subscription patterns indexed on their literal parts, so a lookup only
+/// evaluates the patterns that can match plus the ones starting with a wildcard.
+#[derive(Debug, Default)]
+pub struct SubscriptionIndex {
+    patterns: Vec<(KeyPattern, Vec<String>)>,
+    positions: HashMap<String, usize>,
+    by_type: HashMap<String, Vec<usize>>,
+    by_src: HashMap<String, Vec<usize>>,
+    by_dst: HashMap<String, Vec<usize>>,
+    wildcards: Vec<usize>,
+}
+
+impl SubscriptionIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_subscriptions(subscriptions: &DashMap<String, Vec<Subscription>>) -> Self {
+        let mut index = Self::new();
+        for entry in subscriptions.iter() {
+            for subscription in entry.value() {
+                index.insert(entry.key(), &subscription.destination);
+            }
+        }
+        index
+    }
+
+    pub fn insert(&mut self, pattern: &str, destination: &str) {
+        let position = match self.positions.get(pattern) {
+            Some(position) => *position,
+            None => {
+                let position = self.patterns.len();
+                let key_pattern = KeyPattern::parse(pattern);
+                match &key_pattern {
+                    KeyPattern::MsgType(Chunk::Literal(msg_type)) => {
+                        self.by_type.entry(msg_type.clone()).or_default().push(position)
+                    }
+                    KeyPattern::KeyExpr(chunks) => {
+                        match (
+                            chunks.first().and_then(Chunk::literal),
+                            chunks.get(1).and_then(Chunk::literal),
+                        ) {
+                            (Some("src"), Some(src)) => {
+                                self.by_src.entry(src.to_string()).or_default().push(position)
+                            }
+                            (Some("dst"), Some(dst)) => {
+                                self.by_dst.entry(dst.to_string()).or_default().push(position)
+                            }
+                            _ => self.wildcards.push(position),
+                        }
+                    }
+                    _ => self.wildcards.push(position),
+                }
+                self.patterns.push((key_pattern, vec![]));
+                self.positions.insert(pattern.to_string(), position);
+                position
+            }
+        };
+        let destinations = &mut self.patterns[position].1;
+        if !destinations.iter().any(|d| d == destination) {
+            destinations.push(destination.to_string());
+        }
+    }
+
+    /// Destinations subscribed to a message, each destination only once.
+    pub fn destinations(&self, src: &str, dst: &str, msg_type: &str) -> Vec<String> {
+        let candidates = self
+            .by_type
+            .get(msg_type)
+            .into_iter()
+            .chain(self.by_src.get(src))
+            .chain(self.by_dst.get(dst))
+            .flatten()
+            .chain(self.wildcards.iter());
+
+        let mut result: Vec<String> = vec![];
+        for position in candidates {
+            let (pattern, destinations) = &self.patterns[*position];
+            if !pattern.matches(src, dst, msg_type) {
+                continue;
+            }
+            for destination in destinations {
+                if !result.contains(destination) {
+                    result.push(destination.clone());
+                }
+            }
+        }
+        result
+    }
+
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
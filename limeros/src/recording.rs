@@ -0,0 +1,318 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::msgs::{Msg, UdpMessage};
+
+/*
+   Log file of recorded UdpMessage envelopes, all integers little endian :
+
+   file    := header record* index trailer
+   header  := "LIMEREC\0" version:u16 start_us:u64      start of the recording, µs since the epoch
+   record  := length:u32 offset_us:u64 envelope         envelope = CBOR UdpMessage of length bytes,
+                                                        offset_us = µs since start
+   index   := count:u32 ( offset_us:u64 position:u64 )*  position = file offset of the record
+   trailer := index_position:u64 "LIMEIDX\0"
+
+   The index is written when the recording is closed. A file without trailer, e.g. from a
+   recorder that was killed, is read by scanning the records up to the first incomplete one.
+*/
+
+pub const MAGIC: &[u8; 8] = b"LIMEREC\0";
+pub const INDEX_MAGIC: &[u8; 8] = b"LIMEIDX\0";
+pub const VERSION: u16 = 1;
+const HEADER_LEN: u64 = 8 + 2 + 8;
+const RECORD_HEADER_LEN: u64 = 4 + 8;
+const TRAILER_LEN: u64 = 8 + 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IndexEntry {
+    pub offset_us: u64,
+    pub position: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub offset_us: u64,
+    pub message: UdpMessage,
+}
+
+pub struct RecordWriter<W: Write + Seek> {
+    out: W,
+    start_us: u64,
+    position: u64,
+    index: Vec<IndexEntry>,
+}
+
+impl RecordWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P, start_us: u64) -> Result<Self> {
+        RecordWriter::new(BufWriter::new(File::create(path)?), start_us)
+    }
+}
+
+impl<W: Write + Seek> RecordWriter<W> {
+    pub fn new(mut out: W, start_us: u64) -> Result<Self> {
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+        out.write_all(&start_us.to_le_bytes())?;
+        Ok(Self {
+            out,
+            start_us,
+            position: HEADER_LEN,
+            index: vec![],
+        })
+    }
+
+    pub fn start_us(&self) -> u64 {
+        self.start_us
+    }
+
+    /// Append a message received `offset_us` after the start, offsets must not decrease
+    pub fn write(&mut self, offset_us: u64, message: &UdpMessage) -> Result<()> {
+        if self
+            .index
+            .last()
+            .is_some_and(|last| last.offset_us > offset_us)
+        {
+            return Err(anyhow::anyhow!(
+                "Record offset {} goes back in time",
+                offset_us
+            ));
+        }
+        let envelope = message.cbor_serialize()?;
+        self.out.write_all(&(envelope.len() as u32).to_le_bytes())?;
+        self.out.write_all(&offset_us.to_le_bytes())?;
+        self.out.write_all(&envelope)?;
+        self.index.push(IndexEntry {
+            offset_us,
+            position: self.position,
+        });
+        self.position += RECORD_HEADER_LEN + envelope.len() as u64;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        Ok(self.out.flush()?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Write the index and trailer
+    pub fn finish(mut self) -> Result<W> {
+        let index_position = self.position;
+        self.out
+            .write_all(&(self.index.len() as u32).to_le_bytes())?;
+        for entry in &self.index {
+            self.out.write_all(&entry.offset_us.to_le_bytes())?;
+            self.out.write_all(&entry.position.to_le_bytes())?;
+        }
+        self.out.write_all(&index_position.to_le_bytes())?;
+        self.out.write_all(INDEX_MAGIC)?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+pub struct RecordReader<R: Read + Seek> {
+    input: R,
+    start_us: u64,
+    index: Vec<IndexEntry>,
+}
+
+impl RecordReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        RecordReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> RecordReader<R> {
+    pub fn new(mut input: R) -> Result<Self> {
+        let mut magic = [0u8; 8];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(anyhow::anyhow!("Not a limeros recording"));
+        }
+        let version = read_u16(&mut input)?;
+        if version != VERSION {
+            return Err(anyhow::anyhow!("Unsupported recording version {}", version));
+        }
+        let start_us = read_u64(&mut input)?;
+        let index = match read_index(&mut input)? {
+            Some(index) => index,
+            None => scan(&mut input)?,
+        };
+        Ok(Self {
+            input,
+            start_us,
+            index,
+        })
+    }
+
+    pub fn start_us(&self) -> u64 {
+        self.start_us
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn index(&self) -> &[IndexEntry] {
+        &self.index
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_micros(self.index.last().map(|e| e.offset_us).unwrap_or(0))
+    }
+
+    /// Position of the first record at or after `offset_us`
+    pub fn seek_time(&self, offset_us: u64) -> usize {
+        self.index.partition_point(|e| e.offset_us < offset_us)
+    }
+
+    pub fn read(&mut self, position: usize) -> Result<Record> {
+        let entry = self
+            .index
+            .get(position)
+            .ok_or_else(|| anyhow::anyhow!("No record {}", position))?;
+        self.input.seek(SeekFrom::Start(entry.position))?;
+        let (offset_us, envelope) = read_record(&mut self.input)?;
+        Ok(Record {
+            offset_us,
+            message: UdpMessage::cbor_deserialize(&envelope)?,
+        })
+    }
+
+    /// Records from `position` on
+    pub fn records(&mut self, position: usize) -> impl Iterator<Item = Result<Record>> + '_ {
+        (position..self.index.len()).map(move |i| self.read(i))
+    }
+}
+
+fn read_u16<R: Read>(input: &mut R) -> Result<u16> {
+    let mut bytes = [0u8; 2];
+    input.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn read_u32<R: Read>(input: &mut R) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(input: &mut R) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    input.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_record<R: Read>(input: &mut R) -> Result<(u64, Vec<u8>)> {
+    let length = read_u32(input)?;
+    let offset_us = read_u64(input)?;
+    let mut envelope = vec![0u8; length as usize];
+    input.read_exact(&mut envelope)?;
+    Ok((offset_us, envelope))
+}
+
+/*
+   index from the trailer, None when the file has no valid trailer
+*/
+fn read_index<R: Read + Seek>(input: &mut R) -> Result<Option<Vec<IndexEntry>>> {
+    let file_len = input.seek(SeekFrom::End(0))?;
+    if file_len < HEADER_LEN + 4 + TRAILER_LEN {
+        return Ok(None);
+    }
+    input.seek(SeekFrom::End(-(TRAILER_LEN as i64)))?;
+    let index_position = read_u64(input)?;
+    let mut magic = [0u8; 8];
+    input.read_exact(&mut magic)?;
+    if &magic != INDEX_MAGIC || index_position < HEADER_LEN || index_position >= file_len {
+        return Ok(None);
+    }
+    input.seek(SeekFrom::Start(index_position))?;
+    let count = read_u32(input)?;
+    if index_position + 4 + count as u64 * 16 + TRAILER_LEN != file_len {
+        return Ok(None);
+    }
+    let mut index = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        index.push(IndexEntry {
+            offset_us: read_u64(input)?,
+            position: read_u64(input)?,
+        });
+    }
+    Ok(Some(index))
+}
+
+/*
+   rebuild the index of an unfinished recording, a truncated last record is ignored
+*/
+fn scan<R: Read + Seek>(input: &mut R) -> Result<Vec<IndexEntry>> {
+    let file_len = input.seek(SeekFrom::End(0))?;
+    let mut position = input.seek(SeekFrom::Start(HEADER_LEN))?;
+    let mut index = vec![];
+    while position + RECORD_HEADER_LEN <= file_len {
+        let length = read_u32(input)? as u64;
+        let offset_us = read_u64(input)?;
+        if position + RECORD_HEADER_LEN + length > file_len {
+            break;
+        }
+        index.push(IndexEntry {
+            offset_us,
+            position,
+        });
+        position = input.seek(SeekFrom::Current(length as i64))?;
+    }
+    Ok(index)
+}
+
+/// Selection of the recorded messages to replay, empty lists select everything
+#[derive(Debug, Clone, Default)]
+pub struct RecordFilter {
+    pub sources: Vec<String>,
+    pub msg_types: Vec<String>,
+}
+
+impl RecordFilter {
+    pub fn matches(&self, message: &UdpMessage) -> bool {
+        let selected = |list: &Vec<String>, value: &Option<String>| {
+            list.is_empty() || value.as_ref().is_some_and(|v| list.contains(v))
+        };
+        selected(&self.sources, &message.src) && selected(&self.msg_types, &message.msg_type)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    Original,
+    /// 2.0 replays twice as fast
+    Scaled(f64),
+    /// one message per step, the replayer waits for the user
+    Stepped,
+}
+
+impl ReplaySpeed {
+    /// Wait between two records, None when stepping
+    pub fn delay(&self, previous_us: u64, offset_us: u64) -> Option<Duration> {
+        let gap = Duration::from_micros(offset_us.saturating_sub(previous_us));
+        match self {
+            ReplaySpeed::Original => Some(gap),
+            ReplaySpeed::Scaled(factor) if *factor > 0.0 => Some(gap.div_f64(*factor)),
+            ReplaySpeed::Scaled(_) => Some(Duration::ZERO),
+            ReplaySpeed::Stepped => None,
+        }
+    }
+}
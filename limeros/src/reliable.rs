@@ -0,0 +1,195 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use log::{info, warn};
+use tokio::time::Instant;
+
+use crate::msgs::UdpMessage;
+
+// --- CONSTANTS ---
+pub const ACK_MSG_TYPE: &str = "Ack";
+pub const RETRANSMIT_TIMEOUT: Duration = Duration::from_millis(300);
+pub const MAX_ATTEMPTS: u32 = 8;
+/// How long a message for an endpoint not yet seen by Scout is kept
+pub const UNDELIVERED_TTL: Duration = Duration::from_secs(5);
+pub const UNDELIVERED_MAX: usize = 1000;
+/// Number of sequence numbers remembered per peer for duplicate detection
+pub const SEQ_WINDOW: u32 = 64;
+
+/// Sliding window over the sequence numbers received from one peer
+#[derive(Debug, Default, Clone)]
+pub struct SeqWindow {
+    highest: Option<u32>,
+    mask: u64,
+}
+
+impl SeqWindow {
+    /// Returns false when `seq` was already received
+    pub fn accept(&mut self, seq: u32) -> bool {
+        let Some(highest) = self.highest else {
+            self.reset(seq);
+            return true;
+        };
+        let ahead = seq.wrapping_sub(highest);
+        let behind = highest.wrapping_sub(seq);
+        if ahead != 0 && ahead < u32::MAX / 2 {
+            self.mask = if ahead >= SEQ_WINDOW { 0 } else { self.mask << ahead };
+            self.mask |= 1;
+            self.highest = Some(seq);
+            true
+        } else if behind >= SEQ_WINDOW {
+            // far behind the window, the peer restarted its sequence
+            self.reset(seq);
+            true
+        } else {
+            let bit = 1u64 << behind;
+            let new = self.mask & bit == 0;
+            self.mask |= bit;
+            new
+        }
+    }
+
+    fn reset(&mut self, seq: u32) {
+        self.highest = Some(seq);
+        self.mask = 1;
+    }
+}
+
+struct Unacked {
+    message: UdpMessage,
+    sent_at: Instant,
+    attempts: u32,
+}
+
+/// Sequence numbers, retransmission and undelivered queue for reliable sends
+#[derive(Default)]
+pub struct ReliableState {
+    tx_seq: DashMap<String, u32>,
+    unacked: DashMap<(String, u32), Unacked>,
+    rx_windows: DashMap<String, SeqWindow>,
+    undelivered: std::sync::Mutex<VecDeque<(Instant, UdpMessage)>>,
+    // messages dropped from the undelivered queue, per destination
+    undelivered_dropped: DashMap<String, u64>,
+}
+
+impl ReliableState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_seq(&self, dst: &str) -> u32 {
+        let mut seq = self
+            .tx_seq
+            .entry(dst.to_string())
+            .or_insert_with(rand::random::<u32>);
+        let current = *seq;
+        *seq = current.wrapping_add(1);
+        current
+    }
+
+    /// Remember a sent message until it is acked
+    pub fn track(&self, message: &UdpMessage) {
+        let (Some(dst), Some(seq)) = (message.dst.clone(), message.seq) else {
+            return;
+        };
+        self.unacked.entry((dst, seq)).or_insert_with(|| Unacked {
+            message: message.clone(),
+            sent_at: Instant::now(),
+            attempts: 1,
+        });
+    }
+
+    pub fn ack(&self, src: &str, seq: u32) -> bool {
+        self.unacked.remove(&(src.to_string(), seq)).is_some()
+    }
+
+    pub fn unacked_count(&self) -> usize {
+        self.unacked.len()
+    }
+
+    /// Returns false for a duplicate of an already received message
+    pub fn accept(&self, src: &str, seq: u32) -> bool {
+        self.rx_windows
+            .entry(src.to_string())
+            .or_default()
+            .accept(seq)
+    }
+
+    /// Messages whose ack is overdue, messages out of attempts are dropped
+    pub fn due_retransmits(&self) -> Vec<UdpMessage> {
+        let mut due = vec![];
+        self.unacked.retain(|(dst, seq), unacked| {
+            if unacked.sent_at.elapsed() < RETRANSMIT_TIMEOUT {
+                return true;
+            }
+            if unacked.attempts >= MAX_ATTEMPTS {
+                warn!(
+                    "No ack for {:?} seq {} from {} after {} attempts",
+                    unacked.message.msg_type, seq, dst, unacked.attempts
+                );
+                return false;
+            }
+            unacked.attempts += 1;
+            unacked.sent_at = Instant::now();
+            due.push(unacked.message.clone());
+            true
+        });
+        due
+    }
+
+    /// Keep a message for an endpoint Scout has not discovered yet
+    pub fn queue_undelivered(&self, message: UdpMessage) {
+        let mut undelivered = self.undelivered.lock().unwrap();
+        if undelivered.len() >= UNDELIVERED_MAX {
+            if let Some((_, dropped)) = undelivered.pop_front() {
+                info!("Undelivered queue full, dropped {:?}", dropped.msg_type);
+                self.count_dropped(&dropped);
+            }
+        }
+        undelivered.push_back((Instant::now(), message));
+    }
+
+    /// Take the queued messages whose endpoint is now known, expired messages are dropped
+    pub fn take_deliverable<F>(&self, is_known: F) -> Vec<UdpMessage>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut deliverable = vec![];
+        self.undelivered.lock().unwrap().retain(|(queued_at, message)| {
+            let dst = message.dst.as_deref().unwrap_or("");
+            if is_known(dst) {
+                deliverable.push(message.clone());
+                false
+            } else if queued_at.elapsed() >= UNDELIVERED_TTL {
+                info!("Unknown endpoint: {}, dropped {:?}", dst, message.msg_type);
+                self.count_dropped(message);
+                false
+            } else {
+                true
+            }
+        });
+        deliverable
+    }
+
+    /// Messages waiting for their endpoint to be discovered
+    pub fn undelivered_count(&self) -> usize {
+        self.undelivered.lock().unwrap().len()
+    }
+
+    /// Messages dropped because their endpoint was not discovered in time, per destination
+    pub fn undelivered_dropped(&self) -> Vec<(String, u64)> {
+        let mut dropped: Vec<(String, u64)> = self
+            .undelivered_dropped
+            .iter()
+            .map(|d| (d.key().clone(), *d.value()))
+            .collect();
+        dropped.sort();
+        dropped
+    }
+
+    fn count_dropped(&self, message: &UdpMessage) {
+        let dst = message.dst.clone().unwrap_or_default();
+        *self.undelivered_dropped.entry(dst).or_insert(0) += 1;
+    }
+}
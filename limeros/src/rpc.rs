@@ -0,0 +1,194 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{oneshot, Mutex};
+
+use crate::codec::Codec;
+use crate::msgs::{Msg, TypedMessage, UdpMessage};
+use crate::txqueue::TxSender;
+use crate::UdpMessageHandler;
+
+// --- CONSTANTS ---
+/// Number of times a request is sent before giving up
+pub const RPC_ATTEMPTS: u32 = 3;
+/// Replies a service keeps for requests that are sent again
+pub const REPLY_CACHE_SIZE: usize = 64;
+
+#[derive(Debug)]
+pub enum RpcError {
+    /// No reply arrived within the timeout, after all attempts
+    Timeout {
+        dst: String,
+        msg_type: &'static str,
+        attempts: u32,
+    },
+    /// The TX queue or the reply channel was closed
+    QueueClosed,
+    Encode(anyhow::Error),
+    Decode(anyhow::Error),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Timeout {
+                dst,
+                msg_type,
+                attempts,
+            } => write!(
+                f,
+                "no {} from {} after {} attempts",
+                msg_type, dst, attempts
+            ),
+            RpcError::QueueClosed => write!(f, "queue closed"),
+            RpcError::Encode(e) => write!(f, "encode error: {}", e),
+            RpcError::Decode(e) => write!(f, "decode error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+/// A request waiting for its reply, keyed by correlation id in `UdpNode`
+pub(crate) struct PendingRequest {
+    pub msg_type: &'static str,
+    pub reply: oneshot::Sender<UdpMessage>,
+}
+
+/// Wait time per attempt. Each retry waits twice as long as the previous one,
+/// the sum of all attempts equals `timeout`.
+pub fn attempt_timeouts(timeout: Duration, attempts: u32) -> Vec<Duration> {
+    let attempts = attempts.clamp(1, 16);
+    let slots = (1u32 << attempts) - 1; // 1 + 2 + 4 + ...
+    let first = timeout / slots;
+    (0..attempts).map(|i| first * (1u32 << i)).collect()
+}
+
+enum Cached {
+    Running,
+    Replied(UdpMessage),
+}
+
+/// Requests by (source, correlation id) that a service has seen, the oldest are forgotten first
+#[derive(Default)]
+struct ReplyCache {
+    entries: HashMap<(String, u32), Cached>,
+    order: VecDeque<(String, u32)>,
+}
+
+impl ReplyCache {
+    /// None for a request that is new, it is marked as running
+    fn begin(&mut self, key: &(String, u32)) -> Option<&Cached> {
+        if !self.entries.contains_key(key) {
+            if self.order.len() >= REPLY_CACHE_SIZE {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+            self.entries.insert(key.clone(), Cached::Running);
+            return None;
+        }
+        self.entries.get(key)
+    }
+
+    fn finish(&mut self, key: &(String, u32), reply: Option<UdpMessage>) {
+        match reply {
+            Some(reply) => {
+                if let Some(cached) = self.entries.get_mut(key) {
+                    *cached = Cached::Replied(reply);
+                }
+            }
+            // a failed request runs again when it is retried
+            None => {
+                self.entries.remove(key);
+                self.order.retain(|k| k != key);
+            }
+        }
+    }
+}
+
+/// Server side of a request/reply pair, replies to the source of the request
+/// with the same correlation id and codec. A retried request gets the same reply
+/// again, the callback runs once per correlation id.
+pub struct ServiceWrapper<Req, Rep, F> {
+    callback: F,
+    node_id: Arc<Mutex<String>>,
+    sender: TxSender,
+    replies: std::sync::Mutex<ReplyCache>,
+    _marker: std::marker::PhantomData<(Req, Rep)>,
+}
+
+impl<Req, Rep, F> ServiceWrapper<Req, Rep, F> {
+    pub fn new(callback: F, node_id: Arc<Mutex<String>>, sender: TxSender) -> Self {
+        Self {
+            callback,
+            node_id,
+            sender,
+            replies: std::sync::Mutex::new(ReplyCache::default()),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Req, Rep, F, Fut> UdpMessageHandler for ServiceWrapper<Req, Rep, F>
+where
+    Req: TypedMessage + Msg,
+    Rep: TypedMessage + Msg,
+    F: Fn(String, Req) -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Rep> + Send,
+{
+    async fn handle(&self, udp_message: &UdpMessage) -> anyhow::Result<()> {
+        let source = udp_message
+            .src
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Request without source"))?;
+        let key = udp_message.correlation_id.map(|id| (source.clone(), id));
+        if let Some(key) = &key {
+            let cached = match self.replies.lock().unwrap().begin(key) {
+                None => None,
+                Some(Cached::Running) => return Ok(()),
+                Some(Cached::Replied(reply)) => Some(reply.clone()),
+            };
+            if let Some(reply) = cached {
+                return Ok(self.sender.send(reply).await?);
+            }
+        }
+        let packet = self.reply(source, udp_message).await;
+        if let Some(key) = &key {
+            self.replies
+                .lock()
+                .unwrap()
+                .finish(key, packet.as_ref().ok().cloned());
+        }
+        Ok(self.sender.send(packet?).await?)
+    }
+}
+
+impl<Req, Rep, F, Fut> ServiceWrapper<Req, Rep, F>
+where
+    Req: TypedMessage + Msg,
+    Rep: TypedMessage + Msg,
+    F: Fn(String, Req) -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Rep> + Send,
+{
+    async fn reply(&self, source: String, udp_message: &UdpMessage) -> anyhow::Result<UdpMessage> {
+        let payload = udp_message.payload.clone().unwrap_or_default();
+        let codec = Codec::of(udp_message);
+        let request: Req = codec.decode(&payload)?;
+        let reply = (self.callback)(source.clone(), request).await;
+
+        Ok(UdpMessage {
+            src: Some(self.node_id.lock().await.clone()),
+            dst: Some(source),
+            msg_type: Some(Rep::MSG_TYPE.to_string()),
+            payload: Some(codec.encode(&reply)?),
+            codec: Some(codec),
+            correlation_id: udp_message.correlation_id,
+            ..Default::default()
+        })
+    }
+}
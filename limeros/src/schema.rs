@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::msgs::{self, Codec, TypedMessage, UdpMessage};
+pub use crate::msgs::{EnumDescriptor, FieldDescriptor, MessageDescriptor};
+
+/*
+   Runtime view of the message types generated from message.proto. The registry knows the
+   fields of every registered type, so tools can decode a JSON or CBOR payload into a Value
+   tree without depending on the Rust type. A payload of an unknown type is decoded as is :
+   CBOR map keys are then the field indexes instead of the field names.
+*/
+
+/// Type of a field, resolved against the registered enums and messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    Bool,
+    Int,
+    UInt,
+    Float,
+    Text,
+    Bytes,
+    Enum(&'static str),
+    Message(&'static str),
+    Unknown(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+    List(Vec<Value>),
+    /// fields in declaration order
+    Map(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        match self {
+            Value::Map(fields) => fields.iter().find(|(k, _)| k == field).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+            _ => None,
+        }
+    }
+
+    /// Leaves of the tree with their path, "motor.speed" or "peers.1"
+    pub fn flatten(&self) -> Vec<(String, Value)> {
+        let mut leaves = vec![];
+        self.flatten_into(String::new(), &mut leaves);
+        leaves
+    }
+
+    fn flatten_into(&self, path: String, leaves: &mut Vec<(String, Value)>) {
+        let child = |name: &str| {
+            if path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}.{}", path, name)
+            }
+        };
+        match self {
+            Value::Map(fields) => fields
+                .iter()
+                .for_each(|(k, v)| v.flatten_into(child(k), leaves)),
+            Value::List(items) => items
+                .iter()
+                .enumerate()
+                .for_each(|(i, v)| v.flatten_into(child(&i.to_string()), leaves)),
+            leaf => leaves.push((path, leaf.clone())),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Value::Null => serde_json::Value::Null,
+            Value::Bool(b) => (*b).into(),
+            Value::Int(i) => (*i).into(),
+            Value::Float(f) => (*f).into(),
+            Value::Text(s) => s.clone().into(),
+            Value::Bytes(bytes) => hex(bytes).into(),
+            Value::List(items) => items.iter().map(Value::to_json).collect(),
+            Value::Map(fields) => fields
+                .iter()
+                .map(|(k, v)| (k.clone(), v.to_json()))
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+        }
+    }
+
+    fn from_json(json: serde_json::Value) -> Value {
+        match json {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(b) => Value::Bool(b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Value::Int(i),
+                None => Value::Float(n.as_f64().unwrap_or(f64::NAN)),
+            },
+            serde_json::Value::String(s) => Value::Text(s),
+            serde_json::Value::Array(items) => {
+                Value::List(items.into_iter().map(Value::from_json).collect())
+            }
+            serde_json::Value::Object(map) => Value::Map(
+                map.into_iter()
+                    .map(|(k, v)| (k, Value::from_json(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn from_cbor(cbor: serde_cbor::Value) -> Value {
+        use serde_cbor::Value as Cbor;
+        match cbor {
+            Cbor::Bool(b) => Value::Bool(b),
+            Cbor::Integer(i) => match i64::try_from(i) {
+                Ok(i) => Value::Int(i),
+                Err(_) => Value::Float(i as f64),
+            },
+            Cbor::Float(f) => Value::Float(f),
+            Cbor::Text(s) => Value::Text(s),
+            Cbor::Bytes(bytes) => Value::Bytes(bytes),
+            Cbor::Array(items) => Value::List(items.into_iter().map(Value::from_cbor).collect()),
+            Cbor::Map(map) => Value::Map(
+                map.into_iter()
+                    .map(|(k, v)| (cbor_key(&k), Value::from_cbor(v)))
+                    .collect(),
+            ),
+            Cbor::Tag(_, value) => Value::from_cbor(*value),
+            _ => Value::Null,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Text(s) => write!(f, "{}", s),
+            Value::Bytes(bytes) => write!(f, "0x{}", hex(bytes)),
+            list_or_map => write!(f, "{}", list_or_map.to_json()),
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn cbor_key(key: &serde_cbor::Value) -> String {
+    match key {
+        serde_cbor::Value::Text(s) => s.clone(),
+        serde_cbor::Value::Integer(i) => i.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    messages: HashMap<&'static str, MessageDescriptor>,
+    ids: HashMap<u32, &'static str>,
+    enums: HashMap<&'static str, EnumDescriptor>,
+}
+
+impl Registry {
+    /// Registry of all the types in `msgs`
+    pub fn builtin() -> Self {
+        let mut registry = Registry::default();
+        msgs::MESSAGES
+            .iter()
+            .for_each(|m| registry.register_descriptor(*m));
+        msgs::ENUMS.iter().for_each(|e| registry.register_enum(*e));
+        registry
+    }
+
+    pub fn register<T: TypedMessage>(&mut self) {
+        self.register_descriptor(MessageDescriptor {
+            name: T::MSG_TYPE,
+            id: T::ID,
+            fields: T::FIELDS,
+        });
+    }
+
+    pub fn register_descriptor(&mut self, descriptor: MessageDescriptor) {
+        self.ids.insert(descriptor.id, descriptor.name);
+        self.messages.insert(descriptor.name, descriptor);
+    }
+
+    pub fn register_enum(&mut self, descriptor: EnumDescriptor) {
+        self.enums.insert(descriptor.name, descriptor);
+    }
+
+    pub fn get(&self, msg_type: &str) -> Option<&MessageDescriptor> {
+        self.messages.get(msg_type)
+    }
+
+    pub fn get_by_id(&self, id: u32) -> Option<&MessageDescriptor> {
+        self.ids.get(&id).and_then(|name| self.messages.get(name))
+    }
+
+    /// Registered messages sorted by name
+    pub fn messages(&self) -> Vec<&MessageDescriptor> {
+        let mut messages: Vec<&MessageDescriptor> = self.messages.values().collect();
+        messages.sort_by_key(|m| m.name);
+        messages
+    }
+
+    pub fn field_type(&self, field: &FieldDescriptor) -> FieldType {
+        match field.type_name {
+            "bool" => FieldType::Bool,
+            "i32" | "i64" => FieldType::Int,
+            "u32" | "u64" => FieldType::UInt,
+            "f32" | "f64" => FieldType::Float,
+            "String" => FieldType::Text,
+            "Vec<u8>" => FieldType::Bytes,
+            name if self.enums.contains_key(name) => FieldType::Enum(name),
+            name if self.messages.contains_key(name) => FieldType::Message(name),
+            name => FieldType::Unknown(name),
+        }
+    }
+
+    /// Field names and types of a registered message
+    pub fn fields(&self, msg_type: &str) -> Option<Vec<(&'static str, FieldType)>> {
+        self.get(msg_type).map(|m| {
+            m.fields
+                .iter()
+                .map(|f| (f.name, self.field_type(f)))
+                .collect()
+        })
+    }
+
+    /// Decode the payload of a registered message type
+    pub fn decode(&self, msg_type: &str, codec: Codec, payload: &[u8]) -> Result<Value> {
+        let descriptor = self
+            .get(msg_type)
+            .ok_or_else(|| anyhow::anyhow!("Unknown message type {}", msg_type))?;
+        let value = match codec {
+            Codec::Json => Value::from_json(serde_json::from_slice(payload)?),
+            Codec::Cbor => Value::from_cbor(serde_cbor::from_slice(payload)?),
+        };
+        self.typed(descriptor, codec, value)
+    }
+
+    /// Decode the payload of any message, as is when the type is unknown and as bytes
+    /// when it can't be parsed at all
+    pub fn decode_message(&self, udp_message: &UdpMessage) -> Value {
+        let Some(payload) = udp_message.payload.as_deref() else {
+            return Value::Null;
+        };
+        let codec = Codec::of(udp_message);
+        if let Some(msg_type) = udp_message.msg_type.as_deref() {
+            if let Ok(value) = self.decode(msg_type, codec, payload) {
+                return value;
+            }
+        }
+        let raw = match codec {
+            Codec::Json => serde_json::from_slice(payload).map(Value::from_json).ok(),
+            Codec::Cbor => serde_cbor::from_slice(payload).map(Value::from_cbor).ok(),
+        };
+        raw.unwrap_or_else(|| Value::Bytes(payload.to_vec()))
+    }
+
+    /*
+       names the fields of a decoded map and gives each value the type of its field,
+       fields that are not in the descriptor are kept under their own key
+    */
+    fn typed(&self, descriptor: &MessageDescriptor, codec: Codec, value: Value) -> Result<Value> {
+        let Value::Map(entries) = value else {
+            return Err(anyhow::anyhow!("{} payload is not a map", descriptor.name));
+        };
+        let mut entries: Vec<Option<(String, Value)>> = entries.into_iter().map(Some).collect();
+        let mut fields = vec![];
+        for field in descriptor.fields {
+            let key = match codec {
+                Codec::Json => field.name.to_string(),
+                Codec::Cbor => field.index.to_string(),
+            };
+            let Some((_, value)) = entries
+                .iter_mut()
+                .find(|e| e.as_ref().is_some_and(|(k, _)| *k == key))
+                .and_then(Option::take)
+            else {
+                continue;
+            };
+            let value = match value {
+                Value::List(items) if field.repeated => Value::List(
+                    items
+                        .into_iter()
+                        .map(|item| self.typed_field(field, codec, item))
+                        .collect::<Result<_>>()?,
+                ),
+                value => self.typed_field(field, codec, value)?,
+            };
+            fields.push((field.name.to_string(), value));
+        }
+        fields.extend(entries.into_iter().flatten());
+        Ok(Value::Map(fields))
+    }
+
+    fn typed_field(&self, field: &FieldDescriptor, codec: Codec, value: Value) -> Result<Value> {
+        Ok(match (self.field_type(field), value) {
+            (FieldType::Float, Value::Int(i)) => Value::Float(i as f64),
+            (FieldType::Bytes, Value::List(items)) => Value::Bytes(
+                items
+                    .iter()
+                    .map(|i| i.as_f64().unwrap_or_default() as u8)
+                    .collect(),
+            ),
+            // minicbor encodes a unit variant as [index, []]
+            (FieldType::Enum(name), Value::List(items)) if !items.is_empty() => {
+                self.enum_value(name, items[0].clone())
+            }
+            (FieldType::Enum(name), value) => self.enum_value(name, value),
+            (FieldType::Message(name), value) => self.typed(&self.messages[name], codec, value)?,
+            (_, value) => value,
+        })
+    }
+
+    fn enum_value(&self, name: &str, value: Value) -> Value {
+        let Value::Int(index) = value else {
+            return value;
+        };
+        self.enums[name]
+            .values
+            .iter()
+            .find(|(_, n)| *n as i64 == index)
+            .map(|(variant, _)| Value::Text(variant.to_string()))
+            .unwrap_or(Value::Int(index))
+    }
+}
@@ -1,20 +1,46 @@
 use anyhow::Result;
 use dashmap::DashMap;
 use log::{debug, error, info};
-use socket2::{Domain, Protocol, Socket, Type};
-use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
-use tokio::net::UdpSocket;
+use std::time::Duration;
+use tokio::sync::broadcast;
 use tokio::task::JoinHandle;
+use tokio::time::Instant;
 
 
+use crate::codec::Codec;
+use crate::interface::{self, NetInterface};
 use crate::msgs::{Alive, Msg, UdpMessage};
+use crate::security::Security;
+use crate::transport::{Network, Transport, UdpNetwork};
 
 // --- CONSTANTS ---
 pub const MULTICAST_PORT: u16 = 50000;
-pub const HEARTBEAT_INTERVAL: u64 = 3;
+/// Default seconds between Alive messages and between checks for dead endpoints
+pub const HEARTBEAT_INTERVAL: u64 = 1;
+/// Default seconds without Alive after which an endpoint is removed
 pub const PEER_TIMEOUT: u64 = 6;
+pub const EVENT_CAPACITY: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct ScoutConfig {
+    pub heartbeat_interval: Duration,
+    pub peer_timeout: Duration,
+    /// Interface names or addresses to join the multicast group on, empty lets the OS choose
+    pub interfaces: Vec<String>,
+}
+
+impl Default for ScoutConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval: Duration::from_secs(HEARTBEAT_INTERVAL),
+            peer_timeout: Duration::from_secs(PEER_TIMEOUT),
+            interfaces: vec![],
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Subscription {
@@ -31,49 +57,111 @@ pub struct Endpoint {
     pub subscribe: Vec<String>,
     pub publish: Vec<String>,
     pub services: Vec<String>,
+    /// Name of the local interface the endpoint was seen on
+    pub interface: Option<String>,
+}
+
+impl Endpoint {
+    /// Same address and advertised lists, the last_seen time is ignored
+    pub fn same_as(&self, other: &Endpoint) -> bool {
+        self.addr == other.addr
+            && self.interface == other.interface
+            && self.subscribe == other.subscribe
+            && self.publish == other.publish
+            && self.services == other.services
+    }
+}
+
+/// Presence of endpoints as seen by Scout
+#[derive(Clone, Debug)]
+pub enum EndpointEvent {
+    EndpointJoined(Endpoint),
+    EndpointLeft(Endpoint),
+    /// address moved or subscribe/publish/services lists changed
+    EndpointChanged { old: Endpoint, new: Endpoint },
+}
+
+impl EndpointEvent {
+    /// Event for an Alive received from `new`, `old` is the endpoint known before
+    pub fn on_alive(old: Option<Endpoint>, new: &Endpoint) -> Option<EndpointEvent> {
+        match old {
+            None => Some(EndpointEvent::EndpointJoined(new.clone())),
+            Some(old) if !old.same_as(new) => Some(EndpointEvent::EndpointChanged {
+                old,
+                new: new.clone(),
+            }),
+            Some(_) => None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            EndpointEvent::EndpointJoined(endpoint) | EndpointEvent::EndpointLeft(endpoint) => {
+                &endpoint.name
+            }
+            EndpointEvent::EndpointChanged { new, .. } => &new.name,
+        }
+    }
 }
 
 //======================================================================================================================
 pub struct Scout {
     multicast_addr: SocketAddr,
-    multicast_socket: Arc<UdpSocket>,
+    multicast_socket: Arc<dyn Transport>,
+    interfaces: Vec<NetInterface>,
     pub endpoints: Arc<DashMap<String, Endpoint>>,
     pub subscriptions: Arc<DashMap<String, Vec<Subscription>>>,
+    subscriptions_version: AtomicU64,
+    security: Option<Arc<Security>>,
+    config: ScoutConfig,
+    events: broadcast::Sender<EndpointEvent>,
 }
 
 impl Scout {
     pub async fn new(multicast_str: String) -> Result<Arc<Self>> {
-        let multicast_addr: SocketAddrV4 = multicast_str.parse()?;
-        let socket2 = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
-        socket2.set_reuse_address(true)?;
-        #[cfg(not(windows))]
-        socket2.set_reuse_port(true)?; // Unix specific, helps with load balancing/binding
-
-        // Bind to 0.0.0.0:5000 (or multicast IP directly on Windows sometimes)
-        socket2.bind(
-            &format!("0.0.0.0:{}", MULTICAST_PORT)
-                .parse::<SocketAddr>()
-                .unwrap()
-                .into(),
-        )?;
-
-        // Join the group
-        socket2.join_multicast_v4(&multicast_addr.ip(), &Ipv4Addr::UNSPECIFIED)?;
-        socket2.set_nonblocking(true)?;
-
-        let multicast_socket = UdpSocket::from_std(socket2.into())?;
-        info!("Multicast socket bound to {:?}", multicast_addr);
+        let network: Arc<dyn Network> = Arc::new(UdpNetwork);
+        Scout::with_config(multicast_str, ScoutConfig::default(), None, network).await
+    }
+
+    /// With security, Alive messages that are not sealed with the network key are rejected.
+    /// The multicast socket is opened on `network`, UDP or in memory for tests.
+    pub async fn with_config(
+        multicast_str: String,
+        config: ScoutConfig,
+        security: Option<Arc<Security>>,
+        network: Arc<dyn Network>,
+    ) -> Result<Arc<Self>> {
+        let multicast_addr: SocketAddr = multicast_str.parse()?;
+        if !multicast_addr.ip().is_multicast() {
+            return Err(anyhow::anyhow!("Not a multicast address: {}", multicast_addr));
+        }
+        let ipv6 = multicast_addr.is_ipv6();
+        let interfaces = network.interfaces(&config.interfaces, ipv6)?;
+        let multicast_socket = network.bind_multicast(multicast_addr, &interfaces)?;
+        info!(
+            "Multicast socket bound to {:?} on {:?}",
+            multicast_addr,
+            interfaces.iter().map(|i| i.name.clone()).collect::<Vec<String>>()
+        );
         Ok(Arc::new(Self {
-            multicast_addr: SocketAddr::V4(multicast_addr),
-            multicast_socket: Arc::new(multicast_socket),
+            multicast_addr,
+            multicast_socket,
+            interfaces,
             endpoints: Arc::new(DashMap::new()),
             subscriptions: Arc::new(DashMap::new()),
+            subscriptions_version: AtomicU64::new(0),
+            security,
+            config,
+            events: broadcast::channel(EVENT_CAPACITY).0,
         }))
     }
 
     pub(crate) fn handle_multicast_packet(&self, data: &[u8], addr: SocketAddr) -> Result<()> {
         let data_vec = data.to_vec();
-        let udp_message = UdpMessage::cbor_deserialize(&data_vec)?;
+        let mut udp_message = UdpMessage::cbor_deserialize(&data_vec)?;
+        if let Some(security) = &self.security {
+            udp_message = security.open(udp_message)?;
+        }
 
         debug!(
             "MC Recv {:?} from {:?}",
@@ -95,23 +183,35 @@ impl Scout {
 
     //    let subscriptions = self.subscriptions.clone();
 
-        let alive = Alive::json_deserialize(payload)?;
+        let alive: Alive = Codec::of(&udp_message).decode(payload)?;
         let subscribe = alive.subscribe.clone().unwrap_or_default();
         let publish = alive.publish.clone().unwrap_or_default();
         let services = alive.services.clone().unwrap_or_default();
 
+        // A node on several interfaces is heard on each, stay on the current address while it is alive
+        if let Some(current) = self.endpoints.get(&src) {
+            if current.addr != addr && current.last_seen.elapsed() < self.config.heartbeat_interval * 2 {
+                debug!("{} also seen at {}, staying on {}", src, addr, current.addr);
+                return Ok(());
+            }
+        }
+
         // Update endpoint
-        self.endpoints.insert(
-            src.clone(),
-            Endpoint {
-                name: src.clone(),
-                addr,
-                last_seen: Instant::now(),
-                subscribe: subscribe.clone(),
-                publish: publish.clone(),
-                services: services.clone(),
-            },
-        );
+        let endpoint = Endpoint {
+            name: src.clone(),
+            addr,
+            last_seen: Instant::now(),
+            subscribe: subscribe.clone(),
+            publish: publish.clone(),
+            services: services.clone(),
+            interface: interface::interface_of(&self.interfaces, &addr).map(|i| i.name.clone()),
+        };
+        let previous = self.endpoints.insert(src.clone(), endpoint.clone());
+
+        // Drop subscriptions no longer advertised
+        if previous.as_ref().is_some_and(|old| old.subscribe != subscribe) {
+            self.remove_subscriptions(&src, |pattern| !subscribe.iter().any(|s| s == pattern));
+        }
 
         // Update subscriptions
         if let Some(subs) = alive.subscribe.as_ref() {
@@ -130,14 +230,39 @@ impl Scout {
                     existing.last_seen = Instant::now();
                 } else {
                     entry.push(subscription);
+                    self.subscriptions_version.fetch_add(1, Ordering::Relaxed);
                 }
                 debug!("Updated subscription for {} to {}", sub, src);
             }
         }
 
+        if let Some(event) = EndpointEvent::on_alive(previous, &endpoint) {
+            info!("{:?}", event);
+            let _ = self.events.send(event);
+        }
+
         Ok(())
     }
 
+    /*
+       remove the subscriptions of destination for which the pattern filter returns true
+    */
+    fn remove_subscriptions<F>(&self, destination: &str, filter: F)
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.subscriptions.iter_mut().for_each(|mut sub_entry| {
+            if filter(sub_entry.key()) {
+                sub_entry
+                    .value_mut()
+                    .retain(|s| s.destination != destination);
+            }
+        });
+        // if no more subscriptions for a key, remove the key
+        self.subscriptions.retain(|_k, v| !v.is_empty());
+        self.subscriptions_version.fetch_add(1, Ordering::Relaxed);
+    }
+
     fn start_multicast_receiver(node: Arc<Self>) -> JoinHandle<()> {
         let multicast_socket = node.multicast_socket.clone();
         let discovery_node = node.clone();
@@ -157,30 +282,27 @@ impl Scout {
 
     async fn prune(scout: Arc<Self>) -> JoinHandle<()> {
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(HEARTBEAT_INTERVAL));
+            let peer_timeout = scout.config.peer_timeout;
+            let mut interval = tokio::time::interval(scout.config.heartbeat_interval);
             loop {
                 interval.tick().await;
-                let peer_count = scout.endpoints.len();
-                scout.endpoints.iter_mut().for_each(|mut entry| {
-                    let endpoint = &mut *entry.value_mut();
-                    if endpoint.last_seen.elapsed().as_secs() >= PEER_TIMEOUT {
-                        info!("Endpoint {} timed out", entry.key());
+                let timed_out: Vec<String> = scout
+                    .endpoints
+                    .iter()
+                    .filter(|entry| entry.value().last_seen.elapsed() >= peer_timeout)
+                    .map(|entry| entry.key().clone())
+                    .collect();
+                for name in timed_out {
+                    // the endpoint may have sent an Alive in the meantime
+                    let removed = scout
+                        .endpoints
+                        .remove_if(&name, |_, endpoint| endpoint.last_seen.elapsed() >= peer_timeout);
+                    if let Some((_, endpoint)) = removed {
+                        info!("Endpoint {} timed out", name);
                         // remove subscriptions where destination matches
-                        scout.subscriptions.iter_mut().for_each(|mut sub_entry| {
-                            sub_entry
-                                .value_mut()
-                                .retain(|s| s.destination != *entry.key());
-                        });
-                        // if no more subscriptions for a key, remove the key
-                        scout.subscriptions.retain(|_k, v| !v.is_empty());
+                        scout.remove_subscriptions(&name, |_| true);
+                        let _ = scout.events.send(EndpointEvent::EndpointLeft(endpoint));
                     }
-                });
-                scout
-                    .endpoints
-                    .retain(|_, endpoint| endpoint.last_seen.elapsed().as_secs() < PEER_TIMEOUT);
-                let pruned_count = peer_count - scout.endpoints.len();
-                if pruned_count > 0 {
-                    info!("pruned {} dead endpoints", pruned_count);
                 }
                 debug!(
                     "Endpoints {:?}",
@@ -211,6 +333,36 @@ impl Scout {
         })
     }
 
+    /// Stream of endpoints joining, leaving and changing
+    pub fn events(&self) -> broadcast::Receiver<EndpointEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn config(&self) -> &ScoutConfig {
+        &self.config
+    }
+
+    pub fn multicast_addr(&self) -> SocketAddr {
+        self.multicast_addr
+    }
+
+    /// Selected interfaces, empty when the OS chooses
+    pub fn interfaces(&self) -> &[NetInterface] {
+        &self.interfaces
+    }
+
+    /// Interface name an endpoint was seen on
+    pub fn endpoint_interface(&self, endpoint: &str) -> Option<String> {
+        self.endpoints
+            .get(endpoint)
+            .and_then(|entry| entry.value().interface.clone())
+    }
+
+    /// Incremented each time a subscription is added or removed
+    pub fn subscriptions_version(&self) -> u64 {
+        self.subscriptions_version.load(Ordering::Relaxed)
+    }
+
     pub fn endpoint_to_addr(&self, endpoint: &str) -> Option<SocketAddr> {
         if let Some(entry) = self.endpoints.get(endpoint) {
             Some(entry.value().addr)
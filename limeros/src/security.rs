@@ -0,0 +1,230 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use dashmap::DashMap;
+use serde::Deserialize;
+
+use crate::msgs::UdpMessage;
+
+// --- CONSTANTS ---
+/// Out of order packets from one peer are accepted within this window
+pub const REPLAY_WINDOW_US: u64 = 5_000_000;
+pub const DEFAULT_MAX_CLOCK_SKEW_SECS: u64 = 30;
+const NONCE_LEN: usize = 12;
+
+/*
+   Pre-shared key configuration, e.g. limeros.json :
+   {
+       "network": "mower",
+       "key": "<64 hex digits>",
+       "max_clock_skew_secs": 30
+   }
+*/
+#[derive(Debug, Clone, Deserialize)]
+pub struct SecurityConfig {
+    pub network: String,
+    pub key: String,
+    #[serde(default = "default_max_clock_skew_secs")]
+    pub max_clock_skew_secs: u64,
+}
+
+fn default_max_clock_skew_secs() -> u64 {
+    DEFAULT_MAX_CLOCK_SKEW_SECS
+}
+
+impl SecurityConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let data = std::fs::read(path.as_ref())?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+}
+
+/// Timestamps of the nonces received from one sender
+#[derive(Debug, Default)]
+pub struct ReplayWindow {
+    highest: u64,
+    recent: BTreeSet<u64>,
+}
+
+impl ReplayWindow {
+    /// Returns false for a timestamp already seen or older than the window
+    pub fn accept(&mut self, timestamp: u64) -> bool {
+        if timestamp + REPLAY_WINDOW_US < self.highest {
+            return false;
+        }
+        if !self.recent.insert(timestamp) {
+            return false;
+        }
+        if timestamp > self.highest {
+            self.highest = timestamp;
+            let oldest = self.highest.saturating_sub(REPLAY_WINDOW_US);
+            self.recent = self.recent.split_off(&oldest);
+        }
+        true
+    }
+}
+
+/// AEAD ( ChaCha20-Poly1305 ) over the payload of every `UdpMessage`, the envelope
+/// is authenticated as associated data. The nonce is a microsecond timestamp and
+/// 4 random bytes, so receivers can reject replays and stale packets.
+pub struct Security {
+    network: String,
+    cipher: ChaCha20Poly1305,
+    max_clock_skew_us: u64,
+    last_timestamp: AtomicU64,
+    // per authenticated `src`, a transport address can be spoofed or change
+    windows: DashMap<String, ReplayWindow>,
+    last_eviction: AtomicU64,
+}
+
+impl Security {
+    pub fn new(config: &SecurityConfig) -> Result<Self> {
+        let key = hex::decode(config.key.trim())?;
+        if key.len() != 32 {
+            return Err(anyhow::anyhow!("Key must be 32 bytes, got {}", key.len()));
+        }
+        Ok(Self {
+            network: config.network.clone(),
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+            max_clock_skew_us: config.max_clock_skew_secs * 1_000_000,
+            last_timestamp: AtomicU64::new(0),
+            windows: DashMap::new(),
+            last_eviction: AtomicU64::new(0),
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new(&SecurityConfig::load(path)?)
+    }
+
+    pub fn seal(&self, message: UdpMessage) -> Result<UdpMessage> {
+        // strictly increasing, two packets never share a nonce
+        let now = now_us();
+        let timestamp = self
+            .last_timestamp
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+                Some(now.max(last + 1))
+            })
+            .map(|last| now.max(last + 1))
+            .unwrap_or(now);
+        self.seal_at(message, timestamp)
+    }
+
+    /// Seal with an explicit nonce timestamp in microseconds since the epoch
+    pub fn seal_at(&self, message: UdpMessage, timestamp: u64) -> Result<UdpMessage> {
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..8].copy_from_slice(&timestamp.to_be_bytes());
+        nonce[8..].copy_from_slice(&rand::random::<[u8; 4]>());
+        let plaintext = message.payload.clone().unwrap_or_default();
+        let aad = self.associated_data(&message);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| anyhow::anyhow!("Encryption failed"))?;
+        Ok(UdpMessage {
+            payload: Some(ciphertext),
+            nonce: Some(nonce.to_vec()),
+            ..message
+        })
+    }
+
+    /// Decrypt and verify a message, rejects unsealed, tampered, replayed and
+    /// stale messages. Replays are tracked per `src`, which is authenticated.
+    pub fn open(&self, message: UdpMessage) -> Result<UdpMessage> {
+        let nonce = message
+            .nonce
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Unauthenticated message"))?;
+        if nonce.len() != NONCE_LEN {
+            return Err(anyhow::anyhow!("Invalid nonce length {}", nonce.len()));
+        }
+        let timestamp = u64::from_be_bytes(nonce[..8].try_into()?);
+        if self.max_clock_skew_us > 0 && now_us().abs_diff(timestamp) > self.max_clock_skew_us {
+            return Err(anyhow::anyhow!("Stale message"));
+        }
+        let ciphertext = message.payload.clone().unwrap_or_default();
+        let aad = self.associated_data(&message);
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| anyhow::anyhow!("Authentication failed"))?;
+        // only authentic packets move the window
+        let sender = message.src.clone().unwrap_or_default();
+        if !self.windows.entry(sender).or_default().accept(timestamp) {
+            return Err(anyhow::anyhow!("Replayed message"));
+        }
+        self.evict_idle();
+        Ok(UdpMessage {
+            payload: Some(plaintext),
+            nonce: None,
+            ..message
+        })
+    }
+
+    /// Number of senders with a replay window
+    pub fn senders(&self) -> usize {
+        self.windows.len()
+    }
+
+    // A window whose newest nonce is older than the clock skew only rejects what is
+    // stale anyway, so it can go. Without a skew limit the windows are kept.
+    fn evict_idle(&self) {
+        if self.max_clock_skew_us == 0 {
+            return;
+        }
+        let now = now_us();
+        let last = self.last_eviction.load(Ordering::Relaxed);
+        if now.saturating_sub(last) < self.max_clock_skew_us
+            || self
+                .last_eviction
+                .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+        let oldest = now.saturating_sub(self.max_clock_skew_us);
+        self.windows.retain(|_, window| window.highest >= oldest);
+    }
+
+    fn associated_data(&self, message: &UdpMessage) -> Vec<u8> {
+        let fields = [
+            Some(self.network.clone()),
+            message.src.clone(),
+            message.dst.clone(),
+            message.msg_type.clone(),
+            message.correlation_id.map(|c| c.to_string()),
+            message.seq.map(|s| s.to_string()),
+            message.codec.map(|c| format!("{:?}", c)),
+        ];
+        fields
+            .iter()
+            .map(|f| f.clone().unwrap_or_default())
+            .collect::<Vec<String>>()
+            .join("\0")
+            .into_bytes()
+    }
+}
+
+fn now_us() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(0)
+}
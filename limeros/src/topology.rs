@@ -0,0 +1,336 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::msgs::BrokerRatesRep;
+use crate::pattern::{Chunk, KeyPattern};
+use crate::scout::{Endpoint, Scout};
+
+/*
+   Graph of who talks to whom, built from what the endpoints advertise in Alive :
+   endpoint -> topic for each msg_type published, topic -> endpoint for each subscription and
+   service -> endpoint for each service. A subscription pattern is drawn from the topics it
+   matches, or as a pattern node when it matches no known publication.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Endpoint,
+    Topic,
+    Pattern,
+    Service,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub kind: NodeKind,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeKind {
+    Publish,
+    Subscribe,
+    Serve,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+    /// messages per second observed by the broker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+}
+
+/// Messages per second from `src` of `msg_type`, received by the broker when `dst` is None
+/// and forwarded to `dst` otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowRate {
+    pub src: String,
+    pub dst: Option<String>,
+    pub msg_type: String,
+    pub rate: f64,
+}
+
+impl FlowRate {
+    pub fn from_reply(reply: &BrokerRatesRep) -> Vec<FlowRate> {
+        let src = reply.src.clone().unwrap_or_default();
+        let dst = reply.dst.clone().unwrap_or_default();
+        let msg_type = reply.msg_type.clone().unwrap_or_default();
+        let rate = reply.rate.clone().unwrap_or_default();
+        src.into_iter()
+            .zip(dst)
+            .zip(msg_type)
+            .zip(rate)
+            .map(|(((src, dst), msg_type), rate)| FlowRate {
+                src,
+                dst: Some(dst).filter(|d| !d.is_empty()),
+                msg_type,
+                rate: rate as f64,
+            })
+            .collect()
+    }
+
+    pub fn to_reply(flows: &[FlowRate]) -> BrokerRatesRep {
+        BrokerRatesRep {
+            src: Some(flows.iter().map(|f| f.src.clone()).collect()),
+            dst: Some(
+                flows
+                    .iter()
+                    .map(|f| f.dst.clone().unwrap_or_default())
+                    .collect(),
+            ),
+            msg_type: Some(flows.iter().map(|f| f.msg_type.clone()).collect()),
+            rate: Some(flows.iter().map(|f| f.rate as f32).collect()),
+        }
+    }
+}
+
+fn node_id(kind: NodeKind, name: &str) -> String {
+    let prefix = match kind {
+        NodeKind::Endpoint => "endpoint",
+        NodeKind::Topic => "topic",
+        NodeKind::Pattern => "pattern",
+        NodeKind::Service => "service",
+    };
+    format!("{}:{}", prefix, name)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Topology {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl Topology {
+    pub fn from_scout(scout: &Scout) -> Self {
+        let endpoints: Vec<Endpoint> = scout.endpoints.iter().map(|e| e.value().clone()).collect();
+        let subscriptions: Vec<(String, String)> = scout
+            .subscriptions
+            .iter()
+            .flat_map(|entry| {
+                let pattern = entry.key().clone();
+                entry
+                    .value()
+                    .iter()
+                    .map(|s| (pattern.clone(), s.destination.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Topology::build(&endpoints, &subscriptions)
+    }
+
+    /// Graph of the endpoints, `subscriptions` are (pattern, destination) pairs
+    /// known besides the ones the endpoints advertise.
+    pub fn build(endpoints: &[Endpoint], subscriptions: &[(String, String)]) -> Self {
+        let mut topology = Topology::default();
+        let mut endpoints: Vec<&Endpoint> = endpoints.iter().collect();
+        endpoints.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut publications: BTreeSet<(String, String)> = BTreeSet::new();
+        let mut subscribers: BTreeSet<(String, String)> = subscriptions.iter().cloned().collect();
+        for endpoint in &endpoints {
+            topology.add_node(NodeKind::Endpoint, &endpoint.name);
+            for msg_type in &endpoint.publish {
+                publications.insert((endpoint.name.clone(), msg_type.clone()));
+            }
+            for pattern in &endpoint.subscribe {
+                subscribers.insert((pattern.clone(), endpoint.name.clone()));
+            }
+        }
+        for (publisher, msg_type) in &publications {
+            let topic = topology.add_node(NodeKind::Topic, msg_type);
+            let from = node_id(NodeKind::Endpoint, publisher);
+            topology.add_edge(from, topic, EdgeKind::Publish);
+        }
+        for (pattern, subscriber) in &subscribers {
+            let to = topology.add_node(NodeKind::Endpoint, subscriber);
+            let topics: BTreeSet<&String> = match KeyPattern::parse(pattern) {
+                KeyPattern::MsgType(Chunk::Literal(_)) => [pattern].into(),
+                key_pattern => publications
+                    .iter()
+                    .filter(|(publisher, msg_type)| {
+                        key_pattern.matches(publisher, "broker", msg_type)
+                    })
+                    .map(|(_, msg_type)| msg_type)
+                    .collect(),
+            };
+            if topics.is_empty() {
+                let from = topology.add_node(NodeKind::Pattern, pattern);
+                topology.add_edge(from, to.clone(), EdgeKind::Subscribe);
+            }
+            for msg_type in topics {
+                let from = topology.add_node(NodeKind::Topic, msg_type);
+                topology.add_edge(from, to.clone(), EdgeKind::Subscribe);
+            }
+        }
+        for endpoint in &endpoints {
+            for service in &endpoint.services {
+                let from = topology.add_node(NodeKind::Service, service);
+                let to = node_id(NodeKind::Endpoint, &endpoint.name);
+                topology.add_edge(from, to, EdgeKind::Serve);
+            }
+        }
+        topology
+    }
+
+    fn add_node(&mut self, kind: NodeKind, name: &str) -> String {
+        let id = node_id(kind, name);
+        if !self.nodes.iter().any(|n| n.id == id) {
+            self.nodes.push(GraphNode {
+                id: id.clone(),
+                kind,
+                name: name.to_string(),
+            });
+        }
+        id
+    }
+
+    fn add_edge(&mut self, from: String, to: String, kind: EdgeKind) {
+        if !self.edges.iter().any(|e| e.from == from && e.to == to) {
+            self.edges.push(GraphEdge {
+                from,
+                to,
+                kind,
+                rate: None,
+            });
+        }
+    }
+
+    fn name_of<'a>(&'a self, id: &'a str) -> &'a str {
+        self.nodes
+            .iter()
+            .find(|n| n.id == id)
+            .map(|n| n.name.as_str())
+            .unwrap_or(id)
+    }
+
+    /// Leave out an endpoint and its edges, e.g. the node drawing the graph
+    pub fn remove_endpoint(&mut self, name: &str) {
+        let id = node_id(NodeKind::Endpoint, name);
+        self.nodes.retain(|n| n.id != id);
+        self.edges.retain(|e| e.from != id && e.to != id);
+        // topics and patterns left without edges
+        let edges = &self.edges;
+        self.nodes.retain(|n| {
+            n.kind == NodeKind::Endpoint || edges.iter().any(|e| e.from == n.id || e.to == n.id)
+        });
+    }
+
+    /// Annotate the publish and subscribe edges with the rates observed by the broker
+    pub fn with_rates(mut self, flows: &[FlowRate]) -> Self {
+        let rates: Vec<Option<f64>> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let from = self.name_of(&edge.from);
+                let to = self.name_of(&edge.to);
+                let (src, dst, msg_type) = match edge.kind {
+                    EdgeKind::Publish => (Some(from), None, to),
+                    EdgeKind::Subscribe if edge.from.starts_with("topic:") => {
+                        (None, Some(to), from)
+                    }
+                    _ => return None,
+                };
+                Some(
+                    flows
+                        .iter()
+                        .filter(|f| f.msg_type == msg_type && f.dst.as_deref() == dst)
+                        .filter(|f| src.is_none_or(|src| f.src == src))
+                        .map(|f| f.rate)
+                        .sum(),
+                )
+            })
+            .collect();
+        for (edge, rate) in self.edges.iter_mut().zip(rates) {
+            edge.rate = rate;
+        }
+        self
+    }
+
+    pub fn to_dot(&self) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut dot = String::from("digraph limeros {\n    rankdir=LR;\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                NodeKind::Endpoint => "shape=box",
+                NodeKind::Topic => "shape=ellipse",
+                NodeKind::Pattern => "shape=ellipse, style=dashed",
+                NodeKind::Service => "shape=hexagon",
+            };
+            dot += &format!(
+                "    {} [label={}, {}];\n",
+                quote(&node.id),
+                quote(&node.name),
+                shape
+            );
+        }
+        for edge in &self.edges {
+            let mut attributes = vec![];
+            if edge.kind == EdgeKind::Serve {
+                attributes.push("style=dashed".to_string());
+            }
+            if let Some(rate) = edge.rate {
+                attributes.push(format!("label={}", quote(&format_rate(rate))));
+            }
+            let attributes = if attributes.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attributes.join(", "))
+            };
+            dot += &format!(
+                "    {} -> {}{};\n",
+                quote(&edge.from),
+                quote(&edge.to),
+                attributes
+            );
+        }
+        dot += "}\n";
+        dot
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        // mermaid ids can't hold ':' or '/', nodes are numbered
+        let index = |id: &str| self.nodes.iter().position(|n| n.id == id).unwrap_or(0);
+        let label = |s: &str| format!("\"{}\"", s.replace('"', "#quot;"));
+        let mut mermaid = String::from("flowchart LR\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let name = label(&node.name);
+            let shape = match node.kind {
+                NodeKind::Endpoint => format!("[{}]", name),
+                NodeKind::Topic => format!("([{}])", name),
+                NodeKind::Pattern => format!("[/{}/]", name),
+                NodeKind::Service => format!("{{{{{}}}}}", name),
+            };
+            mermaid += &format!("    n{}{}\n", i, shape);
+        }
+        for edge in &self.edges {
+            let arrow = match (edge.kind, edge.rate) {
+                (EdgeKind::Serve, _) => "-.->".to_string(),
+                (_, Some(rate)) => format!("-->|{}|", format_rate(rate)),
+                (_, None) => "-->".to_string(),
+            };
+            mermaid += &format!(
+                "    n{} {} n{}\n",
+                index(&edge.from),
+                arrow,
+                index(&edge.to)
+            );
+        }
+        mermaid
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn format_rate(rate: f64) -> String {
+    format!("{:.1}/s", rate)
+}
@@ -0,0 +1,299 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use log::debug;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+
+use crate::interface::{self, NetInterface};
+
+/*
+   The socket layer of Scout and UdpNode. `UdpNetwork` opens real UDP sockets,
+   `MemoryNetwork` connects the nodes of one process, so tests can run a broker and
+   clients without a network and with tokio's paused clock.
+*/
+
+#[async_trait::async_trait]
+pub trait Transport: Send + Sync {
+    async fn send_to(&self, data: &[u8], target: SocketAddr) -> Result<usize>;
+    async fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)>;
+    fn local_addr(&self) -> Result<SocketAddr>;
+}
+
+/// Opens the sockets of a node
+pub trait Network: Send + Sync {
+    /// Interfaces matching the selectors, empty selectors let the OS choose
+    fn interfaces(&self, selectors: &[String], ipv6: bool) -> Result<Vec<NetInterface>>;
+    fn bind_multicast(
+        &self,
+        group: SocketAddr,
+        interfaces: &[NetInterface],
+    ) -> Result<Arc<dyn Transport>>;
+    fn bind_unicast(
+        &self,
+        interface: Option<&NetInterface>,
+        ipv6: bool,
+    ) -> Result<Arc<dyn Transport>>;
+}
+
+#[async_trait::async_trait]
+impl Transport for UdpSocket {
+    async fn send_to(&self, data: &[u8], target: SocketAddr) -> Result<usize> {
+        Ok(UdpSocket::send_to(self, data, target).await?)
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        Ok(UdpSocket::recv_from(self, buf).await?)
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(UdpSocket::local_addr(self)?)
+    }
+}
+
+/// UDP sockets on the interfaces of this host
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UdpNetwork;
+
+impl Network for UdpNetwork {
+    fn interfaces(&self, selectors: &[String], ipv6: bool) -> Result<Vec<NetInterface>> {
+        interface::resolve(selectors, ipv6)
+    }
+
+    fn bind_multicast(
+        &self,
+        group: SocketAddr,
+        interfaces: &[NetInterface],
+    ) -> Result<Arc<dyn Transport>> {
+        let socket = interface::bind_multicast(group, interfaces)?;
+        Ok(Arc::new(UdpSocket::from_std(socket)?))
+    }
+
+    fn bind_unicast(
+        &self,
+        interface: Option<&NetInterface>,
+        ipv6: bool,
+    ) -> Result<Arc<dyn Transport>> {
+        let socket = interface::bind_unicast(interface, ipv6)?;
+        Ok(Arc::new(UdpSocket::from_std(socket)?))
+    }
+}
+
+/// Conditions of the simulated network, the same for every datagram
+#[derive(Debug, Clone, Default)]
+pub struct LinkConfig {
+    pub latency: Duration,
+    /// random extra delay up to this value, datagrams overtake each other
+    pub jitter: Duration,
+    /// probability to drop a datagram
+    pub loss: f64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MemoryStats {
+    pub delivered: u64,
+    pub dropped: u64,
+}
+
+type Datagram = (Vec<u8>, SocketAddr);
+
+struct Hub {
+    sockets: HashMap<SocketAddr, mpsc::UnboundedSender<Datagram>>,
+    groups: HashMap<SocketAddr, Vec<SocketAddr>>,
+    disconnected: HashSet<SocketAddr>,
+    link: LinkConfig,
+    rng: StdRng,
+    next_host: u32,
+    stats: MemoryStats,
+}
+
+impl Hub {
+    fn allocate(&mut self, ipv6: bool) -> SocketAddr {
+        self.next_host += 1;
+        let ip = if ipv6 {
+            IpAddr::V6(Ipv6Addr::new(
+                0xfd00,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                self.next_host as u16,
+            ))
+        } else {
+            IpAddr::V4(Ipv4Addr::from(0x0A00_0000 + self.next_host))
+        };
+        SocketAddr::new(ip, 40000)
+    }
+
+    /// Delay of one datagram, None when it is lost
+    fn delay(&mut self) -> Option<Duration> {
+        if self.link.loss > 0.0 && self.rng.random::<f64>() < self.link.loss {
+            self.stats.dropped += 1;
+            return None;
+        }
+        self.stats.delivered += 1;
+        let jitter = self.link.jitter.as_micros() as u64;
+        let extra = if jitter > 0 {
+            self.rng.random_range(0..=jitter)
+        } else {
+            0
+        };
+        Some(self.link.latency + Duration::from_micros(extra))
+    }
+}
+
+/// In-process network with multicast groups, latency, loss and reordering.
+/// Loss and jitter are drawn from a seeded generator, so runs are repeatable.
+#[derive(Clone)]
+pub struct MemoryNetwork {
+    hub: Arc<Mutex<Hub>>,
+}
+
+impl Default for MemoryNetwork {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl MemoryNetwork {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            hub: Arc::new(Mutex::new(Hub {
+                sockets: HashMap::new(),
+                groups: HashMap::new(),
+                disconnected: HashSet::new(),
+                link: LinkConfig::default(),
+                rng: StdRng::seed_from_u64(seed),
+                next_host: 0,
+                stats: MemoryStats::default(),
+            })),
+        }
+    }
+
+    pub fn set_link(&self, link: LinkConfig) {
+        self.hub.lock().unwrap().link = link;
+    }
+
+    pub fn stats(&self) -> MemoryStats {
+        self.hub.lock().unwrap().stats
+    }
+
+    /// Cut a socket off as if its node crashed, it no longer sends nor receives
+    pub fn disconnect(&self, addr: SocketAddr) {
+        self.hub.lock().unwrap().disconnected.insert(addr);
+    }
+
+    pub fn reconnect(&self, addr: SocketAddr) {
+        self.hub.lock().unwrap().disconnected.remove(&addr);
+    }
+
+    fn bind(&self, ipv6: bool, group: Option<SocketAddr>) -> Arc<dyn Transport> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut hub = self.hub.lock().unwrap();
+        let addr = hub.allocate(ipv6);
+        hub.sockets.insert(addr, sender);
+        if let Some(group) = group {
+            hub.groups.entry(group).or_default().push(addr);
+        }
+        Arc::new(MemorySocket {
+            addr,
+            hub: self.hub.clone(),
+            receiver: tokio::sync::Mutex::new(receiver),
+        })
+    }
+}
+
+impl Network for MemoryNetwork {
+    fn interfaces(&self, selectors: &[String], _ipv6: bool) -> Result<Vec<NetInterface>> {
+        if selectors.is_empty() {
+            Ok(vec![])
+        } else {
+            Err(anyhow::anyhow!("Interfaces are not simulated"))
+        }
+    }
+
+    fn bind_multicast(
+        &self,
+        group: SocketAddr,
+        _interfaces: &[NetInterface],
+    ) -> Result<Arc<dyn Transport>> {
+        Ok(self.bind(group.is_ipv6(), Some(group)))
+    }
+
+    fn bind_unicast(
+        &self,
+        _interface: Option<&NetInterface>,
+        ipv6: bool,
+    ) -> Result<Arc<dyn Transport>> {
+        Ok(self.bind(ipv6, None))
+    }
+}
+
+struct MemorySocket {
+    addr: SocketAddr,
+    hub: Arc<Mutex<Hub>>,
+    receiver: tokio::sync::Mutex<mpsc::UnboundedReceiver<Datagram>>,
+}
+
+#[async_trait::async_trait]
+impl Transport for MemorySocket {
+    async fn send_to(&self, data: &[u8], target: SocketAddr) -> Result<usize> {
+        let mut hub = self.hub.lock().unwrap();
+        if hub.disconnected.contains(&self.addr) {
+            return Err(anyhow::anyhow!("Socket {} disconnected", self.addr));
+        }
+        let targets = if target.ip().is_multicast() {
+            hub.groups.get(&target).cloned().unwrap_or_default()
+        } else {
+            vec![target]
+        };
+        for target in targets {
+            // like UDP, datagrams to nobody vanish
+            let Some(sender) = hub.sockets.get(&target).cloned() else {
+                debug!("No socket at {}", target);
+                continue;
+            };
+            if hub.disconnected.contains(&target) {
+                continue;
+            }
+            let Some(delay) = hub.delay() else {
+                continue;
+            };
+            let datagram = (data.to_vec(), self.addr);
+            if delay.is_zero() {
+                let _ = sender.send(datagram);
+            } else {
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = sender.send(datagram);
+                });
+            }
+        }
+        Ok(data.len())
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        let (data, src) = self
+            .receiver
+            .lock()
+            .await
+            .recv()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Socket {} disconnected", self.addr))?;
+        // a datagram larger than the buffer is truncated, as with UDP
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok((len, src))
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.addr)
+    }
+}
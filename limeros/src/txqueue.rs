@@ -0,0 +1,382 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+use crate::msgs::UdpMessage;
+use crate::reliable;
+
+/*
+   Transmit queue of UdpNode, between the senders and the unicast sender task.
+
+   - one queue per priority, the sender task always takes the highest priority first, so a
+     flood of telemetry can't hold back a command. A full queue only blocks senders of its
+     own priority.
+   - a coalesced msg_type keeps only the latest queued message per destination : a new one
+     replaces the one still waiting, in place. Reliable messages and requests are never
+     coalesced.
+   - a token bucket per destination limits the messages per second, messages over the
+     limit wait in the queue. Critical messages are never held back.
+*/
+
+// --- CONSTANTS ---
+/// Messages queued per priority before senders wait
+pub const QUEUE_CAPACITY: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    /// not rate limited, e.g. an emergency stop
+    Critical,
+}
+
+impl Priority {
+    const ALL: [Priority; 4] = [
+        Priority::Critical,
+        Priority::High,
+        Priority::Normal,
+        Priority::Low,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Token bucket : `rate` messages per second on average, up to `burst` at once
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub rate: f64,
+    pub burst: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TxConfig {
+    pub capacity: usize,
+    /// priority per msg_type, Normal when not listed
+    pub priorities: HashMap<String, Priority>,
+    /// msg_types where only the latest queued message counts
+    pub coalesce: HashSet<String>,
+    /// limit per destination
+    pub rate_limits: HashMap<String, RateLimit>,
+    /// limit of the destinations not in `rate_limits`
+    pub default_rate_limit: Option<RateLimit>,
+}
+
+impl Default for TxConfig {
+    fn default() -> Self {
+        Self {
+            capacity: QUEUE_CAPACITY,
+            // a late ack makes the peer retransmit
+            priorities: [(reliable::ACK_MSG_TYPE.to_string(), Priority::High)].into(),
+            coalesce: HashSet::new(),
+            rate_limits: HashMap::new(),
+            default_rate_limit: None,
+        }
+    }
+}
+
+impl TxConfig {
+    pub fn priority_of(&self, msg_type: &str) -> Priority {
+        self.priorities.get(msg_type).copied().unwrap_or_default()
+    }
+
+    fn rate_limit_of(&self, dst: &str) -> Option<RateLimit> {
+        self.rate_limits
+            .get(dst)
+            .copied()
+            .or(self.default_rate_limit)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxError {
+    /// try_send found the queue of the message priority full
+    Full,
+    /// the sender task is gone
+    Closed,
+}
+
+impl fmt::Display for TxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxError::Full => write!(f, "queue full"),
+            TxError::Closed => write!(f, "queue closed"),
+        }
+    }
+}
+
+impl std::error::Error for TxError {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TxStats {
+    pub sent: u64,
+    /// queued messages replaced by a newer one
+    pub coalesced: u64,
+    /// messages that waited for their destination's rate limit
+    pub rate_limited: u64,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self, limit: RateLimit, now: Instant) {
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.rate).min(limit.burst.max(1) as f64);
+        self.updated = now;
+    }
+
+    /// Time until a token is available
+    fn wait(&self, limit: RateLimit) -> Duration {
+        if self.tokens >= 1.0 || limit.rate <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / limit.rate)
+        }
+    }
+}
+
+struct State {
+    config: TxConfig,
+    queues: [VecDeque<UdpMessage>; 4],
+    buckets: HashMap<String, TokenBucket>,
+    // destinations whose head of line waits for a token, counted once per wait
+    throttled: HashSet<String>,
+    stats: TxStats,
+    closed: bool,
+}
+
+impl State {
+    fn coalescible(&self, message: &UdpMessage) -> bool {
+        message.seq.is_none()
+            && message.correlation_id.is_none()
+            && message
+                .msg_type
+                .as_ref()
+                .is_some_and(|t| self.config.coalesce.contains(t))
+    }
+
+    /*
+       None when the destination may send now, else how long until it may
+    */
+    fn throttle(&mut self, dst: &str, now: Instant) -> Option<Duration> {
+        let limit = self.config.rate_limit_of(dst)?;
+        let bucket = self
+            .buckets
+            .entry(dst.to_string())
+            .or_insert_with(|| TokenBucket {
+                tokens: limit.burst.max(1) as f64,
+                updated: now,
+            });
+        bucket.refill(limit, now);
+        match bucket.wait(limit) {
+            wait if wait.is_zero() => None,
+            wait => Some(wait),
+        }
+    }
+
+    fn take_token(&mut self, dst: &str) {
+        if let Some(bucket) = self.buckets.get_mut(dst) {
+            bucket.tokens -= 1.0;
+        }
+    }
+
+    /*
+       next message to send, or how long to wait for a rate limit when all are held back
+    */
+    fn next(&mut self, now: Instant) -> Result<UdpMessage, Option<Duration>> {
+        let mut earliest: Option<Duration> = None;
+        for priority in Priority::ALL {
+            // messages to a throttled destination stay in order behind the first one
+            let mut blocked: HashSet<String> = HashSet::new();
+            for i in 0..self.queues[priority.index()].len() {
+                let dst = self.queues[priority.index()][i]
+                    .dst
+                    .clone()
+                    .unwrap_or_default();
+                if blocked.contains(&dst) {
+                    continue;
+                }
+                if priority != Priority::Critical {
+                    if let Some(wait) = self.throttle(&dst, now) {
+                        if self.throttled.insert(dst.clone()) {
+                            self.stats.rate_limited += 1;
+                        }
+                        earliest = Some(earliest.map_or(wait, |e| e.min(wait)));
+                        blocked.insert(dst);
+                        continue;
+                    }
+                    self.take_token(&dst);
+                }
+                self.throttled.remove(&dst);
+                self.stats.sent += 1;
+                return Ok(self.queues[priority.index()].remove(i).unwrap());
+            }
+        }
+        Err(earliest)
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    // a message was queued, for the receiver
+    queued: Notify,
+    // a message left the queue, for waiting senders
+    room: Notify,
+}
+
+/// Create a queue, the receiver goes to the task that sends
+pub fn tx_queue(config: TxConfig) -> (TxSender, TxReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            config,
+            queues: Default::default(),
+            buckets: HashMap::new(),
+            throttled: HashSet::new(),
+            stats: TxStats::default(),
+            closed: false,
+        }),
+        queued: Notify::new(),
+        room: Notify::new(),
+    });
+    (
+        TxSender {
+            shared: shared.clone(),
+        },
+        TxReceiver { shared },
+    )
+}
+
+#[derive(Clone)]
+pub struct TxSender {
+    shared: Arc<Shared>,
+}
+
+impl TxSender {
+    /// Queue a message, waits while the queue of its priority is full
+    pub async fn send(&self, message: UdpMessage) -> Result<(), TxError> {
+        loop {
+            let room = self.shared.room.notified();
+            match self.push(&message) {
+                Err(TxError::Full) => room.await,
+                result => return result,
+            }
+        }
+    }
+
+    /// Queue a message, fails when the queue of its priority is full
+    pub fn try_send(&self, message: UdpMessage) -> Result<(), TxError> {
+        self.push(&message)
+    }
+
+    fn push(&self, message: &UdpMessage) -> Result<(), TxError> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.closed {
+            return Err(TxError::Closed);
+        }
+        let priority = state
+            .config
+            .priority_of(message.msg_type.as_deref().unwrap_or_default());
+        if state.coalescible(message) {
+            let queued = state.queues[priority.index()].iter_mut().find(|m| {
+                m.dst == message.dst && m.msg_type == message.msg_type && m.seq.is_none()
+            });
+            if let Some(queued) = queued {
+                *queued = message.clone();
+                state.stats.coalesced += 1;
+                return Ok(());
+            }
+        }
+        if state.queues[priority.index()].len() >= state.config.capacity {
+            return Err(TxError::Full);
+        }
+        state.queues[priority.index()].push_back(message.clone());
+        drop(state);
+        self.shared.queued.notify_one();
+        Ok(())
+    }
+
+    pub fn set_priority(&self, msg_type: &str, priority: Priority) {
+        let mut state = self.shared.state.lock().unwrap();
+        state
+            .config
+            .priorities
+            .insert(msg_type.to_string(), priority);
+    }
+
+    pub fn set_coalescing(&self, msg_type: &str, coalesce: bool) {
+        let mut state = self.shared.state.lock().unwrap();
+        if coalesce {
+            state.config.coalesce.insert(msg_type.to_string());
+        } else {
+            state.config.coalesce.remove(msg_type);
+        }
+    }
+
+    /// Limit the messages to `dst`, None removes the limit
+    pub fn set_rate_limit(&self, dst: &str, limit: Option<RateLimit>) {
+        let mut state = self.shared.state.lock().unwrap();
+        match limit {
+            Some(limit) => state.config.rate_limits.insert(dst.to_string(), limit),
+            None => state.config.rate_limits.remove(dst),
+        };
+        state.buckets.remove(dst);
+    }
+
+    /// Number of messages waiting
+    pub fn len(&self) -> usize {
+        let state = self.shared.state.lock().unwrap();
+        state.queues.iter().map(VecDeque::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> TxStats {
+        self.shared.state.lock().unwrap().stats
+    }
+}
+
+pub struct TxReceiver {
+    shared: Arc<Shared>,
+}
+
+impl TxReceiver {
+    /// Next message allowed to go out, highest priority first
+    pub async fn recv(&mut self) -> UdpMessage {
+        loop {
+            let queued = self.shared.queued.notified();
+            let next = self.shared.state.lock().unwrap().next(Instant::now());
+            match next {
+                Ok(message) => {
+                    self.shared.room.notify_waiters();
+                    return message;
+                }
+                Err(None) => queued.await,
+                Err(Some(wait)) => {
+                    tokio::select! {
+                        _ = queued => {}
+                        _ = tokio::time::sleep(wait) => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for TxReceiver {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().closed = true;
+        self.shared.room.notify_waiters();
+    }
+}
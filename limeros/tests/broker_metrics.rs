@@ -0,0 +1,156 @@
+mod common;
+use common::node;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::broker::Forwarder;
+use limeros::metrics::{self, BrokerMetrics, MessageCounters};
+use limeros::msgs::{BrokerMetricsEvent, SysEvent};
+use limeros::transport::MemoryNetwork;
+use limeros::{Codec, NodeStats};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+#[test]
+fn prometheus_text_format() {
+    let counters = MessageCounters::new();
+    counters.received("esp1", "SysEvent");
+    counters.received("esp1", "SysEvent");
+    counters.received("esp\"2", "LogEvent");
+    counters.forwarded("brain", "SysEvent");
+    let metrics = BrokerMetrics {
+        received: counters.received_counts(),
+        forwarded: counters.forwarded_counts(),
+        node: NodeStats {
+            decode_errors: 3,
+            unknown_endpoint_drops: vec![("ghost".to_string(), 5)],
+            tx_queue: 7,
+            ..Default::default()
+        },
+        endpoints: vec![("esp1".to_string(), Duration::from_millis(1500))],
+    };
+
+    let text = metrics.to_prometheus();
+    for line in [
+        "# TYPE limeros_messages_received_total counter",
+        "limeros_messages_received_total{src=\"esp1\",msg_type=\"SysEvent\"} 2",
+        "limeros_messages_received_total{src=\"esp\\\"2\",msg_type=\"LogEvent\"} 1",
+        "limeros_messages_forwarded_total{dst=\"brain\",msg_type=\"SysEvent\"} 1",
+        "limeros_dropped_unknown_endpoint_total{dst=\"ghost\"} 5",
+        "limeros_decode_errors_total 3",
+        "# TYPE limeros_tx_queue_depth gauge",
+        "limeros_tx_queue_depth 7",
+        "limeros_endpoint_last_seen_seconds{endpoint=\"esp1\"} 1.5",
+    ] {
+        assert!(text.lines().any(|l| l == line), "missing {}", line);
+    }
+
+    let event = metrics.to_event();
+    assert_eq!(event.endpoint, Some(vec!["esp1".to_string()]));
+    assert_eq!(event.last_seen_ms, Some(vec![1500]));
+    assert_eq!(event.received, Some(vec![2]));
+    assert_eq!(event.forwarded, Some(vec![0]));
+    assert_eq!(event.dropped_unknown, Some(5));
+    assert_eq!(event.queue_depth, Some(7));
+}
+
+#[tokio::test(start_paused = true)]
+async fn broker_counts_and_publishes_its_metrics() {
+    let network = MemoryNetwork::new(1);
+    let broker = node(&network, "broker").await;
+    let forwarder = Arc::new(Forwarder::new(broker.sender(), broker.scout()));
+    let counters = forwarder.counters();
+    broker.add_generic_handler(forwarder.clone()).await;
+    let esp1 = node(&network, "esp1").await;
+    let monitor = node(&network, "monitor").await;
+
+    let (tx, mut rx) = mpsc::channel(10);
+    monitor.on::<BrokerMetricsEvent, _, _>(move |src, event| {
+        let tx = tx.clone();
+        async move {
+            let _ = tx.send((src, event)).await;
+        }
+    });
+    monitor.add_subscription("SysEvent").await;
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    for _ in 0..3 {
+        esp1.send_event(SysEvent::default()).await.unwrap();
+    }
+    // never discovered, dropped once the undelivered queue expires it
+    broker
+        .send_msg_to("ghost", SysEvent::default())
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(6)).await;
+
+    let metrics = BrokerMetrics::collect(&counters, &broker);
+    assert_eq!(
+        metrics.received,
+        vec![("esp1".to_string(), "SysEvent".to_string(), 3)]
+    );
+    assert_eq!(
+        metrics.forwarded,
+        vec![("monitor".to_string(), "SysEvent".to_string(), 3)]
+    );
+    assert_eq!(
+        metrics.node.unknown_endpoint_drops,
+        vec![("ghost".to_string(), 1)]
+    );
+    assert_eq!(metrics.node.undelivered, 0);
+    let names: Vec<&str> = metrics.endpoints.iter().map(|(e, _)| e.as_str()).collect();
+    assert!(names.contains(&"esp1") && names.contains(&"monitor"));
+    assert!(metrics
+        .endpoints
+        .iter()
+        .all(|(_, last_seen)| *last_seen < Duration::from_secs(2)));
+
+    forwarder
+        .publish(Codec::Cbor, &metrics.to_event())
+        .await
+        .unwrap();
+    let (src, event) = timeout(Duration::from_secs(1), rx.recv())
+        .await
+        .expect("metrics not published")
+        .unwrap();
+    assert_eq!(src, "broker");
+    let esp1_index = event
+        .endpoint
+        .unwrap()
+        .iter()
+        .position(|e| e == "esp1")
+        .unwrap();
+    assert_eq!(event.received.unwrap()[esp1_index], 3);
+    assert_eq!(event.dropped_unknown, Some(1));
+}
+
+async fn get(addr: std::net::SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[tokio::test]
+async fn http_endpoint_serves_metrics_and_health() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = metrics::serve_listener(listener, || "limeros_endpoints 2\n".to_string());
+
+    let response = get(addr, "GET /metrics HTTP/1.1\r\nHost: broker\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Content-Type: text/plain; version=0.0.4\r\n"));
+    assert!(response.ends_with("\r\n\r\nlimeros_endpoints 2\n"));
+
+    let response = get(addr, "GET /health HTTP/1.1\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with("ok\n"));
+
+    let response = get(addr, "GET /other HTTP/1.1\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    server.abort();
+}
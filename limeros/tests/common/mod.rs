@@ -0,0 +1,38 @@
+//! Fixtures shared by the tests that run nodes on a MemoryNetwork
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::scout::EndpointEvent;
+use limeros::transport::MemoryNetwork;
+use limeros::{NodeConfig, UdpNode};
+use tokio::time::timeout;
+
+pub const GROUP: &str = "239.0.0.1:50000";
+
+pub async fn node(network: &MemoryNetwork, id: &str) -> Arc<UdpNode> {
+    let config = NodeConfig {
+        network: Arc::new(network.clone()),
+        ..Default::default()
+    };
+    UdpNode::with_config(id, GROUP, config).await.unwrap()
+}
+
+pub async fn wait_for_endpoint(node: &UdpNode, name: &str) {
+    let mut events = node.endpoint_events();
+    if node.scout().endpoint_to_addr(name).is_some() {
+        return;
+    }
+    timeout(Duration::from_secs(5), async {
+        loop {
+            if let Ok(EndpointEvent::EndpointJoined(endpoint)) = events.recv().await {
+                if endpoint.name == name {
+                    return;
+                }
+            }
+        }
+    })
+    .await
+    .expect("endpoint not discovered");
+}
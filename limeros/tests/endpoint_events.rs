@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use limeros::scout::{Endpoint, EndpointEvent};
+
+fn endpoint(addr: &str, subscribe: &[&str]) -> Endpoint {
+    Endpoint {
+        name: "esp1".to_string(),
+        addr: addr.parse().unwrap(),
+        last_seen: Instant::now(),
+        subscribe: subscribe.iter().map(|s| s.to_string()).collect(),
+        publish: vec![],
+        services: vec![],
+        interface: None,
+    }
+}
+
+#[test]
+fn first_alive_is_a_join() {
+    let new = endpoint("10.0.0.1:5000", &["HoverboardCmd"]);
+
+    match EndpointEvent::on_alive(None, &new) {
+        Some(EndpointEvent::EndpointJoined(e)) => assert_eq!(e.name, "esp1"),
+        other => panic!("expected join, got {:?}", other),
+    }
+}
+
+#[test]
+fn repeated_alive_is_silent() {
+    let old = endpoint("10.0.0.1:5000", &["HoverboardCmd"]);
+    let mut new = old.clone();
+    new.last_seen = old.last_seen + Duration::from_secs(1);
+
+    assert!(EndpointEvent::on_alive(Some(old), &new).is_none());
+}
+
+#[test]
+fn moved_or_resubscribed_endpoint_is_a_change() {
+    let old = endpoint("10.0.0.1:5000", &["HoverboardCmd"]);
+
+    let moved = endpoint("10.0.0.1:5001", &["HoverboardCmd"]);
+    match EndpointEvent::on_alive(Some(old.clone()), &moved) {
+        Some(EndpointEvent::EndpointChanged { old, new }) => {
+            assert_eq!(old.addr.port(), 5000);
+            assert_eq!(new.addr.port(), 5001);
+        }
+        other => panic!("expected change, got {:?}", other),
+    }
+
+    let resubscribed = endpoint("10.0.0.1:5000", &["HoverboardCmd", "LogInfo"]);
+    let event = EndpointEvent::on_alive(Some(old), &resubscribed).unwrap();
+    assert!(matches!(event, EndpointEvent::EndpointChanged { .. }));
+    assert_eq!(event.name(), "esp1");
+}
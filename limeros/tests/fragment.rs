@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use limeros::fragment::{fragment, Reassembler};
+use limeros::msgs::{Msg, UdpMessage};
+
+fn camera_image(len: usize) -> UdpMessage {
+    UdpMessage {
+        dst: Some("broker".to_string()),
+        src: Some("cam1".to_string()),
+        msg_type: Some("CameraEvent".to_string()),
+        payload: Some((0..len).map(|i| i as u8).collect()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fragments_fit_mtu_and_reassemble_out_of_order() {
+    let message = camera_image(10_000);
+    let mut fragments = fragment(&message, 512, 1).unwrap();
+    assert!(fragments.len() > 1);
+    for f in &fragments {
+        assert!(f.cbor_serialize().unwrap().len() <= 512);
+    }
+
+    fragments.reverse();
+    let duplicate = fragments[0].clone();
+    let mut reassembler = Reassembler::default();
+    let mut complete = None;
+    for f in fragments {
+        if let Some(m) = reassembler.push(f) {
+            complete = Some(m);
+        }
+    }
+    assert!(reassembler.push(duplicate).is_none());
+
+    let complete = complete.unwrap();
+    assert_eq!(complete.payload, message.payload);
+    assert_eq!(complete.msg_type, message.msg_type);
+    assert_eq!(complete.frag_id, None);
+    assert_eq!(reassembler.stats().reassembled, 1);
+}
+
+#[test]
+fn unfragmented_messages_pass_through() {
+    let mut reassembler = Reassembler::default();
+    let message = camera_image(10);
+    assert_eq!(reassembler.push(message.clone()).unwrap().payload, message.payload);
+}
+
+#[test]
+fn incomplete_messages_are_dropped_on_timeout_and_memory_limit() {
+    let fragments = fragment(&camera_image(4_000), 512, 7).unwrap();
+
+    let mut reassembler = Reassembler::new(Duration::ZERO, usize::MAX);
+    assert!(reassembler.push(fragments[0].clone()).is_none());
+    reassembler.expire();
+    assert_eq!(reassembler.stats().dropped_timeout, 1);
+
+    let mut reassembler = Reassembler::new(Duration::from_secs(60), 1_000);
+    let mut results = fragments.into_iter().map(|f| reassembler.push(f));
+    assert!(results.all(|r| r.is_none()));
+    assert!(reassembler.stats().dropped_memory > 0);
+    assert_eq!(reassembler.stats().reassembled, 0);
+}
+
+#[test]
+fn hostile_fragment_count_is_refused_before_allocating() {
+    let mut hostile = fragment(&camera_image(4_000), 512, 9).unwrap().remove(0);
+    hostile.frag_count = Some(u32::MAX - 1);
+    let mut reassembler = Reassembler::default();
+    assert!(reassembler.push(hostile).is_none());
+    assert_eq!(reassembler.stats().dropped_memory, 1);
+}
+
+#[test]
+fn memory_limit_holds_over_all_sources() {
+    let from = |src: &str, f: &UdpMessage| UdpMessage {
+        src: Some(src.to_string()),
+        ..f.clone()
+    };
+    let fragments = fragment(&camera_image(4_000), 512, 3).unwrap();
+    let (last, first) = fragments.split_last().unwrap();
+    let mut reassembler = Reassembler::new(Duration::from_secs(60), 4_500).with_total_limit(6_000);
+    for f in first {
+        assert!(reassembler.push(from("cam1", f)).is_none());
+    }
+    for f in first {
+        assert!(reassembler.push(from("cam2", f)).is_none());
+    }
+    assert!(reassembler.stats().dropped_memory > 0);
+
+    // cam2 doesn't push out what cam1 already has
+    let complete = reassembler.push(from("cam1", last)).unwrap();
+    assert_eq!(complete.payload.unwrap().len(), 4_000);
+}
@@ -0,0 +1,96 @@
+use std::net::{IpAddr, SocketAddr};
+
+use limeros::interface::{interface_of, select, NetInterface};
+
+fn interfaces() -> Vec<NetInterface> {
+    let interface = |name: &str, addr: &str, netmask: &str, index: u32| NetInterface {
+        name: name.to_string(),
+        addr: addr.parse::<IpAddr>().unwrap(),
+        netmask: netmask.parse::<IpAddr>().unwrap(),
+        index,
+    };
+    vec![
+        interface("eth0", "192.168.1.10", "255.255.255.0", 2),
+        interface("eth0", "fe80::1", "ffff:ffff:ffff:ffff::", 2),
+        interface("wlan0", "10.0.0.5", "255.255.0.0", 3),
+        interface("wlan0", "fe80::2", "ffff:ffff:ffff:ffff::", 3),
+        interface("wlan0", "2001:db8::2", "ffff:ffff:ffff:ffff::", 3),
+        interface("wlan0", "10.1.0.5", "255.255.0.0", 3),
+    ]
+}
+
+#[test]
+fn select_by_name_or_address_per_family() {
+    let available = interfaces();
+
+    let v4 = select(
+        &available,
+        &["eth0".to_string(), "10.0.0.5".to_string()],
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        v4.iter().map(|i| i.name.as_str()).collect::<Vec<_>>(),
+        vec!["eth0", "wlan0"]
+    );
+    assert!(v4.iter().all(|i| i.addr.is_ipv4()));
+
+    let v6 = select(&available, &["wlan0".to_string()], true).unwrap();
+    assert_eq!(v6.len(), 1);
+    assert_eq!(v6[0].index, 3);
+
+    assert!(select(&available, &["eth1".to_string()], false).is_err());
+    assert!(select(&available, &["192.168.1.10".to_string()], true).is_err());
+}
+
+#[test]
+fn interface_with_several_addresses_is_selected_once() {
+    let available = interfaces();
+
+    let v6 = select(
+        &available,
+        &["wlan0".to_string(), "2001:db8::2".to_string()],
+        true,
+    )
+    .unwrap();
+    assert_eq!(v6.len(), 1);
+    assert_eq!(v6[0].addr, "fe80::2".parse::<IpAddr>().unwrap());
+
+    let v4 = select(
+        &available,
+        &["10.1.0.5".to_string(), "wlan0".to_string()],
+        false,
+    )
+    .unwrap();
+    assert_eq!(v4.len(), 1);
+    assert_eq!(v4[0].addr, "10.1.0.5".parse::<IpAddr>().unwrap());
+}
+
+#[test]
+fn multicast_joins_each_interface_once() {
+    let Ok(available) = limeros::interface::list() else {
+        return;
+    };
+    let Some(lo) = available
+        .iter()
+        .find(|i| i.addr.is_loopback() && i.addr.is_ipv4())
+    else {
+        return;
+    };
+    // the same interface twice, as a host with several addresses on it lists it
+    let twice = vec![lo.clone(), lo.clone()];
+    limeros::interface::bind_multicast("239.255.77.1:0".parse().unwrap(), &twice).unwrap();
+}
+
+#[test]
+fn peers_are_tagged_with_their_interface() {
+    let available = interfaces();
+    let name = |peer: &str| {
+        interface_of(&available, &peer.parse::<SocketAddr>().unwrap()).map(|i| i.name.clone())
+    };
+
+    assert_eq!(name("192.168.1.77:40000"), Some("eth0".to_string()));
+    assert_eq!(name("10.0.200.1:40000"), Some("wlan0".to_string()));
+    assert_eq!(name("[fe80::99%3]:40000"), Some("wlan0".to_string()));
+    assert_eq!(name("172.16.0.1:40000"), None);
+}
@@ -0,0 +1,140 @@
+mod common;
+use common::node;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::broker::{CacheConfig, CachePolicy, Forwarder, LastValueCache};
+use limeros::msgs::{SysEvent, UdpMessage, WifiEvent};
+use limeros::transport::MemoryNetwork;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+fn event(src: &str, msg_type: &str, value: u8) -> UdpMessage {
+    UdpMessage {
+        src: Some(src.to_string()),
+        dst: Some("broker".to_string()),
+        msg_type: Some(msg_type.to_string()),
+        payload: Some(vec![value]),
+        ..Default::default()
+    }
+}
+
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|p| p.to_string()).collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn keeps_the_latest_values_per_source_and_type() {
+    let config = CacheConfig {
+        types: [
+            (
+                "LogEvent".to_string(),
+                CachePolicy::parse_rule("LogEvent=3").unwrap().1,
+            ),
+            (
+                "HoverboardEvent".to_string(),
+                CachePolicy::parse_rule("HoverboardEvent=0").unwrap().1,
+            ),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    let cache = LastValueCache::new(config);
+    for value in 0..5 {
+        cache.store(&event("esp1", "SysEvent", value));
+        cache.store(&event("esp1", "LogEvent", value));
+        cache.store(&event("esp1", "HoverboardEvent", value));
+        tokio::time::advance(Duration::from_millis(10)).await;
+    }
+    cache.store(&event("esp2", "SysEvent", 9));
+    // replies are not state
+    cache.store(&UdpMessage {
+        correlation_id: Some(1),
+        ..event("esp1", "PingRep", 0)
+    });
+
+    let payloads = |messages: Vec<UdpMessage>| -> Vec<u8> {
+        messages
+            .into_iter()
+            .map(|m| m.payload.unwrap()[0])
+            .collect()
+    };
+    assert_eq!(cache.len(), 5);
+    assert_eq!(
+        payloads(cache.matching(&patterns(&["SysEvent"]))),
+        vec![4, 9]
+    );
+    assert_eq!(
+        payloads(cache.matching(&patterns(&["src/esp1/LogEvent"]))),
+        vec![2, 3, 4]
+    );
+    assert!(cache.matching(&patterns(&["HoverboardEvent"])).is_empty());
+    assert!(cache.matching(&patterns(&["PingRep"])).is_empty());
+    assert_eq!(cache.matching(&patterns(&["**"])).len(), 5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn expired_values_are_not_replayed() {
+    let (msg_type, policy) = CachePolicy::parse_rule("WifiEvent=1:60").unwrap();
+    assert_eq!(msg_type, "WifiEvent");
+    assert_eq!(policy.ttl, Some(Duration::from_secs(60)));
+    assert!(CachePolicy::parse_rule("WifiEvent").is_err());
+
+    let cache = LastValueCache::new(CacheConfig {
+        types: [(msg_type, policy)].into(),
+        ..Default::default()
+    });
+    cache.store(&event("esp1", "WifiEvent", 1));
+    cache.store(&event("esp1", "SysEvent", 1));
+
+    tokio::time::advance(Duration::from_secs(59)).await;
+    assert_eq!(cache.matching(&patterns(&["*"])).len(), 2);
+    tokio::time::advance(Duration::from_secs(2)).await;
+    let replay = cache.matching(&patterns(&["*"]));
+    assert_eq!(replay.len(), 1);
+    assert_eq!(replay[0].msg_type.as_deref(), Some("SysEvent"));
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_joiner_gets_the_cached_values() {
+    let network = MemoryNetwork::new(1);
+    let broker = node(&network, "broker").await;
+    let cache = Arc::new(LastValueCache::new(CacheConfig::default()));
+    cache.clone().start_replay(broker.scout(), broker.sender());
+    broker
+        .add_generic_handler(
+            Forwarder::new(broker.sender(), broker.scout()).with_cache(cache.clone()),
+        )
+        .await;
+
+    let esp1 = node(&network, "esp1").await;
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    esp1.send_event(SysEvent {
+        uptime: Some(42),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    esp1.send_event(WifiEvent::default()).await.unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(cache.len(), 2);
+
+    // joins long after the event, subscribed from its first Alive
+    let monitor = node(&network, "monitor").await;
+    let (tx, mut rx) = mpsc::channel(10);
+    monitor.on::<SysEvent, _, _>(move |src, event| {
+        let tx = tx.clone();
+        async move {
+            let _ = tx.send((src, event)).await;
+        }
+    });
+
+    let (src, event) = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("no cached SysEvent")
+        .unwrap();
+    assert_eq!(src, "esp1");
+    assert_eq!(event.uptime, Some(42));
+}
@@ -0,0 +1,260 @@
+mod common;
+use common::{node, wait_for_endpoint};
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::broker::Forwarder;
+use limeros::msgs::{HoverboardCmd, PingRep, PingReq, SysEvent, TypedMessage};
+use limeros::scout::{EndpointEvent, PEER_TIMEOUT};
+use limeros::transport::{LinkConfig, MemoryNetwork};
+use limeros::HANDLER_QUEUE_CAPACITY;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::time::{timeout, Instant};
+
+#[tokio::test(start_paused = true)]
+async fn broker_forwards_events_to_subscribers() {
+    let network = MemoryNetwork::new(1);
+    let broker = node(&network, "broker").await;
+    broker
+        .add_generic_handler(Forwarder::new(broker.sender(), broker.scout()))
+        .await;
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+
+    let (tx, mut rx) = mpsc::channel(10);
+    esp1.on::<SysEvent, _, _>(move |src, event| {
+        let tx = tx.clone();
+        async move {
+            let _ = tx.send((src, event)).await;
+        }
+    });
+    esp1.add_subscription("src/brain/SysEvent").await;
+
+    wait_for_endpoint(&brain, "broker").await;
+    wait_for_endpoint(&broker, "esp1").await;
+    // the subscription travels with the next Alive
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    brain
+        .send_event(SysEvent {
+            uptime: Some(42),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let (src, event) = timeout(Duration::from_secs(1), rx.recv())
+        .await
+        .expect("event not forwarded")
+        .unwrap();
+    assert_eq!(src, "brain");
+    assert_eq!(event.uptime, Some(42));
+}
+
+#[tokio::test(start_paused = true)]
+async fn scout_prunes_silent_endpoints() {
+    let network = MemoryNetwork::new(2);
+    let broker = node(&network, "broker").await;
+    let esp1 = node(&network, "esp1").await;
+    esp1.add_subscription("HoverboardCmd").await;
+
+    wait_for_endpoint(&broker, "esp1").await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert!(broker
+        .get_subscriptions()
+        .await
+        .contains_key("HoverboardCmd"));
+
+    let mut events = broker.endpoint_events();
+    for addr in esp1.local_addrs() {
+        network.disconnect(addr);
+    }
+    let silent_since = Instant::now();
+
+    let left = timeout(Duration::from_secs(PEER_TIMEOUT * 2), async {
+        loop {
+            if let Ok(EndpointEvent::EndpointLeft(endpoint)) = events.recv().await {
+                return endpoint;
+            }
+        }
+    })
+    .await
+    .expect("endpoint not pruned");
+
+    assert_eq!(left.name, "esp1");
+    assert!(silent_since.elapsed() >= Duration::from_secs(PEER_TIMEOUT - 1));
+    assert!(broker.scout().endpoint_to_addr("esp1").is_none());
+    assert!(!broker
+        .get_subscriptions()
+        .await
+        .contains_key("HoverboardCmd"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn reliable_messages_are_dispatched_once_over_a_lossy_link() {
+    let network = MemoryNetwork::new(3);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+
+    let (tx, mut rx) = mpsc::channel(100);
+    esp1.on::<PingReq, _, _>(move |_src, ping| {
+        let tx = tx.clone();
+        async move {
+            let _ = tx.send(ping.number.unwrap_or_default()).await;
+        }
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    network.set_link(LinkConfig {
+        latency: Duration::from_millis(5),
+        jitter: Duration::from_millis(20),
+        loss: 0.2,
+    });
+    for number in 0..20 {
+        brain
+            .send_reliable_to(
+                "esp1",
+                PingReq {
+                    number: Some(number),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut received = vec![];
+    while received.len() < 20 {
+        let number = timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("reliable message lost")
+            .unwrap();
+        received.push(number);
+    }
+    // no duplicates show up later either
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert!(rx.try_recv().is_err());
+
+    received.sort();
+    assert_eq!(received, (0..20).collect::<Vec<u32>>());
+    assert!(network.stats().dropped > 0);
+    assert_eq!(brain.unacked_count(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn typed_handler_sees_messages_in_order() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+
+    let (tx, mut rx) = mpsc::channel(100);
+    esp1.on::<PingReq, _, _>(move |_src, ping| {
+        let tx = tx.clone();
+        async move {
+            let number = ping.number.unwrap_or_default();
+            // earlier messages take longer, a task per message would finish them last
+            tokio::time::sleep(Duration::from_millis(20 - number as u64)).await;
+            let _ = tx.send(number).await;
+        }
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    for number in 0..20 {
+        brain
+            .send_typed("brain", "esp1", PingReq { number: Some(number) })
+            .await
+            .unwrap();
+    }
+
+    let mut received = vec![];
+    while received.len() < 20 {
+        let number = timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("message lost")
+            .unwrap();
+        received.push(number);
+    }
+    assert_eq!(received, (0..20).collect::<Vec<u32>>());
+}
+
+#[tokio::test(start_paused = true)]
+async fn slow_typed_handler_drops_what_does_not_fit_its_queue() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+
+    // the handler waits for a permit per message, nothing is handled until they are released
+    let permits = Arc::new(Semaphore::new(0));
+    let (tx, mut rx) = mpsc::channel(200);
+    let handler_permits = permits.clone();
+    esp1.on::<PingReq, _, _>(move |_src, ping| {
+        let (tx, permits) = (tx.clone(), handler_permits.clone());
+        async move {
+            permits.acquire().await.unwrap().forget();
+            let _ = tx.send(ping.number.unwrap_or_default()).await;
+        }
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    let sent = HANDLER_QUEUE_CAPACITY as u32 + 10;
+    for number in 0..sent {
+        brain
+            .send_typed("brain", "esp1", PingReq { number: Some(number) })
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let dropped = esp1.handler_error_count(PingReq::MSG_TYPE) as u32;
+    // the message the handler is waiting on is no longer in the queue
+    assert!((9..=10).contains(&dropped), "dropped {}", dropped);
+
+    permits.add_permits(sent as usize);
+    let mut received = vec![];
+    while let Ok(Some(number)) = timeout(Duration::from_secs(1), rx.recv()).await {
+        received.push(number);
+    }
+    // the oldest are handled in order, the newest were dropped
+    assert_eq!(received, (0..sent - dropped).collect::<Vec<u32>>());
+}
+
+#[tokio::test(start_paused = true)]
+async fn alive_advertises_handlers_events_and_services() {
+    let network = MemoryNetwork::new(4);
+    let broker = node(&network, "broker").await;
+    let esp1 = node(&network, "esp1").await;
+
+    esp1.on::<HoverboardCmd, _, _>(|_src, _cmd| async {});
+    esp1.serve::<PingReq, PingRep, _, _>(|_src, ping| async move {
+        PingRep {
+            number: ping.number,
+        }
+    });
+    esp1.send_event(SysEvent::default()).await.unwrap();
+    esp1.add_subscription("src/brain/**").await;
+
+    wait_for_endpoint(&broker, "esp1").await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let endpoint = broker.get_endpoints().await.get("esp1").unwrap().clone();
+    assert_eq!(endpoint.subscribe, vec!["HoverboardCmd", "src/brain/**"]);
+    assert_eq!(endpoint.publish, vec!["SysEvent"]);
+    assert_eq!(endpoint.services, vec!["PingReq"]);
+
+    // dropping the handler withdraws the subscription
+    let mut events = broker.endpoint_events();
+    assert!(esp1.remove_handler(HoverboardCmd::MSG_TYPE));
+    let changed = timeout(Duration::from_secs(3), async {
+        loop {
+            if let Ok(EndpointEvent::EndpointChanged { new, .. }) = events.recv().await {
+                return new;
+            }
+        }
+    })
+    .await
+    .expect("subscription not withdrawn");
+    assert_eq!(changed.subscribe, vec!["src/brain/**"]);
+    assert!(!broker
+        .get_subscriptions()
+        .await
+        .contains_key("HoverboardCmd"));
+}
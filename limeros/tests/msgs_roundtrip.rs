@@ -1,4 +1,5 @@
 use limeros::msgs::{Alive, Msg, SysEvent, UdpMessage};
+use limeros::Codec;
 
 
 
@@ -11,6 +12,13 @@ fn udp_message_cbor_roundtrip_preserves_payload_bytes() {
         src: Some("src".to_string()),
         msg_type: Some("SysEvent".to_string()),
         payload: Some(vec![0, 1, 2, 3, 255]),
+        correlation_id: Some(7),
+        seq: Some(8),
+        frag_id: Some(9),
+        frag_index: Some(1),
+        frag_count: Some(2),
+        codec: Some(Codec::Cbor),
+        nonce: Some(vec![1; 12]),
     };
 
     let bytes = msg.cbor_serialize().unwrap();
@@ -20,6 +28,13 @@ fn udp_message_cbor_roundtrip_preserves_payload_bytes() {
     assert_eq!(decoded.src, msg.src);
     assert_eq!(decoded.msg_type, msg.msg_type);
     assert_eq!(decoded.payload, msg.payload);
+    assert_eq!(decoded.correlation_id, msg.correlation_id);
+    assert_eq!(decoded.seq, msg.seq);
+    assert_eq!(decoded.frag_id, msg.frag_id);
+    assert_eq!(decoded.frag_index, msg.frag_index);
+    assert_eq!(decoded.frag_count, msg.frag_count);
+    assert_eq!(decoded.codec, msg.codec);
+    assert_eq!(decoded.nonce, msg.nonce);
 }
 
 #[test]
@@ -36,3 +51,36 @@ fn sys_event_json_roundtrip_preserves_fields() {
     assert_eq!(decoded.uptime, e.uptime);
     assert_eq!(decoded.cpu_board, e.cpu_board);
 }
+
+
+#[test]
+fn codec_detect_matches_encoding() {
+    let e = SysEvent {
+        uptime: Some(789),
+        ..Default::default()
+    };
+
+    for codec in [Codec::Json, Codec::Cbor] {
+        let bytes = codec.encode(&e).unwrap();
+        assert_eq!(Codec::detect(&bytes), codec);
+        let decoded: SysEvent = codec.decode(&bytes).unwrap();
+        assert_eq!(decoded.uptime, e.uptime);
+    }
+}
+
+#[test]
+fn codec_from_envelope_wins_over_detection() {
+    let msg = UdpMessage {
+        payload: Some(b"{}".to_vec()),
+        codec: Some(Codec::Cbor),
+        ..Default::default()
+    };
+    assert_eq!(Codec::of(&msg), Codec::Cbor);
+    assert_eq!(
+        Codec::of(&UdpMessage {
+            codec: None,
+            ..msg
+        }),
+        Codec::Json
+    );
+}
@@ -0,0 +1,149 @@
+use std::io::Cursor;
+use std::time::Duration;
+
+use limeros::msgs::{Codec, UdpMessage};
+use limeros::recording::{
+    RecordFilter, RecordReader, RecordWriter, ReplaySpeed, INDEX_MAGIC, MAGIC, VERSION,
+};
+
+fn message(src: &str, msg_type: &str, payload: &[u8]) -> UdpMessage {
+    UdpMessage {
+        src: Some(src.to_string()),
+        dst: Some("recorder".to_string()),
+        msg_type: Some(msg_type.to_string()),
+        payload: Some(payload.to_vec()),
+        codec: Some(Codec::Json),
+        ..Default::default()
+    }
+}
+
+fn recording() -> Vec<u8> {
+    let mut writer = RecordWriter::new(Cursor::new(vec![]), 1_700_000_000_000_000).unwrap();
+    writer
+        .write(0, &message("esp1", "HoverboardEvent", b"{\"speed\":1}"))
+        .unwrap();
+    writer
+        .write(20_000, &message("ps4", "Ps4Event", b"{\"axis_lx\":-3}"))
+        .unwrap();
+    writer
+        .write(20_000, &message("esp1", "MotorEvent", b"{}"))
+        .unwrap();
+    writer
+        .write(1_500_000, &message("esp1", "HoverboardEvent", &[0u8; 3000]))
+        .unwrap();
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn file_layout_matches_the_documented_format() {
+    let data = recording();
+
+    assert_eq!(&data[..8], MAGIC);
+    assert_eq!(u16::from_le_bytes([data[8], data[9]]), VERSION);
+    assert_eq!(
+        u64::from_le_bytes(data[10..18].try_into().unwrap()),
+        1_700_000_000_000_000
+    );
+    assert_eq!(&data[data.len() - 8..], INDEX_MAGIC);
+    let index_position =
+        u64::from_le_bytes(data[data.len() - 16..data.len() - 8].try_into().unwrap()) as usize;
+    let count = u32::from_le_bytes(data[index_position..index_position + 4].try_into().unwrap());
+    assert_eq!(count, 4);
+    // first record right after the header
+    let first = u64::from_le_bytes(
+        data[index_position + 12..index_position + 20]
+            .try_into()
+            .unwrap(),
+    );
+    assert_eq!(first, 18);
+}
+
+#[test]
+fn roundtrip_keeps_envelopes_and_offsets() {
+    let mut reader = RecordReader::new(Cursor::new(recording())).unwrap();
+
+    assert_eq!(reader.len(), 4);
+    assert_eq!(reader.start_us(), 1_700_000_000_000_000);
+    assert_eq!(reader.duration(), Duration::from_millis(1500));
+
+    let records: Vec<_> = reader.records(0).map(|r| r.unwrap()).collect();
+    assert_eq!(
+        records.iter().map(|r| r.offset_us).collect::<Vec<_>>(),
+        vec![0, 20_000, 20_000, 1_500_000]
+    );
+    assert_eq!(records[1].message.src.as_deref(), Some("ps4"));
+    assert_eq!(records[1].message.msg_type.as_deref(), Some("Ps4Event"));
+    assert_eq!(records[1].message.codec, Some(Codec::Json));
+    assert_eq!(records[3].message.payload.as_ref().unwrap().len(), 3000);
+
+    assert_eq!(reader.seek_time(20_000), 1);
+    assert_eq!(reader.seek_time(20_001), 3);
+    assert_eq!(reader.seek_time(2_000_000), 4);
+    assert_eq!(
+        reader.read(2).unwrap().message.msg_type.as_deref(),
+        Some("MotorEvent")
+    );
+}
+
+#[test]
+fn unfinished_recording_is_scanned_up_to_the_truncated_record() {
+    let mut writer = RecordWriter::new(Cursor::new(vec![]), 0).unwrap();
+    writer
+        .write(0, &message("esp1", "HoverboardEvent", b"{}"))
+        .unwrap();
+    writer
+        .write(1000, &message("esp1", "MotorEvent", b"{}"))
+        .unwrap();
+    let full = writer.finish().unwrap().into_inner();
+    let index_position =
+        u64::from_le_bytes(full[full.len() - 16..full.len() - 8].try_into().unwrap()) as usize;
+    // recorder killed : no index, and the last record only half written
+    let data = full[..index_position - 3].to_vec();
+
+    let mut reader = RecordReader::new(Cursor::new(data)).unwrap();
+    assert_eq!(reader.len(), 1);
+    assert_eq!(
+        reader.read(0).unwrap().message.msg_type.as_deref(),
+        Some("HoverboardEvent")
+    );
+}
+
+#[test]
+fn offsets_cannot_go_back() {
+    let mut writer = RecordWriter::new(Cursor::new(vec![]), 0).unwrap();
+    writer
+        .write(10, &message("esp1", "MotorEvent", b"{}"))
+        .unwrap();
+    assert!(writer
+        .write(5, &message("esp1", "MotorEvent", b"{}"))
+        .is_err());
+}
+
+#[test]
+fn filter_by_source_and_type() {
+    let filter = RecordFilter {
+        sources: vec!["esp1".to_string()],
+        msg_types: vec!["HoverboardEvent".to_string(), "MotorEvent".to_string()],
+    };
+    assert!(filter.matches(&message("esp1", "HoverboardEvent", b"{}")));
+    assert!(!filter.matches(&message("ps4", "HoverboardEvent", b"{}")));
+    assert!(!filter.matches(&message("esp1", "Ps4Event", b"{}")));
+    assert!(RecordFilter::default().matches(&message("ps4", "Ps4Event", b"{}")));
+}
+
+#[test]
+fn replay_speed_scales_the_gaps() {
+    assert_eq!(
+        ReplaySpeed::Original.delay(1_000, 21_000),
+        Some(Duration::from_millis(20))
+    );
+    assert_eq!(
+        ReplaySpeed::Scaled(4.0).delay(1_000, 21_000),
+        Some(Duration::from_millis(5))
+    );
+    assert_eq!(
+        ReplaySpeed::Scaled(0.0).delay(0, 1_000),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(ReplaySpeed::Stepped.delay(0, 1_000), None);
+}
@@ -0,0 +1,66 @@
+use limeros::msgs::UdpMessage;
+use limeros::reliable::{ReliableState, SeqWindow, SEQ_WINDOW};
+
+fn message(dst: &str, seq: u32) -> UdpMessage {
+    UdpMessage {
+        dst: Some(dst.to_string()),
+        src: Some("brain".to_string()),
+        msg_type: Some("HoverboardCmd".to_string()),
+        seq: Some(seq),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn seq_window_drops_duplicates_and_accepts_reordering() {
+    let mut window = SeqWindow::default();
+
+    assert!(window.accept(10));
+    assert!(window.accept(12));
+    assert!(window.accept(11));
+    assert!(!window.accept(11));
+    assert!(!window.accept(12));
+    assert!(window.accept(13));
+}
+
+#[test]
+fn seq_window_handles_wrap_around_and_restart() {
+    let mut window = SeqWindow::default();
+
+    assert!(window.accept(u32::MAX));
+    assert!(window.accept(0));
+    assert!(!window.accept(u32::MAX));
+    // a peer restarting far behind the window is accepted again
+    assert!(window.accept(0u32.wrapping_sub(SEQ_WINDOW + 10)));
+}
+
+#[test]
+fn ack_removes_tracked_message() {
+    let state = ReliableState::new();
+    let seq = state.next_seq("esp1");
+    assert_eq!(state.next_seq("esp1"), seq.wrapping_add(1));
+
+    state.track(&message("esp1", seq));
+    state.track(&message("esp1", seq));
+    assert_eq!(state.unacked_count(), 1);
+
+    assert!(!state.ack("esp2", seq));
+    assert!(state.ack("esp1", seq));
+    assert_eq!(state.unacked_count(), 0);
+    assert!(state.due_retransmits().is_empty());
+}
+
+#[test]
+fn undelivered_messages_wait_for_discovery() {
+    let state = ReliableState::new();
+    state.queue_undelivered(message("esp1", 1));
+    state.queue_undelivered(message("esp2", 2));
+
+    let deliverable = state.take_deliverable(|dst| dst == "esp2");
+    assert_eq!(deliverable.len(), 1);
+    assert_eq!(deliverable[0].seq, Some(2));
+
+    let deliverable = state.take_deliverable(|_| true);
+    assert_eq!(deliverable.len(), 1);
+    assert_eq!(deliverable[0].seq, Some(1));
+}
@@ -0,0 +1,192 @@
+mod common;
+use common::{node, wait_for_endpoint};
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::msgs::{PingRep, PingReq, TypedMessage, UdpMessage};
+use limeros::rpc::{attempt_timeouts, RpcError, ServiceWrapper, RPC_ATTEMPTS};
+use limeros::transport::MemoryNetwork;
+use limeros::txqueue::{tx_queue, TxConfig, TxSender};
+use limeros::{Codec, UdpMessageHandler};
+use tokio::time::Instant;
+
+#[test]
+fn attempt_timeouts_double_and_fill_the_timeout() {
+    let waits = attempt_timeouts(Duration::from_millis(700), 3);
+
+    assert_eq!(
+        waits,
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(400)
+        ]
+    );
+    assert_eq!(waits.iter().sum::<Duration>(), Duration::from_millis(700));
+}
+
+#[tokio::test(start_paused = true)]
+async fn request_gets_the_reply_of_serve() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+    esp1.serve::<PingReq, PingRep, _, _>(|_src, ping| async move {
+        PingRep {
+            number: ping.number.map(|n| n + 1),
+        }
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    let reply: PingRep = brain
+        .request("esp1", PingReq { number: Some(41) }, Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(reply.number, Some(42));
+}
+
+#[tokio::test(start_paused = true)]
+async fn request_retries_then_times_out() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+    // a handler that never replies
+    let received = Arc::new(AtomicU32::new(0));
+    let counter = received.clone();
+    esp1.on::<PingReq, _, _>(move |_src, _ping| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        async {}
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    let start = Instant::now();
+    let result: Result<PingRep, RpcError> = brain
+        .request("esp1", PingReq { number: Some(1) }, Duration::from_millis(700))
+        .await;
+    match result {
+        Err(RpcError::Timeout { dst, attempts, .. }) => {
+            assert_eq!(dst, "esp1");
+            assert_eq!(attempts, RPC_ATTEMPTS);
+        }
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert!(start.elapsed() >= Duration::from_millis(700));
+    assert_eq!(received.load(Ordering::Relaxed), RPC_ATTEMPTS);
+}
+
+/// Answers a PingReq twice : first with another correlation id, then with the right one
+struct WrongThenRight {
+    sender: TxSender,
+}
+
+#[async_trait::async_trait]
+impl UdpMessageHandler for WrongThenRight {
+    async fn handle(&self, request: &UdpMessage) -> anyhow::Result<()> {
+        if request.msg_type.as_deref() != Some(PingReq::MSG_TYPE) {
+            return Ok(());
+        }
+        let correlation_id = request.correlation_id.unwrap();
+        for (correlation_id, number) in [(correlation_id.wrapping_add(1), 666), (correlation_id, 7)] {
+            let reply = UdpMessage {
+                src: Some("esp1".to_string()),
+                dst: request.src.clone(),
+                msg_type: Some(PingRep::MSG_TYPE.to_string()),
+                payload: Some(Codec::Json.encode(&PingRep { number: Some(number) })?),
+                codec: Some(Codec::Json),
+                correlation_id: Some(correlation_id),
+                ..Default::default()
+            };
+            self.sender.send(reply).await?;
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn reply_with_another_correlation_id_is_ignored() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+    esp1.add_generic_handler(WrongThenRight {
+        sender: esp1.sender(),
+    })
+    .await;
+    wait_for_endpoint(&brain, "esp1").await;
+
+    let reply: PingRep = brain
+        .request("esp1", PingReq { number: Some(1) }, Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(reply.number, Some(7));
+}
+
+#[tokio::test(start_paused = true)]
+async fn slow_handler_runs_once_for_a_retried_request() {
+    let network = MemoryNetwork::new(1);
+    let esp1 = node(&network, "esp1").await;
+    let brain = node(&network, "brain").await;
+    let runs = Arc::new(AtomicU32::new(0));
+    let counter = runs.clone();
+    // replies after the first and second attempt timed out, both are sent again
+    esp1.serve::<PingReq, PingRep, _, _>(move |_src, ping| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        async move {
+            tokio::time::sleep(Duration::from_millis(350)).await;
+            PingRep {
+                number: ping.number.map(|n| n * 2),
+            }
+        }
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+
+    let reply: PingRep = brain
+        .request("esp1", PingReq { number: Some(21) }, Duration::from_millis(700))
+        .await
+        .unwrap();
+    assert_eq!(reply.number, Some(42));
+    assert_eq!(runs.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn retried_request_gets_the_cached_reply() {
+    let (sender, mut receiver) = tx_queue(TxConfig::default());
+    let runs = Arc::new(AtomicU32::new(0));
+    let counter = runs.clone();
+    let service = ServiceWrapper::<PingReq, PingRep, _>::new(
+        move |_src: String, ping: PingReq| {
+            let run = counter.fetch_add(1, Ordering::Relaxed);
+            async move {
+                PingRep {
+                    number: ping.number.map(|n| n + run),
+                }
+            }
+        },
+        Arc::new(tokio::sync::Mutex::new("esp1".to_string())),
+        sender,
+    );
+    let request = |correlation_id| UdpMessage {
+        src: Some("brain".to_string()),
+        dst: Some("esp1".to_string()),
+        msg_type: Some(PingReq::MSG_TYPE.to_string()),
+        payload: Some(Codec::Json.encode(&PingReq { number: Some(10) }).unwrap()),
+        codec: Some(Codec::Json),
+        correlation_id: Some(correlation_id),
+        ..Default::default()
+    };
+    let number = |reply: UdpMessage| {
+        assert_eq!(reply.dst.as_deref(), Some("brain"));
+        Codec::Json.decode::<PingRep>(&reply.payload.unwrap()).unwrap().number
+    };
+
+    service.handle(&request(5)).await.unwrap();
+    service.handle(&request(5)).await.unwrap();
+    assert_eq!(number(receiver.recv().await), Some(10));
+    assert_eq!(number(receiver.recv().await), Some(10));
+    assert_eq!(runs.load(Ordering::Relaxed), 1);
+
+    // another correlation id is another request
+    service.handle(&request(6)).await.unwrap();
+    assert_eq!(number(receiver.recv().await), Some(11));
+    assert_eq!(runs.load(Ordering::Relaxed), 2);
+}
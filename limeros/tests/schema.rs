@@ -0,0 +1,130 @@
+use limeros::msgs::{
+    BrokerRatesRep, Codec, LogEvent, LogLevel, Msg, TypedMessage, UdpMessage, ZenohEvent,
+};
+use limeros::schema::{FieldType, Registry, Value};
+
+fn log_event() -> LogEvent {
+    LogEvent {
+        level: Some(LogLevel::Warn),
+        message: Some("low battery".to_string()),
+        line: Some(42),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn builtin_registry_describes_the_generated_types() {
+    let registry = Registry::builtin();
+
+    let descriptor = registry.get_by_id(LogEvent::ID).unwrap();
+    assert_eq!(descriptor.name, LogEvent::MSG_TYPE);
+    let fields = registry.fields("LogEvent").unwrap();
+    assert_eq!(fields[0], ("level", FieldType::Enum("LogLevel")));
+    assert_eq!(fields[1], ("message", FieldType::Text));
+    assert_eq!(fields[2], ("error_code", FieldType::Int));
+    assert_eq!(fields[5], ("timestamp", FieldType::UInt));
+    assert!(registry.fields("NoSuchMessage").is_none());
+    assert!(registry
+        .messages()
+        .iter()
+        .any(|m| m.name == "HoverboardEvent"));
+}
+
+#[test]
+fn json_and_cbor_decode_to_the_same_tree() {
+    let registry = Registry::builtin();
+    let event = log_event();
+
+    let from_json = registry
+        .decode("LogEvent", Codec::Json, &event.json_serialize().unwrap())
+        .unwrap();
+    let from_cbor = registry
+        .decode("LogEvent", Codec::Cbor, &event.cbor_serialize().unwrap())
+        .unwrap();
+
+    assert_eq!(from_json, from_cbor);
+    assert_eq!(
+        from_cbor,
+        Value::Map(vec![
+            ("level".to_string(), Value::Text("Warn".to_string())),
+            (
+                "message".to_string(),
+                Value::Text("low battery".to_string())
+            ),
+            ("line".to_string(), Value::Int(42)),
+        ])
+    );
+}
+
+#[test]
+fn repeated_fields_and_floats() {
+    let registry = Registry::builtin();
+    let zenoh = ZenohEvent {
+        peers: Some(vec!["a".to_string(), "b".to_string()]),
+        ..Default::default()
+    };
+    let value = registry
+        .decode("ZenohEvent", Codec::Cbor, &zenoh.cbor_serialize().unwrap())
+        .unwrap();
+    assert_eq!(
+        value.flatten(),
+        vec![
+            ("peers.0".to_string(), Value::Text("a".to_string())),
+            ("peers.1".to_string(), Value::Text("b".to_string())),
+        ]
+    );
+
+    // a whole float in JSON is still a float
+    let value = registry
+        .decode("BrokerRatesRep", Codec::Json, br#"{"rate":[2,2.5]}"#)
+        .unwrap();
+    assert_eq!(
+        value.get("rate"),
+        Some(&Value::List(vec![Value::Float(2.0), Value::Float(2.5)]))
+    );
+    let rates = BrokerRatesRep {
+        rate: Some(vec![2.5]),
+        ..Default::default()
+    };
+    let value = registry
+        .decode(
+            "BrokerRatesRep",
+            Codec::Cbor,
+            &rates.cbor_serialize().unwrap(),
+        )
+        .unwrap();
+    assert_eq!(value.get("rate").unwrap().to_string(), "[2.5]");
+}
+
+#[test]
+fn unknown_types_are_shown_raw() {
+    let registry = Registry::default();
+    let mut message = UdpMessage {
+        msg_type: Some("LogEvent".to_string()),
+        payload: Some(log_event().cbor_serialize().unwrap()),
+        codec: Some(Codec::Cbor),
+        ..Default::default()
+    };
+    assert!(registry.decode("LogEvent", Codec::Cbor, &[]).is_err());
+
+    // without schema the CBOR fields keep their index
+    let value = registry.decode_message(&message);
+    assert_eq!(
+        value.get("3"),
+        Some(&Value::Text("low battery".to_string()))
+    );
+
+    message.payload = Some(br#"{"temperature":21.5}"#.to_vec());
+    message.codec = Some(Codec::Json);
+    let value = registry.decode_message(&message);
+    assert_eq!(value.get("temperature").unwrap().as_f64(), Some(21.5));
+
+    message.payload = Some(vec![0x01, 0x02]);
+    message.codec = Some(Codec::Cbor);
+    let value = registry.decode_message(&message);
+    assert_eq!(
+        value.flatten(),
+        vec![(String::new(), Value::Bytes(vec![1, 2]))]
+    );
+    assert_eq!(value.to_string(), "0x0102");
+}
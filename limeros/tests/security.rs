@@ -0,0 +1,187 @@
+mod common;
+use common::{wait_for_endpoint, GROUP};
+
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use limeros::codec::Codec;
+use limeros::msgs::{Msg, PingReq, UdpMessage};
+use limeros::security::{Security, SecurityConfig};
+use limeros::transport::{MemoryNetwork, Network};
+use limeros::{NodeConfig, UdpNode};
+use tokio::sync::mpsc;
+
+const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+fn config(key: &str) -> SecurityConfig {
+    SecurityConfig {
+        network: "mower".to_string(),
+        key: key.to_string(),
+        max_clock_skew_secs: 30,
+    }
+}
+
+fn hoverboard_cmd() -> UdpMessage {
+    UdpMessage {
+        src: Some("brain".to_string()),
+        dst: Some("esp1".to_string()),
+        msg_type: Some("HoverboardCmd".to_string()),
+        payload: Some(br#"{"speed":100,"steer":0}"#.to_vec()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sealed_message_opens_with_the_same_key() {
+    let security = Security::new(&config(KEY)).unwrap();
+    let sealed = security.seal(hoverboard_cmd()).unwrap();
+    assert_ne!(sealed.payload, hoverboard_cmd().payload);
+
+    let opened = security.open(sealed).unwrap();
+    assert_eq!(opened.payload, hoverboard_cmd().payload);
+    assert_eq!(opened.nonce, None);
+}
+
+#[test]
+fn replayed_message_is_rejected() {
+    let security = Security::new(&config(KEY)).unwrap();
+    let sealed = security.seal(hoverboard_cmd()).unwrap();
+
+    assert!(security.open(sealed.clone()).is_ok());
+    assert!(security.open(sealed).is_err());
+}
+
+#[test]
+fn replay_window_is_per_authenticated_sender() {
+    let receiver = Security::new(&config(KEY)).unwrap();
+    let sealed = Security::new(&config(KEY)).unwrap().seal(hoverboard_cmd()).unwrap();
+
+    // the transport address isn't part of the message, an attacker picks any port
+    assert!(receiver.open(sealed.clone()).is_ok());
+    assert!(receiver.open(sealed.clone()).is_err());
+
+    // the same nonce under another sender fails authentication, src is associated data
+    let mut other_src = sealed;
+    other_src.src = Some("esp1".to_string());
+    assert!(receiver.open(other_src).is_err());
+    assert_eq!(receiver.senders(), 1);
+}
+
+async fn secure_node(network: &MemoryNetwork, id: &str) -> Arc<UdpNode> {
+    let config = NodeConfig {
+        network: Arc::new(network.clone()),
+        security: Some(Security::new(&config(KEY)).unwrap()),
+        ..Default::default()
+    };
+    UdpNode::with_config(id, GROUP, config).await.unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn captured_packet_resent_from_another_port_is_dropped() {
+    let network = MemoryNetwork::new(3);
+    let esp1 = secure_node(&network, "esp1").await;
+    let brain = secure_node(&network, "brain").await;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    esp1.on::<PingReq, _, _>(move |_src, ping| {
+        let _ = tx.send(ping.number);
+        async {}
+    });
+    wait_for_endpoint(&brain, "esp1").await;
+    let esp1_addr = brain.scout().endpoint_to_addr("esp1").unwrap();
+
+    // what an eavesdropper recorded of a PingReq from brain
+    let captured = Security::new(&config(KEY))
+        .unwrap()
+        .seal(UdpMessage {
+            src: Some("brain".to_string()),
+            dst: Some("esp1".to_string()),
+            msg_type: Some("PingReq".to_string()),
+            payload: Some(Codec::Cbor.encode(&PingReq { number: Some(1) }).unwrap()),
+            codec: Some(Codec::Cbor),
+            ..Default::default()
+        })
+        .unwrap()
+        .cbor_serialize()
+        .unwrap();
+    for _ in 0..2 {
+        let socket = network.bind_unicast(None, false).unwrap();
+        socket.send_to(&captured, esp1_addr).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    assert_eq!(rx.try_recv().unwrap(), Some(1));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn idle_sender_windows_are_evicted() {
+    let receiver = Security::new(&SecurityConfig {
+        max_clock_skew_secs: 1,
+        ..config(KEY)
+    })
+    .unwrap();
+    let sender = Security::new(&config(KEY)).unwrap();
+    assert!(receiver.open(sender.seal(hoverboard_cmd()).unwrap()).is_ok());
+    assert_eq!(receiver.senders(), 1);
+
+    std::thread::sleep(std::time::Duration::from_millis(1_100));
+    let esp1 = UdpMessage {
+        src: Some("esp1".to_string()),
+        ..hoverboard_cmd()
+    };
+    assert!(receiver.open(sender.seal(esp1).unwrap()).is_ok());
+    assert_eq!(receiver.senders(), 1);
+}
+
+#[test]
+fn tampered_payload_or_envelope_is_rejected() {
+    let security = Security::new(&config(KEY)).unwrap();
+    let sealed = security.seal(hoverboard_cmd()).unwrap();
+
+    let mut payload = sealed.clone();
+    payload.payload.as_mut().unwrap()[0] ^= 1;
+    assert!(security.open(payload).is_err());
+
+    let mut msg_type = sealed.clone();
+    msg_type.msg_type = Some("SysCmd".to_string());
+    assert!(security.open(msg_type).is_err());
+
+    let mut dst = sealed;
+    dst.dst = Some("esp2".to_string());
+    assert!(security.open(dst).is_err());
+}
+
+#[test]
+fn unsealed_stale_and_foreign_messages_are_rejected() {
+    let security = Security::new(&config(KEY)).unwrap();
+    assert!(security.open(hoverboard_cmd()).is_err());
+
+    let stale = security.seal_at(hoverboard_cmd(), 1_000_000).unwrap();
+    assert!(security.open(stale).is_err());
+
+    let other_key = KEY.replace("00", "ff");
+    let foreign = Security::new(&config(&other_key))
+        .unwrap()
+        .seal(hoverboard_cmd())
+        .unwrap();
+    assert!(security.open(foreign).is_err());
+}
+
+#[test]
+fn config_loads_from_json_file() {
+    let path = std::env::temp_dir().join(format!("limeros-psk-{}.json", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    write!(file, r#"{{ "network": "mower", "key": "{}" }}"#, KEY).unwrap();
+
+    let config = SecurityConfig::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(config.network, "mower");
+    assert_eq!(config.max_clock_skew_secs, 30);
+    assert!(Security::new(&config).is_ok());
+    assert!(Security::new(&SecurityConfig {
+        key: "0011".to_string(),
+        ..config
+    })
+    .is_err());
+}
@@ -0,0 +1,70 @@
+use limeros::pattern::{KeyPattern, SubscriptionIndex};
+
+#[test]
+fn msg_type_patterns_glob_within_the_type() {
+    assert!(KeyPattern::parse("HoverboardEvent").matches("esp1", "broker", "HoverboardEvent"));
+    assert!(KeyPattern::parse("Hoverboard*").matches("esp1", "broker", "HoverboardCmd"));
+    assert!(KeyPattern::parse("*").matches("esp1", "broker", "SysEvent"));
+    assert!(!KeyPattern::parse("Hoverboard*").matches("esp1", "broker", "SysEvent"));
+}
+
+#[test]
+fn globs_backtrack_to_the_last_star() {
+    let matches = |pattern: &str, msg_type: &str| {
+        KeyPattern::parse(pattern).matches("esp1", "broker", msg_type)
+    };
+    assert!(matches("*Event", "HoverboardEvent"));
+    assert!(matches("H*b*Event", "HoverboardEvent"));
+    assert!(matches("Hover**Event", "HoverEvent"));
+    assert!(matches("*o*o*", "HoverboardEvent"));
+    assert!(!matches("*o*o*o*", "HoverboardEvent"));
+    assert!(!matches("Hoverboard*Cmd", "HoverboardEvent"));
+    assert!(matches("src/esp*/Sys*", "SysEvent"));
+    assert!(matches("**/SysEvent", "SysEvent"));
+    assert!(matches("src/**/**/SysEvent", "SysEvent"));
+    assert!(!matches("src/**/esp1/**/broker", "SysEvent"));
+}
+
+#[test]
+fn hostile_patterns_do_not_backtrack_exponentially() {
+    // exponential for a recursive glob
+    let pattern = format!("{}b", "a*".repeat(30));
+    let msg_type = "a".repeat(200);
+    let start = std::time::Instant::now();
+    assert!(!KeyPattern::parse(&pattern).matches("esp1", "broker", &msg_type));
+    assert!(!KeyPattern::parse(&format!("**/{}/**/**/**/x", pattern)).matches("esp1", "broker", &msg_type));
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+}
+
+#[test]
+fn key_expressions_match_source_and_destination() {
+    assert!(KeyPattern::parse("src/esp1/*").matches("esp1", "broker", "SysEvent"));
+    assert!(!KeyPattern::parse("src/esp1/*").matches("esp2", "broker", "SysEvent"));
+    assert!(KeyPattern::parse("src/*/WifiEvent").matches("esp2", "broker", "WifiEvent"));
+    assert!(KeyPattern::parse("src/**").matches("esp2", "broker", "WifiEvent"));
+    assert!(KeyPattern::parse("dst/broker/Sys*").matches("esp2", "broker", "SysEvent"));
+    assert!(KeyPattern::parse("**").matches("esp2", "broker", "SysEvent"));
+    assert!(!KeyPattern::parse("src/esp1").matches("esp1", "broker", "SysEvent"));
+}
+
+#[test]
+fn overlapping_patterns_deliver_once_per_destination() {
+    let mut index = SubscriptionIndex::new();
+    index.insert("HoverboardEvent", "tui");
+    index.insert("Hoverboard*", "tui");
+    index.insert("src/esp1/**", "tui");
+    index.insert("*", "monitor");
+    index.insert("HoverboardEvent", "brain");
+    index.insert("HoverboardEvent", "brain");
+
+    assert_eq!(index.len(), 4);
+    assert_eq!(
+        index.destinations("esp1", "broker", "HoverboardEvent"),
+        vec!["tui", "brain", "monitor"]
+    );
+    assert_eq!(index.destinations("esp2", "broker", "SysEvent"), vec!["monitor"]);
+    assert_eq!(
+        index.destinations("esp1", "broker", "SysEvent"),
+        vec!["tui", "monitor"]
+    );
+}
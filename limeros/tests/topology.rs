@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use limeros::broker::FlowRates;
+use limeros::scout::Endpoint;
+use limeros::topology::{EdgeKind, FlowRate, NodeKind, Topology};
+
+fn endpoint(name: &str, subscribe: &[&str], publish: &[&str], services: &[&str]) -> Endpoint {
+    let list = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+    Endpoint {
+        name: name.to_string(),
+        addr: "10.0.0.1:5000".parse().unwrap(),
+        last_seen: Instant::now(),
+        subscribe: list(subscribe),
+        publish: list(publish),
+        services: list(services),
+        interface: None,
+    }
+}
+
+fn network() -> Vec<Endpoint> {
+    vec![
+        endpoint("esp1", &[], &["HoverboardInfo", "LogInfo"], &["PingReq"]),
+        endpoint("pc", &["HoverboardInfo", "Log*"], &["HoverboardCmd"], &[]),
+        endpoint("logger", &["Debug*"], &[], &[]),
+    ]
+}
+
+fn has_edge(topology: &Topology, from: &str, to: &str, kind: EdgeKind) -> bool {
+    topology
+        .edges
+        .iter()
+        .any(|e| e.from == from && e.to == to && e.kind == kind)
+}
+
+#[tokio::test]
+async fn graph_of_publications_subscriptions_and_services() {
+    let topology = Topology::build(&network(), &[]);
+
+    assert!(has_edge(
+        &topology,
+        "endpoint:esp1",
+        "topic:HoverboardInfo",
+        EdgeKind::Publish
+    ));
+    assert!(has_edge(
+        &topology,
+        "topic:HoverboardInfo",
+        "endpoint:pc",
+        EdgeKind::Subscribe
+    ));
+    // a pattern is drawn from the topics it matches
+    assert!(has_edge(
+        &topology,
+        "topic:LogInfo",
+        "endpoint:pc",
+        EdgeKind::Subscribe
+    ));
+    assert!(!topology.nodes.iter().any(|n| n.name == "Log*"));
+    // or as a pattern node when nothing matches
+    assert!(has_edge(
+        &topology,
+        "pattern:Debug*",
+        "endpoint:logger",
+        EdgeKind::Subscribe
+    ));
+    assert!(has_edge(
+        &topology,
+        "service:PingReq",
+        "endpoint:esp1",
+        EdgeKind::Serve
+    ));
+    // published without subscriber is still a topic
+    assert!(topology
+        .nodes
+        .iter()
+        .any(|n| n.id == "topic:HoverboardCmd" && n.kind == NodeKind::Topic));
+}
+
+#[tokio::test]
+async fn broker_subscriptions_and_removed_endpoints() {
+    let subscriptions = vec![("HoverboardCmd".to_string(), "esp1".to_string())];
+    let mut topology = Topology::build(&network(), &subscriptions);
+    assert!(has_edge(
+        &topology,
+        "topic:HoverboardCmd",
+        "endpoint:esp1",
+        EdgeKind::Subscribe
+    ));
+
+    topology.remove_endpoint("logger");
+    assert!(!topology.nodes.iter().any(|n| n.id == "endpoint:logger"));
+    // the pattern only the logger subscribed to goes with it
+    assert!(!topology.nodes.iter().any(|n| n.id == "pattern:Debug*"));
+}
+
+#[tokio::test]
+async fn exports_dot_mermaid_and_json() {
+    let topology = Topology::build(&network(), &[]);
+
+    let dot = topology.to_dot();
+    assert!(dot.starts_with("digraph limeros {"));
+    assert!(dot.contains("\"endpoint:esp1\" [label=\"esp1\", shape=box];"));
+    assert!(dot.contains("\"endpoint:esp1\" -> \"topic:HoverboardInfo\";"));
+    assert!(dot.contains("\"service:PingReq\" -> \"endpoint:esp1\" [style=dashed];"));
+
+    let mermaid = topology.to_mermaid();
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    assert!(mermaid.contains("[\"esp1\"]"));
+    assert!(mermaid.contains("([\"HoverboardInfo\"])"));
+    assert!(mermaid.contains("[/\"Debug*\"/]"));
+    assert!(mermaid.contains("-.->"));
+
+    let json: serde_json::Value = serde_json::from_str(&topology.to_json().unwrap()).unwrap();
+    assert_eq!(
+        json["nodes"].as_array().unwrap().len(),
+        topology.nodes.len()
+    );
+    assert_eq!(json["nodes"][0]["kind"], "endpoint");
+    assert!(json["edges"][0].get("rate").is_none());
+}
+
+#[tokio::test]
+async fn rates_annotate_the_edges() {
+    let flows = vec![
+        FlowRate {
+            src: "esp1".to_string(),
+            dst: None,
+            msg_type: "HoverboardInfo".to_string(),
+            rate: 10.0,
+        },
+        FlowRate {
+            src: "esp1".to_string(),
+            dst: Some("pc".to_string()),
+            msg_type: "HoverboardInfo".to_string(),
+            rate: 9.5,
+        },
+    ];
+    let reply = FlowRate::to_reply(&flows);
+    assert_eq!(FlowRate::from_reply(&reply), flows);
+
+    let topology = Topology::build(&network(), &[]).with_rates(&flows);
+    let rate = |from: &str, to: &str| {
+        topology
+            .edges
+            .iter()
+            .find(|e| e.from == from && e.to == to)
+            .unwrap()
+            .rate
+    };
+    assert_eq!(rate("endpoint:esp1", "topic:HoverboardInfo"), Some(10.0));
+    assert_eq!(rate("topic:HoverboardInfo", "endpoint:pc"), Some(9.5));
+    assert_eq!(rate("topic:LogInfo", "endpoint:pc"), Some(0.0));
+    assert_eq!(rate("service:PingReq", "endpoint:esp1"), None);
+    assert!(topology.to_dot().contains("[label=\"10.0/s\"]"));
+    assert!(topology.to_mermaid().contains("-->|9.5/s|"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn flow_rates_over_a_sliding_window() {
+    let rates = FlowRates::new(Duration::from_secs(2));
+    for i in 0..4 {
+        if i > 0 {
+            tokio::time::advance(Duration::from_millis(500)).await;
+        }
+        rates.record("esp1", None, "HoverboardInfo");
+        rates.record("esp1", Some("pc"), "HoverboardInfo");
+    }
+    // 4 messages in 1.5 seconds, the window is not full yet
+    let flows = rates.rates();
+    assert_eq!(flows.len(), 2);
+    assert_eq!(flows[0].dst, None);
+    assert!((flows[0].rate - 4.0 / 1.5).abs() < 1e-9);
+    assert_eq!(flows[1].dst.as_deref(), Some("pc"));
+
+    // nothing within the window any more
+    tokio::time::advance(Duration::from_secs(3)).await;
+    assert!(rates.rates().is_empty());
+}
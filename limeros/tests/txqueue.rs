@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use tokio::time::{timeout, Instant};
+
+use limeros::msgs::UdpMessage;
+use limeros::txqueue::{tx_queue, Priority, RateLimit, TxConfig, TxError};
+
+fn message(dst: &str, msg_type: &str, correlation_id: u32) -> UdpMessage {
+    UdpMessage {
+        dst: Some(dst.to_string()),
+        msg_type: Some(msg_type.to_string()),
+        correlation_id: Some(correlation_id),
+        ..Default::default()
+    }
+}
+
+fn telemetry(dst: &str, value: u8) -> UdpMessage {
+    UdpMessage {
+        dst: Some(dst.to_string()),
+        msg_type: Some("HoverboardEvent".to_string()),
+        payload: Some(vec![value]),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn higher_priority_goes_first() {
+    let mut config = TxConfig::default();
+    config
+        .priorities
+        .insert("LawnmowerManualCmd".to_string(), Priority::Critical);
+    config
+        .priorities
+        .insert("LogEvent".to_string(), Priority::Low);
+    let (sender, mut receiver) = tx_queue(config);
+
+    sender.send(message("broker", "LogEvent", 1)).await.unwrap();
+    sender.send(telemetry("broker", 1)).await.unwrap();
+    sender
+        .send(message("mower", "LawnmowerManualCmd", 2))
+        .await
+        .unwrap();
+
+    let order: Vec<String> = [
+        receiver.recv().await,
+        receiver.recv().await,
+        receiver.recv().await,
+    ]
+    .into_iter()
+    .map(|m| m.msg_type.unwrap())
+    .collect();
+    assert_eq!(
+        order,
+        vec!["LawnmowerManualCmd", "HoverboardEvent", "LogEvent"]
+    );
+}
+
+#[tokio::test]
+async fn a_full_queue_only_blocks_its_own_priority() {
+    let config = TxConfig {
+        capacity: 2,
+        priorities: [("LawnmowerManualCmd".to_string(), Priority::Critical)].into(),
+        ..Default::default()
+    };
+    let (sender, mut receiver) = tx_queue(config);
+
+    sender
+        .try_send(message("broker", "HoverboardEvent", 1))
+        .unwrap();
+    sender
+        .try_send(message("broker", "HoverboardEvent", 2))
+        .unwrap();
+    assert_eq!(
+        sender.try_send(message("broker", "HoverboardEvent", 3)),
+        Err(TxError::Full)
+    );
+    sender
+        .try_send(message("mower", "LawnmowerManualCmd", 4))
+        .unwrap();
+
+    // a waiting sender gets through once the queue drains
+    let waiting = {
+        let sender = sender.clone();
+        tokio::spawn(async move { sender.send(message("broker", "HoverboardEvent", 5)).await })
+    };
+    assert_eq!(receiver.recv().await.correlation_id, Some(4));
+    assert_eq!(receiver.recv().await.correlation_id, Some(1));
+    timeout(Duration::from_secs(1), waiting)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(sender.len(), 2);
+
+    drop(receiver);
+    assert_eq!(
+        sender.send(message("broker", "HoverboardEvent", 6)).await,
+        Err(TxError::Closed)
+    );
+}
+
+#[tokio::test]
+async fn latest_value_wins_per_destination() {
+    let (sender, mut receiver) = tx_queue(TxConfig::default());
+    sender.set_coalescing("HoverboardEvent", true);
+
+    for value in 0..10 {
+        sender.send(telemetry("broker", value)).await.unwrap();
+    }
+    sender.send(telemetry("pc", 20)).await.unwrap();
+    // requests are never replaced
+    sender
+        .send(message("broker", "HoverboardEvent", 1))
+        .await
+        .unwrap();
+
+    assert_eq!(sender.len(), 3);
+    assert_eq!(receiver.recv().await.payload, Some(vec![9]));
+    assert_eq!(receiver.recv().await.payload, Some(vec![20]));
+    assert_eq!(receiver.recv().await.correlation_id, Some(1));
+    assert_eq!(sender.stats().coalesced, 9);
+}
+
+#[tokio::test(start_paused = true)]
+async fn rate_limit_per_destination() {
+    let (sender, mut receiver) = tx_queue(TxConfig::default());
+    sender.set_rate_limit(
+        "broker",
+        Some(RateLimit {
+            rate: 10.0,
+            burst: 2,
+        }),
+    );
+    sender.set_priority("LawnmowerManualCmd", Priority::Critical);
+    for value in 0..4 {
+        sender.send(telemetry("broker", value)).await.unwrap();
+    }
+    sender.send(telemetry("pc", 10)).await.unwrap();
+
+    let start = Instant::now();
+    // the burst goes out at once, the unlimited destination is not held back
+    assert_eq!(receiver.recv().await.payload, Some(vec![0]));
+    assert_eq!(receiver.recv().await.payload, Some(vec![1]));
+    assert_eq!(receiver.recv().await.payload, Some(vec![10]));
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // then one message per 100 ms, in order
+    assert_eq!(receiver.recv().await.payload, Some(vec![2]));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+    // critical messages skip the limit
+    sender
+        .send(message("broker", "LawnmowerManualCmd", 1))
+        .await
+        .unwrap();
+    assert_eq!(receiver.recv().await.correlation_id, Some(1));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+    assert_eq!(receiver.recv().await.payload, Some(vec![3]));
+    assert_eq!(start.elapsed(), Duration::from_millis(200));
+    assert_eq!(sender.stats().sent, 6);
+    assert!(sender.stats().rate_limited >= 2);
+}
@@ -0,0 +1,459 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow, bail};
+use convert_case::{Case, Casing};
+use log::warn;
+use serde::Serialize;
+
+use crate::parser::MermaidStateDiagram;
+use crate::types::{Note, State, StateReference, StateType, Transition};
+
+/*
+   The diagram flattened to what the rust_fsm.tera template needs.
+
+   A state belongs to the composite state whose body mentions it, a composite can so be
+   declared at the top level and still be nested, as in syntax/states.md.
+   Transition labels are read as "Event [guard] / action", all three become identifiers.
+   "X --> [*]" inside composite C enters the generated leaf state CFinal, an unlabeled
+   transition leaving C is its completion transition, taken as soon as CFinal is entered.
+   Other unlabeled transitions have no trigger and are skipped.
+*/
+
+#[derive(Debug, Serialize)]
+pub struct FsmState {
+    /// enum variant
+    pub name: String,
+    /// id in the diagram
+    pub id: String,
+    pub description: Option<String>,
+    pub parent: Option<String>,
+    /// initial substate of a composite state
+    pub initial: Option<String>,
+    /// for a final state, the completion transition of its composite as (source, target)
+    pub completion: Option<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FsmTransition {
+    pub source: String,
+    pub target: String,
+    pub event: String,
+    pub guard: Option<String>,
+    pub action: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StateMachine {
+    pub name: String,
+    pub initial: String,
+    pub states: Vec<FsmState>,
+    pub events: Vec<String>,
+    pub guards: Vec<String>,
+    pub actions: Vec<String>,
+    pub transitions: Vec<FsmTransition>,
+}
+
+struct ScopedTransition {
+    scope: Option<String>,
+    transition: Transition,
+}
+
+#[derive(Default)]
+struct Collector {
+    /// state id to its parent, in the order of first appearance
+    order: Vec<String>,
+    parents: BTreeMap<String, Option<String>>,
+    descriptions: BTreeMap<String, String>,
+    transitions: Vec<ScopedTransition>,
+}
+
+impl Collector {
+    fn mention(&mut self, id: &str, scope: Option<&str>) -> Result<()> {
+        match self.parents.get(id) {
+            None => {
+                self.order.push(id.to_string());
+                self.parents
+                    .insert(id.to_string(), scope.map(str::to_string));
+            }
+            Some(None) => {
+                self.parents
+                    .insert(id.to_string(), scope.map(str::to_string));
+            }
+            Some(Some(parent)) => {
+                if let Some(scope) = scope
+                    && parent != scope
+                {
+                    bail!("state {} is in both {} and {}", id, parent, scope);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn collect(
+        &mut self,
+        scope: Option<&str>,
+        states: &[State],
+        transitions: &[Transition],
+        notes: &[Note],
+    ) -> Result<()> {
+        for state in states {
+            if state.state_type != StateType::Normal {
+                bail!("{:?} state {} is not supported", state.state_type, state.id);
+            }
+            if state.concurrent {
+                bail!("concurrent regions in {} are not supported", state.id);
+            }
+            self.mention(&state.id, scope)?;
+            if let Some(description) = state.description.as_ref().or(state.name.as_ref()) {
+                self.describe(&state.id, description);
+            }
+            self.collect(
+                Some(&state.id),
+                &state.states,
+                &state.transitions,
+                &state.notes,
+            )?;
+        }
+        for note in notes {
+            self.describe(&note.state, &note.content);
+        }
+        for transition in transitions {
+            for reference in [&transition.from, &transition.to] {
+                match reference {
+                    StateReference::State(id) => self.mention(id, scope)?,
+                    StateReference::WithSuffix { state, suffix } => {
+                        bail!("{}#{:?} is not supported", state, suffix)
+                    }
+                    _ => {}
+                }
+            }
+            self.transitions.push(ScopedTransition {
+                scope: scope.map(str::to_string),
+                transition: transition.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// the first description or note becomes the doc comment of the state
+    fn describe(&mut self, id: &str, text: &str) {
+        let line = text
+            .replace("\\n", " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.descriptions.entry(id.to_string()).or_insert(line);
+    }
+
+    fn is_composite(&self, id: &str) -> bool {
+        self.parents.values().any(|p| p.as_deref() == Some(id))
+    }
+}
+
+fn final_state(scope: &Option<String>) -> String {
+    match scope {
+        Some(scope) => format!("{}Final", scope),
+        None => "Final".to_string(),
+    }
+}
+
+fn identifier(text: &str, case: Case) -> Result<String> {
+    let words: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let ident = words.trim().to_case(case);
+    match ident.chars().next() {
+        Some(c) if c.is_alphabetic() => Ok(ident),
+        _ => Err(anyhow!("'{}' is not usable as an identifier", text)),
+    }
+}
+
+/// "Event [guard] / action" into its identifiers
+pub fn parse_label(label: &str) -> Result<(String, Option<String>, Option<String>)> {
+    let (trigger, action) = match label.split_once('/') {
+        Some((trigger, action)) => (trigger, Some(action)),
+        None => (label, None),
+    };
+    let (event, guard) = match trigger.split_once('[') {
+        Some((event, guard)) => (event, Some(guard.trim().trim_end_matches(']'))),
+        None => (trigger, None),
+    };
+    Ok((
+        identifier(event, Case::Pascal)?,
+        guard.map(|g| identifier(g, Case::Snake)).transpose()?,
+        action.map(|a| identifier(a, Case::Snake)).transpose()?,
+    ))
+}
+
+fn push_unique(list: &mut Vec<String>, item: &str) {
+    if !list.iter().any(|i| i == item) {
+        list.push(item.to_string());
+    }
+}
+
+impl StateMachine {
+    pub fn from_diagram(name: &str, diagram: &MermaidStateDiagram) -> Result<Self> {
+        let mut collector = Collector::default();
+        collector.collect(None, &diagram.states, &diagram.transitions, &diagram.notes)?;
+
+        let mut initials: BTreeMap<Option<String>, String> = BTreeMap::new();
+        let mut labeled = vec![];
+        let mut unlabeled = vec![];
+        for scoped in &collector.transitions {
+            let transition = &scoped.transition;
+            match (&transition.from, &transition.to) {
+                (StateReference::Initial, StateReference::State(to)) => {
+                    if let Some(other) = initials.insert(scoped.scope.clone(), to.clone()) {
+                        bail!(
+                            "two initial states {} and {} in {}",
+                            other,
+                            to,
+                            scoped.scope.as_deref().unwrap_or("the diagram")
+                        );
+                    }
+                }
+                (StateReference::Initial, _) => bail!("[*] --> [*] has no state"),
+                (StateReference::State(from), _) => match &transition.label {
+                    Some(label) => labeled.push((scoped, from, label)),
+                    None => unlabeled.push((scoped, from)),
+                },
+                _ => {}
+            }
+        }
+
+        // final states are leaves of the scope they are reached in
+        let mut finals: Vec<(String, Option<String>)> = vec![];
+        let mut target = |scoped: &ScopedTransition| match &scoped.transition.to {
+            StateReference::State(id) => id.clone(),
+            _ => {
+                let state = final_state(&scoped.scope);
+                if !finals.iter().any(|(f, _)| *f == state) {
+                    finals.push((state.clone(), scoped.scope.clone()));
+                }
+                state
+            }
+        };
+
+        let mut machine = StateMachine {
+            name: name.to_string(),
+            initial: String::new(),
+            states: vec![],
+            events: vec![],
+            guards: vec![],
+            actions: vec![],
+            transitions: vec![],
+        };
+        for (scoped, from, label) in labeled {
+            let (event, guard, action) = parse_label(label)?;
+            push_unique(&mut machine.events, &event);
+            if let Some(guard) = &guard {
+                push_unique(&mut machine.guards, guard);
+            }
+            if let Some(action) = &action {
+                push_unique(&mut machine.actions, action);
+            }
+            machine.transitions.push(FsmTransition {
+                source: from.clone(),
+                target: target(scoped),
+                event,
+                guard,
+                action,
+            });
+        }
+        let mut completions = BTreeMap::new();
+        for (scoped, from) in unlabeled {
+            let completes = final_state(&Some(from.clone()));
+            let composite_final = machine.transitions.iter().any(|t| t.target == completes);
+            if !composite_final {
+                warn!(
+                    "{} --> {:?} has no event, skipped",
+                    from, scoped.transition.to
+                );
+                continue;
+            }
+            if completions
+                .insert(completes, (from.clone(), target(scoped)))
+                .is_some()
+            {
+                bail!("{} has more than one completion transition", from);
+            }
+        }
+        for (state, scope) in &finals {
+            if collector.parents.contains_key(state) {
+                bail!("state {} collides with a generated final state", state);
+            }
+            collector.order.push(state.clone());
+            collector.parents.insert(state.clone(), scope.clone());
+        }
+
+        // a single top level state, as Top in states.md, needs no [*] -->
+        let top: Vec<&String> = collector
+            .order
+            .iter()
+            .filter(|id| collector.parents[*id].is_none())
+            .collect();
+        machine.initial = match (initials.remove(&None), top.as_slice()) {
+            (Some(initial), _) => initial,
+            (None, [top]) => top.to_string(),
+            (None, _) => bail!("the diagram has no [*] --> initial state"),
+        };
+        for id in &collector.order {
+            let parent = collector.parents[id].clone();
+            let initial = initials.remove(&Some(id.clone()));
+            if collector.is_composite(id) {
+                match &initial {
+                    None => bail!("composite state {} has no [*] --> initial state", id),
+                    Some(initial) if collector.parents[initial].as_ref() != Some(id) => {
+                        bail!("initial state {} is not inside {}", initial, id)
+                    }
+                    _ => {}
+                }
+            } else if let Some(initial) = initial {
+                bail!("[*] --> {} in {} which has no substates", initial, id);
+            }
+            // a state nested in its own substate never reaches the top
+            let mut ancestor = parent.clone();
+            for _ in 0..collector.order.len() {
+                ancestor = ancestor.and_then(|a| collector.parents[&a].clone());
+            }
+            if ancestor.is_some() {
+                bail!("state {} is nested in itself", id);
+            }
+            machine.states.push(FsmState {
+                name: id.clone(),
+                id: id.clone(),
+                description: collector.descriptions.get(id).cloned(),
+                parent,
+                initial,
+                completion: completions.remove(id),
+            });
+        }
+
+        // variants and identifiers in the generated code are Pascal case
+        let variant = |id: &String| id.to_case(Case::Pascal);
+        for state in &mut machine.states {
+            state.name = variant(&state.id);
+            state.parent = state.parent.as_ref().map(variant);
+            state.initial = state.initial.as_ref().map(variant);
+            state.completion = state
+                .completion
+                .as_ref()
+                .map(|(source, target)| (variant(source), variant(target)));
+        }
+        for transition in &mut machine.transitions {
+            transition.source = variant(&transition.source);
+            transition.target = variant(&transition.target);
+        }
+        machine.initial = variant(&machine.initial);
+        Ok(machine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states_md() -> StateMachine {
+        let text = std::fs::read_to_string("syntax/states.md").unwrap();
+        let diagram = MermaidStateDiagram::parse(crate::mermaid_block(&text)).unwrap();
+        StateMachine::from_diagram("States", &diagram).unwrap()
+    }
+
+    fn from_text(text: &str) -> Result<StateMachine> {
+        let diagram = MermaidStateDiagram::parse(text).map_err(|e| anyhow!("{}", e))?;
+        StateMachine::from_diagram("Test", &diagram)
+    }
+
+    fn some(text: &str) -> Option<String> {
+        Some(text.to_string())
+    }
+
+    #[test]
+    fn label_is_event_guard_and_action() {
+        assert_eq!(
+            parse_label("start moving [engine ok] / start engine").unwrap(),
+            (
+                "StartMoving".to_string(),
+                some("engine_ok"),
+                some("start_engine")
+            )
+        );
+        assert_eq!(
+            parse_label("BatteryLow").unwrap(),
+            ("BatteryLow".to_string(), None, None)
+        );
+        assert_eq!(
+            parse_label("honk / beep").unwrap(),
+            ("Honk".to_string(), None, some("beep"))
+        );
+        assert_eq!(
+            parse_label(" stopped [ at dock ] ").unwrap(),
+            ("Stopped".to_string(), some("at_dock"), None)
+        );
+        assert_eq!(parse_label("speed >= 60").unwrap().0, "Speed60");
+    }
+
+    #[test]
+    fn label_without_identifier_is_refused() {
+        assert!(parse_label("").is_err());
+        assert!(parse_label("[ready] / go").is_err());
+        assert!(parse_label("60 km/h").is_err());
+        assert!(parse_label("go / 2x").is_err());
+    }
+
+    #[test]
+    fn states_md_nests_composites_declared_at_the_top() {
+        let fsm = states_md();
+        assert_eq!(fsm.initial, "Top");
+        let state = |name: &str| fsm.states.iter().find(|s| s.name == name).unwrap();
+        assert_eq!(state("Top").parent, None);
+        assert_eq!(state("Top").initial, some("Idle"));
+        assert_eq!(state("RemoteControl").parent, some("Top"));
+        assert_eq!(state("HumanDriving").parent, some("RemoteControl"));
+        assert_eq!(state("Detour").parent, some("Autonomous"));
+        assert_eq!(state("Detour").initial, some("FollowAvoidancePath"));
+        // reached by FollowAvoidancePath --> [*]
+        assert_eq!(state("DetourFinal").parent, some("Detour"));
+        assert_eq!(fsm.states.len(), 12);
+    }
+
+    #[test]
+    fn states_md_transitions_and_events() {
+        let fsm = states_md();
+        assert_eq!(fsm.transitions.len(), 13);
+        assert!(fsm.guards.is_empty() && fsm.actions.is_empty());
+        assert_eq!(
+            fsm.events.first().map(String::as_str),
+            Some("StartManualMode")
+        );
+        assert!(fsm.events.iter().any(|e| e == "DetourPointReached"));
+        let collision = fsm
+            .transitions
+            .iter()
+            .find(|t| t.event == "Collision")
+            .unwrap();
+        assert_eq!(
+            (collision.source.as_str(), collision.target.as_str()),
+            ("Cutting", "Detour")
+        );
+    }
+
+    #[test]
+    fn unsupported_diagrams_are_refused() {
+        // no initial state among several top level states
+        assert!(from_text("stateDiagram-v2\n    A --> B : go\n").is_err());
+        // composite without initial state
+        assert!(
+            from_text(
+                "stateDiagram-v2\n    [*] --> A\n    state A {\n        B --> C : go\n    }\n"
+            )
+            .is_err()
+        );
+        // two initial states
+        assert!(
+            from_text("stateDiagram-v2\n    [*] --> A\n    [*] --> B\n    A --> B : go\n").is_err()
+        );
+    }
+}
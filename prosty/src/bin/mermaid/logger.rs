@@ -14,11 +14,16 @@ pub fn init() {
                 chrono::Local::now().format("%H:%M:%S.%3f"),
                 record.level(),
                 name,
-                record.file().unwrap_or("unknown").rsplit_once('/').unwrap().1,
+                record
+                    .file()
+                    .unwrap_or("unknown")
+                    .rsplit_once('/')
+                    .unwrap()
+                    .1,
                 record.line().unwrap_or(0),
                 record.args()
             )
         })
         .filter(None, log::LevelFilter::Info)
         .init();
-}
\ No newline at end of file
+}
@@ -0,0 +1,83 @@
+// https://docs.mermaidchart.com/mermaid-oss/syntax/stateDiagram.html
+mod codegen;
+mod logger;
+use logger::init;
+mod parser;
+mod types;
+use crate::codegen::StateMachine;
+use crate::parser::MermaidStateDiagram;
+use clap::Parser;
+use convert_case::{Case, Casing};
+use log::{error, info};
+use std::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Markdown or .mmd file with a stateDiagram-v2
+    /// example: --input ../zenoh-brain/docs/lawnmower.md
+    #[arg(short, long, default_value = "syntax/states.md")]
+    input: String,
+    /// Output directory for the generated state machine
+    #[arg(short, long, default_value = "examples")]
+    output: String,
+    /// Name of the state machine and of the generated file
+    /// default: the name of the input file
+    #[arg(short, long)]
+    name: Option<String>,
+}
+
+/// The first ```mermaid block of a markdown file, the text itself when there is none
+fn mermaid_block(text: &str) -> &str {
+    let Some(start) = text.find("```mermaid") else {
+        return text;
+    };
+    let body = start + "```mermaid".len();
+    match text[body..].find("\n```") {
+        Some(end) => &text[start..body + end + "\n```".len()],
+        None => &text[start..],
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    init();
+    let input = fs::read_to_string(&args.input)?;
+    let diagram = MermaidStateDiagram::parse(mermaid_block(&input))?;
+
+    info!("Parsed {} states", diagram.states.len());
+    info!("Parsed {} transitions", diagram.transitions.len());
+
+    let name = match &args.name {
+        Some(name) => name.clone(),
+        None => Path::new(&args.input)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("fsm")
+            .to_string(),
+    };
+    let fsm = StateMachine::from_diagram(&name.to_case(Case::Pascal), &diagram)?;
+    info!(
+        "{} has {} states, {} events and {} transitions",
+        fsm.name,
+        fsm.states.len(),
+        fsm.events.len(),
+        fsm.transitions.len()
+    );
+
+    let tera = Tera::new("src/*.tera")?;
+    let mut context = Context::new();
+    context.insert("fsm", &fsm);
+    context.insert("source", &args.input);
+    let rendered = tera.render("rust_fsm.tera", &context).map_err(|e| {
+        error!("Error rendering template: {}", e);
+        e
+    })?;
+    let rust_name = format!("{}/{}.rs", args.output, name.to_case(Case::Snake));
+    fs::write(&rust_name, rendered)?;
+    info!("Generated Rust state machine written to {}", rust_name);
+
+    Ok(())
+}
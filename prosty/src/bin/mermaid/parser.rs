@@ -0,0 +1,150 @@
+use crate::types::Rule;
+use crate::types::StateDiagramParser;
+use crate::types::{Note, State, StateReference, StateSuffix, StateType, Transition};
+use pest::Parser;
+use pest::iterators::Pair;
+
+pub struct MermaidStateDiagram {
+    pub direction: Option<String>,
+    pub states: Vec<State>,
+    pub transitions: Vec<Transition>,
+    pub notes: Vec<Note>,
+}
+
+impl MermaidStateDiagram {
+    pub fn parse(input: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let pairs = StateDiagramParser::parse(Rule::stateDiagram, input)?;
+
+        let mut diagram = MermaidStateDiagram {
+            direction: None,
+            states: Vec::new(),
+            transitions: Vec::new(),
+            notes: Vec::new(),
+        };
+
+        // the top level is parsed as the body of an anonymous state
+        let mut root = State::new("");
+        for pair in pairs.into_iter().next().unwrap().into_inner() {
+            if pair.as_rule() == Rule::direction {
+                diagram.direction = pair.into_inner().next().map(|p| p.as_str().to_string());
+            } else {
+                Self::parse_statement(&mut root, pair);
+            }
+        }
+        diagram.states = root.states;
+        diagram.transitions = root.transitions;
+        diagram.notes = root.notes;
+
+        Ok(diagram)
+    }
+
+    fn parse_statement(parent: &mut State, pair: Pair<Rule>) {
+        match pair.as_rule() {
+            Rule::state_declaration => {
+                parent.states.push(Self::parse_state(pair));
+            }
+            Rule::state_description => {
+                let mut inner = pair.into_inner();
+                let mut state = State::new(inner.next().unwrap().as_str());
+                state.description = inner.next().map(|p| p.as_str().trim().to_string());
+                parent.states.push(state);
+            }
+            Rule::state_reference => {
+                if let StateReference::State(id) = Self::parse_state_reference(pair, false) {
+                    parent.states.push(State::new(&id));
+                }
+            }
+            Rule::transition => {
+                parent.transitions.push(Self::parse_transition(pair));
+            }
+            Rule::note => {
+                parent.notes.push(Self::parse_note(pair));
+            }
+            Rule::divider => parent.concurrent = true,
+            _ => {}
+        }
+    }
+
+    fn parse_state(pair: Pair<Rule>) -> State {
+        let mut state = State::new("");
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::state_id => state.id = inner_pair.as_str().to_string(),
+                Rule::state_type => {
+                    state.state_type = match inner_pair.into_inner().next().unwrap().as_str() {
+                        "choice" => StateType::Choice,
+                        "fork" => StateType::Fork,
+                        "join" => StateType::Join,
+                        _ => StateType::Normal,
+                    };
+                }
+                Rule::state_name => {
+                    let content = inner_pair.into_inner().next().unwrap().into_inner();
+                    state.name = Some(content.as_str().to_string());
+                }
+                Rule::state_body => {
+                    for body_item in inner_pair.into_inner() {
+                        Self::parse_statement(&mut state, body_item);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        state
+    }
+
+    fn parse_transition(pair: Pair<Rule>) -> Transition {
+        let mut inner_pairs = pair.into_inner();
+
+        let from = Self::parse_state_reference(inner_pairs.next().unwrap(), false);
+        // the operator
+        inner_pairs.next();
+        let to = Self::parse_state_reference(inner_pairs.next().unwrap(), true);
+        let label = inner_pairs
+            .next()
+            .and_then(|label| label.into_inner().next())
+            .map(|text| text.as_str().trim().to_string())
+            .filter(|text| !text.is_empty());
+
+        Transition { from, to, label }
+    }
+
+    /// [*] is the initial state as source and the final state as target
+    fn parse_state_reference(pair: Pair<Rule>, target: bool) -> StateReference {
+        let mut inner = pair.into_inner();
+        let first = inner.next().unwrap();
+        if first.as_rule() == Rule::initial_final {
+            return if target {
+                StateReference::Final
+            } else {
+                StateReference::Initial
+            };
+        }
+        let state = first.as_str().to_string();
+        match inner.next() {
+            Some(suffix) => {
+                let suffix = match suffix.into_inner().next().unwrap().as_str() {
+                    "entry" => StateSuffix::Entry,
+                    "exit" => StateSuffix::Exit,
+                    _ => StateSuffix::Do,
+                };
+                StateReference::WithSuffix { state, suffix }
+            }
+            None => StateReference::State(state),
+        }
+    }
+
+    fn parse_note(pair: Pair<Rule>) -> Note {
+        let mut inner = pair.into_inner();
+        // the position only matters for drawing
+        inner.next();
+        let state = inner.next().unwrap().as_str().to_string();
+        let content = inner
+            .next()
+            .map(|text| text.as_str().trim().to_string())
+            .unwrap_or_default();
+        Note { state, content }
+    }
+}
@@ -4,17 +4,15 @@ use pest_derive::Parser;
 #[grammar = "syntax/state_diagram.pest"]
 pub struct StateDiagramParser;
 
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StateType {
     Normal,
     Choice,
     Fork,
     Join,
-    Note,
-    Divider,
 }
 
+/// A state as it is written in the diagram, the same state can be written several times
 #[derive(Debug, Clone)]
 pub struct State {
     pub id: String,
@@ -24,18 +22,33 @@ pub struct State {
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
     pub notes: Vec<Note>,
+    /// the body is split in concurrent regions by "--"
+    pub concurrent: bool,
+}
+
+impl State {
+    pub fn new(id: &str) -> Self {
+        State {
+            id: id.to_string(),
+            state_type: StateType::Normal,
+            name: None,
+            description: None,
+            states: Vec::new(),
+            transitions: Vec::new(),
+            notes: Vec::new(),
+            concurrent: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Transition {
     pub from: StateReference,
     pub to: StateReference,
-    pub operator: TransitionOperator,
     pub label: Option<String>,
-    pub properties: Vec<Property>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StateReference {
     State(String),
     Initial,
@@ -43,53 +56,15 @@ pub enum StateReference {
     WithSuffix { state: String, suffix: StateSuffix },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StateSuffix {
     Entry,
     Exit,
     Do,
 }
 
-#[derive(Debug, Clone)]
-pub enum TransitionOperator {
-    Simple,
-    Thick,
-    Dotted,
-    Length(usize),
-    Styled(String),
-}
-
-#[derive(Debug, Clone)]
-pub struct Property {
-    pub name: String,
-    pub value: PropertyValue,
-}
-
-#[derive(Debug, Clone)]
-pub enum PropertyValue {
-    String(String),
-    Number(f64),
-    Boolean(bool),
-    Identifier(String),
-}
-
 #[derive(Debug, Clone)]
 pub struct Note {
-    pub position: NotePosition,
-    pub state: StateReference,
+    pub state: String,
     pub content: String,
 }
-
-#[derive(Debug, Clone)]
-pub enum NotePosition {
-    Right,
-    Left,
-    Top,
-    Bottom,
-    Default,
-}
-
-#[derive(Debug, Clone)]
-pub struct Config {
-    pub directives: Vec<(String, PropertyValue)>,
-}
\ No newline at end of file
@@ -1,9 +1,10 @@
 use convert_case::Casing;
 use protobuf_parser::{FieldType, FileDescriptor};
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 mod logger;
-use anyhow::Result;
+use anyhow::{Context as _, Result, anyhow, bail};
 use log::{error, info};
 use logger::init;
 
@@ -56,75 +57,357 @@ struct Args {
     lang: Lang,
     #[arg(short, long, value_enum, default_value_t = Format::Json)]
     format: Format,
+    /// Directories searched for imported .proto files, after the directory of the importing file
+    /// example: -I ../proto -I proto/common
+    #[arg(short = 'I', long)]
+    include: Vec<String>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    /*  pkg = Path::new(&args.proto)
-    .parent()
-    .expect("Failed to get parent directory");*/
     init();
-    let proto_file = Path::new(&args.input);
-    let proto_content = fs::read_to_string(proto_file).expect("Failed to read proto file");
-
-    let res = FileDescriptor::parse(&proto_content.as_bytes());
-    if res.is_err() {
-        panic!("Failed to parse proto file: {:?}", res.err());
-    }
-    let fd = res.unwrap();
+    let files = load_protos(Path::new(&args.input), &args.include)?;
     info!("Proto file parsed successfully.");
+    let types = TypeTable::new(&files);
     let tera_file = format!("{}_{}.tera", args.lang.as_str(), args.format.as_str());
     info!("Using template file: {}", tera_file);
 
-    if args.lang == Lang::Rust {
-        info!(
-            "Generating Rust code in {} format to {}",
-            args.format.as_str(),
-            args.output
-        );
-        let tera_file = format!("rust_{}.tera", args.format.as_str());
-        let package = fd.package.clone();
-        let messages = convert_rust_types(&fd);
-        let enums = convert_enum_rust_types(&fd);
-        let rust_name = format!("{}/{}.rs", args.output, &fd.package);
-        let rendered = render(&package, &enums, &messages, tera_file.as_str())?;
-        fs::write(&rust_name, rendered)?;
-
-        info!("Generated Rust code written to {}", rust_name);
-    } else if args.lang == Lang::Cpp {
-        info!(
-            "Generating C++ code in {} format to {}",
-            args.format.as_str(),
-            args.output
-        );
-        let tera_file_src = format!("cpp_{}_src.tera", args.format.as_str());
-        let tera_file_inc = format!("cpp_{}_inc.tera", args.format.as_str());
-        let package = fd.package.clone();
-        let messages = convert_cpp_types(&fd);
-        let enums = convert_enum_cpp_types(&fd);
-        let cpp_name = format!("{}/{}.cpp", args.output, &fd.package);
-        let inc_name = format!("{}/{}.h", args.output, &fd.package);
-        let rendered = render(&package, &enums, &messages, tera_file_src.as_str())?;
-        fs::write(&cpp_name, rendered)?;
-        info!(
-            "Generating Rust code from {} format to {}",
-            tera_file_src,
-            cpp_name
-        );
-
-        let header_rendered = render(&package, &enums, &messages, tera_file_inc.as_str())?;
-        fs::write(&inc_name, header_rendered)?;
-        info!(
-            "Generating C++ code from {} format to {}",
-            tera_file_inc,
-            inc_name
-        );
-    } else {
-        error!("Unsupported language: {:?}", args.lang);
+    // every package in the input and its imports gets its own file
+    for package in packages(&files) {
+        if args.lang == Lang::Rust {
+            info!(
+                "Generating Rust code in {} format to {}",
+                args.format.as_str(),
+                args.output
+            );
+            let tera_file = format!("rust_{}.tera", args.format.as_str());
+            let messages = convert_rust_types(&package, &types)?;
+            let enums = convert_enum_rust_types(&package);
+            let imports = imported_packages(&package, &types)?;
+            let rust_name = format!("{}/{}.rs", args.output, &package.name);
+            let rendered = render(
+                &package.name,
+                &imports,
+                &enums,
+                &messages,
+                tera_file.as_str(),
+            )?;
+            fs::write(&rust_name, rendered)?;
+
+            info!("Generated Rust code written to {}", rust_name);
+        } else if args.lang == Lang::Cpp {
+            info!(
+                "Generating C++ code in {} format to {}",
+                args.format.as_str(),
+                args.output
+            );
+            let tera_file_src = format!("cpp_{}_src.tera", args.format.as_str());
+            let tera_file_inc = format!("cpp_{}_inc.tera", args.format.as_str());
+            let messages = convert_cpp_types(&package, &types)?;
+            let enums = convert_enum_cpp_types(&package);
+            let imports = imported_packages(&package, &types)?;
+            let cpp_name = format!("{}/{}.cpp", args.output, &package.name);
+            let inc_name = format!("{}/{}.h", args.output, &package.name);
+            let rendered = render(
+                &package.name,
+                &imports,
+                &enums,
+                &messages,
+                tera_file_src.as_str(),
+            )?;
+            fs::write(&cpp_name, rendered)?;
+            info!(
+                "Generating Rust code from {} format to {}",
+                tera_file_src,
+                cpp_name
+            );
+
+            let header_rendered = render(
+                &package.name,
+                &imports,
+                &enums,
+                &messages,
+                tera_file_inc.as_str(),
+            )?;
+            fs::write(&inc_name, header_rendered)?;
+            info!(
+                "Generating C++ code from {} format to {}",
+                tera_file_inc,
+                inc_name
+            );
+        } else {
+            error!("Unsupported language: {:?}", args.lang);
+        }
+    }
+    Ok(())
+}
+
+struct ProtoFile {
+    path: PathBuf,
+    fd: FileDescriptor,
+}
+
+/// The input file and everything it imports, imported files first
+fn load_protos(input: &Path, includes: &[String]) -> Result<Vec<ProtoFile>> {
+    let mut files = vec![];
+    load_proto(input, includes, &mut files, &mut vec![])?;
+    Ok(files)
+}
+
+fn load_proto(
+    path: &Path,
+    includes: &[String],
+    files: &mut Vec<ProtoFile>,
+    loading: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let canonical =
+        fs::canonicalize(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    if files.iter().any(|f| f.path == canonical) {
+        return Ok(());
+    }
+    if loading.contains(&canonical) {
+        bail!("Import cycle through {}", path.display());
+    }
+    let proto_content = fs::read_to_string(path)?;
+    let fd = FileDescriptor::parse(proto_content.as_bytes())
+        .map_err(|e| anyhow!("Failed to parse {}: {:?}", path.display(), e))?;
+
+    loading.push(canonical.clone());
+    for import in &fd.import_paths {
+        let import = Path::new(import);
+        let found = path
+            .parent()
+            .map(Path::to_path_buf)
+            .into_iter()
+            .chain(includes.iter().map(PathBuf::from))
+            .map(|dir| dir.join(import))
+            .find(|candidate| candidate.is_file())
+            .ok_or_else(|| {
+                anyhow!(
+                    "Import {} of {} not found",
+                    import.display(),
+                    path.display()
+                )
+            })?;
+        load_proto(&found, includes, files, loading)?;
     }
+    loading.pop();
+    files.push(ProtoFile {
+        path: canonical,
+        fd,
+    });
     Ok(())
 }
 
+/// The files of one package, messages and enums nested in messages are flattened
+/// to top level types named after their parents, Outer.Inner becomes OuterInner
+struct Package<'a> {
+    name: String,
+    messages: Vec<Scoped<'a, protobuf_parser::Message>>,
+    enums: Vec<Scoped<'a, protobuf_parser::Enumeration>>,
+}
+
+struct Scoped<'a, T> {
+    /// the messages it is nested in, outermost first
+    path: Vec<String>,
+    item: &'a T,
+}
+
+impl<T> Scoped<'_, T> {
+    fn flat_name(&self, name: &str) -> String {
+        format!("{}{}", self.path.concat(), name)
+    }
+}
+
+fn packages(files: &[ProtoFile]) -> Vec<Package<'_>> {
+    let mut packages: Vec<Package> = vec![];
+    for file in files {
+        let index = match packages.iter().position(|p| p.name == file.fd.package) {
+            Some(index) => index,
+            None => {
+                packages.push(Package {
+                    name: file.fd.package.clone(),
+                    messages: vec![],
+                    enums: vec![],
+                });
+                packages.len() - 1
+            }
+        };
+        let package = &mut packages[index];
+        package.enums.extend(file.fd.enums.iter().map(|item| Scoped {
+            path: vec![],
+            item,
+        }));
+        for msg in &file.fd.messages {
+            flatten(vec![], msg, package);
+        }
+    }
+    packages
+}
+
+fn flatten<'a>(path: Vec<String>, msg: &'a protobuf_parser::Message, package: &mut Package<'a>) {
+    let mut inner = path.clone();
+    inner.push(msg.name.clone());
+    package.messages.push(Scoped { path, item: msg });
+    package.enums.extend(msg.enums.iter().map(|item| Scoped {
+        path: inner.clone(),
+        item,
+    }));
+    for nested in &msg.messages {
+        flatten(inner.clone(), nested, package);
+    }
+}
+
+struct TypeRef {
+    name: String,
+    package: String,
+    is_enum: bool,
+}
+
+impl TypeRef {
+    fn kind(&self) -> &'static str {
+        if self.is_enum { "enum" } else { "message" }
+    }
+}
+
+/// A field type with its references replaced by their generated names
+struct ResolvedType {
+    typ: FieldType,
+    /// scalar, bytes, enum, message or map
+    kind: &'static str,
+    /// for a map, the kind of the value
+    value_kind: Option<&'static str>,
+}
+
+/// All messages and enums by their full name, package.Outer.Inner
+struct TypeTable {
+    types: HashMap<String, TypeRef>,
+}
+
+impl TypeTable {
+    fn new(files: &[ProtoFile]) -> Self {
+        let mut types = HashMap::new();
+        for package in packages(files) {
+            let prefix: Vec<&str> = package.name.split('.').filter(|p| !p.is_empty()).collect();
+            let mut add = |path: &[String], name: &str, is_enum: bool| {
+                let mut full: Vec<&str> = prefix.clone();
+                full.extend(path.iter().map(String::as_str));
+                full.push(name);
+                types.insert(
+                    full.join("."),
+                    TypeRef {
+                        name: format!("{}{}", path.concat(), name),
+                        package: package.name.clone(),
+                        is_enum,
+                    },
+                );
+            };
+            for msg in &package.messages {
+                add(&msg.path, &msg.item.name, false);
+            }
+            for en in &package.enums {
+                add(&en.path, &en.item.name, true);
+            }
+        }
+        TypeTable { types }
+    }
+
+    /// Looks a type up as protoc does, from the innermost scope outwards
+    fn resolve(&self, package: &str, path: &[String], reference: &str) -> Result<&TypeRef> {
+        if let Some(absolute) = reference.strip_prefix('.') {
+            return self
+                .types
+                .get(absolute)
+                .ok_or_else(|| anyhow!("Unknown type {}", reference));
+        }
+        let mut scope: Vec<&str> = package.split('.').filter(|p| !p.is_empty()).collect();
+        scope.extend(path.iter().map(String::as_str));
+        loop {
+            let mut candidate = scope.clone();
+            candidate.push(reference);
+            if let Some(found) = self.types.get(&candidate.join(".")) {
+                return Ok(found);
+            }
+            if scope.pop().is_none() {
+                bail!("Unknown type {} in {}", reference, path.join("."));
+            }
+        }
+    }
+
+    /// The field type with message and enum references replaced by their generated names,
+    /// the kind comes from the type the reference resolved to, names may repeat across packages
+    fn resolve_field_type(
+        &self,
+        package: &str,
+        path: &[String],
+        field_type: &FieldType,
+    ) -> Result<ResolvedType> {
+        let resolved = |typ: FieldType, kind: &'static str| ResolvedType {
+            typ,
+            kind,
+            value_kind: None,
+        };
+        Ok(match field_type {
+            FieldType::MessageOrEnum(reference) => {
+                let found = self.resolve(package, path, reference)?;
+                resolved(FieldType::MessageOrEnum(found.name.clone()), found.kind())
+            }
+            FieldType::Map(key_value) => {
+                let key = self.resolve_field_type(package, path, &key_value.0)?;
+                let value = self.resolve_field_type(package, path, &key_value.1)?;
+                ResolvedType {
+                    typ: FieldType::Map(Box::new((key.typ, value.typ))),
+                    kind: "map",
+                    value_kind: Some(value.kind),
+                }
+            }
+            FieldType::Group(_) => bail!("Groups are not supported, use a nested message"),
+            FieldType::Bytes => resolved(FieldType::Bytes, "bytes"),
+            other => resolved(other.clone(), "scalar"),
+        })
+    }
+}
+
+/// Packages whose types are used by fields of this package
+fn imported_packages(package: &Package, types: &TypeTable) -> Result<Vec<String>> {
+    let mut imports: Vec<String> = vec![];
+    for msg in &package.messages {
+        let fields = msg
+            .item
+            .fields
+            .iter()
+            .chain(msg.item.oneofs.iter().flat_map(|o| o.fields.iter()));
+        for field in fields {
+            let mut references = vec![];
+            match &field.typ {
+                FieldType::MessageOrEnum(reference) => references.push(reference),
+                FieldType::Map(key_value) => {
+                    if let FieldType::MessageOrEnum(reference) = &key_value.1 {
+                        references.push(reference);
+                    }
+                }
+                _ => {}
+            }
+            for reference in references {
+                let found = types.resolve(&package.name, &msg.flat_path(), reference)?;
+                if found.package != package.name && !imports.contains(&found.package) {
+                    imports.push(found.package.clone());
+                }
+            }
+        }
+    }
+    Ok(imports)
+}
+
+impl Scoped<'_, protobuf_parser::Message> {
+    /// the scope for the types referenced by the fields of this message
+    fn flat_path(&self) -> Vec<String> {
+        let mut path = self.path.clone();
+        path.push(self.item.name.clone());
+        path
+    }
+}
+
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -135,14 +418,33 @@ struct Field {
     repeated: bool,
     optional: bool,
     source_type: String,
+    /// scalar, bytes, enum, message or map
+    kind: String,
+    /// for a map, the target types and the kind of the value
+    key_type: Option<String>,
+    value_type: Option<String>,
+    value_kind: Option<String>,
+    /// the oneof this field is a member of
+    oneof: Option<String>,
 }
 
 #[derive(Serialize)]
 struct Message {
     name: String,
     fields: Vec<Field>,
+    oneofs: Vec<OneOf>,
     msg_id: u32,
 }
+
+/// A oneof, its members are optional fields of the message of which at most one is set
+#[derive(Serialize)]
+struct OneOf {
+    name: String,
+    type_name: String,
+    /// (variant, field name, target type)
+    members: Vec<(String, String, String)>,
+}
+
 #[derive(Serialize)]
 struct EnumType {
     name: String,
@@ -158,12 +460,11 @@ fn field_type_to_rust_type(field_type: &FieldType) -> String {
         FieldType::Uint64 => "u64".to_string(),
         FieldType::String => "String".to_string(),
         FieldType::MessageOrEnum(msg_name) => msg_name.clone(),
-        FieldType::Map(_other_name) => format!(
-            "std::collections::HashMap<{},{}>",
-            field_type_to_rust_type(&_other_name.0),
-            field_type_to_rust_type(&_other_name.1)
-        )
-        .to_string(),
+        FieldType::Map(key_value) => format!(
+            "std::collections::BTreeMap<{},{}>",
+            field_type_to_rust_type(&key_value.0),
+            field_type_to_rust_type(&key_value.1)
+        ),
         FieldType::Bytes => "Vec<u8>".to_string(),
         FieldType::Bool => "bool".to_string(),
         FieldType::Double => "f64".to_string(),
@@ -173,7 +474,7 @@ fn field_type_to_rust_type(field_type: &FieldType) -> String {
         FieldType::Fixed64 => "u64".to_string(),
         FieldType::Sfixed32 => "i32".to_string(),
         FieldType::Sfixed64 => "i64".to_string(),
-        FieldType::Group(_) => "/* group */".to_string(),
+        FieldType::Group(_) => unreachable!("groups are rejected by TypeTable::resolve_field_type"),
     }
 }
 
@@ -186,12 +487,11 @@ fn field_type_to_cpp_type(field_type: &FieldType) -> String {
         FieldType::Uint64 => "uint64_t".to_string(),
         FieldType::String => "std::string".to_string(),
         FieldType::MessageOrEnum(msg_name) => msg_name.clone(),
-        FieldType::Map(_other_name) => format!(
+        FieldType::Map(key_value) => format!(
             "std::unordered_map<{},{}>",
-            field_type_to_cpp_type(&_other_name.0),
-            field_type_to_cpp_type(&_other_name.1)
-        )
-        .to_string(),
+            field_type_to_cpp_type(&key_value.0),
+            field_type_to_cpp_type(&key_value.1)
+        ),
         FieldType::Bytes => "Bytes".to_string(),
         FieldType::Bool => "bool".to_string(),
         FieldType::Double => "double".to_string(),
@@ -201,115 +501,148 @@ fn field_type_to_cpp_type(field_type: &FieldType) -> String {
         FieldType::Fixed64 => "uint64_t".to_string(),
         FieldType::Sfixed32 => "int32_t".to_string(),
         FieldType::Sfixed64 => "int64_t".to_string(),
-        FieldType::Group(_) => "/* group */".to_string(),
+        FieldType::Group(_) => unreachable!("groups are rejected by TypeTable::resolve_field_type"),
     }
 }
 
-fn convert_rust_types(fd: &FileDescriptor) -> Vec<Message> {
-    fd.messages
-        .iter()
-        .map(|msg| {
-            let fields = msg
-                .fields
-                .iter()
-                .map(|f| Field {
-                    name: f.name.clone(),
-                    index: f.number,
-                    target_type: field_type_to_rust_type(&f.typ),
-                    repeated: match f.rule {
-                        protobuf_parser::Rule::Repeated => true,
-                        _ => false,
-                    },
-                    optional: match f.rule {
-                        protobuf_parser::Rule::Optional => true,
-                        _ => false,
-                    },
-                    source_type: format!("{:?}", f.typ),
-                })
-                .collect();
+fn convert_rust_types(package: &Package, types: &TypeTable) -> Result<Vec<Message>> {
+    convert_types(package, types, field_type_to_rust_type)
+}
 
-            Message {
-                name: msg.name.clone(),
-                fields,
-                msg_id: fnv1a_16(&msg.name.as_bytes()) as u32,
+fn convert_cpp_types(package: &Package, types: &TypeTable) -> Result<Vec<Message>> {
+    convert_types(package, types, field_type_to_cpp_type)
+}
+
+fn convert_types(
+    package: &Package,
+    types: &TypeTable,
+    target: fn(&FieldType) -> String,
+) -> Result<Vec<Message>> {
+    let mut messages = vec![];
+    for msg in &package.messages {
+        let path = msg.flat_path();
+        let name = msg.flat_name(&msg.item.name);
+        let members = msg
+            .item
+            .oneofs
+            .iter()
+            .flat_map(|o| o.fields.iter().map(move |f| (f, Some(o.name.clone()))));
+        let mut fields: Vec<Field> = vec![];
+        for (f, oneof) in msg.item.fields.iter().map(|f| (f, None)).chain(members) {
+            if fields.iter().any(|known| known.name == f.name) {
+                continue;
             }
-        })
-        .collect()
+            let resolved = types.resolve_field_type(&package.name, &path, &f.typ)?;
+            let typ = resolved.typ;
+            let map = match &typ {
+                FieldType::Map(key_value) => Some(key_value),
+                _ => None,
+            };
+            fields.push(Field {
+                name: f.name.clone(),
+                index: f.number,
+                target_type: target(&typ),
+                // a map is one field, not repeated entries
+                repeated: map.is_none() && matches!(f.rule, protobuf_parser::Rule::Repeated),
+                // at most one member of a oneof is set
+                optional: map.is_some()
+                    || oneof.is_some()
+                    || matches!(f.rule, protobuf_parser::Rule::Optional),
+                source_type: format!("{:?}", f.typ),
+                kind: resolved.kind.to_string(),
+                key_type: map.map(|key_value| target(&key_value.0)),
+                value_type: map.map(|key_value| target(&key_value.1)),
+                value_kind: resolved.value_kind.map(str::to_string),
+                oneof,
+            });
+        }
+        let oneofs = msg
+            .item
+            .oneofs
+            .iter()
+            .map(|o| OneOf {
+                name: o.name.clone(),
+                type_name: format!("{}{}", name, o.name.to_case(convert_case::Case::Pascal)),
+                members: fields
+                    .iter()
+                    .filter(|f| f.oneof.as_deref() == Some(o.name.as_str()))
+                    .map(|f| {
+                        (
+                            f.name.to_case(convert_case::Case::Pascal),
+                            f.name.clone(),
+                            f.target_type.clone(),
+                        )
+                    })
+                    .collect(),
+            })
+            .collect();
+        messages.push(Message {
+            msg_id: fnv1a_16(name.as_bytes()) as u32,
+            name,
+            fields,
+            oneofs,
+        });
+    }
+    in_dependency_order(messages)
 }
 
-fn convert_cpp_types(fd: &FileDescriptor) -> Vec<Message> {
-    fd.messages
-        .iter()
-        .map(|msg| {
-            let fields = msg
-                .fields
-                .iter()
-                .map(|f| Field {
-                    name: f.name.clone(),
-                    index: f.number,
-                    target_type: field_type_to_cpp_type(&f.typ),
-                    repeated: match f.rule {
-                        protobuf_parser::Rule::Repeated => true,
-                        _ => false,
-                    },
-                    optional: match f.rule {
-                        protobuf_parser::Rule::Optional => true,
-                        _ => false,
-                    },
-                    source_type: format!("{:?}", f.typ),
+/// Messages used as field type are defined before the messages using them, as C++ needs
+fn in_dependency_order(mut messages: Vec<Message>) -> Result<Vec<Message>> {
+    let mut ordered: Vec<Message> = vec![];
+    while !messages.is_empty() {
+        let ready = messages.iter().position(|msg| {
+            msg.fields.iter().all(|f| {
+                let used = match f.kind.as_str() {
+                    "message" => Some(&f.target_type),
+                    "map" if f.value_kind.as_deref() == Some("message") => f.value_type.as_ref(),
+                    _ => None,
+                };
+                used.is_none_or(|used| {
+                    ordered.iter().any(|o| &o.name == used)
+                        || !messages.iter().any(|m| &m.name == used)
                 })
-                .collect();
+            })
+        });
+        match ready {
+            Some(index) => ordered.push(messages.remove(index)),
+            None => bail!(
+                "Recursive message fields between {}",
+                messages
+                    .iter()
+                    .map(|m| m.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+    Ok(ordered)
+}
 
-            Message {
-                name: msg.name.clone(),
-                fields,
-                msg_id: fnv1a_16(&msg.name.as_bytes()) as u32,
-            }
-        })
-        .collect()
+fn convert_enum_rust_types(package: &Package) -> Vec<EnumType> {
+    convert_enum_types(package, convert_case::Case::Pascal)
 }
 
-fn convert_enum_rust_types(fd: &FileDescriptor) -> Vec<EnumType> {
-    let enums: Vec<EnumType> = fd
-        .enums
-        .iter()
-        .map(|e| {
-            let values: Vec<(String, i32)> = e
-                .values
-                .iter()
-                .map(|v| (v.name.clone().to_case(convert_case::Case::Pascal), v.number))
-                .collect();
-            EnumType {
-                name: e.name.clone(),
-                values,
-            }
-        })
-        .collect();
-    enums
+fn convert_enum_cpp_types(package: &Package) -> Vec<EnumType> {
+    convert_enum_types(package, convert_case::Case::Constant)
 }
 
-fn convert_enum_cpp_types(fd: &FileDescriptor) -> Vec<EnumType> {
-    let enums: Vec<EnumType> = fd
+fn convert_enum_types(package: &Package, case: convert_case::Case) -> Vec<EnumType> {
+    package
         .enums
         .iter()
         .map(|e| {
             let values: Vec<(String, i32)> = e
+                .item
                 .values
                 .iter()
-                .map(|v| {
-                    (
-                        v.name.clone().to_case(convert_case::Case::Constant),
-                        v.number,
-                    )
-                })
+                .map(|v| (v.name.clone().to_case(case), v.number))
                 .collect();
             EnumType {
-                name: e.name.clone(),
+                name: e.flat_name(&e.item.name),
                 values,
             }
         })
-        .collect();
-    enums
+        .collect()
 }
 
 use tera::{Context, Tera};
@@ -338,6 +671,7 @@ fn fnv1a_16(data: &[u8]) -> u16 {
 
 fn render(
     package: &String,
+    imports: &Vec<String>,
     enums: &Vec<EnumType>,
     messages: &Vec<Message>,
     tera_file: &str,
@@ -347,6 +681,7 @@ fn render(
     context.insert("messages", messages);
     context.insert("enums", enums);
     context.insert("package", package);
+    context.insert("imports", imports);
     tera.render(tera_file, &context).map_err(|e| {
         error!("Error rendering template: {}", e);
         anyhow::Error::new(e) // Convert std::io::Error to anyhow::Error
@@ -0,0 +1,122 @@
+//! Compares the generated code with the files in tests/golden, run with PROSTY_BLESS=1
+//! to write the current output as the new golden files after a deliberate change.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// the generated Rust of sensors.proto, compiled as the modules of a crate like it is used
+#[allow(dead_code)]
+#[path = "golden/sensors/rust_mix/common.rs"]
+mod common;
+#[allow(dead_code)]
+#[path = "golden/sensors/rust_mix/sensors.rs"]
+mod sensors;
+#[allow(dead_code)]
+#[path = "golden/sensors/rust_mix/status.rs"]
+mod status;
+
+const TARGETS: [(&str, &str); 5] = [("rust", "mix"), ("rust", "json"), ("rust", "cbor"), ("cpp", "mix"), ("cpp", "json")];
+
+fn generate(proto: &str, lang: &str, format: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let stem = Path::new(proto).file_stem().unwrap().to_str().unwrap();
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{stem}_{lang}_{format}"));
+    let _ = fs::remove_dir_all(&output);
+    fs::create_dir_all(&output).unwrap();
+    // the templates are loaded from src/ relative to the working directory
+    let status = Command::new(env!("CARGO_BIN_EXE_prosty"))
+        .current_dir(root)
+        .args(["-i", proto, "-o", output.to_str().unwrap(), "-l", lang, "-f", format])
+        .status()
+        .unwrap();
+    assert!(status.success(), "prosty failed for {proto} {lang} {format}");
+    output
+}
+
+fn check(proto: &str) {
+    let stem = Path::new(proto).file_stem().unwrap().to_str().unwrap();
+    let bless = std::env::var_os("PROSTY_BLESS").is_some();
+    for (lang, format) in TARGETS {
+        let output = generate(proto, lang, format);
+        let golden = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(stem)
+            .join(format!("{lang}_{format}"));
+        let mut generated: Vec<_> = fs::read_dir(&output)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        generated.sort();
+        if bless {
+            let _ = fs::remove_dir_all(&golden);
+            fs::create_dir_all(&golden).unwrap();
+        }
+        for name in &generated {
+            let actual = fs::read_to_string(output.join(name)).unwrap();
+            let expected_path = golden.join(name);
+            if bless {
+                fs::write(&expected_path, &actual).unwrap();
+                continue;
+            }
+            let expected = fs::read_to_string(&expected_path)
+                .unwrap_or_else(|_| panic!("missing golden file {}", expected_path.display()));
+            assert!(
+                actual == expected,
+                "{} differs from {}, rerun with PROSTY_BLESS=1 if the change is intended",
+                output.join(name).display(),
+                expected_path.display()
+            );
+        }
+        let expected_count = fs::read_dir(&golden).unwrap().count();
+        assert_eq!(generated.len(), expected_count, "files generated in {}", output.display());
+    }
+}
+
+#[test]
+fn message_proto() {
+    check("syntax/message.proto");
+}
+
+#[test]
+fn oneof_nested_map_and_import() {
+    check("tests/proto/sensors.proto");
+}
+
+#[test]
+fn generated_rust_roundtrips_maps_imports_and_oneof() {
+    use common::{Measurement, Unit};
+    use sensors::{Command, CommandAction, Msg, Sensor, SensorCalibration, Status};
+    use std::collections::BTreeMap;
+
+    let calibration = SensorCalibration { offset: Some(0.5), scale: Some(2.0) };
+    let sensor = Sensor {
+        name: Some("front".to_string()),
+        last: Some(Measurement { value: Some(1.25), unit: Some(Unit::Meter) }),
+        limits: Some(BTreeMap::from([("max".to_string(), 40)])),
+        ranges: Some(BTreeMap::from([(3, calibration.clone())])),
+        history: Some(vec![Measurement { value: Some(1.0), unit: None }]),
+        status: Some(Status { ok: Some(true) }),
+        statuses: Some(BTreeMap::from([("left".to_string(), Status { ok: Some(false) })])),
+        ..Default::default()
+    };
+    for bytes in [sensor.json_serialize().unwrap(), sensor.cbor_serialize().unwrap()] {
+        let decoded = match Sensor::json_deserialize(&bytes) {
+            Ok(decoded) => decoded,
+            Err(_) => Sensor::cbor_deserialize(&bytes).unwrap(),
+        };
+        assert_eq!(decoded.last.unwrap().unit, Some(Unit::Meter));
+        assert_eq!(decoded.limits.unwrap()["max"], 40);
+        assert_eq!(decoded.ranges.unwrap()[&3].scale, Some(2.0));
+        assert_eq!(decoded.statuses.unwrap()["left"].ok, Some(false));
+        assert_eq!(decoded.history.unwrap().len(), 1);
+    }
+
+    let mut command = Command { reboot_reason: Some("update".to_string()), ..Default::default() };
+    command.set_action(CommandAction::Calibrate(calibration));
+    assert!(command.reboot_reason.is_none());
+    let decoded = Command::cbor_deserialize(&command.cbor_serialize().unwrap()).unwrap();
+    match decoded.action() {
+        Some(CommandAction::Calibrate(calibration)) => assert_eq!(calibration.offset, Some(0.5)),
+        other => panic!("expected Calibrate, got {:?}", other),
+    }
+}
@@ -0,0 +1,207 @@
+// Generated by prosty mermaid from tests/mermaid/car.md, edit the diagram instead
+#![allow(dead_code, clippy::match_single_binding)]
+
+/// States of the Car machine, the active state is always a leaf
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    /// parked
+    Still,
+    /// This state has internal transitions
+    Moving,
+    Accelerating,
+    Cruising,
+    Braking,
+    Crash,
+    MovingFinal,
+    Final,
+}
+
+impl State {
+    pub const ALL: [State; 8] = [
+        State::Still,
+        State::Moving,
+        State::Accelerating,
+        State::Cruising,
+        State::Braking,
+        State::Crash,
+        State::MovingFinal,
+        State::Final,
+    ];
+
+    /// The id of the state in the diagram
+    pub fn name(self) -> &'static str {
+        match self {
+            State::Still => "Still",
+            State::Moving => "Moving",
+            State::Accelerating => "Accelerating",
+            State::Cruising => "Cruising",
+            State::Braking => "Braking",
+            State::Crash => "Crash",
+            State::MovingFinal => "MovingFinal",
+            State::Final => "Final",
+        }
+    }
+
+    /// The composite state this state is in
+    pub fn parent(self) -> Option<State> {
+        match self {
+            State::Accelerating => Some(State::Moving),
+            State::Cruising => Some(State::Moving),
+            State::Braking => Some(State::Moving),
+            State::MovingFinal => Some(State::Moving),
+            _ => None,
+        }
+    }
+
+    /// The substate entered with a composite state
+    pub fn initial(self) -> Option<State> {
+        match self {
+            State::Moving => Some(State::Accelerating),
+            _ => None,
+        }
+    }
+
+    /// The transition (source, target) taken as soon as this final state is entered
+    fn completion(self) -> Option<(State, State)> {
+        match self {
+            State::MovingFinal => Some((State::Moving, State::Still)),
+            _ => None,
+        }
+    }
+
+    /// True for the state itself and for all composite states it is in
+    pub fn is_in(self, ancestor: State) -> bool {
+        let mut state = Some(self);
+        while let Some(s) = state {
+            if s == ancestor {
+                return true;
+            }
+            state = s.parent();
+        }
+        false
+    }
+}
+
+/// Events taken from the transition labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    Speed60,
+    ObstacleDetected,
+    ObstacleCleared,
+    Stopped,
+    StartMoving,
+    StopMoving,
+    Crash,
+    Tow,
+    Honk,
+}
+
+/// The [guard] conditions of the transition labels
+pub trait Guards {
+    fn engine_ok(&self) -> bool;
+}
+
+/// The / actions of the transition labels and the entry and exit of every state
+pub trait Actions {
+    fn on_entry(&mut self, _state: State) {}
+    fn on_exit(&mut self, _state: State) {}
+    fn start_engine(&mut self);
+    fn stop_engine(&mut self);
+    fn beep(&mut self);
+}
+
+type Action<H> = Option<fn(&mut H)>;
+
+/// Enters the initial state, returns the active leaf
+pub fn start<H: Actions>(hooks: &mut H) -> State {
+    enter(hooks, None, State::Still)
+}
+
+/// Handles the event in the active leaf state or the innermost composite state with a
+/// transition for it, returns the new leaf or None when the event is not handled
+pub fn transition<H: Guards + Actions>(hooks: &mut H, state: State, event: Event) -> Option<State> {
+    let (source, target, action) = select(hooks, state, event)?;
+    let mut leaf = traverse(hooks, state, source, target, action);
+    while let Some((source, target)) = leaf.completion() {
+        leaf = traverse(hooks, leaf, source, target, None);
+    }
+    Some(leaf)
+}
+
+#[allow(unreachable_patterns, unused_variables)]
+fn select<H: Guards + Actions>(hooks: &H, state: State, event: Event) -> Option<(State, State, Action<H>)> {
+    let mut current = Some(state);
+    while let Some(source) = current {
+        match (source, event) {
+            (State::Accelerating, Event::Speed60) => {
+                return Some((source, State::Cruising, None));
+            }
+            (State::Cruising, Event::ObstacleDetected) => {
+                return Some((source, State::Braking, None));
+            }
+            (State::Braking, Event::ObstacleCleared) => {
+                return Some((source, State::Accelerating, None));
+            }
+            (State::Braking, Event::Stopped) => {
+                return Some((source, State::MovingFinal, None));
+            }
+            (State::Still, Event::StartMoving) if hooks.engine_ok() => {
+                return Some((source, State::Moving, Some(<H as Actions>::start_engine as fn(&mut H))));
+            }
+            (State::Moving, Event::StopMoving) => {
+                return Some((source, State::Still, Some(<H as Actions>::stop_engine as fn(&mut H))));
+            }
+            (State::Moving, Event::Crash) => {
+                return Some((source, State::Crash, None));
+            }
+            (State::Crash, Event::Tow) => {
+                return Some((source, State::Final, None));
+            }
+            (State::Moving, Event::Honk) => {
+                return Some((source, State::Moving, Some(<H as Actions>::beep as fn(&mut H))));
+            }
+            _ => {}
+        }
+        current = source.parent();
+    }
+    None
+}
+
+/// Leaves the states up to the innermost state containing source and target, runs the
+/// action and enters the target
+fn traverse<H: Actions>(hooks: &mut H, leaf: State, source: State, target: State, action: Action<H>) -> State {
+    let mut scope = source.parent();
+    while let Some(s) = scope {
+        if target != s && target.is_in(s) {
+            break;
+        }
+        scope = s.parent();
+    }
+    let mut exiting = Some(leaf);
+    while let Some(state) = exiting.filter(|s| Some(*s) != scope) {
+        hooks.on_exit(state);
+        exiting = state.parent();
+    }
+    if let Some(action) = action {
+        action(hooks);
+    }
+    enter(hooks, scope, target)
+}
+
+fn enter<H: Actions>(hooks: &mut H, scope: Option<State>, target: State) -> State {
+    let mut path = vec![];
+    let mut entering = Some(target);
+    while let Some(state) = entering.filter(|s| Some(*s) != scope) {
+        path.push(state);
+        entering = state.parent();
+    }
+    for state in path.into_iter().rev() {
+        hooks.on_entry(state);
+    }
+    let mut leaf = target;
+    while let Some(initial) = leaf.initial() {
+        hooks.on_entry(initial);
+        leaf = initial;
+    }
+    leaf
+}
@@ -0,0 +1,235 @@
+// Generated by prosty mermaid from syntax/states.md, edit the diagram instead
+#![allow(dead_code, clippy::match_single_binding)]
+
+/// States of the States machine, the active state is always a leaf
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Top,
+    Idle,
+    RemoteControl,
+    Autonomous,
+    Detour,
+    FollowAvoidancePath,
+    HumanDriving,
+    StraightDriving,
+    Cutting,
+    GoCharging,
+    Charging,
+    DetourFinal,
+}
+
+impl State {
+    pub const ALL: [State; 12] = [
+        State::Top,
+        State::Idle,
+        State::RemoteControl,
+        State::Autonomous,
+        State::Detour,
+        State::FollowAvoidancePath,
+        State::HumanDriving,
+        State::StraightDriving,
+        State::Cutting,
+        State::GoCharging,
+        State::Charging,
+        State::DetourFinal,
+    ];
+
+    /// The id of the state in the diagram
+    pub fn name(self) -> &'static str {
+        match self {
+            State::Top => "Top",
+            State::Idle => "Idle",
+            State::RemoteControl => "RemoteControl",
+            State::Autonomous => "Autonomous",
+            State::Detour => "Detour",
+            State::FollowAvoidancePath => "FollowAvoidancePath",
+            State::HumanDriving => "HumanDriving",
+            State::StraightDriving => "StraightDriving",
+            State::Cutting => "Cutting",
+            State::GoCharging => "GoCharging",
+            State::Charging => "Charging",
+            State::DetourFinal => "DetourFinal",
+        }
+    }
+
+    /// The composite state this state is in
+    pub fn parent(self) -> Option<State> {
+        match self {
+            State::Idle => Some(State::Top),
+            State::RemoteControl => Some(State::Top),
+            State::Autonomous => Some(State::Top),
+            State::Detour => Some(State::Autonomous),
+            State::FollowAvoidancePath => Some(State::Detour),
+            State::HumanDriving => Some(State::RemoteControl),
+            State::StraightDriving => Some(State::RemoteControl),
+            State::Cutting => Some(State::Autonomous),
+            State::GoCharging => Some(State::Autonomous),
+            State::Charging => Some(State::Autonomous),
+            State::DetourFinal => Some(State::Detour),
+            _ => None,
+        }
+    }
+
+    /// The substate entered with a composite state
+    pub fn initial(self) -> Option<State> {
+        match self {
+            State::Top => Some(State::Idle),
+            State::RemoteControl => Some(State::HumanDriving),
+            State::Autonomous => Some(State::Cutting),
+            State::Detour => Some(State::FollowAvoidancePath),
+            _ => None,
+        }
+    }
+
+    /// The transition (source, target) taken as soon as this final state is entered
+    fn completion(self) -> Option<(State, State)> {
+        match self {
+            _ => None,
+        }
+    }
+
+    /// True for the state itself and for all composite states it is in
+    pub fn is_in(self, ancestor: State) -> bool {
+        let mut state = Some(self);
+        while let Some(s) = state {
+            if s == ancestor {
+                return true;
+            }
+            state = s.parent();
+        }
+        false
+    }
+}
+
+/// Events taken from the transition labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    StartManualMode,
+    StartAutonomousMode,
+    StopManualMode,
+    StopAutonomousMode,
+    DetourPointReached,
+    StartStraight,
+    BatteryLow,
+    BatteryReached,
+    BatteryHigh,
+    Collision,
+}
+
+/// The [guard] conditions of the transition labels
+pub trait Guards {
+}
+
+/// The / actions of the transition labels and the entry and exit of every state
+pub trait Actions {
+    fn on_entry(&mut self, _state: State) {}
+    fn on_exit(&mut self, _state: State) {}
+}
+
+type Action<H> = Option<fn(&mut H)>;
+
+/// Enters the initial state, returns the active leaf
+pub fn start<H: Actions>(hooks: &mut H) -> State {
+    enter(hooks, None, State::Top)
+}
+
+/// Handles the event in the active leaf state or the innermost composite state with a
+/// transition for it, returns the new leaf or None when the event is not handled
+pub fn transition<H: Guards + Actions>(hooks: &mut H, state: State, event: Event) -> Option<State> {
+    let (source, target, action) = select(hooks, state, event)?;
+    let mut leaf = traverse(hooks, state, source, target, action);
+    while let Some((source, target)) = leaf.completion() {
+        leaf = traverse(hooks, leaf, source, target, None);
+    }
+    Some(leaf)
+}
+
+#[allow(unreachable_patterns, unused_variables)]
+fn select<H: Guards + Actions>(hooks: &H, state: State, event: Event) -> Option<(State, State, Action<H>)> {
+    let mut current = Some(state);
+    while let Some(source) = current {
+        match (source, event) {
+            (State::Idle, Event::StartManualMode) => {
+                return Some((source, State::RemoteControl, None));
+            }
+            (State::Idle, Event::StartAutonomousMode) => {
+                return Some((source, State::Autonomous, None));
+            }
+            (State::RemoteControl, Event::StopManualMode) => {
+                return Some((source, State::Idle, None));
+            }
+            (State::RemoteControl, Event::StartAutonomousMode) => {
+                return Some((source, State::Autonomous, None));
+            }
+            (State::Autonomous, Event::StopAutonomousMode) => {
+                return Some((source, State::Idle, None));
+            }
+            (State::Autonomous, Event::StartManualMode) => {
+                return Some((source, State::RemoteControl, None));
+            }
+            (State::FollowAvoidancePath, Event::DetourPointReached) => {
+                return Some((source, State::DetourFinal, None));
+            }
+            (State::HumanDriving, Event::StartStraight) => {
+                return Some((source, State::StraightDriving, None));
+            }
+            (State::Cutting, Event::BatteryLow) => {
+                return Some((source, State::GoCharging, None));
+            }
+            (State::GoCharging, Event::BatteryReached) => {
+                return Some((source, State::Charging, None));
+            }
+            (State::Charging, Event::BatteryHigh) => {
+                return Some((source, State::Cutting, None));
+            }
+            (State::Cutting, Event::Collision) => {
+                return Some((source, State::Detour, None));
+            }
+            (State::Detour, Event::DetourPointReached) => {
+                return Some((source, State::Cutting, None));
+            }
+            _ => {}
+        }
+        current = source.parent();
+    }
+    None
+}
+
+/// Leaves the states up to the innermost state containing source and target, runs the
+/// action and enters the target
+fn traverse<H: Actions>(hooks: &mut H, leaf: State, source: State, target: State, action: Action<H>) -> State {
+    let mut scope = source.parent();
+    while let Some(s) = scope {
+        if target != s && target.is_in(s) {
+            break;
+        }
+        scope = s.parent();
+    }
+    let mut exiting = Some(leaf);
+    while let Some(state) = exiting.filter(|s| Some(*s) != scope) {
+        hooks.on_exit(state);
+        exiting = state.parent();
+    }
+    if let Some(action) = action {
+        action(hooks);
+    }
+    enter(hooks, scope, target)
+}
+
+fn enter<H: Actions>(hooks: &mut H, scope: Option<State>, target: State) -> State {
+    let mut path = vec![];
+    let mut entering = Some(target);
+    while let Some(state) = entering.filter(|s| Some(*s) != scope) {
+        path.push(state);
+        entering = state.parent();
+    }
+    for state in path.into_iter().rev() {
+        hooks.on_entry(state);
+    }
+    let mut leaf = target;
+    while let Some(initial) = leaf.initial() {
+        hooks.on_entry(initial);
+        leaf = initial;
+    }
+    leaf
+}
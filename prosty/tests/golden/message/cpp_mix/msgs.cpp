@@ -0,0 +1,8419 @@
+#include "msgs.h"
+
+// Helper macros for JSON values, target is a JsonVariant or a member of a JsonObject
+
+
+
+
+
+
+
+Result<Bytes> Alive::json_serialize(const Alive& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Alive*> Alive::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Alive*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Alive* msg = new Alive();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Alive*>::Ok(msg);
+    }
+
+void Alive::json_encode(JsonObject obj, const Alive& msg) {
+        if (msg.subscribe.size()) {
+                    JsonArray arr = obj["subscribe"].to<JsonArray>();
+                    for (const auto& item : msg.subscribe) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.publish.size()) {
+                    JsonArray arr = obj["publish"].to<JsonArray>();
+                    for (const auto& item : msg.publish) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.services.size()) {
+                    JsonArray arr = obj["services"].to<JsonArray>();
+                    for (const auto& item : msg.services) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        }
+
+void Alive::json_decode(JsonObjectConst obj, Alive& msg) {
+        if (obj["subscribe"].is<JsonArrayConst>()) {
+                    msg.subscribe.clear();
+                    for (JsonVariantConst v : obj["subscribe"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.subscribe.push_back(item);
+                    }
+                }
+        if (obj["publish"].is<JsonArrayConst>()) {
+                    msg.publish.clear();
+                    for (JsonVariantConst v : obj["publish"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.publish.push_back(item);
+                    }
+                }
+        if (obj["services"].is<JsonArrayConst>()) {
+                    msg.services.clear();
+                    for (JsonVariantConst v : obj["services"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.services.push_back(item);
+                    }
+                }
+        }
+
+
+Result<Bytes> UdpMessage::json_serialize(const UdpMessage& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<UdpMessage*> UdpMessage::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<UdpMessage*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        UdpMessage* msg = new UdpMessage();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<UdpMessage*>::Ok(msg);
+    }
+
+void UdpMessage::json_encode(JsonObject obj, const UdpMessage& msg) {
+        if (msg.dst)
+                    obj["dst"] = *msg.dst;
+        if (msg.src)
+                    obj["src"] = *msg.src;
+        if (msg.msg_type)
+                    obj["msg_type"] = *msg.msg_type;
+        if (msg.payload)
+                    obj["payload"] = base64_encode(*msg.payload);
+        if (msg.correlation_id)
+                    obj["correlation_id"] = *msg.correlation_id;
+        if (msg.seq)
+                    obj["seq"] = *msg.seq;
+        if (msg.frag_id)
+                    obj["frag_id"] = *msg.frag_id;
+        if (msg.frag_index)
+                    obj["frag_index"] = *msg.frag_index;
+        if (msg.frag_count)
+                    obj["frag_count"] = *msg.frag_count;
+        if (msg.codec)
+                    obj["codec"] = static_cast<int>(*msg.codec);
+        if (msg.nonce)
+                    obj["nonce"] = base64_encode(*msg.nonce);
+        }
+
+void UdpMessage::json_decode(JsonObjectConst obj, UdpMessage& msg) {
+        if (obj["dst"].is<std::string>())
+                    msg.dst = obj["dst"].as<std::string>();
+        if (obj["src"].is<std::string>())
+                    msg.src = obj["src"].as<std::string>();
+        if (obj["msg_type"].is<std::string>())
+                    msg.msg_type = obj["msg_type"].as<std::string>();
+        if (obj["payload"].is<std::string>())
+                    msg.payload = base64_decode(obj["payload"].as<std::string>());
+        if (obj["correlation_id"].is<uint32_t>())
+                    msg.correlation_id = obj["correlation_id"].as<uint32_t>();
+        if (obj["seq"].is<uint32_t>())
+                    msg.seq = obj["seq"].as<uint32_t>();
+        if (obj["frag_id"].is<uint32_t>())
+                    msg.frag_id = obj["frag_id"].as<uint32_t>();
+        if (obj["frag_index"].is<uint32_t>())
+                    msg.frag_index = obj["frag_index"].as<uint32_t>();
+        if (obj["frag_count"].is<uint32_t>())
+                    msg.frag_count = obj["frag_count"].as<uint32_t>();
+        if (obj["codec"].is<int>())
+                    msg.codec = static_cast<Codec>(obj["codec"].as<int>());
+        if (obj["nonce"].is<std::string>())
+                    msg.nonce = base64_decode(obj["nonce"].as<std::string>());
+        }
+
+
+Result<Bytes> UdpMessageCbor::json_serialize(const UdpMessageCbor& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<UdpMessageCbor*> UdpMessageCbor::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<UdpMessageCbor*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        UdpMessageCbor* msg = new UdpMessageCbor();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<UdpMessageCbor*>::Ok(msg);
+    }
+
+void UdpMessageCbor::json_encode(JsonObject obj, const UdpMessageCbor& msg) {
+        if (msg.dst)
+                    obj["dst"] = *msg.dst;
+        if (msg.src)
+                    obj["src"] = *msg.src;
+        if (msg.msg_type)
+                    obj["msg_type"] = *msg.msg_type;
+        if (msg.payload)
+                    obj["payload"] = base64_encode(*msg.payload);
+        }
+
+void UdpMessageCbor::json_decode(JsonObjectConst obj, UdpMessageCbor& msg) {
+        if (obj["dst"].is<uint32_t>())
+                    msg.dst = obj["dst"].as<uint32_t>();
+        if (obj["src"].is<uint32_t>())
+                    msg.src = obj["src"].as<uint32_t>();
+        if (obj["msg_type"].is<uint32_t>())
+                    msg.msg_type = obj["msg_type"].as<uint32_t>();
+        if (obj["payload"].is<std::string>())
+                    msg.payload = base64_decode(obj["payload"].as<std::string>());
+        }
+
+
+Result<Bytes> ZenohEvent::json_serialize(const ZenohEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<ZenohEvent*> ZenohEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<ZenohEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        ZenohEvent* msg = new ZenohEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<ZenohEvent*>::Ok(msg);
+    }
+
+void ZenohEvent::json_encode(JsonObject obj, const ZenohEvent& msg) {
+        if (msg.zid)
+                    obj["zid"] = *msg.zid;
+        if (msg.what_am_i)
+                    obj["what_am_i"] = *msg.what_am_i;
+        if (msg.peers.size()) {
+                    JsonArray arr = obj["peers"].to<JsonArray>();
+                    for (const auto& item : msg.peers) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.prefix)
+                    obj["prefix"] = *msg.prefix;
+        if (msg.routers.size()) {
+                    JsonArray arr = obj["routers"].to<JsonArray>();
+                    for (const auto& item : msg.routers) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.connect)
+                    obj["connect"] = *msg.connect;
+        if (msg.listen)
+                    obj["listen"] = *msg.listen;
+        }
+
+void ZenohEvent::json_decode(JsonObjectConst obj, ZenohEvent& msg) {
+        if (obj["zid"].is<std::string>())
+                    msg.zid = obj["zid"].as<std::string>();
+        if (obj["what_am_i"].is<std::string>())
+                    msg.what_am_i = obj["what_am_i"].as<std::string>();
+        if (obj["peers"].is<JsonArrayConst>()) {
+                    msg.peers.clear();
+                    for (JsonVariantConst v : obj["peers"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.peers.push_back(item);
+                    }
+                }
+        if (obj["prefix"].is<std::string>())
+                    msg.prefix = obj["prefix"].as<std::string>();
+        if (obj["routers"].is<JsonArrayConst>()) {
+                    msg.routers.clear();
+                    for (JsonVariantConst v : obj["routers"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.routers.push_back(item);
+                    }
+                }
+        if (obj["connect"].is<std::string>())
+                    msg.connect = obj["connect"].as<std::string>();
+        if (obj["listen"].is<std::string>())
+                    msg.listen = obj["listen"].as<std::string>();
+        }
+
+
+Result<Bytes> LogEvent::json_serialize(const LogEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LogEvent*> LogEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LogEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LogEvent* msg = new LogEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LogEvent*>::Ok(msg);
+    }
+
+void LogEvent::json_encode(JsonObject obj, const LogEvent& msg) {
+        if (msg.level)
+                    obj["level"] = static_cast<int>(*msg.level);
+        if (msg.message)
+                    obj["message"] = *msg.message;
+        if (msg.error_code)
+                    obj["error_code"] = *msg.error_code;
+        if (msg.file)
+                    obj["file"] = *msg.file;
+        if (msg.line)
+                    obj["line"] = *msg.line;
+        if (msg.timestamp)
+                    obj["timestamp"] = *msg.timestamp;
+        }
+
+void LogEvent::json_decode(JsonObjectConst obj, LogEvent& msg) {
+        if (obj["level"].is<int>())
+                    msg.level = static_cast<LogLevel>(obj["level"].as<int>());
+        if (obj["message"].is<std::string>())
+                    msg.message = obj["message"].as<std::string>();
+        if (obj["error_code"].is<int32_t>())
+                    msg.error_code = obj["error_code"].as<int32_t>();
+        if (obj["file"].is<std::string>())
+                    msg.file = obj["file"].as<std::string>();
+        if (obj["line"].is<int32_t>())
+                    msg.line = obj["line"].as<int32_t>();
+        if (obj["timestamp"].is<uint64_t>())
+                    msg.timestamp = obj["timestamp"].as<uint64_t>();
+        }
+
+
+Result<Bytes> SysCmd::json_serialize(const SysCmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<SysCmd*> SysCmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<SysCmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        SysCmd* msg = new SysCmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<SysCmd*>::Ok(msg);
+    }
+
+void SysCmd::json_encode(JsonObject obj, const SysCmd& msg) {
+        obj["src"] = msg.src;
+        if (msg.set_time)
+                    obj["set_time"] = *msg.set_time;
+        if (msg.reboot)
+                    obj["reboot"] = *msg.reboot;
+        if (msg.console)
+                    obj["console"] = *msg.console;
+        }
+
+void SysCmd::json_decode(JsonObjectConst obj, SysCmd& msg) {
+        if (obj["src"].is<std::string>())
+                    msg.src = obj["src"].as<std::string>();
+        if (obj["set_time"].is<uint64_t>())
+                    msg.set_time = obj["set_time"].as<uint64_t>();
+        if (obj["reboot"].is<bool>())
+                    msg.reboot = obj["reboot"].as<bool>();
+        if (obj["console"].is<std::string>())
+                    msg.console = obj["console"].as<std::string>();
+        }
+
+
+Result<Bytes> SysEvent::json_serialize(const SysEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<SysEvent*> SysEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<SysEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        SysEvent* msg = new SysEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<SysEvent*>::Ok(msg);
+    }
+
+void SysEvent::json_encode(JsonObject obj, const SysEvent& msg) {
+        if (msg.utc)
+                    obj["utc"] = *msg.utc;
+        if (msg.uptime)
+                    obj["uptime"] = *msg.uptime;
+        if (msg.free_heap)
+                    obj["free_heap"] = *msg.free_heap;
+        if (msg.flash)
+                    obj["flash"] = *msg.flash;
+        if (msg.cpu_board)
+                    obj["cpu_board"] = *msg.cpu_board;
+        if (msg.build_date)
+                    obj["build_date"] = *msg.build_date;
+        }
+
+void SysEvent::json_decode(JsonObjectConst obj, SysEvent& msg) {
+        if (obj["utc"].is<uint64_t>())
+                    msg.utc = obj["utc"].as<uint64_t>();
+        if (obj["uptime"].is<uint64_t>())
+                    msg.uptime = obj["uptime"].as<uint64_t>();
+        if (obj["free_heap"].is<uint64_t>())
+                    msg.free_heap = obj["free_heap"].as<uint64_t>();
+        if (obj["flash"].is<uint64_t>())
+                    msg.flash = obj["flash"].as<uint64_t>();
+        if (obj["cpu_board"].is<std::string>())
+                    msg.cpu_board = obj["cpu_board"].as<std::string>();
+        if (obj["build_date"].is<std::string>())
+                    msg.build_date = obj["build_date"].as<std::string>();
+        }
+
+
+Result<Bytes> WifiEvent::json_serialize(const WifiEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<WifiEvent*> WifiEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<WifiEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        WifiEvent* msg = new WifiEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<WifiEvent*>::Ok(msg);
+    }
+
+void WifiEvent::json_encode(JsonObject obj, const WifiEvent& msg) {
+        if (msg.ssid)
+                    obj["ssid"] = *msg.ssid;
+        if (msg.bssid)
+                    obj["bssid"] = *msg.bssid;
+        if (msg.rssi)
+                    obj["rssi"] = *msg.rssi;
+        if (msg.ip)
+                    obj["ip"] = *msg.ip;
+        if (msg.mac)
+                    obj["mac"] = *msg.mac;
+        if (msg.channel)
+                    obj["channel"] = *msg.channel;
+        if (msg.gateway)
+                    obj["gateway"] = *msg.gateway;
+        if (msg.netmask)
+                    obj["netmask"] = *msg.netmask;
+        }
+
+void WifiEvent::json_decode(JsonObjectConst obj, WifiEvent& msg) {
+        if (obj["ssid"].is<std::string>())
+                    msg.ssid = obj["ssid"].as<std::string>();
+        if (obj["bssid"].is<std::string>())
+                    msg.bssid = obj["bssid"].as<std::string>();
+        if (obj["rssi"].is<int32_t>())
+                    msg.rssi = obj["rssi"].as<int32_t>();
+        if (obj["ip"].is<std::string>())
+                    msg.ip = obj["ip"].as<std::string>();
+        if (obj["mac"].is<std::string>())
+                    msg.mac = obj["mac"].as<std::string>();
+        if (obj["channel"].is<int32_t>())
+                    msg.channel = obj["channel"].as<int32_t>();
+        if (obj["gateway"].is<std::string>())
+                    msg.gateway = obj["gateway"].as<std::string>();
+        if (obj["netmask"].is<std::string>())
+                    msg.netmask = obj["netmask"].as<std::string>();
+        }
+
+
+Result<Bytes> MulticastEvent::json_serialize(const MulticastEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<MulticastEvent*> MulticastEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<MulticastEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        MulticastEvent* msg = new MulticastEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<MulticastEvent*>::Ok(msg);
+    }
+
+void MulticastEvent::json_encode(JsonObject obj, const MulticastEvent& msg) {
+        if (msg.group)
+                    obj["group"] = *msg.group;
+        if (msg.port)
+                    obj["port"] = *msg.port;
+        if (msg.mtu)
+                    obj["mtu"] = *msg.mtu;
+        }
+
+void MulticastEvent::json_decode(JsonObjectConst obj, MulticastEvent& msg) {
+        if (obj["group"].is<std::string>())
+                    msg.group = obj["group"].as<std::string>();
+        if (obj["port"].is<int32_t>())
+                    msg.port = obj["port"].as<int32_t>();
+        if (obj["mtu"].is<uint32_t>())
+                    msg.mtu = obj["mtu"].as<uint32_t>();
+        }
+
+
+Result<Bytes> PingReq::json_serialize(const PingReq& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<PingReq*> PingReq::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<PingReq*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        PingReq* msg = new PingReq();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<PingReq*>::Ok(msg);
+    }
+
+void PingReq::json_encode(JsonObject obj, const PingReq& msg) {
+        if (msg.number)
+                    obj["number"] = *msg.number;
+        }
+
+void PingReq::json_decode(JsonObjectConst obj, PingReq& msg) {
+        if (obj["number"].is<uint32_t>())
+                    msg.number = obj["number"].as<uint32_t>();
+        }
+
+
+Result<Bytes> PingRep::json_serialize(const PingRep& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<PingRep*> PingRep::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<PingRep*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        PingRep* msg = new PingRep();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<PingRep*>::Ok(msg);
+    }
+
+void PingRep::json_encode(JsonObject obj, const PingRep& msg) {
+        if (msg.number)
+                    obj["number"] = *msg.number;
+        }
+
+void PingRep::json_decode(JsonObjectConst obj, PingRep& msg) {
+        if (obj["number"].is<uint32_t>())
+                    msg.number = obj["number"].as<uint32_t>();
+        }
+
+
+Result<Bytes> HoverboardEventRaw::json_serialize(const HoverboardEventRaw& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<HoverboardEventRaw*> HoverboardEventRaw::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<HoverboardEventRaw*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        HoverboardEventRaw* msg = new HoverboardEventRaw();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<HoverboardEventRaw*>::Ok(msg);
+    }
+
+void HoverboardEventRaw::json_encode(JsonObject obj, const HoverboardEventRaw& msg) {
+        if (msg.ctrl_mod)
+                    obj["ctrl_mod"] = *msg.ctrl_mod;
+        if (msg.ctrl_typ)
+                    obj["ctrl_typ"] = *msg.ctrl_typ;
+        if (msg.cur_mot_max)
+                    obj["cur_mot_max"] = *msg.cur_mot_max;
+        if (msg.rpm_mot_max)
+                    obj["rpm_mot_max"] = *msg.rpm_mot_max;
+        if (msg.fi_weak_ena)
+                    obj["fi_weak_ena"] = *msg.fi_weak_ena;
+        if (msg.fi_weak_hi)
+                    obj["fi_weak_hi"] = *msg.fi_weak_hi;
+        if (msg.fi_weak_lo)
+                    obj["fi_weak_lo"] = *msg.fi_weak_lo;
+        if (msg.fi_weak_max)
+                    obj["fi_weak_max"] = *msg.fi_weak_max;
+        if (msg.phase_adv_max_deg)
+                    obj["phase_adv_max_deg"] = *msg.phase_adv_max_deg;
+        if (msg.input1_raw)
+                    obj["input1_raw"] = *msg.input1_raw;
+        if (msg.input1_typ)
+                    obj["input1_typ"] = *msg.input1_typ;
+        if (msg.input1_min)
+                    obj["input1_min"] = *msg.input1_min;
+        if (msg.input1_mid)
+                    obj["input1_mid"] = *msg.input1_mid;
+        if (msg.input1_max)
+                    obj["input1_max"] = *msg.input1_max;
+        if (msg.input1_cmd)
+                    obj["input1_cmd"] = *msg.input1_cmd;
+        if (msg.input2_raw)
+                    obj["input2_raw"] = *msg.input2_raw;
+        if (msg.input2_typ)
+                    obj["input2_typ"] = *msg.input2_typ;
+        if (msg.input2_min)
+                    obj["input2_min"] = *msg.input2_min;
+        if (msg.input2_mid)
+                    obj["input2_mid"] = *msg.input2_mid;
+        if (msg.input2_max)
+                    obj["input2_max"] = *msg.input2_max;
+        if (msg.input2_cmd)
+                    obj["input2_cmd"] = *msg.input2_cmd;
+        if (msg.aux_input1_raw)
+                    obj["aux_input1_raw"] = *msg.aux_input1_raw;
+        if (msg.aux_input1_typ)
+                    obj["aux_input1_typ"] = *msg.aux_input1_typ;
+        if (msg.aux_input1_min)
+                    obj["aux_input1_min"] = *msg.aux_input1_min;
+        if (msg.aux_input1_mid)
+                    obj["aux_input1_mid"] = *msg.aux_input1_mid;
+        if (msg.aux_input1_max)
+                    obj["aux_input1_max"] = *msg.aux_input1_max;
+        if (msg.aux_input1_cmd)
+                    obj["aux_input1_cmd"] = *msg.aux_input1_cmd;
+        if (msg.aux_input2_raw)
+                    obj["aux_input2_raw"] = *msg.aux_input2_raw;
+        if (msg.aux_input2_typ)
+                    obj["aux_input2_typ"] = *msg.aux_input2_typ;
+        if (msg.aux_input2_min)
+                    obj["aux_input2_min"] = *msg.aux_input2_min;
+        if (msg.aux_input2_mid)
+                    obj["aux_input2_mid"] = *msg.aux_input2_mid;
+        if (msg.aux_input2_max)
+                    obj["aux_input2_max"] = *msg.aux_input2_max;
+        if (msg.aux_input2_cmd)
+                    obj["aux_input2_cmd"] = *msg.aux_input2_cmd;
+        if (msg.dc_curr)
+                    obj["dc_curr"] = *msg.dc_curr;
+        if (msg.rdc_curr)
+                    obj["rdc_curr"] = *msg.rdc_curr;
+        if (msg.ldc_curr)
+                    obj["ldc_curr"] = *msg.ldc_curr;
+        if (msg.cmdl)
+                    obj["cmdl"] = *msg.cmdl;
+        if (msg.cmdr)
+                    obj["cmdr"] = *msg.cmdr;
+        if (msg.spd_avg)
+                    obj["spd_avg"] = *msg.spd_avg;
+        if (msg.spdl)
+                    obj["spdl"] = *msg.spdl;
+        if (msg.spdr)
+                    obj["spdr"] = *msg.spdr;
+        if (msg.filter_rate)
+                    obj["filter_rate"] = *msg.filter_rate;
+        if (msg.spd_coef)
+                    obj["spd_coef"] = *msg.spd_coef;
+        if (msg.str_coef)
+                    obj["str_coef"] = *msg.str_coef;
+        if (msg.batv)
+                    obj["batv"] = *msg.batv;
+        if (msg.temp)
+                    obj["temp"] = *msg.temp;
+        }
+
+void HoverboardEventRaw::json_decode(JsonObjectConst obj, HoverboardEventRaw& msg) {
+        if (obj["ctrl_mod"].is<int32_t>())
+                    msg.ctrl_mod = obj["ctrl_mod"].as<int32_t>();
+        if (obj["ctrl_typ"].is<int32_t>())
+                    msg.ctrl_typ = obj["ctrl_typ"].as<int32_t>();
+        if (obj["cur_mot_max"].is<int32_t>())
+                    msg.cur_mot_max = obj["cur_mot_max"].as<int32_t>();
+        if (obj["rpm_mot_max"].is<int32_t>())
+                    msg.rpm_mot_max = obj["rpm_mot_max"].as<int32_t>();
+        if (obj["fi_weak_ena"].is<int32_t>())
+                    msg.fi_weak_ena = obj["fi_weak_ena"].as<int32_t>();
+        if (obj["fi_weak_hi"].is<int32_t>())
+                    msg.fi_weak_hi = obj["fi_weak_hi"].as<int32_t>();
+        if (obj["fi_weak_lo"].is<int32_t>())
+                    msg.fi_weak_lo = obj["fi_weak_lo"].as<int32_t>();
+        if (obj["fi_weak_max"].is<int32_t>())
+                    msg.fi_weak_max = obj["fi_weak_max"].as<int32_t>();
+        if (obj["phase_adv_max_deg"].is<int32_t>())
+                    msg.phase_adv_max_deg = obj["phase_adv_max_deg"].as<int32_t>();
+        if (obj["input1_raw"].is<int32_t>())
+                    msg.input1_raw = obj["input1_raw"].as<int32_t>();
+        if (obj["input1_typ"].is<int32_t>())
+                    msg.input1_typ = obj["input1_typ"].as<int32_t>();
+        if (obj["input1_min"].is<int32_t>())
+                    msg.input1_min = obj["input1_min"].as<int32_t>();
+        if (obj["input1_mid"].is<int32_t>())
+                    msg.input1_mid = obj["input1_mid"].as<int32_t>();
+        if (obj["input1_max"].is<int32_t>())
+                    msg.input1_max = obj["input1_max"].as<int32_t>();
+        if (obj["input1_cmd"].is<int32_t>())
+                    msg.input1_cmd = obj["input1_cmd"].as<int32_t>();
+        if (obj["input2_raw"].is<int32_t>())
+                    msg.input2_raw = obj["input2_raw"].as<int32_t>();
+        if (obj["input2_typ"].is<int32_t>())
+                    msg.input2_typ = obj["input2_typ"].as<int32_t>();
+        if (obj["input2_min"].is<int32_t>())
+                    msg.input2_min = obj["input2_min"].as<int32_t>();
+        if (obj["input2_mid"].is<int32_t>())
+                    msg.input2_mid = obj["input2_mid"].as<int32_t>();
+        if (obj["input2_max"].is<int32_t>())
+                    msg.input2_max = obj["input2_max"].as<int32_t>();
+        if (obj["input2_cmd"].is<int32_t>())
+                    msg.input2_cmd = obj["input2_cmd"].as<int32_t>();
+        if (obj["aux_input1_raw"].is<int32_t>())
+                    msg.aux_input1_raw = obj["aux_input1_raw"].as<int32_t>();
+        if (obj["aux_input1_typ"].is<int32_t>())
+                    msg.aux_input1_typ = obj["aux_input1_typ"].as<int32_t>();
+        if (obj["aux_input1_min"].is<int32_t>())
+                    msg.aux_input1_min = obj["aux_input1_min"].as<int32_t>();
+        if (obj["aux_input1_mid"].is<int32_t>())
+                    msg.aux_input1_mid = obj["aux_input1_mid"].as<int32_t>();
+        if (obj["aux_input1_max"].is<int32_t>())
+                    msg.aux_input1_max = obj["aux_input1_max"].as<int32_t>();
+        if (obj["aux_input1_cmd"].is<int32_t>())
+                    msg.aux_input1_cmd = obj["aux_input1_cmd"].as<int32_t>();
+        if (obj["aux_input2_raw"].is<int32_t>())
+                    msg.aux_input2_raw = obj["aux_input2_raw"].as<int32_t>();
+        if (obj["aux_input2_typ"].is<int32_t>())
+                    msg.aux_input2_typ = obj["aux_input2_typ"].as<int32_t>();
+        if (obj["aux_input2_min"].is<int32_t>())
+                    msg.aux_input2_min = obj["aux_input2_min"].as<int32_t>();
+        if (obj["aux_input2_mid"].is<int32_t>())
+                    msg.aux_input2_mid = obj["aux_input2_mid"].as<int32_t>();
+        if (obj["aux_input2_max"].is<int32_t>())
+                    msg.aux_input2_max = obj["aux_input2_max"].as<int32_t>();
+        if (obj["aux_input2_cmd"].is<int32_t>())
+                    msg.aux_input2_cmd = obj["aux_input2_cmd"].as<int32_t>();
+        if (obj["dc_curr"].is<int32_t>())
+                    msg.dc_curr = obj["dc_curr"].as<int32_t>();
+        if (obj["rdc_curr"].is<int32_t>())
+                    msg.rdc_curr = obj["rdc_curr"].as<int32_t>();
+        if (obj["ldc_curr"].is<int32_t>())
+                    msg.ldc_curr = obj["ldc_curr"].as<int32_t>();
+        if (obj["cmdl"].is<int32_t>())
+                    msg.cmdl = obj["cmdl"].as<int32_t>();
+        if (obj["cmdr"].is<int32_t>())
+                    msg.cmdr = obj["cmdr"].as<int32_t>();
+        if (obj["spd_avg"].is<int32_t>())
+                    msg.spd_avg = obj["spd_avg"].as<int32_t>();
+        if (obj["spdl"].is<int32_t>())
+                    msg.spdl = obj["spdl"].as<int32_t>();
+        if (obj["spdr"].is<int32_t>())
+                    msg.spdr = obj["spdr"].as<int32_t>();
+        if (obj["filter_rate"].is<int32_t>())
+                    msg.filter_rate = obj["filter_rate"].as<int32_t>();
+        if (obj["spd_coef"].is<int32_t>())
+                    msg.spd_coef = obj["spd_coef"].as<int32_t>();
+        if (obj["str_coef"].is<int32_t>())
+                    msg.str_coef = obj["str_coef"].as<int32_t>();
+        if (obj["batv"].is<int32_t>())
+                    msg.batv = obj["batv"].as<int32_t>();
+        if (obj["temp"].is<int32_t>())
+                    msg.temp = obj["temp"].as<int32_t>();
+        }
+
+
+Result<Bytes> HoverboardEvent::json_serialize(const HoverboardEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<HoverboardEvent*> HoverboardEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<HoverboardEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        HoverboardEvent* msg = new HoverboardEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<HoverboardEvent*>::Ok(msg);
+    }
+
+void HoverboardEvent::json_encode(JsonObject obj, const HoverboardEvent& msg) {
+        if (msg.ctrl_mod)
+                    obj["ctrl_mod"] = *msg.ctrl_mod;
+        if (msg.ctrl_typ)
+                    obj["ctrl_typ"] = *msg.ctrl_typ;
+        if (msg.cur_mot_max)
+                    obj["cur_mot_max"] = *msg.cur_mot_max;
+        if (msg.rpm_mot_max)
+                    obj["rpm_mot_max"] = *msg.rpm_mot_max;
+        if (msg.fi_weak_ena)
+                    obj["fi_weak_ena"] = *msg.fi_weak_ena;
+        if (msg.fi_weak_hi)
+                    obj["fi_weak_hi"] = *msg.fi_weak_hi;
+        if (msg.fi_weak_lo)
+                    obj["fi_weak_lo"] = *msg.fi_weak_lo;
+        if (msg.fi_weak_max)
+                    obj["fi_weak_max"] = *msg.fi_weak_max;
+        if (msg.phase_adv_max_deg)
+                    obj["phase_adv_max_deg"] = *msg.phase_adv_max_deg;
+        if (msg.input1_raw)
+                    obj["input1_raw"] = *msg.input1_raw;
+        if (msg.input1_typ)
+                    obj["input1_typ"] = *msg.input1_typ;
+        if (msg.input1_min)
+                    obj["input1_min"] = *msg.input1_min;
+        if (msg.input1_mid)
+                    obj["input1_mid"] = *msg.input1_mid;
+        if (msg.input1_max)
+                    obj["input1_max"] = *msg.input1_max;
+        if (msg.input1_cmd)
+                    obj["input1_cmd"] = *msg.input1_cmd;
+        if (msg.input2_raw)
+                    obj["input2_raw"] = *msg.input2_raw;
+        if (msg.input2_typ)
+                    obj["input2_typ"] = *msg.input2_typ;
+        if (msg.input2_min)
+                    obj["input2_min"] = *msg.input2_min;
+        if (msg.input2_mid)
+                    obj["input2_mid"] = *msg.input2_mid;
+        if (msg.input2_max)
+                    obj["input2_max"] = *msg.input2_max;
+        if (msg.input2_cmd)
+                    obj["input2_cmd"] = *msg.input2_cmd;
+        if (msg.aux_input1_raw)
+                    obj["aux_input1_raw"] = *msg.aux_input1_raw;
+        if (msg.aux_input1_typ)
+                    obj["aux_input1_typ"] = *msg.aux_input1_typ;
+        if (msg.aux_input1_min)
+                    obj["aux_input1_min"] = *msg.aux_input1_min;
+        if (msg.aux_input1_mid)
+                    obj["aux_input1_mid"] = *msg.aux_input1_mid;
+        if (msg.aux_input1_max)
+                    obj["aux_input1_max"] = *msg.aux_input1_max;
+        if (msg.aux_input1_cmd)
+                    obj["aux_input1_cmd"] = *msg.aux_input1_cmd;
+        if (msg.aux_input2_raw)
+                    obj["aux_input2_raw"] = *msg.aux_input2_raw;
+        if (msg.aux_input2_typ)
+                    obj["aux_input2_typ"] = *msg.aux_input2_typ;
+        if (msg.aux_input2_min)
+                    obj["aux_input2_min"] = *msg.aux_input2_min;
+        if (msg.aux_input2_mid)
+                    obj["aux_input2_mid"] = *msg.aux_input2_mid;
+        if (msg.aux_input2_max)
+                    obj["aux_input2_max"] = *msg.aux_input2_max;
+        if (msg.aux_input2_cmd)
+                    obj["aux_input2_cmd"] = *msg.aux_input2_cmd;
+        if (msg.dc_curr)
+                    obj["dc_curr"] = *msg.dc_curr;
+        if (msg.rdc_curr)
+                    obj["rdc_curr"] = *msg.rdc_curr;
+        if (msg.ldc_curr)
+                    obj["ldc_curr"] = *msg.ldc_curr;
+        if (msg.cmdl)
+                    obj["cmdl"] = *msg.cmdl;
+        if (msg.cmdr)
+                    obj["cmdr"] = *msg.cmdr;
+        if (msg.spd_avg)
+                    obj["spd_avg"] = *msg.spd_avg;
+        if (msg.spdl)
+                    obj["spdl"] = *msg.spdl;
+        if (msg.spdr)
+                    obj["spdr"] = *msg.spdr;
+        if (msg.filter_rate)
+                    obj["filter_rate"] = *msg.filter_rate;
+        if (msg.spd_coef)
+                    obj["spd_coef"] = *msg.spd_coef;
+        if (msg.str_coef)
+                    obj["str_coef"] = *msg.str_coef;
+        if (msg.batv)
+                    obj["batv"] = *msg.batv;
+        if (msg.temp)
+                    obj["temp"] = *msg.temp;
+        }
+
+void HoverboardEvent::json_decode(JsonObjectConst obj, HoverboardEvent& msg) {
+        if (obj["ctrl_mod"].is<int32_t>())
+                    msg.ctrl_mod = obj["ctrl_mod"].as<int32_t>();
+        if (obj["ctrl_typ"].is<int32_t>())
+                    msg.ctrl_typ = obj["ctrl_typ"].as<int32_t>();
+        if (obj["cur_mot_max"].is<int32_t>())
+                    msg.cur_mot_max = obj["cur_mot_max"].as<int32_t>();
+        if (obj["rpm_mot_max"].is<int32_t>())
+                    msg.rpm_mot_max = obj["rpm_mot_max"].as<int32_t>();
+        if (obj["fi_weak_ena"].is<int32_t>())
+                    msg.fi_weak_ena = obj["fi_weak_ena"].as<int32_t>();
+        if (obj["fi_weak_hi"].is<int32_t>())
+                    msg.fi_weak_hi = obj["fi_weak_hi"].as<int32_t>();
+        if (obj["fi_weak_lo"].is<int32_t>())
+                    msg.fi_weak_lo = obj["fi_weak_lo"].as<int32_t>();
+        if (obj["fi_weak_max"].is<int32_t>())
+                    msg.fi_weak_max = obj["fi_weak_max"].as<int32_t>();
+        if (obj["phase_adv_max_deg"].is<int32_t>())
+                    msg.phase_adv_max_deg = obj["phase_adv_max_deg"].as<int32_t>();
+        if (obj["input1_raw"].is<int32_t>())
+                    msg.input1_raw = obj["input1_raw"].as<int32_t>();
+        if (obj["input1_typ"].is<int32_t>())
+                    msg.input1_typ = obj["input1_typ"].as<int32_t>();
+        if (obj["input1_min"].is<int32_t>())
+                    msg.input1_min = obj["input1_min"].as<int32_t>();
+        if (obj["input1_mid"].is<int32_t>())
+                    msg.input1_mid = obj["input1_mid"].as<int32_t>();
+        if (obj["input1_max"].is<int32_t>())
+                    msg.input1_max = obj["input1_max"].as<int32_t>();
+        if (obj["input1_cmd"].is<int32_t>())
+                    msg.input1_cmd = obj["input1_cmd"].as<int32_t>();
+        if (obj["input2_raw"].is<int32_t>())
+                    msg.input2_raw = obj["input2_raw"].as<int32_t>();
+        if (obj["input2_typ"].is<int32_t>())
+                    msg.input2_typ = obj["input2_typ"].as<int32_t>();
+        if (obj["input2_min"].is<int32_t>())
+                    msg.input2_min = obj["input2_min"].as<int32_t>();
+        if (obj["input2_mid"].is<int32_t>())
+                    msg.input2_mid = obj["input2_mid"].as<int32_t>();
+        if (obj["input2_max"].is<int32_t>())
+                    msg.input2_max = obj["input2_max"].as<int32_t>();
+        if (obj["input2_cmd"].is<int32_t>())
+                    msg.input2_cmd = obj["input2_cmd"].as<int32_t>();
+        if (obj["aux_input1_raw"].is<int32_t>())
+                    msg.aux_input1_raw = obj["aux_input1_raw"].as<int32_t>();
+        if (obj["aux_input1_typ"].is<int32_t>())
+                    msg.aux_input1_typ = obj["aux_input1_typ"].as<int32_t>();
+        if (obj["aux_input1_min"].is<int32_t>())
+                    msg.aux_input1_min = obj["aux_input1_min"].as<int32_t>();
+        if (obj["aux_input1_mid"].is<int32_t>())
+                    msg.aux_input1_mid = obj["aux_input1_mid"].as<int32_t>();
+        if (obj["aux_input1_max"].is<int32_t>())
+                    msg.aux_input1_max = obj["aux_input1_max"].as<int32_t>();
+        if (obj["aux_input1_cmd"].is<int32_t>())
+                    msg.aux_input1_cmd = obj["aux_input1_cmd"].as<int32_t>();
+        if (obj["aux_input2_raw"].is<int32_t>())
+                    msg.aux_input2_raw = obj["aux_input2_raw"].as<int32_t>();
+        if (obj["aux_input2_typ"].is<int32_t>())
+                    msg.aux_input2_typ = obj["aux_input2_typ"].as<int32_t>();
+        if (obj["aux_input2_min"].is<int32_t>())
+                    msg.aux_input2_min = obj["aux_input2_min"].as<int32_t>();
+        if (obj["aux_input2_mid"].is<int32_t>())
+                    msg.aux_input2_mid = obj["aux_input2_mid"].as<int32_t>();
+        if (obj["aux_input2_max"].is<int32_t>())
+                    msg.aux_input2_max = obj["aux_input2_max"].as<int32_t>();
+        if (obj["aux_input2_cmd"].is<int32_t>())
+                    msg.aux_input2_cmd = obj["aux_input2_cmd"].as<int32_t>();
+        if (obj["dc_curr"].is<float>())
+                    msg.dc_curr = obj["dc_curr"].as<float>();
+        if (obj["rdc_curr"].is<float>())
+                    msg.rdc_curr = obj["rdc_curr"].as<float>();
+        if (obj["ldc_curr"].is<float>())
+                    msg.ldc_curr = obj["ldc_curr"].as<float>();
+        if (obj["cmdl"].is<int32_t>())
+                    msg.cmdl = obj["cmdl"].as<int32_t>();
+        if (obj["cmdr"].is<int32_t>())
+                    msg.cmdr = obj["cmdr"].as<int32_t>();
+        if (obj["spd_avg"].is<int32_t>())
+                    msg.spd_avg = obj["spd_avg"].as<int32_t>();
+        if (obj["spdl"].is<int32_t>())
+                    msg.spdl = obj["spdl"].as<int32_t>();
+        if (obj["spdr"].is<int32_t>())
+                    msg.spdr = obj["spdr"].as<int32_t>();
+        if (obj["filter_rate"].is<int32_t>())
+                    msg.filter_rate = obj["filter_rate"].as<int32_t>();
+        if (obj["spd_coef"].is<int32_t>())
+                    msg.spd_coef = obj["spd_coef"].as<int32_t>();
+        if (obj["str_coef"].is<int32_t>())
+                    msg.str_coef = obj["str_coef"].as<int32_t>();
+        if (obj["batv"].is<float>())
+                    msg.batv = obj["batv"].as<float>();
+        if (obj["temp"].is<float>())
+                    msg.temp = obj["temp"].as<float>();
+        }
+
+
+Result<Bytes> HoverboardCmd::json_serialize(const HoverboardCmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<HoverboardCmd*> HoverboardCmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<HoverboardCmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        HoverboardCmd* msg = new HoverboardCmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<HoverboardCmd*>::Ok(msg);
+    }
+
+void HoverboardCmd::json_encode(JsonObject obj, const HoverboardCmd& msg) {
+        if (msg.speed)
+                    obj["speed"] = *msg.speed;
+        if (msg.steer)
+                    obj["steer"] = *msg.steer;
+        }
+
+void HoverboardCmd::json_decode(JsonObjectConst obj, HoverboardCmd& msg) {
+        if (obj["speed"].is<int32_t>())
+                    msg.speed = obj["speed"].as<int32_t>();
+        if (obj["steer"].is<int32_t>())
+                    msg.steer = obj["steer"].as<int32_t>();
+        }
+
+
+Result<Bytes> HoverboardReply::json_serialize(const HoverboardReply& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<HoverboardReply*> HoverboardReply::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<HoverboardReply*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        HoverboardReply* msg = new HoverboardReply();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<HoverboardReply*>::Ok(msg);
+    }
+
+void HoverboardReply::json_encode(JsonObject obj, const HoverboardReply& msg) {
+        if (msg.error_code)
+                    obj["error_code"] = *msg.error_code;
+        if (msg.message)
+                    obj["message"] = *msg.message;
+        }
+
+void HoverboardReply::json_decode(JsonObjectConst obj, HoverboardReply& msg) {
+        if (obj["error_code"].is<int32_t>())
+                    msg.error_code = obj["error_code"].as<int32_t>();
+        if (obj["message"].is<std::string>())
+                    msg.message = obj["message"].as<std::string>();
+        }
+
+
+Result<Bytes> TouchPoint::json_serialize(const TouchPoint& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<TouchPoint*> TouchPoint::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<TouchPoint*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        TouchPoint* msg = new TouchPoint();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<TouchPoint*>::Ok(msg);
+    }
+
+void TouchPoint::json_encode(JsonObject obj, const TouchPoint& msg) {
+        if (msg.active)
+                    obj["active"] = *msg.active;
+        if (msg.id)
+                    obj["id"] = *msg.id;
+        if (msg.x)
+                    obj["x"] = *msg.x;
+        if (msg.y)
+                    obj["y"] = *msg.y;
+        }
+
+void TouchPoint::json_decode(JsonObjectConst obj, TouchPoint& msg) {
+        if (obj["active"].is<bool>())
+                    msg.active = obj["active"].as<bool>();
+        if (obj["id"].is<int32_t>())
+                    msg.id = obj["id"].as<int32_t>();
+        if (obj["x"].is<int32_t>())
+                    msg.x = obj["x"].as<int32_t>();
+        if (obj["y"].is<int32_t>())
+                    msg.y = obj["y"].as<int32_t>();
+        }
+
+
+Result<Bytes> Ps4Event::json_serialize(const Ps4Event& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Ps4Event*> Ps4Event::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Ps4Event*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Ps4Event* msg = new Ps4Event();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Ps4Event*>::Ok(msg);
+    }
+
+void Ps4Event::json_encode(JsonObject obj, const Ps4Event& msg) {
+        if (msg.button_left)
+                    obj["button_left"] = *msg.button_left;
+        if (msg.button_right)
+                    obj["button_right"] = *msg.button_right;
+        if (msg.button_up)
+                    obj["button_up"] = *msg.button_up;
+        if (msg.button_down)
+                    obj["button_down"] = *msg.button_down;
+        if (msg.button_square)
+                    obj["button_square"] = *msg.button_square;
+        if (msg.button_cross)
+                    obj["button_cross"] = *msg.button_cross;
+        if (msg.button_circle)
+                    obj["button_circle"] = *msg.button_circle;
+        if (msg.button_triangle)
+                    obj["button_triangle"] = *msg.button_triangle;
+        if (msg.button_left_shoulder)
+                    obj["button_left_shoulder"] = *msg.button_left_shoulder;
+        if (msg.button_right_shoulder)
+                    obj["button_right_shoulder"] = *msg.button_right_shoulder;
+        if (msg.button_left_trigger)
+                    obj["button_left_trigger"] = *msg.button_left_trigger;
+        if (msg.button_right_trigger)
+                    obj["button_right_trigger"] = *msg.button_right_trigger;
+        if (msg.button_left_joystick)
+                    obj["button_left_joystick"] = *msg.button_left_joystick;
+        if (msg.button_right_joystick)
+                    obj["button_right_joystick"] = *msg.button_right_joystick;
+        if (msg.button_share)
+                    obj["button_share"] = *msg.button_share;
+        if (msg.button_options)
+                    obj["button_options"] = *msg.button_options;
+        if (msg.button_touchpad)
+                    obj["button_touchpad"] = *msg.button_touchpad;
+        if (msg.button_ps)
+                    obj["button_ps"] = *msg.button_ps;
+        if (msg.axis_lx)
+                    obj["axis_lx"] = *msg.axis_lx;
+        if (msg.axis_ly)
+                    obj["axis_ly"] = *msg.axis_ly;
+        if (msg.axis_rx)
+                    obj["axis_rx"] = *msg.axis_rx;
+        if (msg.axis_ry)
+                    obj["axis_ry"] = *msg.axis_ry;
+        if (msg.gyro_x)
+                    obj["gyro_x"] = *msg.gyro_x;
+        if (msg.gyro_y)
+                    obj["gyro_y"] = *msg.gyro_y;
+        if (msg.gyro_z)
+                    obj["gyro_z"] = *msg.gyro_z;
+        if (msg.accel_x)
+                    obj["accel_x"] = *msg.accel_x;
+        if (msg.accel_y)
+                    obj["accel_y"] = *msg.accel_y;
+        if (msg.accel_z)
+                    obj["accel_z"] = *msg.accel_z;
+        if (msg.connected)
+                    obj["connected"] = *msg.connected;
+        if (msg.battery_level)
+                    obj["battery_level"] = *msg.battery_level;
+        if (msg.bluetooth)
+                    obj["bluetooth"] = *msg.bluetooth;
+        if (msg.debug)
+                    obj["debug"] = *msg.debug;
+        if (msg.temp)
+                    obj["temp"] = *msg.temp;
+        }
+
+void Ps4Event::json_decode(JsonObjectConst obj, Ps4Event& msg) {
+        if (obj["button_left"].is<bool>())
+                    msg.button_left = obj["button_left"].as<bool>();
+        if (obj["button_right"].is<bool>())
+                    msg.button_right = obj["button_right"].as<bool>();
+        if (obj["button_up"].is<bool>())
+                    msg.button_up = obj["button_up"].as<bool>();
+        if (obj["button_down"].is<bool>())
+                    msg.button_down = obj["button_down"].as<bool>();
+        if (obj["button_square"].is<bool>())
+                    msg.button_square = obj["button_square"].as<bool>();
+        if (obj["button_cross"].is<bool>())
+                    msg.button_cross = obj["button_cross"].as<bool>();
+        if (obj["button_circle"].is<bool>())
+                    msg.button_circle = obj["button_circle"].as<bool>();
+        if (obj["button_triangle"].is<bool>())
+                    msg.button_triangle = obj["button_triangle"].as<bool>();
+        if (obj["button_left_shoulder"].is<bool>())
+                    msg.button_left_shoulder = obj["button_left_shoulder"].as<bool>();
+        if (obj["button_right_shoulder"].is<bool>())
+                    msg.button_right_shoulder = obj["button_right_shoulder"].as<bool>();
+        if (obj["button_left_trigger"].is<bool>())
+                    msg.button_left_trigger = obj["button_left_trigger"].as<bool>();
+        if (obj["button_right_trigger"].is<bool>())
+                    msg.button_right_trigger = obj["button_right_trigger"].as<bool>();
+        if (obj["button_left_joystick"].is<bool>())
+                    msg.button_left_joystick = obj["button_left_joystick"].as<bool>();
+        if (obj["button_right_joystick"].is<bool>())
+                    msg.button_right_joystick = obj["button_right_joystick"].as<bool>();
+        if (obj["button_share"].is<bool>())
+                    msg.button_share = obj["button_share"].as<bool>();
+        if (obj["button_options"].is<bool>())
+                    msg.button_options = obj["button_options"].as<bool>();
+        if (obj["button_touchpad"].is<bool>())
+                    msg.button_touchpad = obj["button_touchpad"].as<bool>();
+        if (obj["button_ps"].is<bool>())
+                    msg.button_ps = obj["button_ps"].as<bool>();
+        if (obj["axis_lx"].is<int32_t>())
+                    msg.axis_lx = obj["axis_lx"].as<int32_t>();
+        if (obj["axis_ly"].is<int32_t>())
+                    msg.axis_ly = obj["axis_ly"].as<int32_t>();
+        if (obj["axis_rx"].is<int32_t>())
+                    msg.axis_rx = obj["axis_rx"].as<int32_t>();
+        if (obj["axis_ry"].is<int32_t>())
+                    msg.axis_ry = obj["axis_ry"].as<int32_t>();
+        if (obj["gyro_x"].is<int32_t>())
+                    msg.gyro_x = obj["gyro_x"].as<int32_t>();
+        if (obj["gyro_y"].is<int32_t>())
+                    msg.gyro_y = obj["gyro_y"].as<int32_t>();
+        if (obj["gyro_z"].is<int32_t>())
+                    msg.gyro_z = obj["gyro_z"].as<int32_t>();
+        if (obj["accel_x"].is<int32_t>())
+                    msg.accel_x = obj["accel_x"].as<int32_t>();
+        if (obj["accel_y"].is<int32_t>())
+                    msg.accel_y = obj["accel_y"].as<int32_t>();
+        if (obj["accel_z"].is<int32_t>())
+                    msg.accel_z = obj["accel_z"].as<int32_t>();
+        if (obj["connected"].is<bool>())
+                    msg.connected = obj["connected"].as<bool>();
+        if (obj["battery_level"].is<int32_t>())
+                    msg.battery_level = obj["battery_level"].as<int32_t>();
+        if (obj["bluetooth"].is<bool>())
+                    msg.bluetooth = obj["bluetooth"].as<bool>();
+        if (obj["debug"].is<std::string>())
+                    msg.debug = obj["debug"].as<std::string>();
+        if (obj["temp"].is<int32_t>())
+                    msg.temp = obj["temp"].as<int32_t>();
+        }
+
+
+Result<Bytes> Ps4Cmd::json_serialize(const Ps4Cmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Ps4Cmd*> Ps4Cmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Ps4Cmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Ps4Cmd* msg = new Ps4Cmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Ps4Cmd*>::Ok(msg);
+    }
+
+void Ps4Cmd::json_encode(JsonObject obj, const Ps4Cmd& msg) {
+        if (msg.rumble_small)
+                    obj["rumble_small"] = *msg.rumble_small;
+        if (msg.rumble_large)
+                    obj["rumble_large"] = *msg.rumble_large;
+        if (msg.led_red)
+                    obj["led_red"] = *msg.led_red;
+        if (msg.led_green)
+                    obj["led_green"] = *msg.led_green;
+        if (msg.led_blue)
+                    obj["led_blue"] = *msg.led_blue;
+        if (msg.led_flash_on)
+                    obj["led_flash_on"] = *msg.led_flash_on;
+        if (msg.led_flash_off)
+                    obj["led_flash_off"] = *msg.led_flash_off;
+        }
+
+void Ps4Cmd::json_decode(JsonObjectConst obj, Ps4Cmd& msg) {
+        if (obj["rumble_small"].is<int32_t>())
+                    msg.rumble_small = obj["rumble_small"].as<int32_t>();
+        if (obj["rumble_large"].is<int32_t>())
+                    msg.rumble_large = obj["rumble_large"].as<int32_t>();
+        if (obj["led_red"].is<int32_t>())
+                    msg.led_red = obj["led_red"].as<int32_t>();
+        if (obj["led_green"].is<int32_t>())
+                    msg.led_green = obj["led_green"].as<int32_t>();
+        if (obj["led_blue"].is<int32_t>())
+                    msg.led_blue = obj["led_blue"].as<int32_t>();
+        if (obj["led_flash_on"].is<int32_t>())
+                    msg.led_flash_on = obj["led_flash_on"].as<int32_t>();
+        if (obj["led_flash_off"].is<int32_t>())
+                    msg.led_flash_off = obj["led_flash_off"].as<int32_t>();
+        }
+
+
+Result<Bytes> CameraEvent::json_serialize(const CameraEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<CameraEvent*> CameraEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<CameraEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        CameraEvent* msg = new CameraEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<CameraEvent*>::Ok(msg);
+    }
+
+void CameraEvent::json_encode(JsonObject obj, const CameraEvent& msg) {
+        if (msg.width)
+                    obj["width"] = *msg.width;
+        if (msg.height)
+                    obj["height"] = *msg.height;
+        if (msg.format)
+                    obj["format"] = *msg.format;
+        if (msg.data)
+                    obj["data"] = base64_encode(*msg.data);
+        if (msg.led)
+                    obj["led"] = *msg.led;
+        if (msg.quality)
+                    obj["quality"] = *msg.quality;
+        }
+
+void CameraEvent::json_decode(JsonObjectConst obj, CameraEvent& msg) {
+        if (obj["width"].is<int32_t>())
+                    msg.width = obj["width"].as<int32_t>();
+        if (obj["height"].is<int32_t>())
+                    msg.height = obj["height"].as<int32_t>();
+        if (obj["format"].is<std::string>())
+                    msg.format = obj["format"].as<std::string>();
+        if (obj["data"].is<std::string>())
+                    msg.data = base64_decode(obj["data"].as<std::string>());
+        if (obj["led"].is<bool>())
+                    msg.led = obj["led"].as<bool>();
+        if (obj["quality"].is<int32_t>())
+                    msg.quality = obj["quality"].as<int32_t>();
+        }
+
+
+Result<Bytes> CameraCmd::json_serialize(const CameraCmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<CameraCmd*> CameraCmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<CameraCmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        CameraCmd* msg = new CameraCmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<CameraCmd*>::Ok(msg);
+    }
+
+void CameraCmd::json_encode(JsonObject obj, const CameraCmd& msg) {
+        if (msg.led)
+                    obj["led"] = *msg.led;
+        if (msg.capture_tcp_destination)
+                    obj["capture_tcp_destination"] = *msg.capture_tcp_destination;
+        if (msg.quality)
+                    obj["quality"] = *msg.quality;
+        }
+
+void CameraCmd::json_decode(JsonObjectConst obj, CameraCmd& msg) {
+        if (obj["led"].is<bool>())
+                    msg.led = obj["led"].as<bool>();
+        if (obj["capture_tcp_destination"].is<std::string>())
+                    msg.capture_tcp_destination = obj["capture_tcp_destination"].as<std::string>();
+        if (obj["quality"].is<int32_t>())
+                    msg.quality = obj["quality"].as<int32_t>();
+        }
+
+
+Result<Bytes> CameraReply::json_serialize(const CameraReply& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<CameraReply*> CameraReply::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<CameraReply*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        CameraReply* msg = new CameraReply();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<CameraReply*>::Ok(msg);
+    }
+
+void CameraReply::json_encode(JsonObject obj, const CameraReply& msg) {
+        if (msg.error_code)
+                    obj["error_code"] = *msg.error_code;
+        if (msg.message)
+                    obj["message"] = *msg.message;
+        if (msg.data)
+                    obj["data"] = base64_encode(*msg.data);
+        }
+
+void CameraReply::json_decode(JsonObjectConst obj, CameraReply& msg) {
+        if (obj["error_code"].is<int32_t>())
+                    msg.error_code = obj["error_code"].as<int32_t>();
+        if (obj["message"].is<std::string>())
+                    msg.message = obj["message"].as<std::string>();
+        if (obj["data"].is<std::string>())
+                    msg.data = base64_decode(obj["data"].as<std::string>());
+        }
+
+
+Result<Bytes> LawnmowerManualEvent::json_serialize(const LawnmowerManualEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerManualEvent*> LawnmowerManualEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerManualEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerManualEvent* msg = new LawnmowerManualEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerManualEvent*>::Ok(msg);
+    }
+
+void LawnmowerManualEvent::json_encode(JsonObject obj, const LawnmowerManualEvent& msg) {
+        if (msg.speed)
+                    obj["speed"] = *msg.speed;
+        if (msg.steering)
+                    obj["steering"] = *msg.steering;
+        if (msg.blade)
+                    obj["blade"] = *msg.blade;
+        }
+
+void LawnmowerManualEvent::json_decode(JsonObjectConst obj, LawnmowerManualEvent& msg) {
+        if (obj["speed"].is<int32_t>())
+                    msg.speed = obj["speed"].as<int32_t>();
+        if (obj["steering"].is<int32_t>())
+                    msg.steering = obj["steering"].as<int32_t>();
+        if (obj["blade"].is<bool>())
+                    msg.blade = obj["blade"].as<bool>();
+        }
+
+
+Result<Bytes> LawnmowerManualCmd::json_serialize(const LawnmowerManualCmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerManualCmd*> LawnmowerManualCmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerManualCmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerManualCmd* msg = new LawnmowerManualCmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerManualCmd*>::Ok(msg);
+    }
+
+void LawnmowerManualCmd::json_encode(JsonObject obj, const LawnmowerManualCmd& msg) {
+        if (msg.speed)
+                    obj["speed"] = *msg.speed;
+        if (msg.steer)
+                    obj["steer"] = *msg.steer;
+        if (msg.blade)
+                    obj["blade"] = *msg.blade;
+        if (msg.start_manual_control)
+                    obj["start_manual_control"] = *msg.start_manual_control;
+        if (msg.stop_manual_control)
+                    obj["stop_manual_control"] = *msg.stop_manual_control;
+        if (msg.emergency_stop)
+                    obj["emergency_stop"] = *msg.emergency_stop;
+        if (msg.start_auto_mode)
+                    obj["start_auto_mode"] = *msg.start_auto_mode;
+        if (msg.stop_auto_mode)
+                    obj["stop_auto_mode"] = *msg.stop_auto_mode;
+        }
+
+void LawnmowerManualCmd::json_decode(JsonObjectConst obj, LawnmowerManualCmd& msg) {
+        if (obj["speed"].is<float>())
+                    msg.speed = obj["speed"].as<float>();
+        if (obj["steer"].is<float>())
+                    msg.steer = obj["steer"].as<float>();
+        if (obj["blade"].is<bool>())
+                    msg.blade = obj["blade"].as<bool>();
+        if (obj["start_manual_control"].is<bool>())
+                    msg.start_manual_control = obj["start_manual_control"].as<bool>();
+        if (obj["stop_manual_control"].is<bool>())
+                    msg.stop_manual_control = obj["stop_manual_control"].as<bool>();
+        if (obj["emergency_stop"].is<bool>())
+                    msg.emergency_stop = obj["emergency_stop"].as<bool>();
+        if (obj["start_auto_mode"].is<bool>())
+                    msg.start_auto_mode = obj["start_auto_mode"].as<bool>();
+        if (obj["stop_auto_mode"].is<bool>())
+                    msg.stop_auto_mode = obj["stop_auto_mode"].as<bool>();
+        }
+
+
+Result<Bytes> LawnmowerManualReply::json_serialize(const LawnmowerManualReply& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerManualReply*> LawnmowerManualReply::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerManualReply*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerManualReply* msg = new LawnmowerManualReply();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerManualReply*>::Ok(msg);
+    }
+
+void LawnmowerManualReply::json_encode(JsonObject obj, const LawnmowerManualReply& msg) {
+        if (msg.error_code)
+                    obj["error_code"] = *msg.error_code;
+        if (msg.message)
+                    obj["message"] = *msg.message;
+        }
+
+void LawnmowerManualReply::json_decode(JsonObjectConst obj, LawnmowerManualReply& msg) {
+        if (obj["error_code"].is<int32_t>())
+                    msg.error_code = obj["error_code"].as<int32_t>();
+        if (obj["message"].is<std::string>())
+                    msg.message = obj["message"].as<std::string>();
+        }
+
+
+Result<Bytes> LawnmowerAutoEvent::json_serialize(const LawnmowerAutoEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerAutoEvent*> LawnmowerAutoEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerAutoEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerAutoEvent* msg = new LawnmowerAutoEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerAutoEvent*>::Ok(msg);
+    }
+
+void LawnmowerAutoEvent::json_encode(JsonObject obj, const LawnmowerAutoEvent& msg) {
+        if (msg.started)
+                    obj["started"] = *msg.started;
+        if (msg.stopped)
+                    obj["stopped"] = *msg.stopped;
+        if (msg.paused)
+                    obj["paused"] = *msg.paused;
+        if (msg.resumed)
+                    obj["resumed"] = *msg.resumed;
+        if (msg.mode)
+                    obj["mode"] = *msg.mode;
+        if (msg.path)
+                    obj["path"] = *msg.path;
+        }
+
+void LawnmowerAutoEvent::json_decode(JsonObjectConst obj, LawnmowerAutoEvent& msg) {
+        if (obj["started"].is<bool>())
+                    msg.started = obj["started"].as<bool>();
+        if (obj["stopped"].is<bool>())
+                    msg.stopped = obj["stopped"].as<bool>();
+        if (obj["paused"].is<bool>())
+                    msg.paused = obj["paused"].as<bool>();
+        if (obj["resumed"].is<bool>())
+                    msg.resumed = obj["resumed"].as<bool>();
+        if (obj["mode"].is<std::string>())
+                    msg.mode = obj["mode"].as<std::string>();
+        if (obj["path"].is<std::string>())
+                    msg.path = obj["path"].as<std::string>();
+        }
+
+
+Result<Bytes> LawnmowerAutoCmd::json_serialize(const LawnmowerAutoCmd& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerAutoCmd*> LawnmowerAutoCmd::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerAutoCmd*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerAutoCmd* msg = new LawnmowerAutoCmd();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerAutoCmd*>::Ok(msg);
+    }
+
+void LawnmowerAutoCmd::json_encode(JsonObject obj, const LawnmowerAutoCmd& msg) {
+        if (msg.start)
+                    obj["start"] = *msg.start;
+        if (msg.stop)
+                    obj["stop"] = *msg.stop;
+        if (msg.pause)
+                    obj["pause"] = *msg.pause;
+        if (msg.resume)
+                    obj["resume"] = *msg.resume;
+        if (msg.mode)
+                    obj["mode"] = *msg.mode;
+        if (msg.path)
+                    obj["path"] = *msg.path;
+        }
+
+void LawnmowerAutoCmd::json_decode(JsonObjectConst obj, LawnmowerAutoCmd& msg) {
+        if (obj["start"].is<bool>())
+                    msg.start = obj["start"].as<bool>();
+        if (obj["stop"].is<bool>())
+                    msg.stop = obj["stop"].as<bool>();
+        if (obj["pause"].is<bool>())
+                    msg.pause = obj["pause"].as<bool>();
+        if (obj["resume"].is<bool>())
+                    msg.resume = obj["resume"].as<bool>();
+        if (obj["mode"].is<std::string>())
+                    msg.mode = obj["mode"].as<std::string>();
+        if (obj["path"].is<std::string>())
+                    msg.path = obj["path"].as<std::string>();
+        }
+
+
+Result<Bytes> LawnmowerStatus::json_serialize(const LawnmowerStatus& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<LawnmowerStatus*> LawnmowerStatus::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<LawnmowerStatus*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        LawnmowerStatus* msg = new LawnmowerStatus();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<LawnmowerStatus*>::Ok(msg);
+    }
+
+void LawnmowerStatus::json_encode(JsonObject obj, const LawnmowerStatus& msg) {
+        if (msg.battery_level)
+                    obj["battery_level"] = *msg.battery_level;
+        if (msg.blade_status)
+                    obj["blade_status"] = *msg.blade_status;
+        if (msg.current_mode)
+                    obj["current_mode"] = *msg.current_mode;
+        if (msg.error_message)
+                    obj["error_message"] = *msg.error_message;
+        }
+
+void LawnmowerStatus::json_decode(JsonObjectConst obj, LawnmowerStatus& msg) {
+        if (obj["battery_level"].is<int32_t>())
+                    msg.battery_level = obj["battery_level"].as<int32_t>();
+        if (obj["blade_status"].is<bool>())
+                    msg.blade_status = obj["blade_status"].as<bool>();
+        if (obj["current_mode"].is<std::string>())
+                    msg.current_mode = obj["current_mode"].as<std::string>();
+        if (obj["error_message"].is<std::string>())
+                    msg.error_message = obj["error_message"].as<std::string>();
+        }
+
+
+Result<Bytes> MotorEvent::json_serialize(const MotorEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<MotorEvent*> MotorEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<MotorEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        MotorEvent* msg = new MotorEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<MotorEvent*>::Ok(msg);
+    }
+
+void MotorEvent::json_encode(JsonObject obj, const MotorEvent& msg) {
+        if (msg.motor_id)
+                    obj["motor_id"] = *msg.motor_id;
+        if (msg.temperature)
+                    obj["temperature"] = *msg.temperature;
+        if (msg.voltage)
+                    obj["voltage"] = *msg.voltage;
+        if (msg.current)
+                    obj["current"] = *msg.current;
+        if (msg.speed)
+                    obj["speed"] = *msg.speed;
+        if (msg.position)
+                    obj["position"] = *msg.position;
+        }
+
+void MotorEvent::json_decode(JsonObjectConst obj, MotorEvent& msg) {
+        if (obj["motor_id"].is<int32_t>())
+                    msg.motor_id = obj["motor_id"].as<int32_t>();
+        if (obj["temperature"].is<float>())
+                    msg.temperature = obj["temperature"].as<float>();
+        if (obj["voltage"].is<float>())
+                    msg.voltage = obj["voltage"].as<float>();
+        if (obj["current"].is<float>())
+                    msg.current = obj["current"].as<float>();
+        if (obj["speed"].is<float>())
+                    msg.speed = obj["speed"].as<float>();
+        if (obj["position"].is<float>())
+                    msg.position = obj["position"].as<float>();
+        }
+
+
+Result<Bytes> BrokerRatesReq::json_serialize(const BrokerRatesReq& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<BrokerRatesReq*> BrokerRatesReq::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<BrokerRatesReq*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        BrokerRatesReq* msg = new BrokerRatesReq();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<BrokerRatesReq*>::Ok(msg);
+    }
+
+void BrokerRatesReq::json_encode(JsonObject obj, const BrokerRatesReq& msg) {
+        }
+
+void BrokerRatesReq::json_decode(JsonObjectConst obj, BrokerRatesReq& msg) {
+        }
+
+
+Result<Bytes> BrokerRatesRep::json_serialize(const BrokerRatesRep& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<BrokerRatesRep*> BrokerRatesRep::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<BrokerRatesRep*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        BrokerRatesRep* msg = new BrokerRatesRep();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<BrokerRatesRep*>::Ok(msg);
+    }
+
+void BrokerRatesRep::json_encode(JsonObject obj, const BrokerRatesRep& msg) {
+        if (msg.src.size()) {
+                    JsonArray arr = obj["src"].to<JsonArray>();
+                    for (const auto& item : msg.src) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.dst.size()) {
+                    JsonArray arr = obj["dst"].to<JsonArray>();
+                    for (const auto& item : msg.dst) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.msg_type.size()) {
+                    JsonArray arr = obj["msg_type"].to<JsonArray>();
+                    for (const auto& item : msg.msg_type) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.rate.size()) {
+                    JsonArray arr = obj["rate"].to<JsonArray>();
+                    for (const auto& item : msg.rate) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        }
+
+void BrokerRatesRep::json_decode(JsonObjectConst obj, BrokerRatesRep& msg) {
+        if (obj["src"].is<JsonArrayConst>()) {
+                    msg.src.clear();
+                    for (JsonVariantConst v : obj["src"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.src.push_back(item);
+                    }
+                }
+        if (obj["dst"].is<JsonArrayConst>()) {
+                    msg.dst.clear();
+                    for (JsonVariantConst v : obj["dst"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.dst.push_back(item);
+                    }
+                }
+        if (obj["msg_type"].is<JsonArrayConst>()) {
+                    msg.msg_type.clear();
+                    for (JsonVariantConst v : obj["msg_type"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.msg_type.push_back(item);
+                    }
+                }
+        if (obj["rate"].is<JsonArrayConst>()) {
+                    msg.rate.clear();
+                    for (JsonVariantConst v : obj["rate"].as<JsonArrayConst>()) {
+                        float item{};
+                        if (v.is<float>())
+                    item = v.as<float>();
+                        msg.rate.push_back(item);
+                    }
+                }
+        }
+
+
+Result<Bytes> BrokerMetricsEvent::json_serialize(const BrokerMetricsEvent& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<BrokerMetricsEvent*> BrokerMetricsEvent::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<BrokerMetricsEvent*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        BrokerMetricsEvent* msg = new BrokerMetricsEvent();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<BrokerMetricsEvent*>::Ok(msg);
+    }
+
+void BrokerMetricsEvent::json_encode(JsonObject obj, const BrokerMetricsEvent& msg) {
+        if (msg.endpoint.size()) {
+                    JsonArray arr = obj["endpoint"].to<JsonArray>();
+                    for (const auto& item : msg.endpoint) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.last_seen_ms.size()) {
+                    JsonArray arr = obj["last_seen_ms"].to<JsonArray>();
+                    for (const auto& item : msg.last_seen_ms) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.received.size()) {
+                    JsonArray arr = obj["received"].to<JsonArray>();
+                    for (const auto& item : msg.received) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.forwarded.size()) {
+                    JsonArray arr = obj["forwarded"].to<JsonArray>();
+                    for (const auto& item : msg.forwarded) {
+                        arr.add<JsonVariant>() = item;
+                    }
+                }
+        if (msg.dropped_unknown)
+                    obj["dropped_unknown"] = *msg.dropped_unknown;
+        if (msg.decode_errors)
+                    obj["decode_errors"] = *msg.decode_errors;
+        if (msg.queue_depth)
+                    obj["queue_depth"] = *msg.queue_depth;
+        }
+
+void BrokerMetricsEvent::json_decode(JsonObjectConst obj, BrokerMetricsEvent& msg) {
+        if (obj["endpoint"].is<JsonArrayConst>()) {
+                    msg.endpoint.clear();
+                    for (JsonVariantConst v : obj["endpoint"].as<JsonArrayConst>()) {
+                        std::string item{};
+                        if (v.is<std::string>())
+                    item = v.as<std::string>();
+                        msg.endpoint.push_back(item);
+                    }
+                }
+        if (obj["last_seen_ms"].is<JsonArrayConst>()) {
+                    msg.last_seen_ms.clear();
+                    for (JsonVariantConst v : obj["last_seen_ms"].as<JsonArrayConst>()) {
+                        uint32_t item{};
+                        if (v.is<uint32_t>())
+                    item = v.as<uint32_t>();
+                        msg.last_seen_ms.push_back(item);
+                    }
+                }
+        if (obj["received"].is<JsonArrayConst>()) {
+                    msg.received.clear();
+                    for (JsonVariantConst v : obj["received"].as<JsonArrayConst>()) {
+                        uint64_t item{};
+                        if (v.is<uint64_t>())
+                    item = v.as<uint64_t>();
+                        msg.received.push_back(item);
+                    }
+                }
+        if (obj["forwarded"].is<JsonArrayConst>()) {
+                    msg.forwarded.clear();
+                    for (JsonVariantConst v : obj["forwarded"].as<JsonArrayConst>()) {
+                        uint64_t item{};
+                        if (v.is<uint64_t>())
+                    item = v.as<uint64_t>();
+                        msg.forwarded.push_back(item);
+                    }
+                }
+        if (obj["dropped_unknown"].is<uint64_t>())
+                    msg.dropped_unknown = obj["dropped_unknown"].as<uint64_t>();
+        if (obj["decode_errors"].is<uint64_t>())
+                    msg.decode_errors = obj["decode_errors"].as<uint64_t>();
+        if (obj["queue_depth"].is<uint32_t>())
+                    msg.queue_depth = obj["queue_depth"].as<uint32_t>();
+        }
+
+
+
+// Helper macros for serialization and deserialization
+
+
+
+
+// CBOR Serialization/Deserialization
+#define RC_OK(rc) if ( (rc) != CborNoError ) { return Result<Bytes>::Err(-1,"CBOR serialization error"); }
+#define CBOR_OK(rc) { CborError cbor_err = (rc); if ( cbor_err != CborNoError ) { return cbor_err; } }
+
+
+Result<Bytes> Alive::cbor_serialize(const Alive& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Alive::cbor_encode(CborEncoder& encoder, const Alive& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Alive::Field::SUBSCRIBE_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.subscribe.size()));
+            for (const auto & item : msg.subscribe) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Alive::Field::PUBLISH_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.publish.size()));
+            for (const auto & item : msg.publish) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Alive::Field::SERVICES_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.services.size()));
+            for (const auto & item : msg.services) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Alive*> Alive::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Alive*>::Err(-1,"CBOR parse error");
+    }
+
+    Alive* msg = new Alive();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Alive*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Alive*>::Ok(msg);
+}
+
+CborError Alive::cbor_decode(CborValue& it, Alive& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Alive::Field::SUBSCRIBE_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.subscribe.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.subscribe.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case Alive::Field::PUBLISH_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.publish.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.publish.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case Alive::Field::SERVICES_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.services.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.services.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> UdpMessage::cbor_serialize(const UdpMessage& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError UdpMessage::cbor_encode(CborEncoder& encoder, const UdpMessage& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.dst) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::DST_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.dst.value().c_str()));
+            }
+    if (msg.src) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::SRC_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.src.value().c_str()));
+            }
+    if (msg.msg_type) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::MSG_TYPE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.msg_type.value().c_str()));
+            }
+    if (msg.payload) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::PAYLOAD_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.payload.value().data(), msg.payload.value().size()));
+            }
+    if (msg.correlation_id) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::CORRELATION_ID_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.correlation_id.value()));
+            }
+    if (msg.seq) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::SEQ_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.seq.value()));
+            }
+    if (msg.frag_id) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::FRAG_ID_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.frag_id.value()));
+            }
+    if (msg.frag_index) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::FRAG_INDEX_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.frag_index.value()));
+            }
+    if (msg.frag_count) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::FRAG_COUNT_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.frag_count.value()));
+            }
+    if (msg.codec) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::CODEC_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.codec.value()));
+            }
+    if (msg.nonce) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessage::Field::NONCE_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.nonce.value().data(), msg.nonce.value().size()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<UdpMessage*> UdpMessage::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<UdpMessage*>::Err(-1,"CBOR parse error");
+    }
+
+    UdpMessage* msg = new UdpMessage();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<UdpMessage*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<UdpMessage*>::Ok(msg);
+}
+
+CborError UdpMessage::cbor_decode(CborValue& it, UdpMessage& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case UdpMessage::Field::DST_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.dst = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::SRC_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.src = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::MSG_TYPE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.msg_type = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::PAYLOAD_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.payload = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::CORRELATION_ID_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.correlation_id = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::SEQ_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.seq = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::FRAG_ID_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.frag_id = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::FRAG_INDEX_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.frag_index = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::FRAG_COUNT_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.frag_count = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::CODEC_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.codec = static_cast<Codec>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessage::Field::NONCE_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.nonce = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> UdpMessageCbor::cbor_serialize(const UdpMessageCbor& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError UdpMessageCbor::cbor_encode(CborEncoder& encoder, const UdpMessageCbor& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.dst) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessageCbor::Field::DST_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.dst.value()));
+            }
+    if (msg.src) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessageCbor::Field::SRC_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.src.value()));
+            }
+    if (msg.msg_type) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessageCbor::Field::MSG_TYPE_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.msg_type.value()));
+            }
+    if (msg.payload) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, UdpMessageCbor::Field::PAYLOAD_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.payload.value().data(), msg.payload.value().size()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<UdpMessageCbor*> UdpMessageCbor::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<UdpMessageCbor*>::Err(-1,"CBOR parse error");
+    }
+
+    UdpMessageCbor* msg = new UdpMessageCbor();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<UdpMessageCbor*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<UdpMessageCbor*>::Ok(msg);
+}
+
+CborError UdpMessageCbor::cbor_decode(CborValue& it, UdpMessageCbor& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case UdpMessageCbor::Field::DST_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.dst = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessageCbor::Field::SRC_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.src = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessageCbor::Field::MSG_TYPE_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.msg_type = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case UdpMessageCbor::Field::PAYLOAD_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.payload = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> ZenohEvent::cbor_serialize(const ZenohEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError ZenohEvent::cbor_encode(CborEncoder& encoder, const ZenohEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.zid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::ZID_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.zid.value().c_str()));
+            }
+    if (msg.what_am_i) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::WHAT_AM_I_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.what_am_i.value().c_str()));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::PEERS_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.peers.size()));
+            for (const auto & item : msg.peers) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    if (msg.prefix) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::PREFIX_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.prefix.value().c_str()));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::ROUTERS_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.routers.size()));
+            for (const auto & item : msg.routers) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    if (msg.connect) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::CONNECT_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.connect.value().c_str()));
+            }
+    if (msg.listen) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, ZenohEvent::Field::LISTEN_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.listen.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<ZenohEvent*> ZenohEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<ZenohEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    ZenohEvent* msg = new ZenohEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<ZenohEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<ZenohEvent*>::Ok(msg);
+}
+
+CborError ZenohEvent::cbor_decode(CborValue& it, ZenohEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case ZenohEvent::Field::ZID_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.zid = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case ZenohEvent::Field::WHAT_AM_I_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.what_am_i = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case ZenohEvent::Field::PEERS_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.peers.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.peers.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case ZenohEvent::Field::PREFIX_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.prefix = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case ZenohEvent::Field::ROUTERS_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.routers.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.routers.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case ZenohEvent::Field::CONNECT_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.connect = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case ZenohEvent::Field::LISTEN_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.listen = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LogEvent::cbor_serialize(const LogEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LogEvent::cbor_encode(CborEncoder& encoder, const LogEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.level) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::LEVEL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.level.value()));
+            }
+    if (msg.message) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::MESSAGE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.message.value().c_str()));
+            }
+    if (msg.error_code) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::ERROR_CODE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.error_code.value()));
+            }
+    if (msg.file) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::FILE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.file.value().c_str()));
+            }
+    if (msg.line) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::LINE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.line.value()));
+            }
+    if (msg.timestamp) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LogEvent::Field::TIMESTAMP_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.timestamp.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LogEvent*> LogEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LogEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    LogEvent* msg = new LogEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LogEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LogEvent*>::Ok(msg);
+}
+
+CborError LogEvent::cbor_decode(CborValue& it, LogEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LogEvent::Field::LEVEL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.level = static_cast<LogLevel>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LogEvent::Field::MESSAGE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.message = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LogEvent::Field::ERROR_CODE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.error_code = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LogEvent::Field::FILE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.file = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LogEvent::Field::LINE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.line = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LogEvent::Field::TIMESTAMP_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.timestamp = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> SysCmd::cbor_serialize(const SysCmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError SysCmd::cbor_encode(CborEncoder& encoder, const SysCmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    // field: src
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysCmd::Field::SRC_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.src.c_str()));
+    if (msg.set_time) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysCmd::Field::SET_TIME_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.set_time.value()));
+            }
+    if (msg.reboot) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysCmd::Field::REBOOT_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.reboot.value()));
+            }
+    if (msg.console) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysCmd::Field::CONSOLE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.console.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<SysCmd*> SysCmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<SysCmd*>::Err(-1,"CBOR parse error");
+    }
+
+    SysCmd* msg = new SysCmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<SysCmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<SysCmd*>::Ok(msg);
+}
+
+CborError SysCmd::cbor_decode(CborValue& it, SysCmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case SysCmd::Field::SRC_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.src = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysCmd::Field::SET_TIME_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.set_time = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysCmd::Field::REBOOT_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.reboot = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysCmd::Field::CONSOLE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.console = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> SysEvent::cbor_serialize(const SysEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError SysEvent::cbor_encode(CborEncoder& encoder, const SysEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.utc) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::UTC_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.utc.value()));
+            }
+    if (msg.uptime) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::UPTIME_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.uptime.value()));
+            }
+    if (msg.free_heap) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::FREE_HEAP_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.free_heap.value()));
+            }
+    if (msg.flash) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::FLASH_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.flash.value()));
+            }
+    if (msg.cpu_board) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::CPU_BOARD_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.cpu_board.value().c_str()));
+            }
+    if (msg.build_date) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SysEvent::Field::BUILD_DATE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.build_date.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<SysEvent*> SysEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<SysEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    SysEvent* msg = new SysEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<SysEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<SysEvent*>::Ok(msg);
+}
+
+CborError SysEvent::cbor_decode(CborValue& it, SysEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case SysEvent::Field::UTC_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.utc = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysEvent::Field::UPTIME_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.uptime = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysEvent::Field::FREE_HEAP_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.free_heap = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysEvent::Field::FLASH_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.flash = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysEvent::Field::CPU_BOARD_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.cpu_board = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SysEvent::Field::BUILD_DATE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.build_date = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> WifiEvent::cbor_serialize(const WifiEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError WifiEvent::cbor_encode(CborEncoder& encoder, const WifiEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.ssid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::SSID_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.ssid.value().c_str()));
+            }
+    if (msg.bssid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::BSSID_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.bssid.value().c_str()));
+            }
+    if (msg.rssi) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::RSSI_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rssi.value()));
+            }
+    if (msg.ip) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::IP_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.ip.value().c_str()));
+            }
+    if (msg.mac) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::MAC_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.mac.value().c_str()));
+            }
+    if (msg.channel) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::CHANNEL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.channel.value()));
+            }
+    if (msg.gateway) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::GATEWAY_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.gateway.value().c_str()));
+            }
+    if (msg.netmask) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, WifiEvent::Field::NETMASK_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.netmask.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<WifiEvent*> WifiEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<WifiEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    WifiEvent* msg = new WifiEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<WifiEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<WifiEvent*>::Ok(msg);
+}
+
+CborError WifiEvent::cbor_decode(CborValue& it, WifiEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case WifiEvent::Field::SSID_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.ssid = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::BSSID_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.bssid = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::RSSI_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rssi = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::IP_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.ip = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::MAC_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.mac = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::CHANNEL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.channel = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::GATEWAY_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.gateway = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case WifiEvent::Field::NETMASK_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.netmask = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> MulticastEvent::cbor_serialize(const MulticastEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError MulticastEvent::cbor_encode(CborEncoder& encoder, const MulticastEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.group) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MulticastEvent::Field::GROUP_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.group.value().c_str()));
+            }
+    if (msg.port) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MulticastEvent::Field::PORT_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.port.value()));
+            }
+    if (msg.mtu) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MulticastEvent::Field::MTU_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.mtu.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<MulticastEvent*> MulticastEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<MulticastEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    MulticastEvent* msg = new MulticastEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<MulticastEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<MulticastEvent*>::Ok(msg);
+}
+
+CborError MulticastEvent::cbor_decode(CborValue& it, MulticastEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case MulticastEvent::Field::GROUP_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.group = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MulticastEvent::Field::PORT_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.port = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MulticastEvent::Field::MTU_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.mtu = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> PingReq::cbor_serialize(const PingReq& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError PingReq::cbor_encode(CborEncoder& encoder, const PingReq& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.number) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, PingReq::Field::NUMBER_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.number.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<PingReq*> PingReq::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<PingReq*>::Err(-1,"CBOR parse error");
+    }
+
+    PingReq* msg = new PingReq();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<PingReq*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<PingReq*>::Ok(msg);
+}
+
+CborError PingReq::cbor_decode(CborValue& it, PingReq& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case PingReq::Field::NUMBER_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.number = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> PingRep::cbor_serialize(const PingRep& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError PingRep::cbor_encode(CborEncoder& encoder, const PingRep& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.number) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, PingRep::Field::NUMBER_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.number.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<PingRep*> PingRep::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<PingRep*>::Err(-1,"CBOR parse error");
+    }
+
+    PingRep* msg = new PingRep();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<PingRep*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<PingRep*>::Ok(msg);
+}
+
+CborError PingRep::cbor_decode(CborValue& it, PingRep& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case PingRep::Field::NUMBER_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.number = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> HoverboardEventRaw::cbor_serialize(const HoverboardEventRaw& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError HoverboardEventRaw::cbor_encode(CborEncoder& encoder, const HoverboardEventRaw& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.ctrl_mod) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::CTRL_MOD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.ctrl_mod.value()));
+            }
+    if (msg.ctrl_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::CTRL_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.ctrl_typ.value()));
+            }
+    if (msg.cur_mot_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::CUR_MOT_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cur_mot_max.value()));
+            }
+    if (msg.rpm_mot_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::RPM_MOT_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rpm_mot_max.value()));
+            }
+    if (msg.fi_weak_ena) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::FI_WEAK_ENA_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_ena.value()));
+            }
+    if (msg.fi_weak_hi) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::FI_WEAK_HI_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_hi.value()));
+            }
+    if (msg.fi_weak_lo) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::FI_WEAK_LO_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_lo.value()));
+            }
+    if (msg.fi_weak_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::FI_WEAK_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_max.value()));
+            }
+    if (msg.phase_adv_max_deg) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::PHASE_ADV_MAX_DEG_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.phase_adv_max_deg.value()));
+            }
+    if (msg.input1_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_raw.value()));
+            }
+    if (msg.input1_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_typ.value()));
+            }
+    if (msg.input1_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_min.value()));
+            }
+    if (msg.input1_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_mid.value()));
+            }
+    if (msg.input1_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_max.value()));
+            }
+    if (msg.input1_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT1_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_cmd.value()));
+            }
+    if (msg.input2_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_raw.value()));
+            }
+    if (msg.input2_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_typ.value()));
+            }
+    if (msg.input2_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_min.value()));
+            }
+    if (msg.input2_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_mid.value()));
+            }
+    if (msg.input2_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_max.value()));
+            }
+    if (msg.input2_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::INPUT2_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_cmd.value()));
+            }
+    if (msg.aux_input1_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_raw.value()));
+            }
+    if (msg.aux_input1_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_typ.value()));
+            }
+    if (msg.aux_input1_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_min.value()));
+            }
+    if (msg.aux_input1_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_mid.value()));
+            }
+    if (msg.aux_input1_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_max.value()));
+            }
+    if (msg.aux_input1_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT1_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_cmd.value()));
+            }
+    if (msg.aux_input2_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_raw.value()));
+            }
+    if (msg.aux_input2_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_typ.value()));
+            }
+    if (msg.aux_input2_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_min.value()));
+            }
+    if (msg.aux_input2_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_mid.value()));
+            }
+    if (msg.aux_input2_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_max.value()));
+            }
+    if (msg.aux_input2_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::AUX_INPUT2_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_cmd.value()));
+            }
+    if (msg.dc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::DC_CURR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.dc_curr.value()));
+            }
+    if (msg.rdc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::RDC_CURR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rdc_curr.value()));
+            }
+    if (msg.ldc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::LDC_CURR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.ldc_curr.value()));
+            }
+    if (msg.cmdl) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::CMDL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cmdl.value()));
+            }
+    if (msg.cmdr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::CMDR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cmdr.value()));
+            }
+    if (msg.spd_avg) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::SPD_AVG_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spd_avg.value()));
+            }
+    if (msg.spdl) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::SPDL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spdl.value()));
+            }
+    if (msg.spdr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::SPDR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spdr.value()));
+            }
+    if (msg.filter_rate) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::FILTER_RATE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.filter_rate.value()));
+            }
+    if (msg.spd_coef) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::SPD_COEF_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spd_coef.value()));
+            }
+    if (msg.str_coef) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::STR_COEF_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.str_coef.value()));
+            }
+    if (msg.batv) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::BATV_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.batv.value()));
+            }
+    if (msg.temp) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEventRaw::Field::TEMP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.temp.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<HoverboardEventRaw*> HoverboardEventRaw::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<HoverboardEventRaw*>::Err(-1,"CBOR parse error");
+    }
+
+    HoverboardEventRaw* msg = new HoverboardEventRaw();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<HoverboardEventRaw*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<HoverboardEventRaw*>::Ok(msg);
+}
+
+CborError HoverboardEventRaw::cbor_decode(CborValue& it, HoverboardEventRaw& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case HoverboardEventRaw::Field::CTRL_MOD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.ctrl_mod = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::CTRL_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.ctrl_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::CUR_MOT_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cur_mot_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::RPM_MOT_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rpm_mot_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::FI_WEAK_ENA_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_ena = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::FI_WEAK_HI_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_hi = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::FI_WEAK_LO_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_lo = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::FI_WEAK_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::PHASE_ADV_MAX_DEG_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.phase_adv_max_deg = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT1_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::INPUT2_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT1_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::AUX_INPUT2_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::DC_CURR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.dc_curr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::RDC_CURR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rdc_curr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::LDC_CURR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.ldc_curr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::CMDL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cmdl = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::CMDR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cmdr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::SPD_AVG_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spd_avg = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::SPDL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spdl = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::SPDR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spdr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::FILTER_RATE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.filter_rate = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::SPD_COEF_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spd_coef = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::STR_COEF_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.str_coef = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::BATV_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.batv = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEventRaw::Field::TEMP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.temp = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> HoverboardEvent::cbor_serialize(const HoverboardEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError HoverboardEvent::cbor_encode(CborEncoder& encoder, const HoverboardEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.ctrl_mod) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::CTRL_MOD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.ctrl_mod.value()));
+            }
+    if (msg.ctrl_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::CTRL_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.ctrl_typ.value()));
+            }
+    if (msg.cur_mot_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::CUR_MOT_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cur_mot_max.value()));
+            }
+    if (msg.rpm_mot_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::RPM_MOT_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rpm_mot_max.value()));
+            }
+    if (msg.fi_weak_ena) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::FI_WEAK_ENA_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_ena.value()));
+            }
+    if (msg.fi_weak_hi) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::FI_WEAK_HI_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_hi.value()));
+            }
+    if (msg.fi_weak_lo) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::FI_WEAK_LO_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_lo.value()));
+            }
+    if (msg.fi_weak_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::FI_WEAK_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.fi_weak_max.value()));
+            }
+    if (msg.phase_adv_max_deg) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::PHASE_ADV_MAX_DEG_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.phase_adv_max_deg.value()));
+            }
+    if (msg.input1_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_raw.value()));
+            }
+    if (msg.input1_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_typ.value()));
+            }
+    if (msg.input1_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_min.value()));
+            }
+    if (msg.input1_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_mid.value()));
+            }
+    if (msg.input1_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_max.value()));
+            }
+    if (msg.input1_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT1_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input1_cmd.value()));
+            }
+    if (msg.input2_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_raw.value()));
+            }
+    if (msg.input2_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_typ.value()));
+            }
+    if (msg.input2_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_min.value()));
+            }
+    if (msg.input2_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_mid.value()));
+            }
+    if (msg.input2_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_max.value()));
+            }
+    if (msg.input2_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::INPUT2_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.input2_cmd.value()));
+            }
+    if (msg.aux_input1_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_raw.value()));
+            }
+    if (msg.aux_input1_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_typ.value()));
+            }
+    if (msg.aux_input1_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_min.value()));
+            }
+    if (msg.aux_input1_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_mid.value()));
+            }
+    if (msg.aux_input1_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_max.value()));
+            }
+    if (msg.aux_input1_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT1_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input1_cmd.value()));
+            }
+    if (msg.aux_input2_raw) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_RAW_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_raw.value()));
+            }
+    if (msg.aux_input2_typ) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_TYP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_typ.value()));
+            }
+    if (msg.aux_input2_min) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_MIN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_min.value()));
+            }
+    if (msg.aux_input2_mid) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_MID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_mid.value()));
+            }
+    if (msg.aux_input2_max) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_MAX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_max.value()));
+            }
+    if (msg.aux_input2_cmd) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::AUX_INPUT2_CMD_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.aux_input2_cmd.value()));
+            }
+    if (msg.dc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::DC_CURR_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.dc_curr.value()));
+            }
+    if (msg.rdc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::RDC_CURR_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.rdc_curr.value()));
+            }
+    if (msg.ldc_curr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::LDC_CURR_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.ldc_curr.value()));
+            }
+    if (msg.cmdl) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::CMDL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cmdl.value()));
+            }
+    if (msg.cmdr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::CMDR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.cmdr.value()));
+            }
+    if (msg.spd_avg) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::SPD_AVG_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spd_avg.value()));
+            }
+    if (msg.spdl) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::SPDL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spdl.value()));
+            }
+    if (msg.spdr) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::SPDR_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spdr.value()));
+            }
+    if (msg.filter_rate) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::FILTER_RATE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.filter_rate.value()));
+            }
+    if (msg.spd_coef) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::SPD_COEF_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.spd_coef.value()));
+            }
+    if (msg.str_coef) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::STR_COEF_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.str_coef.value()));
+            }
+    if (msg.batv) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::BATV_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.batv.value()));
+            }
+    if (msg.temp) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardEvent::Field::TEMP_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.temp.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<HoverboardEvent*> HoverboardEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<HoverboardEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    HoverboardEvent* msg = new HoverboardEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<HoverboardEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<HoverboardEvent*>::Ok(msg);
+}
+
+CborError HoverboardEvent::cbor_decode(CborValue& it, HoverboardEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case HoverboardEvent::Field::CTRL_MOD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.ctrl_mod = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::CTRL_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.ctrl_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::CUR_MOT_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cur_mot_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::RPM_MOT_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rpm_mot_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::FI_WEAK_ENA_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_ena = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::FI_WEAK_HI_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_hi = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::FI_WEAK_LO_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_lo = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::FI_WEAK_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.fi_weak_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::PHASE_ADV_MAX_DEG_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.phase_adv_max_deg = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT1_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input1_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::INPUT2_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.input2_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT1_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input1_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_RAW_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_raw = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_TYP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_typ = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_MIN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_min = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_MID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_mid = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_MAX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_max = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::AUX_INPUT2_CMD_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.aux_input2_cmd = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::DC_CURR_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.dc_curr = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::RDC_CURR_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.rdc_curr = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::LDC_CURR_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.ldc_curr = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::CMDL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cmdl = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::CMDR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.cmdr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::SPD_AVG_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spd_avg = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::SPDL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spdl = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::SPDR_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spdr = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::FILTER_RATE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.filter_rate = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::SPD_COEF_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.spd_coef = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::STR_COEF_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.str_coef = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::BATV_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.batv = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardEvent::Field::TEMP_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.temp = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> HoverboardCmd::cbor_serialize(const HoverboardCmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError HoverboardCmd::cbor_encode(CborEncoder& encoder, const HoverboardCmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.speed) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardCmd::Field::SPEED_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.speed.value()));
+            }
+    if (msg.steer) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardCmd::Field::STEER_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.steer.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<HoverboardCmd*> HoverboardCmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<HoverboardCmd*>::Err(-1,"CBOR parse error");
+    }
+
+    HoverboardCmd* msg = new HoverboardCmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<HoverboardCmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<HoverboardCmd*>::Ok(msg);
+}
+
+CborError HoverboardCmd::cbor_decode(CborValue& it, HoverboardCmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case HoverboardCmd::Field::SPEED_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.speed = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardCmd::Field::STEER_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.steer = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> HoverboardReply::cbor_serialize(const HoverboardReply& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError HoverboardReply::cbor_encode(CborEncoder& encoder, const HoverboardReply& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.error_code) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardReply::Field::ERROR_CODE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.error_code.value()));
+            }
+    if (msg.message) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, HoverboardReply::Field::MESSAGE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.message.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<HoverboardReply*> HoverboardReply::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<HoverboardReply*>::Err(-1,"CBOR parse error");
+    }
+
+    HoverboardReply* msg = new HoverboardReply();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<HoverboardReply*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<HoverboardReply*>::Ok(msg);
+}
+
+CborError HoverboardReply::cbor_decode(CborValue& it, HoverboardReply& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case HoverboardReply::Field::ERROR_CODE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.error_code = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case HoverboardReply::Field::MESSAGE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.message = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> TouchPoint::cbor_serialize(const TouchPoint& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError TouchPoint::cbor_encode(CborEncoder& encoder, const TouchPoint& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.active) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, TouchPoint::Field::ACTIVE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.active.value()));
+            }
+    if (msg.id) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, TouchPoint::Field::ID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.id.value()));
+            }
+    if (msg.x) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, TouchPoint::Field::X_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.x.value()));
+            }
+    if (msg.y) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, TouchPoint::Field::Y_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.y.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<TouchPoint*> TouchPoint::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<TouchPoint*>::Err(-1,"CBOR parse error");
+    }
+
+    TouchPoint* msg = new TouchPoint();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<TouchPoint*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<TouchPoint*>::Ok(msg);
+}
+
+CborError TouchPoint::cbor_decode(CborValue& it, TouchPoint& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case TouchPoint::Field::ACTIVE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.active = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case TouchPoint::Field::ID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.id = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case TouchPoint::Field::X_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.x = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case TouchPoint::Field::Y_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.y = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> Ps4Event::cbor_serialize(const Ps4Event& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Ps4Event::cbor_encode(CborEncoder& encoder, const Ps4Event& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.button_left) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_LEFT_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_left.value()));
+            }
+    if (msg.button_right) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_RIGHT_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_right.value()));
+            }
+    if (msg.button_up) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_UP_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_up.value()));
+            }
+    if (msg.button_down) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_DOWN_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_down.value()));
+            }
+    if (msg.button_square) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_SQUARE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_square.value()));
+            }
+    if (msg.button_cross) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_CROSS_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_cross.value()));
+            }
+    if (msg.button_circle) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_CIRCLE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_circle.value()));
+            }
+    if (msg.button_triangle) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_TRIANGLE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_triangle.value()));
+            }
+    if (msg.button_left_shoulder) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_LEFT_SHOULDER_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_left_shoulder.value()));
+            }
+    if (msg.button_right_shoulder) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_RIGHT_SHOULDER_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_right_shoulder.value()));
+            }
+    if (msg.button_left_trigger) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_LEFT_TRIGGER_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_left_trigger.value()));
+            }
+    if (msg.button_right_trigger) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_RIGHT_TRIGGER_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_right_trigger.value()));
+            }
+    if (msg.button_left_joystick) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_LEFT_JOYSTICK_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_left_joystick.value()));
+            }
+    if (msg.button_right_joystick) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_RIGHT_JOYSTICK_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_right_joystick.value()));
+            }
+    if (msg.button_share) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_SHARE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_share.value()));
+            }
+    if (msg.button_options) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_OPTIONS_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_options.value()));
+            }
+    if (msg.button_touchpad) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_TOUCHPAD_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_touchpad.value()));
+            }
+    if (msg.button_ps) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BUTTON_PS_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.button_ps.value()));
+            }
+    if (msg.axis_lx) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::AXIS_LX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.axis_lx.value()));
+            }
+    if (msg.axis_ly) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::AXIS_LY_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.axis_ly.value()));
+            }
+    if (msg.axis_rx) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::AXIS_RX_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.axis_rx.value()));
+            }
+    if (msg.axis_ry) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::AXIS_RY_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.axis_ry.value()));
+            }
+    if (msg.gyro_x) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::GYRO_X_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.gyro_x.value()));
+            }
+    if (msg.gyro_y) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::GYRO_Y_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.gyro_y.value()));
+            }
+    if (msg.gyro_z) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::GYRO_Z_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.gyro_z.value()));
+            }
+    if (msg.accel_x) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::ACCEL_X_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.accel_x.value()));
+            }
+    if (msg.accel_y) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::ACCEL_Y_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.accel_y.value()));
+            }
+    if (msg.accel_z) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::ACCEL_Z_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.accel_z.value()));
+            }
+    if (msg.connected) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::CONNECTED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.connected.value()));
+            }
+    if (msg.battery_level) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BATTERY_LEVEL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.battery_level.value()));
+            }
+    if (msg.bluetooth) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::BLUETOOTH_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.bluetooth.value()));
+            }
+    if (msg.debug) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::DEBUG_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.debug.value().c_str()));
+            }
+    if (msg.temp) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Event::Field::TEMP_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.temp.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Ps4Event*> Ps4Event::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Ps4Event*>::Err(-1,"CBOR parse error");
+    }
+
+    Ps4Event* msg = new Ps4Event();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Ps4Event*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Ps4Event*>::Ok(msg);
+}
+
+CborError Ps4Event::cbor_decode(CborValue& it, Ps4Event& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Ps4Event::Field::BUTTON_LEFT_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_left = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_RIGHT_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_right = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_UP_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_up = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_DOWN_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_down = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_SQUARE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_square = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_CROSS_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_cross = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_CIRCLE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_circle = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_TRIANGLE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_triangle = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_LEFT_SHOULDER_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_left_shoulder = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_RIGHT_SHOULDER_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_right_shoulder = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_LEFT_TRIGGER_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_left_trigger = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_RIGHT_TRIGGER_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_right_trigger = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_LEFT_JOYSTICK_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_left_joystick = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_RIGHT_JOYSTICK_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_right_joystick = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_SHARE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_share = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_OPTIONS_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_options = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_TOUCHPAD_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_touchpad = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BUTTON_PS_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.button_ps = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::AXIS_LX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.axis_lx = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::AXIS_LY_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.axis_ly = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::AXIS_RX_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.axis_rx = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::AXIS_RY_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.axis_ry = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::GYRO_X_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.gyro_x = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::GYRO_Y_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.gyro_y = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::GYRO_Z_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.gyro_z = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::ACCEL_X_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.accel_x = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::ACCEL_Y_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.accel_y = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::ACCEL_Z_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.accel_z = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::CONNECTED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.connected = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BATTERY_LEVEL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.battery_level = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::BLUETOOTH_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.bluetooth = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::DEBUG_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.debug = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Event::Field::TEMP_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.temp = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> Ps4Cmd::cbor_serialize(const Ps4Cmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Ps4Cmd::cbor_encode(CborEncoder& encoder, const Ps4Cmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.rumble_small) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::RUMBLE_SMALL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rumble_small.value()));
+            }
+    if (msg.rumble_large) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::RUMBLE_LARGE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.rumble_large.value()));
+            }
+    if (msg.led_red) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::LED_RED_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.led_red.value()));
+            }
+    if (msg.led_green) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::LED_GREEN_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.led_green.value()));
+            }
+    if (msg.led_blue) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::LED_BLUE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.led_blue.value()));
+            }
+    if (msg.led_flash_on) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::LED_FLASH_ON_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.led_flash_on.value()));
+            }
+    if (msg.led_flash_off) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Ps4Cmd::Field::LED_FLASH_OFF_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.led_flash_off.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Ps4Cmd*> Ps4Cmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Ps4Cmd*>::Err(-1,"CBOR parse error");
+    }
+
+    Ps4Cmd* msg = new Ps4Cmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Ps4Cmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Ps4Cmd*>::Ok(msg);
+}
+
+CborError Ps4Cmd::cbor_decode(CborValue& it, Ps4Cmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Ps4Cmd::Field::RUMBLE_SMALL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rumble_small = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::RUMBLE_LARGE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.rumble_large = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::LED_RED_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.led_red = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::LED_GREEN_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.led_green = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::LED_BLUE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.led_blue = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::LED_FLASH_ON_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.led_flash_on = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Ps4Cmd::Field::LED_FLASH_OFF_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.led_flash_off = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> CameraEvent::cbor_serialize(const CameraEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError CameraEvent::cbor_encode(CborEncoder& encoder, const CameraEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.width) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::WIDTH_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.width.value()));
+            }
+    if (msg.height) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::HEIGHT_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.height.value()));
+            }
+    if (msg.format) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::FORMAT_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.format.value().c_str()));
+            }
+    if (msg.data) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::DATA_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.data.value().data(), msg.data.value().size()));
+            }
+    if (msg.led) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::LED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.led.value()));
+            }
+    if (msg.quality) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraEvent::Field::QUALITY_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.quality.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<CameraEvent*> CameraEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<CameraEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    CameraEvent* msg = new CameraEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<CameraEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<CameraEvent*>::Ok(msg);
+}
+
+CborError CameraEvent::cbor_decode(CborValue& it, CameraEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case CameraEvent::Field::WIDTH_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.width = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraEvent::Field::HEIGHT_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.height = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraEvent::Field::FORMAT_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.format = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraEvent::Field::DATA_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.data = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraEvent::Field::LED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.led = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraEvent::Field::QUALITY_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.quality = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> CameraCmd::cbor_serialize(const CameraCmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError CameraCmd::cbor_encode(CborEncoder& encoder, const CameraCmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.led) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraCmd::Field::LED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.led.value()));
+            }
+    if (msg.capture_tcp_destination) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraCmd::Field::CAPTURE_TCP_DESTINATION_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.capture_tcp_destination.value().c_str()));
+            }
+    if (msg.quality) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraCmd::Field::QUALITY_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.quality.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<CameraCmd*> CameraCmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<CameraCmd*>::Err(-1,"CBOR parse error");
+    }
+
+    CameraCmd* msg = new CameraCmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<CameraCmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<CameraCmd*>::Ok(msg);
+}
+
+CborError CameraCmd::cbor_decode(CborValue& it, CameraCmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case CameraCmd::Field::LED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.led = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraCmd::Field::CAPTURE_TCP_DESTINATION_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.capture_tcp_destination = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraCmd::Field::QUALITY_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.quality = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> CameraReply::cbor_serialize(const CameraReply& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError CameraReply::cbor_encode(CborEncoder& encoder, const CameraReply& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.error_code) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraReply::Field::ERROR_CODE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.error_code.value()));
+            }
+    if (msg.message) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraReply::Field::MESSAGE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.message.value().c_str()));
+            }
+    if (msg.data) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, CameraReply::Field::DATA_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.data.value().data(), msg.data.value().size()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<CameraReply*> CameraReply::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<CameraReply*>::Err(-1,"CBOR parse error");
+    }
+
+    CameraReply* msg = new CameraReply();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<CameraReply*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<CameraReply*>::Ok(msg);
+}
+
+CborError CameraReply::cbor_decode(CborValue& it, CameraReply& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case CameraReply::Field::ERROR_CODE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.error_code = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraReply::Field::MESSAGE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.message = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case CameraReply::Field::DATA_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.data = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerManualEvent::cbor_serialize(const LawnmowerManualEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerManualEvent::cbor_encode(CborEncoder& encoder, const LawnmowerManualEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.speed) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualEvent::Field::SPEED_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.speed.value()));
+            }
+    if (msg.steering) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualEvent::Field::STEERING_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.steering.value()));
+            }
+    if (msg.blade) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualEvent::Field::BLADE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.blade.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerManualEvent*> LawnmowerManualEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerManualEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerManualEvent* msg = new LawnmowerManualEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerManualEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerManualEvent*>::Ok(msg);
+}
+
+CborError LawnmowerManualEvent::cbor_decode(CborValue& it, LawnmowerManualEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerManualEvent::Field::SPEED_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.speed = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualEvent::Field::STEERING_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.steering = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualEvent::Field::BLADE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.blade = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerManualCmd::cbor_serialize(const LawnmowerManualCmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerManualCmd::cbor_encode(CborEncoder& encoder, const LawnmowerManualCmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.speed) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::SPEED_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.speed.value()));
+            }
+    if (msg.steer) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::STEER_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.steer.value()));
+            }
+    if (msg.blade) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::BLADE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.blade.value()));
+            }
+    if (msg.start_manual_control) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::START_MANUAL_CONTROL_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.start_manual_control.value()));
+            }
+    if (msg.stop_manual_control) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::STOP_MANUAL_CONTROL_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.stop_manual_control.value()));
+            }
+    if (msg.emergency_stop) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::EMERGENCY_STOP_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.emergency_stop.value()));
+            }
+    if (msg.start_auto_mode) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::START_AUTO_MODE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.start_auto_mode.value()));
+            }
+    if (msg.stop_auto_mode) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualCmd::Field::STOP_AUTO_MODE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.stop_auto_mode.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerManualCmd*> LawnmowerManualCmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerManualCmd*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerManualCmd* msg = new LawnmowerManualCmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerManualCmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerManualCmd*>::Ok(msg);
+}
+
+CborError LawnmowerManualCmd::cbor_decode(CborValue& it, LawnmowerManualCmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerManualCmd::Field::SPEED_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.speed = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::STEER_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.steer = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::BLADE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.blade = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::START_MANUAL_CONTROL_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.start_manual_control = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::STOP_MANUAL_CONTROL_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.stop_manual_control = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::EMERGENCY_STOP_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.emergency_stop = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::START_AUTO_MODE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.start_auto_mode = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualCmd::Field::STOP_AUTO_MODE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.stop_auto_mode = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerManualReply::cbor_serialize(const LawnmowerManualReply& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerManualReply::cbor_encode(CborEncoder& encoder, const LawnmowerManualReply& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.error_code) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualReply::Field::ERROR_CODE_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.error_code.value()));
+            }
+    if (msg.message) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerManualReply::Field::MESSAGE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.message.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerManualReply*> LawnmowerManualReply::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerManualReply*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerManualReply* msg = new LawnmowerManualReply();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerManualReply*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerManualReply*>::Ok(msg);
+}
+
+CborError LawnmowerManualReply::cbor_decode(CborValue& it, LawnmowerManualReply& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerManualReply::Field::ERROR_CODE_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.error_code = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerManualReply::Field::MESSAGE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.message = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerAutoEvent::cbor_serialize(const LawnmowerAutoEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerAutoEvent::cbor_encode(CborEncoder& encoder, const LawnmowerAutoEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.started) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::STARTED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.started.value()));
+            }
+    if (msg.stopped) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::STOPPED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.stopped.value()));
+            }
+    if (msg.paused) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::PAUSED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.paused.value()));
+            }
+    if (msg.resumed) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::RESUMED_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.resumed.value()));
+            }
+    if (msg.mode) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::MODE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.mode.value().c_str()));
+            }
+    if (msg.path) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoEvent::Field::PATH_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.path.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerAutoEvent*> LawnmowerAutoEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerAutoEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerAutoEvent* msg = new LawnmowerAutoEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerAutoEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerAutoEvent*>::Ok(msg);
+}
+
+CborError LawnmowerAutoEvent::cbor_decode(CborValue& it, LawnmowerAutoEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerAutoEvent::Field::STARTED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.started = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoEvent::Field::STOPPED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.stopped = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoEvent::Field::PAUSED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.paused = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoEvent::Field::RESUMED_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.resumed = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoEvent::Field::MODE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.mode = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoEvent::Field::PATH_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.path = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerAutoCmd::cbor_serialize(const LawnmowerAutoCmd& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerAutoCmd::cbor_encode(CborEncoder& encoder, const LawnmowerAutoCmd& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.start) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::START_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.start.value()));
+            }
+    if (msg.stop) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::STOP_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.stop.value()));
+            }
+    if (msg.pause) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::PAUSE_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.pause.value()));
+            }
+    if (msg.resume) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::RESUME_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.resume.value()));
+            }
+    if (msg.mode) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::MODE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.mode.value().c_str()));
+            }
+    if (msg.path) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerAutoCmd::Field::PATH_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.path.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerAutoCmd*> LawnmowerAutoCmd::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerAutoCmd*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerAutoCmd* msg = new LawnmowerAutoCmd();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerAutoCmd*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerAutoCmd*>::Ok(msg);
+}
+
+CborError LawnmowerAutoCmd::cbor_decode(CborValue& it, LawnmowerAutoCmd& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerAutoCmd::Field::START_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.start = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoCmd::Field::STOP_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.stop = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoCmd::Field::PAUSE_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.pause = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoCmd::Field::RESUME_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.resume = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoCmd::Field::MODE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.mode = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerAutoCmd::Field::PATH_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.path = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> LawnmowerStatus::cbor_serialize(const LawnmowerStatus& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError LawnmowerStatus::cbor_encode(CborEncoder& encoder, const LawnmowerStatus& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.battery_level) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerStatus::Field::BATTERY_LEVEL_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.battery_level.value()));
+            }
+    if (msg.blade_status) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerStatus::Field::BLADE_STATUS_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.blade_status.value()));
+            }
+    if (msg.current_mode) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerStatus::Field::CURRENT_MODE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.current_mode.value().c_str()));
+            }
+    if (msg.error_message) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, LawnmowerStatus::Field::ERROR_MESSAGE_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.error_message.value().c_str()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<LawnmowerStatus*> LawnmowerStatus::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<LawnmowerStatus*>::Err(-1,"CBOR parse error");
+    }
+
+    LawnmowerStatus* msg = new LawnmowerStatus();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<LawnmowerStatus*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<LawnmowerStatus*>::Ok(msg);
+}
+
+CborError LawnmowerStatus::cbor_decode(CborValue& it, LawnmowerStatus& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case LawnmowerStatus::Field::BATTERY_LEVEL_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.battery_level = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerStatus::Field::BLADE_STATUS_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.blade_status = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerStatus::Field::CURRENT_MODE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.current_mode = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case LawnmowerStatus::Field::ERROR_MESSAGE_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.error_message = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> MotorEvent::cbor_serialize(const MotorEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError MotorEvent::cbor_encode(CborEncoder& encoder, const MotorEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.motor_id) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::MOTOR_ID_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.motor_id.value()));
+            }
+    if (msg.temperature) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::TEMPERATURE_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.temperature.value()));
+            }
+    if (msg.voltage) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::VOLTAGE_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.voltage.value()));
+            }
+    if (msg.current) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::CURRENT_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.current.value()));
+            }
+    if (msg.speed) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::SPEED_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.speed.value()));
+            }
+    if (msg.position) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, MotorEvent::Field::POSITION_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.position.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<MotorEvent*> MotorEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<MotorEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    MotorEvent* msg = new MotorEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<MotorEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<MotorEvent*>::Ok(msg);
+}
+
+CborError MotorEvent::cbor_decode(CborValue& it, MotorEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case MotorEvent::Field::MOTOR_ID_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.motor_id = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MotorEvent::Field::TEMPERATURE_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.temperature = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MotorEvent::Field::VOLTAGE_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.voltage = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MotorEvent::Field::CURRENT_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.current = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MotorEvent::Field::SPEED_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.speed = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case MotorEvent::Field::POSITION_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.position = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> BrokerRatesReq::cbor_serialize(const BrokerRatesReq& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError BrokerRatesReq::cbor_encode(CborEncoder& encoder, const BrokerRatesReq& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<BrokerRatesReq*> BrokerRatesReq::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<BrokerRatesReq*>::Err(-1,"CBOR parse error");
+    }
+
+    BrokerRatesReq* msg = new BrokerRatesReq();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<BrokerRatesReq*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<BrokerRatesReq*>::Ok(msg);
+}
+
+CborError BrokerRatesReq::cbor_decode(CborValue& it, BrokerRatesReq& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> BrokerRatesRep::cbor_serialize(const BrokerRatesRep& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError BrokerRatesRep::cbor_encode(CborEncoder& encoder, const BrokerRatesRep& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerRatesRep::Field::SRC_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.src.size()));
+            for (const auto & item : msg.src) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerRatesRep::Field::DST_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.dst.size()));
+            for (const auto & item : msg.dst) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerRatesRep::Field::MSG_TYPE_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.msg_type.size()));
+            for (const auto & item : msg.msg_type) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerRatesRep::Field::RATE_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.rate.size()));
+            for (const auto & item : msg.rate) {
+                CBOR_OK(cbor_encode_float(&arrayEncoder, item));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<BrokerRatesRep*> BrokerRatesRep::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<BrokerRatesRep*>::Err(-1,"CBOR parse error");
+    }
+
+    BrokerRatesRep* msg = new BrokerRatesRep();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<BrokerRatesRep*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<BrokerRatesRep*>::Ok(msg);
+}
+
+CborError BrokerRatesRep::cbor_decode(CborValue& it, BrokerRatesRep& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case BrokerRatesRep::Field::SRC_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.src.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.src.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerRatesRep::Field::DST_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.dst.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.dst.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerRatesRep::Field::MSG_TYPE_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.msg_type.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.msg_type.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerRatesRep::Field::RATE_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.rate.clear();
+                while (!cbor_value_at_end(&items)) {
+                    float item{};
+                    {
+    float decoded = 0;
+    if (cbor_value_is_float(&items) && cbor_value_get_float(&items, &decoded) == CborNoError)
+        item = decoded;
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.rate.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> BrokerMetricsEvent::cbor_serialize(const BrokerMetricsEvent& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError BrokerMetricsEvent::cbor_encode(CborEncoder& encoder, const BrokerMetricsEvent& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::ENDPOINT_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.endpoint.size()));
+            for (const auto & item : msg.endpoint) {
+                CBOR_OK(cbor_encode_text_stringz(&arrayEncoder, item.c_str()));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::LAST_SEEN_MS_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.last_seen_ms.size()));
+            for (const auto & item : msg.last_seen_ms) {
+                CBOR_OK(cbor_encode_uint(&arrayEncoder, item));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::RECEIVED_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.received.size()));
+            for (const auto & item : msg.received) {
+                CBOR_OK(cbor_encode_uint(&arrayEncoder, item));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::FORWARDED_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.forwarded.size()));
+            for (const auto & item : msg.forwarded) {
+                CBOR_OK(cbor_encode_uint(&arrayEncoder, item));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    if (msg.dropped_unknown) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::DROPPED_UNKNOWN_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.dropped_unknown.value()));
+            }
+    if (msg.decode_errors) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::DECODE_ERRORS_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.decode_errors.value()));
+            }
+    if (msg.queue_depth) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, BrokerMetricsEvent::Field::QUEUE_DEPTH_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.queue_depth.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<BrokerMetricsEvent*> BrokerMetricsEvent::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<BrokerMetricsEvent*>::Err(-1,"CBOR parse error");
+    }
+
+    BrokerMetricsEvent* msg = new BrokerMetricsEvent();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<BrokerMetricsEvent*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<BrokerMetricsEvent*>::Ok(msg);
+}
+
+CborError BrokerMetricsEvent::cbor_decode(CborValue& it, BrokerMetricsEvent& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case BrokerMetricsEvent::Field::ENDPOINT_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.endpoint.clear();
+                while (!cbor_value_at_end(&items)) {
+                    std::string item{};
+                    {
+    if (cbor_value_is_text_string(&items)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&items, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&items, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        item = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.endpoint.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::LAST_SEEN_MS_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.last_seen_ms.clear();
+                while (!cbor_value_at_end(&items)) {
+                    uint32_t item{};
+                    {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&items) && cbor_value_get_uint64(&items, &decoded) == CborNoError)
+        item = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.last_seen_ms.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::RECEIVED_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.received.clear();
+                while (!cbor_value_at_end(&items)) {
+                    uint64_t item{};
+                    {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&items) && cbor_value_get_uint64(&items, &decoded) == CborNoError)
+        item = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.received.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::FORWARDED_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.forwarded.clear();
+                while (!cbor_value_at_end(&items)) {
+                    uint64_t item{};
+                    {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&items) && cbor_value_get_uint64(&items, &decoded) == CborNoError)
+        item = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&items));
+}
+
+                    msg.forwarded.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::DROPPED_UNKNOWN_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.dropped_unknown = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::DECODE_ERRORS_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.decode_errors = static_cast<uint64_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case BrokerMetricsEvent::Field::QUEUE_DEPTH_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.queue_depth = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
@@ -0,0 +1,155 @@
+#include "common.h"
+
+// Helper macros for JSON values, target is a JsonVariant or a member of a JsonObject
+
+
+
+
+
+
+
+Result<Bytes> Measurement::json_serialize(const Measurement& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Measurement*> Measurement::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Measurement*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Measurement* msg = new Measurement();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Measurement*>::Ok(msg);
+    }
+
+void Measurement::json_encode(JsonObject obj, const Measurement& msg) {
+        if (msg.value)
+                    obj["value"] = *msg.value;
+        if (msg.unit)
+                    obj["unit"] = static_cast<int>(*msg.unit);
+        }
+
+void Measurement::json_decode(JsonObjectConst obj, Measurement& msg) {
+        if (obj["value"].is<double>())
+                    msg.value = obj["value"].as<double>();
+        if (obj["unit"].is<int>())
+                    msg.unit = static_cast<Unit>(obj["unit"].as<int>());
+        }
+
+
+
+// Helper macros for serialization and deserialization
+
+
+
+
+// CBOR Serialization/Deserialization
+#define RC_OK(rc) if ( (rc) != CborNoError ) { return Result<Bytes>::Err(-1,"CBOR serialization error"); }
+#define CBOR_OK(rc) { CborError cbor_err = (rc); if ( cbor_err != CborNoError ) { return cbor_err; } }
+
+
+Result<Bytes> Measurement::cbor_serialize(const Measurement& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Measurement::cbor_encode(CborEncoder& encoder, const Measurement& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.value) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Measurement::Field::VALUE_INDEX));
+            CBOR_OK(cbor_encode_double(&mapEncoder, msg.value.value()));
+            }
+    if (msg.unit) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Measurement::Field::UNIT_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.unit.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Measurement*> Measurement::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Measurement*>::Err(-1,"CBOR parse error");
+    }
+
+    Measurement* msg = new Measurement();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Measurement*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Measurement*>::Ok(msg);
+}
+
+CborError Measurement::cbor_decode(CborValue& it, Measurement& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Measurement::Field::VALUE_INDEX:{
+                {
+    double decoded = 0;
+    if (cbor_value_is_double(&mapIt) && cbor_value_get_double(&mapIt, &decoded) == CborNoError)
+        msg.value = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Measurement::Field::UNIT_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.unit = static_cast<Unit>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
@@ -0,0 +1,46 @@
+#pragma once
+#include <string>
+#include <vector>
+#include <unordered_map>
+#include <cstdint>
+#include <optional>
+#include <ArduinoJson.h>
+#include <cbor.h>
+#include <msg.h>
+#include <serdes.h>
+
+
+
+typedef std::vector<uint8_t> Bytes;
+
+
+typedef enum {
+    NONE = 0,
+    METER = 1,
+    SECOND = 2,
+} Unit;
+
+
+
+class Measurement : public Msg {
+    MSG(Measurement);
+    public:
+    std::optional<double> value;
+    std::optional<Unit> unit;
+    
+    // Field indexes
+        typedef enum {
+        VALUE_INDEX = 1,
+        UNIT_INDEX = 2,
+    } Field;
+    static Result<Bytes> json_serialize(const Measurement&);
+    static Result<Measurement*> json_deserialize(const Bytes&);
+    static Result<Bytes> cbor_serialize(const Measurement&);
+    static Result<Measurement*> cbor_deserialize(const Bytes&);
+    // (de)serialization as a value inside another message
+    static void json_encode(JsonObject, const Measurement&);
+    static void json_decode(JsonObjectConst, Measurement&);
+    static CborError cbor_encode(CborEncoder&, const Measurement&);
+    static CborError cbor_decode(CborValue&, Measurement&);
+};
+
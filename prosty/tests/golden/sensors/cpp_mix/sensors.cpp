@@ -0,0 +1,912 @@
+#include "sensors.h"
+
+// Helper macros for JSON values, target is a JsonVariant or a member of a JsonObject
+
+
+
+
+
+
+
+Result<Bytes> SensorCalibration::json_serialize(const SensorCalibration& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<SensorCalibration*> SensorCalibration::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<SensorCalibration*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        SensorCalibration* msg = new SensorCalibration();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<SensorCalibration*>::Ok(msg);
+    }
+
+void SensorCalibration::json_encode(JsonObject obj, const SensorCalibration& msg) {
+        if (msg.offset)
+                    obj["offset"] = *msg.offset;
+        if (msg.scale)
+                    obj["scale"] = *msg.scale;
+        }
+
+void SensorCalibration::json_decode(JsonObjectConst obj, SensorCalibration& msg) {
+        if (obj["offset"].is<float>())
+                    msg.offset = obj["offset"].as<float>();
+        if (obj["scale"].is<float>())
+                    msg.scale = obj["scale"].as<float>();
+        }
+
+
+Result<Bytes> Status::json_serialize(const Status& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Status*> Status::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Status*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Status* msg = new Status();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Status*>::Ok(msg);
+    }
+
+void Status::json_encode(JsonObject obj, const Status& msg) {
+        if (msg.ok)
+                    obj["ok"] = *msg.ok;
+        }
+
+void Status::json_decode(JsonObjectConst obj, Status& msg) {
+        if (obj["ok"].is<bool>())
+                    msg.ok = obj["ok"].as<bool>();
+        }
+
+
+Result<Bytes> Sensor::json_serialize(const Sensor& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Sensor*> Sensor::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Sensor*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Sensor* msg = new Sensor();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Sensor*>::Ok(msg);
+    }
+
+void Sensor::json_encode(JsonObject obj, const Sensor& msg) {
+        if (msg.name)
+                    obj["name"] = *msg.name;
+        if (msg.kind)
+                    obj["kind"] = static_cast<int>(*msg.kind);
+        if (msg.calibration)
+                    SensorCalibration::json_encode(obj["calibration"].to<JsonObject>(), *msg.calibration);
+        if (msg.last)
+                    Measurement::json_encode(obj["last"].to<JsonObject>(), *msg.last);
+        
+                if (msg.limits.size()) {
+                    JsonObject entries = obj["limits"].to<JsonObject>();
+                    for (const auto& [key, value] : msg.limits) {
+                        entries[key] = value;
+                    }
+                }
+        
+                if (msg.ranges.size()) {
+                    JsonObject entries = obj["ranges"].to<JsonObject>();
+                    for (const auto& [key, value] : msg.ranges) {
+                        SensorCalibration::json_encode(entries[std::to_string(key)].to<JsonObject>(), value);
+                    }
+                }
+        if (msg.history.size()) {
+                    JsonArray arr = obj["history"].to<JsonArray>();
+                    for (const auto& item : msg.history) {
+                        Measurement::json_encode(arr.add<JsonVariant>().to<JsonObject>(), item);
+                    }
+                }
+        if (msg.status)
+                    Status::json_encode(obj["status"].to<JsonObject>(), *msg.status);
+        
+                if (msg.statuses.size()) {
+                    JsonObject entries = obj["statuses"].to<JsonObject>();
+                    for (const auto& [key, value] : msg.statuses) {
+                        Status::json_encode(entries[key].to<JsonObject>(), value);
+                    }
+                }
+        }
+
+void Sensor::json_decode(JsonObjectConst obj, Sensor& msg) {
+        if (obj["name"].is<std::string>())
+                    msg.name = obj["name"].as<std::string>();
+        if (obj["kind"].is<int>())
+                    msg.kind = static_cast<SensorKind>(obj["kind"].as<int>());
+        if (obj["calibration"].is<JsonObjectConst>()) {
+                    SensorCalibration decoded;
+                    SensorCalibration::json_decode(obj["calibration"].as<JsonObjectConst>(), decoded);
+                    msg.calibration = decoded;
+                }
+        if (obj["last"].is<JsonObjectConst>()) {
+                    Measurement decoded;
+                    Measurement::json_decode(obj["last"].as<JsonObjectConst>(), decoded);
+                    msg.last = decoded;
+                }
+        if (obj["limits"].is<JsonObjectConst>()) {
+                    msg.limits.clear();
+                    for (JsonPairConst kv : obj["limits"].as<JsonObjectConst>()) {
+                        int32_t value{};
+                        if (kv.value().is<int32_t>())
+                    value = kv.value().as<int32_t>();
+                        msg.limits[std::string(kv.key().c_str())] = value;
+                    }
+                }
+        if (obj["ranges"].is<JsonObjectConst>()) {
+                    msg.ranges.clear();
+                    for (JsonPairConst kv : obj["ranges"].as<JsonObjectConst>()) {
+                        SensorCalibration value{};
+                        if (kv.value().is<JsonObjectConst>()) {
+                    SensorCalibration decoded;
+                    SensorCalibration::json_decode(kv.value().as<JsonObjectConst>(), decoded);
+                    value = decoded;
+                }
+                        msg.ranges[static_cast<uint32_t>(std::stoull(kv.key().c_str()))] = value;
+                    }
+                }
+        if (obj["history"].is<JsonArrayConst>()) {
+                    msg.history.clear();
+                    for (JsonVariantConst v : obj["history"].as<JsonArrayConst>()) {
+                        Measurement item{};
+                        if (v.is<JsonObjectConst>()) {
+                    Measurement decoded;
+                    Measurement::json_decode(v.as<JsonObjectConst>(), decoded);
+                    item = decoded;
+                }
+                        msg.history.push_back(item);
+                    }
+                }
+        if (obj["status"].is<JsonObjectConst>()) {
+                    Status decoded;
+                    Status::json_decode(obj["status"].as<JsonObjectConst>(), decoded);
+                    msg.status = decoded;
+                }
+        if (obj["statuses"].is<JsonObjectConst>()) {
+                    msg.statuses.clear();
+                    for (JsonPairConst kv : obj["statuses"].as<JsonObjectConst>()) {
+                        Status value{};
+                        if (kv.value().is<JsonObjectConst>()) {
+                    Status decoded;
+                    Status::json_decode(kv.value().as<JsonObjectConst>(), decoded);
+                    value = decoded;
+                }
+                        msg.statuses[std::string(kv.key().c_str())] = value;
+                    }
+                }
+        }
+
+
+Result<Bytes> Command::json_serialize(const Command& msg)  {
+        JsonDocument doc;
+        json_encode(doc.to<JsonObject>(), msg);
+        std::string str;
+        ArduinoJson::serializeJson(doc,str);
+        return Result<Bytes>::Ok(Bytes(str.begin(),str.end()));
+    }
+
+    Result<Command*> Command::json_deserialize(const Bytes& bytes) {
+        JsonDocument doc;
+        auto err = deserializeJson(doc,bytes);
+        if ( err != DeserializationError::Ok || doc.is<JsonObject>() == false ) {
+            return Result<Command*>::Err(-1,"Cannot deserialize as object") ;
+        };
+        Command* msg = new Command();
+        json_decode(doc.as<JsonObjectConst>(), *msg);
+        return Result<Command*>::Ok(msg);
+    }
+
+void Command::json_encode(JsonObject obj, const Command& msg) {
+        if (msg.seq)
+                    obj["seq"] = *msg.seq;
+        if (msg.reboot_reason)
+                    obj["reboot_reason"] = *msg.reboot_reason;
+        if (msg.calibrate)
+                    SensorCalibration::json_encode(obj["calibrate"].to<JsonObject>(), *msg.calibrate);
+        if (msg.firmware)
+                    obj["firmware"] = base64_encode(*msg.firmware);
+        if (msg.set_unit)
+                    obj["set_unit"] = static_cast<int>(*msg.set_unit);
+        }
+
+void Command::json_decode(JsonObjectConst obj, Command& msg) {
+        if (obj["seq"].is<uint32_t>())
+                    msg.seq = obj["seq"].as<uint32_t>();
+        if (obj["reboot_reason"].is<std::string>())
+                    msg.reboot_reason = obj["reboot_reason"].as<std::string>();
+        if (obj["calibrate"].is<JsonObjectConst>()) {
+                    SensorCalibration decoded;
+                    SensorCalibration::json_decode(obj["calibrate"].as<JsonObjectConst>(), decoded);
+                    msg.calibrate = decoded;
+                }
+        if (obj["firmware"].is<std::string>())
+                    msg.firmware = base64_decode(obj["firmware"].as<std::string>());
+        if (obj["set_unit"].is<int>())
+                    msg.set_unit = static_cast<Unit>(obj["set_unit"].as<int>());
+        }
+
+
+
+// Helper macros for serialization and deserialization
+
+
+
+
+// CBOR Serialization/Deserialization
+#define RC_OK(rc) if ( (rc) != CborNoError ) { return Result<Bytes>::Err(-1,"CBOR serialization error"); }
+#define CBOR_OK(rc) { CborError cbor_err = (rc); if ( cbor_err != CborNoError ) { return cbor_err; } }
+
+
+Result<Bytes> SensorCalibration::cbor_serialize(const SensorCalibration& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError SensorCalibration::cbor_encode(CborEncoder& encoder, const SensorCalibration& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.offset) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SensorCalibration::Field::OFFSET_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.offset.value()));
+            }
+    if (msg.scale) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, SensorCalibration::Field::SCALE_INDEX));
+            CBOR_OK(cbor_encode_float(&mapEncoder, msg.scale.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<SensorCalibration*> SensorCalibration::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<SensorCalibration*>::Err(-1,"CBOR parse error");
+    }
+
+    SensorCalibration* msg = new SensorCalibration();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<SensorCalibration*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<SensorCalibration*>::Ok(msg);
+}
+
+CborError SensorCalibration::cbor_decode(CborValue& it, SensorCalibration& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case SensorCalibration::Field::OFFSET_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.offset = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case SensorCalibration::Field::SCALE_INDEX:{
+                {
+    float decoded = 0;
+    if (cbor_value_is_float(&mapIt) && cbor_value_get_float(&mapIt, &decoded) == CborNoError)
+        msg.scale = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> Status::cbor_serialize(const Status& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Status::cbor_encode(CborEncoder& encoder, const Status& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.ok) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Status::Field::OK_INDEX));
+            CBOR_OK(cbor_encode_boolean(&mapEncoder, msg.ok.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Status*> Status::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Status*>::Err(-1,"CBOR parse error");
+    }
+
+    Status* msg = new Status();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Status*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Status*>::Ok(msg);
+}
+
+CborError Status::cbor_decode(CborValue& it, Status& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Status::Field::OK_INDEX:{
+                {
+    bool decoded = false;
+    if (cbor_value_is_boolean(&mapIt) && cbor_value_get_boolean(&mapIt, &decoded) == CborNoError)
+        msg.ok = decoded;
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> Sensor::cbor_serialize(const Sensor& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Sensor::cbor_encode(CborEncoder& encoder, const Sensor& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.name) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::NAME_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.name.value().c_str()));
+            }
+    if (msg.kind) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::KIND_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.kind.value()));
+            }
+    if (msg.calibration) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::CALIBRATION_INDEX));
+            CBOR_OK(SensorCalibration::cbor_encode(mapEncoder, msg.calibration.value()));
+            }
+    if (msg.last) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::LAST_INDEX));
+            CBOR_OK(Measurement::cbor_encode(mapEncoder, msg.last.value()));
+            }
+    if (msg.limits.size()) {
+            CborEncoder entryEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::LIMITS_INDEX));
+            CBOR_OK(cbor_encoder_create_map(&mapEncoder, &entryEncoder, msg.limits.size()));
+            for (const auto& [key, value] : msg.limits) {
+                CBOR_OK(cbor_encode_text_stringz(&entryEncoder, key.c_str()));
+                CBOR_OK(cbor_encode_int(&entryEncoder, value));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &entryEncoder));
+            }
+    if (msg.ranges.size()) {
+            CborEncoder entryEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::RANGES_INDEX));
+            CBOR_OK(cbor_encoder_create_map(&mapEncoder, &entryEncoder, msg.ranges.size()));
+            for (const auto& [key, value] : msg.ranges) {
+                CBOR_OK(cbor_encode_uint(&entryEncoder, key));
+                CBOR_OK(SensorCalibration::cbor_encode(entryEncoder, value));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &entryEncoder));
+            }
+    {
+            CborEncoder arrayEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::HISTORY_INDEX ));
+            CBOR_OK(cbor_encoder_create_array(&mapEncoder, &arrayEncoder, msg.history.size()));
+            for (const auto & item : msg.history) {
+                CBOR_OK(Measurement::cbor_encode(arrayEncoder, item));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &arrayEncoder));
+            }
+    if (msg.status) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::STATUS_INDEX));
+            CBOR_OK(Status::cbor_encode(mapEncoder, msg.status.value()));
+            }
+    if (msg.statuses.size()) {
+            CborEncoder entryEncoder;
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Sensor::Field::STATUSES_INDEX));
+            CBOR_OK(cbor_encoder_create_map(&mapEncoder, &entryEncoder, msg.statuses.size()));
+            for (const auto& [key, value] : msg.statuses) {
+                CBOR_OK(cbor_encode_text_stringz(&entryEncoder, key.c_str()));
+                CBOR_OK(Status::cbor_encode(entryEncoder, value));
+            }
+            CBOR_OK(cbor_encoder_close_container(&mapEncoder, &entryEncoder));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Sensor*> Sensor::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Sensor*>::Err(-1,"CBOR parse error");
+    }
+
+    Sensor* msg = new Sensor();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Sensor*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Sensor*>::Ok(msg);
+}
+
+CborError Sensor::cbor_decode(CborValue& it, Sensor& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Sensor::Field::NAME_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.name = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Sensor::Field::KIND_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.kind = static_cast<SensorKind>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Sensor::Field::CALIBRATION_INDEX:{
+                {
+    SensorCalibration decoded;
+    CBOR_OK(SensorCalibration::cbor_decode(mapIt, decoded));
+    msg.calibration = decoded;
+}
+
+                break;
+            }
+            
+            case Sensor::Field::LAST_INDEX:{
+                {
+    Measurement decoded;
+    CBOR_OK(Measurement::cbor_decode(mapIt, decoded));
+    msg.last = decoded;
+}
+
+                break;
+            }
+            
+            case Sensor::Field::LIMITS_INDEX:{
+                if (!cbor_value_is_map(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue entries;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &entries));
+                msg.limits.clear();
+                while (!cbor_value_at_end(&entries)) {
+                    std::string entryKey{};
+                    int32_t entryValue{};
+                    {
+    if (cbor_value_is_text_string(&entries)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&entries, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&entries, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        entryKey = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&entries));
+}
+
+                    {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&entries) && cbor_value_get_int64(&entries, &decoded) == CborNoError)
+        entryValue = static_cast<int32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&entries));
+}
+
+                    msg.limits[entryKey] = entryValue;
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &entries));
+                break;
+            }
+            
+            case Sensor::Field::RANGES_INDEX:{
+                if (!cbor_value_is_map(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue entries;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &entries));
+                msg.ranges.clear();
+                while (!cbor_value_at_end(&entries)) {
+                    uint32_t entryKey{};
+                    SensorCalibration entryValue{};
+                    {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&entries) && cbor_value_get_uint64(&entries, &decoded) == CborNoError)
+        entryKey = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&entries));
+}
+
+                    {
+    SensorCalibration decoded;
+    CBOR_OK(SensorCalibration::cbor_decode(entries, decoded));
+    entryValue = decoded;
+}
+
+                    msg.ranges[entryKey] = entryValue;
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &entries));
+                break;
+            }
+            
+            case Sensor::Field::HISTORY_INDEX:{
+                if (!cbor_value_is_array(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue items;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &items));
+                msg.history.clear();
+                while (!cbor_value_at_end(&items)) {
+                    Measurement item{};
+                    {
+    Measurement decoded;
+    CBOR_OK(Measurement::cbor_decode(items, decoded));
+    item = decoded;
+}
+
+                    msg.history.push_back(item);
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &items));
+                break;
+            }
+            
+            case Sensor::Field::STATUS_INDEX:{
+                {
+    Status decoded;
+    CBOR_OK(Status::cbor_decode(mapIt, decoded));
+    msg.status = decoded;
+}
+
+                break;
+            }
+            
+            case Sensor::Field::STATUSES_INDEX:{
+                if (!cbor_value_is_map(&mapIt)) {
+                    CBOR_OK(cbor_value_advance(&mapIt));
+                    break;
+                }
+                CborValue entries;
+                CBOR_OK(cbor_value_enter_container(&mapIt, &entries));
+                msg.statuses.clear();
+                while (!cbor_value_at_end(&entries)) {
+                    std::string entryKey{};
+                    Status entryValue{};
+                    {
+    if (cbor_value_is_text_string(&entries)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&entries, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&entries, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        entryKey = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&entries));
+}
+
+                    {
+    Status decoded;
+    CBOR_OK(Status::cbor_decode(entries, decoded));
+    entryValue = decoded;
+}
+
+                    msg.statuses[entryKey] = entryValue;
+                }
+                CBOR_OK(cbor_value_leave_container(&mapIt, &entries));
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
+Result<Bytes> Command::cbor_serialize(const Command& msg)  {
+    // buffer: grow if needed by changing initial size
+    std::vector<uint8_t> buffer(1024);
+    CborEncoder encoder;
+    cbor_encoder_init(&encoder, buffer.data(), buffer.size(), 0);
+    RC_OK(cbor_encode(encoder, msg));
+    // get used size
+    size_t used = cbor_encoder_get_buffer_size(&encoder, buffer.data());
+    return Result<Bytes>::Ok(Bytes(buffer.begin(), buffer.begin() + used));
+}
+
+CborError Command::cbor_encode(CborEncoder& encoder, const Command& msg) {
+    CborEncoder mapEncoder;
+    // Start top-level map
+    CBOR_OK(cbor_encoder_create_map(&encoder, &mapEncoder, CborIndefiniteLength));
+
+    if (msg.seq) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Command::Field::SEQ_INDEX));
+            CBOR_OK(cbor_encode_uint(&mapEncoder, msg.seq.value()));
+            }
+    if (msg.reboot_reason) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Command::Field::REBOOT_REASON_INDEX));
+            CBOR_OK(cbor_encode_text_stringz(&mapEncoder, msg.reboot_reason.value().c_str()));
+            }
+    if (msg.calibrate) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Command::Field::CALIBRATE_INDEX));
+            CBOR_OK(SensorCalibration::cbor_encode(mapEncoder, msg.calibrate.value()));
+            }
+    if (msg.firmware) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Command::Field::FIRMWARE_INDEX));
+            CBOR_OK(cbor_encode_byte_string(&mapEncoder, msg.firmware.value().data(), msg.firmware.value().size()));
+            }
+    if (msg.set_unit) {
+            CBOR_OK(cbor_encode_uint(&mapEncoder, Command::Field::SET_UNIT_INDEX));
+            CBOR_OK(cbor_encode_int(&mapEncoder, msg.set_unit.value()));
+            }
+    return cbor_encoder_close_container(&encoder, &mapEncoder);
+}
+
+ Result<Command*> Command::cbor_deserialize(const Bytes& bytes) {
+    CborParser parser;
+    CborValue it;
+
+    CborError err = cbor_parser_init(bytes.data(), bytes.size(), 0, &parser, &it);
+    if (err != CborNoError) {
+        return Result<Command*>::Err(-1,"CBOR parse error");
+    }
+
+    Command* msg = new Command();
+    err = cbor_decode(it, *msg);
+    if (err != CborNoError) {
+        delete msg;
+        INFO("CBOR deserialization error: %s", cbor_error_string(err));
+        return Result<Command*>::Err(-2,"CBOR deserialization error");
+    }
+    return Result<Command*>::Ok(msg);
+}
+
+CborError Command::cbor_decode(CborValue& it, Command& msg) {
+    if (!cbor_value_is_map(&it)) {
+        return CborErrorIllegalType;
+    }
+
+    // enter map
+    CborValue mapIt;
+    CBOR_OK(cbor_value_enter_container(&it, &mapIt));
+
+    // iterate key/value pairs
+    while (!cbor_value_at_end(&mapIt)) {
+        uint64_t key = 0;
+        if (!cbor_value_is_unsigned_integer(&mapIt)) {
+            // invalid key type
+            return CborErrorIllegalType;
+        }
+        CBOR_OK(cbor_value_get_uint64(&mapIt, &key));
+        CBOR_OK(cbor_value_advance(&mapIt));
+        switch (key) {
+            
+            case Command::Field::SEQ_INDEX:{
+                {
+    uint64_t decoded = 0;
+    if (cbor_value_is_unsigned_integer(&mapIt) && cbor_value_get_uint64(&mapIt, &decoded) == CborNoError)
+        msg.seq = static_cast<uint32_t>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Command::Field::REBOOT_REASON_INDEX:{
+                {
+    if (cbor_value_is_text_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        std::string decoded(len + 1, '\0');
+        size_t size = decoded.size();
+        cbor_value_copy_text_string(&mapIt, decoded.data(), &size, NULL);
+        decoded.resize(size);
+        msg.reboot_reason = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Command::Field::CALIBRATE_INDEX:{
+                {
+    SensorCalibration decoded;
+    CBOR_OK(SensorCalibration::cbor_decode(mapIt, decoded));
+    msg.calibrate = decoded;
+}
+
+                break;
+            }
+            
+            case Command::Field::FIRMWARE_INDEX:{
+                {
+    if (cbor_value_is_byte_string(&mapIt)) {
+        size_t len = 0;
+        cbor_value_calculate_string_length(&mapIt, &len);
+        Bytes decoded(len);
+        cbor_value_copy_byte_string(&mapIt, decoded.data(), &len, NULL);
+        msg.firmware = decoded;
+    }
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            case Command::Field::SET_UNIT_INDEX:{
+                {
+    int64_t decoded = 0;
+    if (cbor_value_is_integer(&mapIt) && cbor_value_get_int64(&mapIt, &decoded) == CborNoError)
+        msg.set_unit = static_cast<Unit>(decoded);
+    CBOR_OK(cbor_value_advance(&mapIt));
+}
+
+                break;
+            }
+            
+            default:
+                // skip unknown key
+                CBOR_OK(cbor_value_advance(&mapIt));
+                break;
+        }
+
+    }
+
+    // leave container
+    return cbor_value_leave_container(&it, &mapIt);
+}
+
@@ -0,0 +1,144 @@
+#pragma once
+#include <string>
+#include <vector>
+#include <unordered_map>
+#include <cstdint>
+#include <optional>
+#include <ArduinoJson.h>
+#include <cbor.h>
+#include <msg.h>
+#include <serdes.h>
+#include "common.h"
+
+
+
+typedef std::vector<uint8_t> Bytes;
+
+
+typedef enum {
+    TEMPERATURE = 0,
+    DISTANCE = 1,
+} SensorKind;
+
+
+
+class SensorCalibration : public Msg {
+    MSG(SensorCalibration);
+    public:
+    std::optional<float> offset;
+    std::optional<float> scale;
+    
+    // Field indexes
+        typedef enum {
+        OFFSET_INDEX = 1,
+        SCALE_INDEX = 2,
+    } Field;
+    static Result<Bytes> json_serialize(const SensorCalibration&);
+    static Result<SensorCalibration*> json_deserialize(const Bytes&);
+    static Result<Bytes> cbor_serialize(const SensorCalibration&);
+    static Result<SensorCalibration*> cbor_deserialize(const Bytes&);
+    // (de)serialization as a value inside another message
+    static void json_encode(JsonObject, const SensorCalibration&);
+    static void json_decode(JsonObjectConst, SensorCalibration&);
+    static CborError cbor_encode(CborEncoder&, const SensorCalibration&);
+    static CborError cbor_decode(CborValue&, SensorCalibration&);
+};
+
+class Status : public Msg {
+    MSG(Status);
+    public:
+    std::optional<bool> ok;
+    
+    // Field indexes
+        typedef enum {
+        OK_INDEX = 1,
+    } Field;
+    static Result<Bytes> json_serialize(const Status&);
+    static Result<Status*> json_deserialize(const Bytes&);
+    static Result<Bytes> cbor_serialize(const Status&);
+    static Result<Status*> cbor_deserialize(const Bytes&);
+    // (de)serialization as a value inside another message
+    static void json_encode(JsonObject, const Status&);
+    static void json_decode(JsonObjectConst, Status&);
+    static CborError cbor_encode(CborEncoder&, const Status&);
+    static CborError cbor_decode(CborValue&, Status&);
+};
+
+class Sensor : public Msg {
+    MSG(Sensor);
+    public:
+    std::optional<std::string> name;
+    std::optional<SensorKind> kind;
+    std::optional<SensorCalibration> calibration;
+    std::optional<Measurement> last;
+    std::unordered_map<std::string,int32_t> limits;
+    std::unordered_map<uint32_t,SensorCalibration> ranges;
+    std::vector<Measurement> history;
+    std::optional<Status> status;
+    std::unordered_map<std::string,Status> statuses;
+    
+    // Field indexes
+        typedef enum {
+        NAME_INDEX = 1,
+        KIND_INDEX = 2,
+        CALIBRATION_INDEX = 3,
+        LAST_INDEX = 4,
+        LIMITS_INDEX = 5,
+        RANGES_INDEX = 6,
+        HISTORY_INDEX = 7,
+        STATUS_INDEX = 8,
+        STATUSES_INDEX = 9,
+    } Field;
+    static Result<Bytes> json_serialize(const Sensor&);
+    static Result<Sensor*> json_deserialize(const Bytes&);
+    static Result<Bytes> cbor_serialize(const Sensor&);
+    static Result<Sensor*> cbor_deserialize(const Bytes&);
+    // (de)serialization as a value inside another message
+    static void json_encode(JsonObject, const Sensor&);
+    static void json_decode(JsonObjectConst, Sensor&);
+    static CborError cbor_encode(CborEncoder&, const Sensor&);
+    static CborError cbor_decode(CborValue&, Sensor&);
+};
+
+class Command : public Msg {
+    MSG(Command);
+    public:
+    std::optional<uint32_t> seq;
+    std::optional<std::string> reboot_reason;
+    std::optional<SensorCalibration> calibrate;
+    std::optional<Bytes> firmware;
+    std::optional<Unit> set_unit;
+    
+    // Field indexes
+        typedef enum {
+        SEQ_INDEX = 1,
+        REBOOT_REASON_INDEX = 2,
+        CALIBRATE_INDEX = 3,
+        FIRMWARE_INDEX = 4,
+        SET_UNIT_INDEX = 5,
+    } Field;
+    // oneof action, the index of the member that is set or 0
+    uint32_t action_case() const {
+        if (reboot_reason) return REBOOT_REASON_INDEX;
+        if (calibrate) return CALIBRATE_INDEX;
+        if (firmware) return FIRMWARE_INDEX;
+        if (set_unit) return SET_UNIT_INDEX;
+        return 0;
+    }
+    void clear_action() {
+        reboot_reason.reset();
+        calibrate.reset();
+        firmware.reset();
+        set_unit.reset();
+    }
+    static Result<Bytes> json_serialize(const Command&);
+    static Result<Command*> json_deserialize(const Bytes&);
+    static Result<Bytes> cbor_serialize(const Command&);
+    static Result<Command*> cbor_deserialize(const Bytes&);
+    // (de)serialization as a value inside another message
+    static void json_encode(JsonObject, const Command&);
+    static void json_decode(JsonObjectConst, Command&);
+    static CborError cbor_encode(CborEncoder&, const Command&);
+    static CborError cbor_decode(CborValue&, Command&);
+};
+
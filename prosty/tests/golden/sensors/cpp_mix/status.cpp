@@ -0,0 +1,21 @@
+#include "status.h"
+
+// Helper macros for JSON values, target is a JsonVariant or a member of a JsonObject
+
+
+
+
+
+
+
+
+// Helper macros for serialization and deserialization
+
+
+
+
+// CBOR Serialization/Deserialization
+#define RC_OK(rc) if ( (rc) != CborNoError ) { return Result<Bytes>::Err(-1,"CBOR serialization error"); }
+#define CBOR_OK(rc) { CborError cbor_err = (rc); if ( cbor_err != CborNoError ) { return cbor_err; } }
+
+
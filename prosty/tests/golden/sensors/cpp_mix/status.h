@@ -0,0 +1,23 @@
+#pragma once
+#include <string>
+#include <vector>
+#include <unordered_map>
+#include <cstdint>
+#include <optional>
+#include <ArduinoJson.h>
+#include <cbor.h>
+#include <msg.h>
+#include <serdes.h>
+
+
+
+typedef std::vector<uint8_t> Bytes;
+
+
+typedef enum {
+    OK = 0,
+    FAILED = 1,
+} Status;
+
+
+
@@ -0,0 +1,180 @@
+use serde::{Serialize, Deserialize};
+use anyhow::Result;
+use crate::value::Value;
+use super::common::*;
+
+pub trait Msg {
+    const ID: u32;
+    const NAME: &'static str;
+    fn serialize(&self) -> Result<Vec<u8>>;
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SensorKind {
+    Temperature,
+    Distance,
+}
+
+
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SensorCalibration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f32>,
+}
+
+impl Msg for SensorCalibration {
+    const ID: u32 = 47200;
+    const NAME: &'static str = "SensorCalibration";
+
+    fn serialize(&self) -> Result<Vec<u8>> {
+        // Use serde_cbor to produce CBOR bytes
+        let s = serde_cbor::to_vec(self)?;
+        Ok(s)
+    }
+
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {
+        let m: SensorCalibration = serde_cbor::from_slice(v.as_slice())?;
+        Ok(m)
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Status {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<bool>,
+}
+
+impl Msg for Status {
+    const ID: u32 = 5672;
+    const NAME: &'static str = "Status";
+
+    fn serialize(&self) -> Result<Vec<u8>> {
+        // Use serde_cbor to produce CBOR bytes
+        let s = serde_cbor::to_vec(self)?;
+        Ok(s)
+    }
+
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {
+        let m: Status = serde_cbor::from_slice(v.as_slice())?;
+        Ok(m)
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Sensor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<SensorKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibration: Option<SensorCalibration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<Measurement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<std::collections::BTreeMap<String,i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<std::collections::BTreeMap<u32,SensorCalibration>>,
+    pub history: Vec<Measurement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<std::collections::BTreeMap<String,Status>>,
+}
+
+impl Msg for Sensor {
+    const ID: u32 = 63274;
+    const NAME: &'static str = "Sensor";
+
+    fn serialize(&self) -> Result<Vec<u8>> {
+        // Use serde_cbor to produce CBOR bytes
+        let s = serde_cbor::to_vec(self)?;
+        Ok(s)
+    }
+
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {
+        let m: Sensor = serde_cbor::from_slice(v.as_slice())?;
+        Ok(m)
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Command {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reboot_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibrate: Option<SensorCalibration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firmware: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_unit: Option<Unit>,
+}
+
+impl Msg for Command {
+    const ID: u32 = 34907;
+    const NAME: &'static str = "Command";
+
+    fn serialize(&self) -> Result<Vec<u8>> {
+        // Use serde_cbor to produce CBOR bytes
+        let s = serde_cbor::to_vec(self)?;
+        Ok(s)
+    }
+
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {
+        let m: Command = serde_cbor::from_slice(v.as_slice())?;
+        Ok(m)
+    }
+}
+
+
+/// The members of oneof action in Command
+#[derive(Debug, Clone)]
+pub enum CommandAction {
+    RebootReason(String),
+    Calibrate(SensorCalibration),
+    Firmware(Vec<u8>),
+    SetUnit(Unit),
+}
+
+impl Command {
+    /// The member of action that is set, the first one when a sender set several
+    pub fn action(&self) -> Option<CommandAction> {
+        if let Some(value) = &self.reboot_reason {
+            return Some(CommandAction::RebootReason(value.clone()));
+        }
+        if let Some(value) = &self.calibrate {
+            return Some(CommandAction::Calibrate(value.clone()));
+        }
+        if let Some(value) = &self.firmware {
+            return Some(CommandAction::Firmware(value.clone()));
+        }
+        if let Some(value) = &self.set_unit {
+            return Some(CommandAction::SetUnit(value.clone()));
+        }
+        None
+    }
+
+    /// Sets one member of action and clears the others
+    pub fn set_action(&mut self, value: CommandAction) {
+        self.reboot_reason = None;
+        self.calibrate = None;
+        self.firmware = None;
+        self.set_unit = None;
+        match value {
+            CommandAction::RebootReason(value) => self.reboot_reason = Some(value),
+            CommandAction::Calibrate(value) => self.calibrate = Some(value),
+            CommandAction::Firmware(value) => self.firmware = Some(value),
+            CommandAction::SetUnit(value) => self.set_unit = Some(value),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+use anyhow::Result;
+use crate::value::Value;
+
+pub trait Msg {
+    const ID: u32;
+    const NAME: &'static str;
+    fn serialize(&self) -> Result<Vec<u8>>;
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Status {
+    Ok,
+    Failed,
+}
+
+
+
+
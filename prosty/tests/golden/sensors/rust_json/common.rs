@@ -0,0 +1,45 @@
+use serde::{Serialize, Deserialize};
+use anyhow::Result;
+
+pub trait Msg {
+    const ID: u32;
+    const NAME: &'static str;
+    fn serialize(&self) -> Result<Vec<u8>>;
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+
+#[derive(Debug, Clone,Serialize,Deserialize)] 
+pub enum Unit {
+    None,
+    Meter,
+    Second,
+} 
+
+
+
+#[derive(Debug, Clone,Serialize,Deserialize,Default)]
+pub struct Measurement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value:Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit:Option<Unit>,
+        
+
+}
+impl Msg for Measurement {
+     const ID: u32 = 9814;
+     const NAME: &'static str = "Measurement";
+
+    fn serialize(&self) -> Result<Vec<u8>> {
+        let s = serde_json::to_vec(self) ?;
+        Ok(s)
+    }
+     
+    fn deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {
+        let m:Measurement = serde_json::from_slice(v.as_slice()) ?;
+        Ok(m)
+        }
+}
+
+    
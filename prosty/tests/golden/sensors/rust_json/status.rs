@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+use anyhow::Result;
+
+pub trait Msg {
+    const ID: u32;
+    const NAME: &'static str;
+    fn serialize(&self) -> Result<Vec<u8>>;
+    fn deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+
+#[derive(Debug, Clone,Serialize,Deserialize)] 
+pub enum Status {
+    Ok,
+    Failed,
+} 
+
+
@@ -0,0 +1,93 @@
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use anyhow::Result;
+use minicbor::{Encode, Decode};
+
+pub trait TypedMessage : DeserializeOwned + Send + Sync +'static{
+    const ID: u32;
+    const MSG_TYPE: &'static str;
+    const FIELDS: &'static [FieldDescriptor];
+}
+pub trait Msg  : Send + Sync {
+    fn type_name(&self) -> &'static str ;
+    fn type_id(&self) -> u32 ;
+    fn cbor_serialize(&self) -> Result<Vec<u8>>;
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+    fn json_serialize(&self) -> Result<Vec<u8>>;
+    fn json_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+/// Field as declared in the .proto, `type_name` is the Rust type of one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub index: u32,
+    pub type_name: &'static str,
+    pub repeated: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub fields: &'static [FieldDescriptor],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumDescriptor {
+    pub name: &'static str,
+    pub values: &'static [(&'static str, u32)],
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
+pub enum Unit {
+    #[n(0)]
+    None,
+    #[n(1)]
+    Meter,
+    #[n(2)]
+    Second,
+}
+
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct Measurement {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<Unit>,
+}
+
+impl TypedMessage for Measurement {
+    const ID: u32 = 9814;
+    const MSG_TYPE: &'static str = "Measurement";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "value", index: 1, type_name: "f64", repeated: false, optional: true },
+        FieldDescriptor { name: "unit", index: 2, type_name: "Unit", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for Measurement {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+
+pub const MESSAGES: &[MessageDescriptor] = &[
+    MessageDescriptor { name: Measurement::MSG_TYPE, id: Measurement::ID, fields: Measurement::FIELDS },
+];
+
+pub const ENUMS: &[EnumDescriptor] = &[
+    EnumDescriptor { name: "Unit", values: &[("None", 0), ("Meter", 1), ("Second", 2)] },
+];
@@ -0,0 +1,262 @@
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use anyhow::Result;
+use minicbor::{Encode, Decode};
+use super::common::*;
+
+pub trait TypedMessage : DeserializeOwned + Send + Sync +'static{
+    const ID: u32;
+    const MSG_TYPE: &'static str;
+    const FIELDS: &'static [FieldDescriptor];
+}
+pub trait Msg  : Send + Sync {
+    fn type_name(&self) -> &'static str ;
+    fn type_id(&self) -> u32 ;
+    fn cbor_serialize(&self) -> Result<Vec<u8>>;
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+    fn json_serialize(&self) -> Result<Vec<u8>>;
+    fn json_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+/// Field as declared in the .proto, `type_name` is the Rust type of one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub index: u32,
+    pub type_name: &'static str,
+    pub repeated: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub fields: &'static [FieldDescriptor],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumDescriptor {
+    pub name: &'static str,
+    pub values: &'static [(&'static str, u32)],
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
+pub enum SensorKind {
+    #[n(0)]
+    Temperature,
+    #[n(1)]
+    Distance,
+}
+
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct SensorCalibration {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<f32>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f32>,
+}
+
+impl TypedMessage for SensorCalibration {
+    const ID: u32 = 47200;
+    const MSG_TYPE: &'static str = "SensorCalibration";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "offset", index: 1, type_name: "f32", repeated: false, optional: true },
+        FieldDescriptor { name: "scale", index: 2, type_name: "f32", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for SensorCalibration {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct Status {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<bool>,
+}
+
+impl TypedMessage for Status {
+    const ID: u32 = 5672;
+    const MSG_TYPE: &'static str = "Status";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "ok", index: 1, type_name: "bool", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for Status {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct Sensor {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<SensorKind>,
+    #[n(3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibration: Option<SensorCalibration>,
+    #[n(4)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<Measurement>,
+    #[n(5)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<std::collections::BTreeMap<String,i32>>,
+    #[n(6)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<std::collections::BTreeMap<u32,SensorCalibration>>,
+    #[n(7)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<Measurement>>,
+    #[n(8)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[n(9)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<std::collections::BTreeMap<String,Status>>,
+}
+
+impl TypedMessage for Sensor {
+    const ID: u32 = 63274;
+    const MSG_TYPE: &'static str = "Sensor";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "name", index: 1, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "kind", index: 2, type_name: "SensorKind", repeated: false, optional: true },
+        FieldDescriptor { name: "calibration", index: 3, type_name: "SensorCalibration", repeated: false, optional: true },
+        FieldDescriptor { name: "last", index: 4, type_name: "Measurement", repeated: false, optional: true },
+        FieldDescriptor { name: "limits", index: 5, type_name: "std::collections::BTreeMap<String,i32>", repeated: false, optional: true },
+        FieldDescriptor { name: "ranges", index: 6, type_name: "std::collections::BTreeMap<u32,SensorCalibration>", repeated: false, optional: true },
+        FieldDescriptor { name: "history", index: 7, type_name: "Measurement", repeated: true, optional: false },
+        FieldDescriptor { name: "status", index: 8, type_name: "Status", repeated: false, optional: true },
+        FieldDescriptor { name: "statuses", index: 9, type_name: "std::collections::BTreeMap<String,Status>", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for Sensor {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default,Encode, Decode)]
+#[cbor(map)]
+pub struct Command {
+    #[n(1)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+    #[n(2)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reboot_reason: Option<String>,
+    #[n(3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibrate: Option<SensorCalibration>,
+    #[cbor(n(4), with = "minicbor::bytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firmware: Option<Vec<u8>>,
+    #[n(5)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_unit: Option<Unit>,
+}
+
+impl TypedMessage for Command {
+    const ID: u32 = 34907;
+    const MSG_TYPE: &'static str = "Command";
+    const FIELDS: &'static [FieldDescriptor] = &[
+        FieldDescriptor { name: "seq", index: 1, type_name: "u32", repeated: false, optional: true },
+        FieldDescriptor { name: "reboot_reason", index: 2, type_name: "String", repeated: false, optional: true },
+        FieldDescriptor { name: "calibrate", index: 3, type_name: "SensorCalibration", repeated: false, optional: true },
+        FieldDescriptor { name: "firmware", index: 4, type_name: "Vec<u8>", repeated: false, optional: true },
+        FieldDescriptor { name: "set_unit", index: 5, type_name: "Unit", repeated: false, optional: true },
+    ];
+}
+
+impl Msg for Command {
+    fn type_name(&self) -> &'static str {<Self as TypedMessage>::MSG_TYPE}
+    fn type_id(&self) -> u32 {<Self as TypedMessage>::ID}
+    fn cbor_serialize(&self) -> Result<Vec<u8>> {Ok(minicbor::to_vec(self)?)}
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized {Ok(minicbor::decode::<Self>(v.as_slice())?)}
+    fn json_serialize(&self) -> Result<Vec<u8>> {Ok(serde_json::to_vec(self) ?)}
+    fn json_deserialize(v:& Vec<u8>) -> Result<Self> where Self : Sized {Ok(serde_json::from_slice(v.as_slice()) ?)}
+}
+    
+
+/// The members of oneof action in Command
+#[derive(Debug, Clone)]
+pub enum CommandAction {
+    RebootReason(String),
+    Calibrate(SensorCalibration),
+    Firmware(Vec<u8>),
+    SetUnit(Unit),
+}
+
+impl Command {
+    /// The member of action that is set, the first one when a sender set several
+    pub fn action(&self) -> Option<CommandAction> {
+        if let Some(value) = &self.reboot_reason {
+            return Some(CommandAction::RebootReason(value.clone()));
+        }
+        if let Some(value) = &self.calibrate {
+            return Some(CommandAction::Calibrate(value.clone()));
+        }
+        if let Some(value) = &self.firmware {
+            return Some(CommandAction::Firmware(value.clone()));
+        }
+        if let Some(value) = &self.set_unit {
+            return Some(CommandAction::SetUnit(value.clone()));
+        }
+        None
+    }
+
+    /// Sets one member of action and clears the others
+    pub fn set_action(&mut self, value: CommandAction) {
+        self.reboot_reason = None;
+        self.calibrate = None;
+        self.firmware = None;
+        self.set_unit = None;
+        match value {
+            CommandAction::RebootReason(value) => self.reboot_reason = Some(value),
+            CommandAction::Calibrate(value) => self.calibrate = Some(value),
+            CommandAction::Firmware(value) => self.firmware = Some(value),
+            CommandAction::SetUnit(value) => self.set_unit = Some(value),
+        }
+    }
+}
+
+pub const MESSAGES: &[MessageDescriptor] = &[
+    MessageDescriptor { name: SensorCalibration::MSG_TYPE, id: SensorCalibration::ID, fields: SensorCalibration::FIELDS },
+    MessageDescriptor { name: Status::MSG_TYPE, id: Status::ID, fields: Status::FIELDS },
+    MessageDescriptor { name: Sensor::MSG_TYPE, id: Sensor::ID, fields: Sensor::FIELDS },
+    MessageDescriptor { name: Command::MSG_TYPE, id: Command::ID, fields: Command::FIELDS },
+];
+
+pub const ENUMS: &[EnumDescriptor] = &[
+    EnumDescriptor { name: "SensorKind", values: &[("Temperature", 0), ("Distance", 1)] },
+];
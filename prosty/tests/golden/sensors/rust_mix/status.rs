@@ -0,0 +1,60 @@
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use anyhow::Result;
+use minicbor::{Encode, Decode};
+
+pub trait TypedMessage : DeserializeOwned + Send + Sync +'static{
+    const ID: u32;
+    const MSG_TYPE: &'static str;
+    const FIELDS: &'static [FieldDescriptor];
+}
+pub trait Msg  : Send + Sync {
+    fn type_name(&self) -> &'static str ;
+    fn type_id(&self) -> u32 ;
+    fn cbor_serialize(&self) -> Result<Vec<u8>>;
+    fn cbor_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+    fn json_serialize(&self) -> Result<Vec<u8>>;
+    fn json_deserialize(v:&Vec<u8>) -> Result<Self> where Self : Sized;
+}
+
+/// Field as declared in the .proto, `type_name` is the Rust type of one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub index: u32,
+    pub type_name: &'static str,
+    pub repeated: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub fields: &'static [FieldDescriptor],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumDescriptor {
+    pub name: &'static str,
+    pub values: &'static [(&'static str, u32)],
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,Encode, Decode)]
+pub enum Status {
+    #[n(0)]
+    Ok,
+    #[n(1)]
+    Failed,
+}
+
+
+
+
+pub const MESSAGES: &[MessageDescriptor] = &[
+];
+
+pub const ENUMS: &[EnumDescriptor] = &[
+    EnumDescriptor { name: "Status", values: &[("Ok", 0), ("Failed", 1)] },
+];
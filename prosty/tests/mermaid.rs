@@ -0,0 +1,162 @@
+//! Renders state diagrams with the mermaid binary and compares the code with the files in
+//! tests/golden/mermaid, run with PROSTY_BLESS=1 to write the current output as the new
+//! golden files. The golden files are modules of this test, so the generated code compiles.
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[path = "golden/mermaid/car.rs"]
+mod car;
+#[path = "golden/mermaid/states.rs"]
+mod states;
+
+fn check(diagram: &str, name: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("mermaid_{name}"));
+    let _ = fs::remove_dir_all(&output);
+    fs::create_dir_all(&output).unwrap();
+    // the template is loaded from src/ relative to the working directory
+    let status = Command::new(env!("CARGO_BIN_EXE_mermaid"))
+        .current_dir(root)
+        .args(["-i", diagram, "-o", output.to_str().unwrap(), "-n", name])
+        .status()
+        .unwrap();
+    assert!(status.success(), "mermaid failed for {diagram}");
+
+    let actual = fs::read_to_string(output.join(format!("{name}.rs"))).unwrap();
+    let golden = root.join("tests/golden/mermaid").join(format!("{name}.rs"));
+    if std::env::var_os("PROSTY_BLESS").is_some() {
+        fs::write(&golden, &actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&golden)
+        .unwrap_or_else(|_| panic!("missing golden file {}", golden.display()));
+    assert!(
+        actual == expected,
+        "{} differs from {}, rerun with PROSTY_BLESS=1 if the change is intended",
+        output.display(),
+        golden.display()
+    );
+}
+
+#[test]
+fn states_md() {
+    check("syntax/states.md", "states");
+}
+
+#[test]
+fn guards_actions_and_completion() {
+    check("tests/mermaid/car.md", "car");
+}
+
+struct Car {
+    trace: Vec<String>,
+    engine: bool,
+}
+
+impl car::Guards for Car {
+    fn engine_ok(&self) -> bool {
+        self.engine
+    }
+}
+
+impl car::Actions for Car {
+    fn on_entry(&mut self, state: car::State) {
+        self.trace.push(format!("enter {}", state.name()))
+    }
+    fn on_exit(&mut self, state: car::State) {
+        self.trace.push(format!("exit {}", state.name()))
+    }
+    fn start_engine(&mut self) {
+        self.trace.push("start_engine".to_string())
+    }
+    fn stop_engine(&mut self) {
+        self.trace.push("stop_engine".to_string())
+    }
+    fn beep(&mut self) {
+        self.trace.push("beep".to_string())
+    }
+}
+
+#[test]
+fn generated_car_runs_entry_exit_and_actions_in_order() {
+    use car::{Event, State, transition};
+    let mut car = Car {
+        trace: vec![],
+        engine: false,
+    };
+    let state = car::start(&mut car);
+    assert_eq!(state, State::Still);
+    assert_eq!(transition(&mut car, state, Event::StartMoving), None);
+
+    car.engine = true;
+    car.trace.clear();
+    let state = transition(&mut car, state, Event::StartMoving).unwrap();
+    assert_eq!(state, State::Accelerating);
+    assert_eq!(
+        car.trace,
+        [
+            "exit Still",
+            "start_engine",
+            "enter Moving",
+            "enter Accelerating"
+        ]
+    );
+
+    // the self transition of a composite leaves and re-enters it
+    let state = transition(&mut car, state, Event::Speed60).unwrap();
+    car.trace.clear();
+    let state = transition(&mut car, state, Event::Honk).unwrap();
+    assert_eq!(state, State::Accelerating);
+    assert_eq!(
+        car.trace,
+        [
+            "exit Cruising",
+            "exit Moving",
+            "beep",
+            "enter Moving",
+            "enter Accelerating"
+        ]
+    );
+
+    // reaching MovingFinal takes the completion transition Moving --> Still
+    let state = transition(&mut car, state, Event::Speed60).unwrap();
+    let state = transition(&mut car, state, Event::ObstacleDetected).unwrap();
+    car.trace.clear();
+    let state = transition(&mut car, state, Event::Stopped).unwrap();
+    assert_eq!(state, State::Still);
+    assert_eq!(
+        car.trace,
+        [
+            "exit Braking",
+            "enter MovingFinal",
+            "exit MovingFinal",
+            "exit Moving",
+            "enter Still"
+        ]
+    );
+
+    let state = transition(&mut car, state, Event::StartMoving).unwrap();
+    let state = transition(&mut car, state, Event::Crash).unwrap();
+    assert_eq!(transition(&mut car, state, Event::Tow), Some(State::Final));
+    assert!(State::Cruising.is_in(State::Moving));
+}
+
+struct Mower;
+impl states::Guards for Mower {}
+impl states::Actions for Mower {}
+
+#[test]
+fn generated_states_md_enters_nested_initial_states() {
+    use states::{Event, State, transition};
+    let mut mower = Mower;
+    let state = states::start(&mut mower);
+    assert_eq!(state, State::Idle);
+    let state = transition(&mut mower, state, Event::StartAutonomousMode).unwrap();
+    assert_eq!(state, State::Cutting);
+    let state = transition(&mut mower, state, Event::Collision).unwrap();
+    assert_eq!(state, State::FollowAvoidancePath);
+    // handled by Top, two composites up
+    let state = transition(&mut mower, state, Event::StartManualMode).unwrap();
+    assert_eq!(state, State::HumanDriving);
+}
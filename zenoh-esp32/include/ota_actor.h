@@ -2,16 +2,20 @@
 #include <driver/gpio.h>
 #include <soc/gpio_num.h>
 #include <esp_ota_ops.h>
+#include <mbedtls/sha256.h>
 
 #ifndef GPIO_LED
 #define GPIO_LED GPIO_NUM_2
 #endif
 
+// OTA_END verifies the SHA-256 of the received image, OTA_COMMIT makes it the boot image
 typedef enum {
     OTA_BEGIN =0,
     OTA_END,
     OTA_WRITE,
-
+    OTA_COMMIT,
+    OTA_ABORT,
+    OTA_ROLLBACK,
 } OtaOperation;
 
 struct OtaMsg : public Serializable
@@ -23,6 +27,8 @@ struct OtaMsg : public Serializable
     Option<std::string> message = nullptr;
     Option<std::string> reply_to = nullptr;
     Option<std::string> partition_label = nullptr;
+    Option<uint32_t> image_size = nullptr;
+    Option<Bytes> sha256 = nullptr;
 
     Res serialize(Serializer &ser) const;
     Res deserialize(Deserializer &des);
@@ -43,8 +49,16 @@ struct OtaCmd
 class OtaActor : public Actor<OtaEvent, OtaCmd>
 {
     int _timer_publish = -1;
-    esp_ota_handle_t _ota_handle;
-    esp_partition_t *_update_partition;
+    int _timer_restart = -1;
+    esp_ota_handle_t _ota_handle = 0;
+    const esp_partition_t *_update_partition = nullptr;
+    // the upload in progress, an OTA_BEGIN for the same image resumes at _received
+    bool _in_progress = false;
+    bool _verified = false;
+    uint32_t _image_size = 0;
+    uint32_t _received = 0; // bytes written without a gap from the start
+    Bytes _sha256;
+    mbedtls_sha256_context _sha_ctx; // over the first _received bytes
 
 public:
     OtaActor();
@@ -54,7 +68,10 @@ public:
     void on_timer(int timer_id);
     void on_start();
  //   Res flash(const uint8_t *data, size_t size);
-    Res ota_begin();
+    Res ota_begin(uint32_t image_size, const Bytes &sha256);
     Res ota_end();
     Res ota_write(uint32_t offset,const Bytes& bytes );
+    Res ota_commit();
+    Res ota_abort();
+    Res ota_rollback();
 };
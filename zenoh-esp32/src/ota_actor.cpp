@@ -6,10 +6,14 @@ OtaActor::OtaActor() : OtaActor("led", 4096, 5, 5) {}
 OtaActor::OtaActor(const char *name, size_t stack_size, int priority, size_t queue_depth) : Actor<OtaEvent, OtaCmd>(stack_size, name, priority, queue_depth)
 {
     _timer_publish = timer_repetitive(5000);
+    _timer_restart = timer_one_shot(1000);
+    timer_stop(_timer_restart);
+    mbedtls_sha256_init(&_sha_ctx);
 }
 
 OtaActor::~OtaActor()
 {
+    mbedtls_sha256_free(&_sha_ctx);
 }
 
 void OtaActor::on_cmd(OtaCmd &cmd)
@@ -17,10 +21,14 @@ void OtaActor::on_cmd(OtaCmd &cmd)
     Res result;
     cmd.publish.for_each([&](auto msg){
         msg.operation.for_each([&](auto operation){
-            if (operation == OTA_BEGIN)
+            OtaMsg reply;
+            reply.offset = msg.offset;
+            if (operation == OTA_BEGIN && msg.image_size && msg.sha256)
             {
-                INFO("OTA_BEGIN");
-                result = ota_begin();
+                INFO("OTA_BEGIN size %d", *msg.image_size);
+                result = ota_begin(*msg.image_size, msg.sha256.ref());
+                // how much of this image is already written, the upload resumes there
+                reply.offset = _received;
             }
             else if (operation == OTA_END)
             {
@@ -30,17 +38,33 @@ void OtaActor::on_cmd(OtaCmd &cmd)
             else if (operation == OTA_WRITE && msg.offset && msg.image)
             {
                 INFO("OTA_WRITE offset %d [%d]", *msg.offset, (*msg.image).size());
+                // a chunk after a gap is not acked, the uploader resends the missing one first
+                if (*msg.offset > _received && _in_progress)
+                    return;
                 result = ota_write(*msg.offset, msg.image.ref());
             }
+            else if (operation == OTA_COMMIT)
+            {
+                INFO("OTA_COMMIT");
+                result = ota_commit();
+            }
+            else if (operation == OTA_ABORT)
+            {
+                INFO("OTA_ABORT");
+                result = ota_abort();
+            }
+            else if (operation == OTA_ROLLBACK)
+            {
+                INFO("OTA_ROLLBACK");
+                result = ota_rollback();
+            }
             else
             {
                 INFO(" invalid message ");
                 result = Res(-1, "invalid message ");
             }
-            OtaMsg reply;
             reply.rc = result.rc();
             reply.message = result.msg();
-            reply.offset = msg.offset;
             reply.operation = msg.operation;
             reply.reply_to = msg.reply_to;
             emit(OtaEvent{.publish = reply});
@@ -51,6 +75,11 @@ void OtaActor::on_cmd(OtaCmd &cmd)
 
 void OtaActor::on_timer(int timer_id)
 {
+    if (timer_id == _timer_restart)
+    {
+        INFO("Restarting into the new image");
+        esp_restart();
+    }
     const esp_partition_t *part = esp_ota_get_running_partition();
     OtaMsg event;
     event.partition_label = part->label;
@@ -61,32 +90,107 @@ void OtaActor::on_start()
 {
 }
 
-Res OtaActor::ota_begin()
+Res OtaActor::ota_begin(uint32_t image_size, const Bytes &sha256)
 {
-    _update_partition = (esp_partition_t *)esp_ota_get_next_update_partition(NULL);
+    if (_in_progress && _image_size == image_size && _sha256 == sha256)
+    {
+        INFO("Resuming upload at %d", _received);
+        return ResOk;
+    }
+    if (_in_progress)
+        ota_abort();
+    _update_partition = esp_ota_get_next_update_partition(NULL);
     if (_update_partition == NULL)
     {
         ERROR("Unable to get next OTA partition");
         return Res(EBADF, "Unable to get next OTA partition");
     }
+    if (image_size > _update_partition->size)
+        return Res(EFBIG, "image larger than partition");
     //    INFO("Writing firmware to partition: %s", _update_partition->label);
-    CHECK_ESP(esp_ota_begin(_update_partition, OTA_SIZE_UNKNOWN, &_ota_handle));
+    CHECK_ESP(esp_ota_begin(_update_partition, image_size, &_ota_handle));
+    CHECK(mbedtls_sha256_starts(&_sha_ctx, 0));
+    _in_progress = true;
+    _verified = false;
+    _image_size = image_size;
+    _received = 0;
+    _sha256 = sha256;
     return ResOk;
 }
 
+// verifies the image, it only becomes bootable with OTA_COMMIT
 Res OtaActor::ota_end()
 {
-    CHECK(esp_ota_end(_ota_handle));
+    if (_verified)
+        return ResOk;
+    if (!_in_progress)
+        return Res(ENOENT, "no OTA_BEGIN");
+    if (_received < _image_size)
+        return Res(EAGAIN, "image incomplete");
+    uint8_t digest[32];
+    CHECK(mbedtls_sha256_finish(&_sha_ctx, digest));
+    if (_sha256 != Bytes(digest, digest + sizeof(digest)))
+    {
+        ota_abort();
+        return Res(EINVAL, "sha256 mismatch");
+    }
+    _in_progress = false;
+    CHECK_ESP(esp_ota_end(_ota_handle));
+    _verified = true;
+    return ResOk;
+}
 
-    // Set the new firmware as bootable
+// writes are appended, a chunk already written is acked again without writing
+Res OtaActor::ota_write(uint32_t offset,const Bytes &data)
+{
+    if (!_in_progress)
+        return Res(ENOENT, "no OTA_BEGIN");
+    uint32_t end = offset + data.size();
+    if (end > _image_size)
+        return Res(EINVAL, "write beyond image size");
+    if (end <= _received)
+        return ResOk;
+    uint32_t skip = _received - offset;
+    CHECK_ESP(esp_ota_write(_ota_handle, data.data() + skip, data.size() - skip));
+    CHECK(mbedtls_sha256_update(&_sha_ctx, data.data() + skip, data.size() - skip));
+    _received = end;
+    return ResOk;
+}
+
+// the restart waits for the reply to leave
+Res OtaActor::ota_commit()
+{
+    if (!_verified)
+        return Res(EINVAL, "image not verified");
     CHECK_ESP(esp_ota_set_boot_partition(_update_partition));
-    INFO("OTA update successful, restarting...");
-    esp_restart();
+    INFO("OTA update committed, restarting...");
+    _verified = false;
+    timer_fire(_timer_restart, 1000);
+    return ResOk;
 }
 
-Res OtaActor::ota_write(uint32_t offset,const Bytes &data)
+Res OtaActor::ota_abort()
+{
+    if (_in_progress)
+        esp_ota_abort(_ota_handle);
+    _in_progress = false;
+    _verified = false;
+    _received = 0;
+    _sha256.clear();
+    return ResOk;
+}
+
+// boots the image in the other slot, the one that ran before the last commit
+Res OtaActor::ota_rollback()
 {
-    CHECK_ESP(esp_ota_write_with_offset(_ota_handle, data.data(), data.size(), offset));
+    ota_abort();
+    const esp_partition_t *previous = esp_ota_get_next_update_partition(NULL);
+    esp_app_desc_t description;
+    if (previous == NULL || esp_ota_get_partition_description(previous, &description) != ESP_OK)
+        return Res(ENOENT, "no previous image");
+    CHECK_ESP(esp_ota_set_boot_partition(previous));
+    INFO("Rolling back to %s, restarting...", description.version);
+    timer_fire(_timer_restart, 1000);
     return ResOk;
 }
 
@@ -129,6 +233,8 @@ Res OtaMsg::serialize(Serializer &ser) const
     ser.serialize(KEY("message"), message);
     ser.serialize(KEY("reply_to"), reply_to);
     ser.serialize(KEY("partition_label"), partition_label);
+    ser.serialize(KEY("image_size"), image_size);
+    ser.serialize(KEY("sha256"), sha256);
     ser.map_end();
     return ResOk;
 }
@@ -152,8 +258,14 @@ Res OtaMsg::deserialize(Deserializer &des)
             return d.deserialize(rc);
         case H("message"):
             return d.deserialize(message);
-        case H("partition_label"):
+        case H("reply_to"):
             return d.deserialize(reply_to);
+        case H("partition_label"):
+            return d.deserialize(partition_label);
+        case H("image_size"):
+            return d.deserialize(image_size);
+        case H("sha256"):
+            return d.deserialize(sha256);
         default:
             INFO("unknown key %d",key);
             return d.skip_next();
@@ -1,48 +1,272 @@
 use basu::async_trait;
-use basu::event;
-// src/main.rs
-
-use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::sync::Arc;
 use std::time::Duration;
-use std::time::Instant;
-use zenoh_linux_eventbus_rs::limero::LawnmowerManualCmd;
 
 use log::info;
-use log::warn;
 
+use crate::eventbus::{ActorImpl, ActorStart, ActorStop, Bus};
+use crate::hsm::{History, Hsm, HsmTimer, State, Transition};
+use crate::limero::{
+    HoverboardCmd, LawnmowerAutoCmd, LawnmowerManualCmd, LawnmowerMode, LawnmowerStatus,
+};
+
+/*
+   Mode machine of the lawnmower, see zenoh-brain/docs/HSM.drawio
+
+   Root
+   ├── Active         deep history, Paused resumes where it left off
+   │   ├── Manual     drives from LawnmowerManualCmd
+   │   └── Auto
+   │       ├── Cutting
+   │       └── Docking
+   ├── Paused         motors off, back to Manual when paused too long
+   └── EmergencyStop  motors off, left only by a manual start after ESTOP_HOLD
+
+   An emergency stop is a transition of Root so it is taken from every state.
+*/
+
+// --- CONSTANTS ---
+/// Time in EmergencyStop before a manual start is accepted
+pub const ESTOP_HOLD: Duration = Duration::from_secs(2);
+/// Pause after which the mower falls back to Manual instead of resuming
+pub const PAUSE_TIMEOUT: Duration = Duration::from_secs(300);
+/// Hoverboard speed for a LawnmowerManualCmd speed of 1.0
+pub const MAX_MOTOR_SPEED: f32 = 1000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MowerState {
+    Root,
+    Active,
+    Manual,
+    Auto,
+    Cutting,
+    Docking,
+    Paused,
+    EmergencyStop,
+}
+
+/// What the mode machine drives
+#[derive(Debug, Default)]
+pub struct Mower {
+    pub speed: f32,
+    pub steer: f32,
+    pub blade: bool,
+    /// the emergency stop was held long enough to be released
+    pub reset_allowed: bool,
+    pub error: Option<String>,
+}
 
-use crate::eventbus::ActorStart;
-use crate::eventbus::as_message;
-use crate::eventbus::on_message;
-use crate::eventbus::{ActorImpl, Bus};
+impl Mower {
+    fn drive(&mut self, bus: &Bus) {
+        bus.emit(HoverboardCmd {
+            speed: Some((self.speed * MAX_MOTOR_SPEED) as i32),
+            steer: Some((self.steer * MAX_MOTOR_SPEED) as i32),
+        });
+    }
+
+    fn stop(&mut self, bus: &Bus) {
+        self.speed = 0.0;
+        self.steer = 0.0;
+        self.blade = false;
+        self.drive(bus);
+    }
+}
+
+fn emergency_stop(cmd: &LawnmowerManualCmd) -> bool {
+    cmd.emergency_stop == Some(true)
+}
+
+fn auto_mode(cmd: &LawnmowerAutoCmd, mode: &str) -> bool {
+    cmd.mode.as_deref() == Some(mode)
+}
+
+pub fn mower_hsm() -> Hsm<MowerState, Mower> {
+    use MowerState::*;
+    Hsm::new("lawnmower", Root, Mower::default())
+        .state(Root, State::new().initial(Active))
+        .state(
+            Active,
+            State::new()
+                .parent(Root)
+                .initial(Manual)
+                .history(History::Deep),
+        )
+        .state(Manual, State::new().parent(Active))
+        .state(
+            Auto,
+            State::new()
+                .parent(Active)
+                .initial(Cutting)
+                .on_exit(|mower: &mut Mower, bus| mower.stop(bus)),
+        )
+        .state(
+            Cutting,
+            State::new()
+                .parent(Auto)
+                .on_entry(|mower: &mut Mower, _| mower.blade = true),
+        )
+        .state(
+            Docking,
+            State::new()
+                .parent(Auto)
+                .on_entry(|mower: &mut Mower, _| mower.blade = false),
+        )
+        .state(
+            Paused,
+            State::new()
+                .parent(Root)
+                .on_entry(|mower: &mut Mower, bus| mower.stop(bus)),
+        )
+        .state(
+            EmergencyStop,
+            State::new()
+                .parent(Root)
+                .on_entry(|mower: &mut Mower, bus| {
+                    mower.stop(bus);
+                    mower.reset_allowed = false;
+                    mower.error = Some("emergency stop".to_string());
+                })
+                .on_exit(|mower: &mut Mower, _| mower.error = None),
+        )
+        // from every state, again from EmergencyStop restarts the hold time. Leaf
+        // transitions are tried first, so every other guard on LawnmowerManualCmd
+        // refuses a command that also has emergency_stop set.
+        .transition(
+            Transition::on::<LawnmowerManualCmd>(Root)
+                .guard(|_, cmd: &LawnmowerManualCmd| emergency_stop(cmd))
+                .to(EmergencyStop),
+        )
+        .transition(
+            Transition::after(EmergencyStop, ESTOP_HOLD)
+                .action(|mower: &mut Mower, _: &HsmTimer, _| mower.reset_allowed = true),
+        )
+        .transition(
+            Transition::on::<LawnmowerManualCmd>(EmergencyStop)
+                .guard(|mower: &Mower, cmd: &LawnmowerManualCmd| {
+                    mower.reset_allowed
+                        && cmd.start_manual_control == Some(true)
+                        && !emergency_stop(cmd)
+                })
+                .to(Manual),
+        )
+        .transition(
+            Transition::on::<LawnmowerManualCmd>(Manual)
+                .guard(|_, cmd: &LawnmowerManualCmd| {
+                    cmd.start_auto_mode == Some(true) && !emergency_stop(cmd)
+                })
+                .to(Auto),
+        )
+        .transition(
+            Transition::on::<LawnmowerManualCmd>(Manual)
+                .guard(|_, cmd: &LawnmowerManualCmd| !emergency_stop(cmd))
+                .action(|mower: &mut Mower, cmd: &LawnmowerManualCmd, bus| {
+                    mower.speed = cmd.speed.unwrap_or(mower.speed);
+                    mower.steer = cmd.steer.unwrap_or(mower.steer);
+                    mower.blade = cmd.blade.unwrap_or(mower.blade);
+                    mower.drive(bus);
+                }),
+        )
+        .transition(
+            Transition::on::<LawnmowerManualCmd>(Auto)
+                .guard(|_, cmd: &LawnmowerManualCmd| {
+                    (cmd.stop_auto_mode == Some(true) || cmd.start_manual_control == Some(true))
+                        && !emergency_stop(cmd)
+                })
+                .to(Manual),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Manual)
+                .guard(|_, cmd: &LawnmowerAutoCmd| cmd.start == Some(true))
+                .to(Auto),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Auto)
+                .guard(|_, cmd: &LawnmowerAutoCmd| cmd.stop == Some(true))
+                .to(Manual),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Cutting)
+                .guard(|_, cmd: &LawnmowerAutoCmd| auto_mode(cmd, "dock"))
+                .to(Docking),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Docking)
+                .guard(|_, cmd: &LawnmowerAutoCmd| auto_mode(cmd, "cut"))
+                .to(Cutting),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Active)
+                .guard(|_, cmd: &LawnmowerAutoCmd| cmd.pause == Some(true))
+                .to(Paused),
+        )
+        .transition(
+            Transition::on::<LawnmowerAutoCmd>(Paused)
+                .guard(|_, cmd: &LawnmowerAutoCmd| cmd.resume == Some(true))
+                .to(Active),
+        )
+        .transition(Transition::after(Paused, PAUSE_TIMEOUT).to(Manual))
+}
 
 pub struct FsmActor {
     bus: Bus,
+    hsm: Hsm<MowerState, Mower>,
 }
 
 impl FsmActor {
     pub fn new(bus: Bus) -> Self {
         FsmActor {
             bus,
+            hsm: mower_hsm(),
+        }
+    }
 
+    pub fn state(&self) -> Option<MowerState> {
+        self.hsm.current()
+    }
+
+    pub fn mode(&self) -> LawnmowerMode {
+        if self.hsm.is_in(MowerState::EmergencyStop) {
+            LawnmowerMode::EmergencyStop
+        } else if self.hsm.is_in(MowerState::Paused) {
+            LawnmowerMode::Paused
+        } else if self.hsm.is_in(MowerState::Auto) {
+            LawnmowerMode::Auto
+        } else {
+            LawnmowerMode::Manual
         }
     }
 
-    fn manual_cmd_handler(&mut self, cmd: &LawnmowerManualCmd) {
-        info!("FsmActor received LawnmowerManualCmd: {:?}", cmd);
-        // Handle the command and update FSM states as needed
+    pub fn mower(&self) -> &Mower {
+        self.hsm.context()
+    }
+
+    pub fn status(&self) -> LawnmowerStatus {
+        LawnmowerStatus {
+            battery_level: None,
+            blade_status: Some(self.mower().blade),
+            current_mode: Some(format!("{:?}", self.mode())),
+            error_message: self.mower().error.clone(),
+        }
     }
 
-    pub fn on_start(&mut self, start: &ActorStart) {
-        info!("FsmActor started");
+    fn on_start(&mut self, start: &ActorStart) {
+        self.bus = start.bus.clone();
+        self.hsm.start(self.bus.clone());
+        info!("FsmActor started in {:?}", self.state());
+        self.bus.emit(self.status());
     }
 }
 
 #[async_trait]
 impl ActorImpl for FsmActor {
-    async fn handle(&mut self, msg: &Arc<dyn std::any::Any + Send + Sync>) {
-        as_message::<LawnmowerManualCmd>(msg).map(|cmd| self.manual_cmd_handler(cmd));
-        as_message::<ActorStart>(msg).map(|ev| self.on_start(ev));
+    async fn handle(&mut self, msg: &Arc<dyn Any + Send + Sync>) {
+        if let Some(start) = msg.downcast_ref::<ActorStart>() {
+            self.on_start(start);
+        } else if msg.is::<ActorStop>() {
+            self.hsm.stop();
+        } else if self.hsm.dispatch(msg) {
+            self.bus.emit(self.status());
+        }
     }
 }
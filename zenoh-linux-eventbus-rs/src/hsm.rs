@@ -0,0 +1,466 @@
+use basu::async_trait;
+use log::{debug, info};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::eventbus::{ActorImpl, ActorStart, ActorStop, Bus};
+
+/*
+   Hierarchical state machine driven by the messages of the eventbus.
+
+   - states form a tree through their parent, a composite state enters its initial child, or
+     with a history the child ( Shallow ) or leaf ( Deep ) that was active when it was left
+   - a message is offered to the active leaf first, then to its ancestors : the first
+     transition on the message type whose guard passes is taken
+   - exit actions run leaf first, then the transition action, then the entry actions
+   - a timer transition is armed on entry of its source state and disarmed on exit, when it
+     expires an HsmTimer goes around the bus so it is handled like any other message
+*/
+
+pub type Message = Arc<dyn Any + Send + Sync>;
+type Action<C> = Box<dyn Fn(&mut C, &Bus) + Send + Sync>;
+type EventAction<C> = Box<dyn Fn(&mut C, &(dyn Any + Send + Sync), &Bus) + Send + Sync>;
+type Guard<C> = Box<dyn Fn(&C, &(dyn Any + Send + Sync)) -> bool + Send + Sync>;
+
+/// Which substate a composite state resumes when it is entered again
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum History {
+    /// always the initial child
+    #[default]
+    None,
+    /// the direct child that was active
+    Shallow,
+    /// the leaf that was active, at any depth
+    Deep,
+}
+
+pub struct State<S, C> {
+    parent: Option<S>,
+    initial: Option<S>,
+    history: History,
+    entry: Option<Action<C>>,
+    exit: Option<Action<C>>,
+}
+
+impl<S, C> Default for State<S, C> {
+    fn default() -> Self {
+        State {
+            parent: None,
+            initial: None,
+            history: History::None,
+            entry: None,
+            exit: None,
+        }
+    }
+}
+
+impl<S, C> State<S, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parent(mut self, parent: S) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Child entered when the state is the target of a transition, makes it composite
+    pub fn initial(mut self, initial: S) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    pub fn history(mut self, history: History) -> Self {
+        self.history = history;
+        self
+    }
+
+    pub fn on_entry<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&mut C, &Bus) + Send + Sync + 'static,
+    {
+        self.entry = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_exit<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&mut C, &Bus) + Send + Sync + 'static,
+    {
+        self.exit = Some(Box::new(f));
+        self
+    }
+}
+
+enum Trigger {
+    Message(TypeId),
+    After(Duration),
+}
+
+pub struct Transition<S, C> {
+    source: S,
+    trigger: Trigger,
+    target: Option<S>,
+    guard: Option<Guard<C>>,
+    action: Option<EventAction<C>>,
+}
+
+impl<S, C> Transition<S, C> {
+    /// Taken on a message of type `E` while `source` is active
+    pub fn on<E: Any>(source: S) -> Self {
+        Transition {
+            source,
+            trigger: Trigger::Message(TypeId::of::<E>()),
+            target: None,
+            guard: None,
+            action: None,
+        }
+    }
+
+    /// Taken when `source` stays active for `delay`, the message is an HsmTimer
+    pub fn after(source: S, delay: Duration) -> Self {
+        Transition {
+            source,
+            trigger: Trigger::After(delay),
+            target: None,
+            guard: None,
+            action: None,
+        }
+    }
+
+    /// Without a target the transition is internal : only the action runs
+    pub fn to(mut self, target: S) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// `E` is the message type of the trigger, the guard fails for any other type
+    pub fn guard<E, F>(mut self, f: F) -> Self
+    where
+        E: Any,
+        F: Fn(&C, &E) -> bool + Send + Sync + 'static,
+    {
+        self.guard = Some(Box::new(move |context, msg| {
+            msg.downcast_ref::<E>().is_some_and(|e| f(context, e))
+        }));
+        self
+    }
+
+    pub fn action<E, F>(mut self, f: F) -> Self
+    where
+        E: Any,
+        F: Fn(&mut C, &E, &Bus) + Send + Sync + 'static,
+    {
+        self.action = Some(Box::new(move |context, msg, bus| {
+            if let Some(e) = msg.downcast_ref::<E>() {
+                f(context, e, bus)
+            }
+        }));
+        self
+    }
+}
+
+/// Expiry of a timer transition, only the machine that armed it acts on it
+#[derive(Debug, Clone)]
+pub struct HsmTimer {
+    pub machine: String,
+    pub transition: usize,
+    pub generation: u64,
+}
+
+pub struct Hsm<S, C> {
+    name: String,
+    states: HashMap<S, State<S, C>>,
+    transitions: Vec<Transition<S, C>>,
+    initial: S,
+    // active states, root first
+    active: Vec<S>,
+    // composite state -> child or leaf to resume
+    history: HashMap<S, S>,
+    // timer transition -> generation of the running timer
+    armed: HashMap<usize, u64>,
+    generation: u64,
+    bus: Option<Bus>,
+    context: C,
+}
+
+impl<S, C> Hsm<S, C>
+where
+    S: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    C: Send + 'static,
+{
+    pub fn new(name: &str, initial: S, context: C) -> Self {
+        Hsm {
+            name: name.to_string(),
+            states: HashMap::new(),
+            transitions: Vec::new(),
+            initial,
+            active: Vec::new(),
+            history: HashMap::new(),
+            armed: HashMap::new(),
+            generation: 0,
+            bus: None,
+            context,
+        }
+    }
+
+    pub fn state(mut self, id: S, state: State<S, C>) -> Self {
+        self.states.insert(id, state);
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition<S, C>) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Enter the initial state, actions and timers emit on `bus`
+    pub fn start(&mut self, bus: Bus) {
+        self.bus = Some(bus);
+        self.exit_to(0);
+        for state in self.path_to(self.initial) {
+            self.enter(state);
+        }
+        self.enter_substates(self.initial);
+        info!("{} started in {:?}", self.name, self.active);
+    }
+
+    /// Exit all states, the machine no longer handles messages until started again
+    pub fn stop(&mut self) {
+        self.exit_to(0);
+    }
+
+    /// Returns true when a transition was taken
+    pub fn dispatch(&mut self, msg: &Message) -> bool {
+        if self.bus.is_none() {
+            return false;
+        }
+        let index = match msg.downcast_ref::<HsmTimer>() {
+            Some(timer) if timer.machine == self.name => {
+                if self.armed.get(&timer.transition) != Some(&timer.generation) {
+                    return false;
+                }
+                self.armed.remove(&timer.transition);
+                self.transitions[timer.transition]
+                    .guard
+                    .as_ref()
+                    .is_none_or(|guard| guard(&self.context, msg.as_ref()))
+                    .then_some(timer.transition)
+            }
+            _ => self.select(msg),
+        };
+        match index {
+            Some(index) => {
+                self.fire(index, msg);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The active leaf state
+    pub fn current(&self) -> Option<S> {
+        self.active.last().copied()
+    }
+
+    /// True when `state` or one of its substates is active
+    pub fn is_in(&self, state: S) -> bool {
+        self.active.contains(&state)
+    }
+
+    /// Active states, outermost first
+    pub fn active(&self) -> &[S] {
+        &self.active
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    /*
+       first transition of the active states on this message type whose guard passes, leaf first
+    */
+    fn select(&self, msg: &Message) -> Option<usize> {
+        let type_id = (**msg).type_id();
+        self.active.iter().rev().find_map(|state| {
+            self.transitions.iter().position(|t| {
+                t.source == *state
+                    && matches!(t.trigger, Trigger::Message(id) if id == type_id)
+                    && t.guard
+                        .as_ref()
+                        .is_none_or(|guard| guard(&self.context, msg.as_ref()))
+            })
+        })
+    }
+
+    fn fire(&mut self, index: usize, msg: &Message) {
+        let source = self.transitions[index].source;
+        let Some(target) = self.transitions[index].target else {
+            self.run_action(index, msg);
+            return;
+        };
+        let source_path = self.path_to(source);
+        let target_path = self.path_to(target);
+        let mut common = source_path
+            .iter()
+            .zip(&target_path)
+            .take_while(|(s, t)| s == t)
+            .count();
+        // a self transition leaves and enters its state again
+        if source == target {
+            common -= 1;
+        }
+        let from = self.current();
+        self.exit_to(common);
+        self.run_action(index, msg);
+        for state in &target_path[common..] {
+            self.enter(*state);
+        }
+        self.enter_substates(target);
+        debug!("{} {:?} -> {:?}", self.name, from, self.current());
+    }
+
+    fn run_action(&mut self, index: usize, msg: &Message) {
+        if let (Some(action), Some(bus)) = (&self.transitions[index].action, &self.bus) {
+            action(&mut self.context, msg.as_ref(), bus);
+        }
+    }
+
+    fn path_to(&self, state: S) -> Vec<S> {
+        let mut path = vec![state];
+        while let Some(parent) = self.states.get(path.last().unwrap()).and_then(|s| s.parent) {
+            path.push(parent);
+        }
+        path.reverse();
+        path
+    }
+
+    fn enter(&mut self, state: S) {
+        self.active.push(state);
+        if let (Some(entry), Some(bus)) = (
+            self.states.get(&state).and_then(|s| s.entry.as_ref()),
+            &self.bus,
+        ) {
+            entry(&mut self.context, bus);
+        }
+        self.arm_timers(state);
+    }
+
+    /*
+       descend from an entered composite state to a leaf, through history or initial states
+    */
+    fn enter_substates(&mut self, state: S) {
+        let Some(definition) = self.states.get(&state) else {
+            return;
+        };
+        let Some(initial) = definition.initial else {
+            return;
+        };
+        let resume = match definition.history {
+            History::None => None,
+            History::Shallow | History::Deep => self.history.get(&state).copied(),
+        };
+        match resume {
+            Some(resume) => {
+                let path = self.path_to(resume);
+                let depth = path.iter().position(|s| *s == state).map_or(0, |i| i + 1);
+                for substate in &path[depth..] {
+                    self.enter(*substate);
+                }
+                self.enter_substates(resume);
+            }
+            None => {
+                self.enter(initial);
+                self.enter_substates(initial);
+            }
+        }
+    }
+
+    /*
+       exit the active states deeper than `depth`, leaf first, remembering the history
+    */
+    fn exit_to(&mut self, depth: usize) {
+        let Some(&leaf) = self.active.last() else {
+            return;
+        };
+        for i in depth..self.active.len().saturating_sub(1) {
+            let state = self.active[i];
+            match self.states.get(&state).map(|s| s.history) {
+                Some(History::Shallow) => {
+                    self.history.insert(state, self.active[i + 1]);
+                }
+                Some(History::Deep) => {
+                    self.history.insert(state, leaf);
+                }
+                _ => {}
+            }
+        }
+        while self.active.len() > depth {
+            let state = self.active.pop().unwrap();
+            self.disarm_timers(state);
+            if let (Some(exit), Some(bus)) = (
+                self.states.get(&state).and_then(|s| s.exit.as_ref()),
+                &self.bus,
+            ) {
+                exit(&mut self.context, bus);
+            }
+        }
+    }
+
+    fn arm_timers(&mut self, state: S) {
+        let Some(bus) = &self.bus else {
+            return;
+        };
+        for (index, transition) in self.transitions.iter().enumerate() {
+            let Trigger::After(delay) = transition.trigger else {
+                continue;
+            };
+            if transition.source != state {
+                continue;
+            }
+            self.generation += 1;
+            self.armed.insert(index, self.generation);
+            let timer = HsmTimer {
+                machine: self.name.clone(),
+                transition: index,
+                generation: self.generation,
+            };
+            let bus = bus.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                bus.emit(timer);
+            });
+        }
+    }
+
+    fn disarm_timers(&mut self, state: S) {
+        let transitions = &self.transitions;
+        self.armed
+            .retain(|index, _| transitions[*index].source != state);
+    }
+}
+
+#[async_trait]
+impl<S, C> ActorImpl for Hsm<S, C>
+where
+    S: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    C: Send + 'static,
+{
+    async fn handle(&mut self, msg: &Arc<dyn Any + Send + Sync>) {
+        if let Some(start) = msg.downcast_ref::<ActorStart>() {
+            self.start(start.bus.clone());
+        } else if msg.is::<ActorStop>() {
+            self.stop();
+        } else {
+            self.dispatch(msg);
+        }
+    }
+}
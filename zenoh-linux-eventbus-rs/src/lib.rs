@@ -1,3 +1,6 @@
 pub mod logger;
 pub mod limero;
 pub mod eventbus;
+pub mod hsm;
+#[cfg(feature = "with-fsm")]
+pub mod fsm_actor;
@@ -106,8 +106,8 @@ pub struct UdpMessage {
     pub dst:Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
     pub src:Option<String>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-    pub type:Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub r#type:Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
     pub payload:Option<Vec<u8>>,
         
@@ -136,8 +136,8 @@ pub struct UdpMessageCbor {
     pub dst:Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
     pub src:Option<u32>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-    pub type:Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub r#type:Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
     pub payload:Option<Vec<u8>>,
         
@@ -8,6 +8,8 @@ mod eventbus;
 mod limero;
 mod logger;
 mod map_actor;
+mod hsm;
+#[cfg(feature = "with-fsm")]
 mod fsm_actor;
 #[cfg(feature = "with-ps4")]
 mod ps4_actor;
@@ -30,6 +32,11 @@ async fn main() -> Result<()> {
 
     eventbus.register_actor(Box::new(map_actor));
     eventbus.register_actor(Box::new(zenoh_actor));
+    #[cfg(feature = "with-fsm")]
+    {
+        let fsm_actor = fsm_actor::FsmActor::new(eventbus.bus());
+        eventbus.register_actor(Box::new(fsm_actor));
+    }
     #[cfg(feature = "with-ps4")]
     {
         let ps4_actor = ps4_actor::Ps4Actor::new(eventbus.bus());
@@ -0,0 +1,187 @@
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use zenoh_linux_eventbus_rs::eventbus::Bus;
+use zenoh_linux_eventbus_rs::hsm::{History, Hsm, HsmTimer, State, Transition};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum S {
+    Top,
+    A,
+    A1,
+    A2,
+    A21,
+    A22,
+    B,
+}
+
+struct Go;
+struct Back;
+struct Next {
+    allowed: bool,
+}
+struct Ping;
+
+type Trace = Vec<String>;
+
+fn traced(state: &'static str) -> State<S, Trace> {
+    State::new()
+        .on_entry(move |trace: &mut Trace, _| trace.push(format!("enter {}", state)))
+        .on_exit(move |trace: &mut Trace, _| trace.push(format!("exit {}", state)))
+}
+
+/*
+   Top
+   ├── A        history under test
+   │   ├── A1
+   │   └── A2
+   │       ├── A21
+   │       └── A22
+   └── B        back to A after 1s
+*/
+fn machine(history: History) -> Hsm<S, Trace> {
+    Hsm::new("test", S::Top, Trace::new())
+        .state(S::Top, traced("Top").initial(S::A))
+        .state(
+            S::A,
+            traced("A").parent(S::Top).initial(S::A1).history(history),
+        )
+        .state(S::A1, traced("A1").parent(S::A))
+        .state(S::A2, traced("A2").parent(S::A).initial(S::A21))
+        .state(S::A21, traced("A21").parent(S::A2))
+        .state(S::A22, traced("A22").parent(S::A2))
+        .state(S::B, traced("B").parent(S::Top))
+        .transition(
+            Transition::on::<Next>(S::A1)
+                .guard(|_, next: &Next| next.allowed)
+                .to(S::A2),
+        )
+        .transition(Transition::on::<Next>(S::A21).to(S::A22))
+        .transition(
+            Transition::on::<Go>(S::A)
+                .action(|trace: &mut Trace, _: &Go, _| trace.push("action".to_string()))
+                .to(S::B),
+        )
+        .transition(Transition::on::<Back>(S::B).to(S::A))
+        .transition(
+            Transition::on::<Ping>(S::Top)
+                .action(|trace: &mut Trace, _: &Ping, _| trace.push("ping".to_string())),
+        )
+        .transition(Transition::after(S::B, Duration::from_secs(1)).to(S::A))
+}
+
+fn message<M: Any + Send + Sync>(msg: M) -> Arc<dyn Any + Send + Sync> {
+    Arc::new(msg)
+}
+
+fn started(history: History) -> (Hsm<S, Trace>, UnboundedReceiver<Arc<dyn Any + Send + Sync>>) {
+    let (sender, receiver) = unbounded_channel();
+    let mut hsm = machine(history);
+    hsm.start(Bus::new(sender));
+    (hsm, receiver)
+}
+
+fn take_trace(hsm: &mut Hsm<S, Trace>) -> Vec<String> {
+    std::mem::take(hsm.context_mut())
+}
+
+/*
+   hand the timers that expired back to the machine, as the eventbus would
+*/
+async fn deliver_timers(
+    hsm: &mut Hsm<S, Trace>,
+    receiver: &mut UnboundedReceiver<Arc<dyn Any + Send + Sync>>,
+) -> usize {
+    tokio::task::yield_now().await;
+    let mut taken = 0;
+    while let Ok(msg) = receiver.try_recv() {
+        if msg.is::<HsmTimer>() && hsm.dispatch(&msg) {
+            taken += 1;
+        }
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn entry_and_exit_order() {
+        let (mut hsm, _receiver) = started(History::None);
+        assert_eq!(
+            take_trace(&mut hsm),
+            vec!["enter Top", "enter A", "enter A1"]
+        );
+        assert_eq!(hsm.active(), &[S::Top, S::A, S::A1]);
+
+        // handled by the parent A, the leaf is left first
+        assert!(hsm.dispatch(&message(Go)));
+        assert_eq!(
+            take_trace(&mut hsm),
+            vec!["exit A1", "exit A", "action", "enter B"]
+        );
+        assert_eq!(hsm.current(), Some(S::B));
+        assert!(hsm.is_in(S::Top) && !hsm.is_in(S::A));
+    }
+
+    #[tokio::test]
+    async fn guards_and_internal_transitions() {
+        let (mut hsm, _receiver) = started(History::None);
+        take_trace(&mut hsm);
+
+        assert!(!hsm.dispatch(&message(Next { allowed: false })));
+        assert_eq!(hsm.current(), Some(S::A1));
+        assert!(hsm.dispatch(&message(Next { allowed: true })));
+        assert_eq!(hsm.current(), Some(S::A21));
+
+        // no state is left or entered
+        take_trace(&mut hsm);
+        assert!(hsm.dispatch(&message(Ping)));
+        assert_eq!(take_trace(&mut hsm), vec!["ping"]);
+        assert_eq!(hsm.current(), Some(S::A21));
+
+        // not a trigger of any active state
+        assert!(!hsm.dispatch(&message(Back)));
+    }
+
+    #[tokio::test]
+    async fn history_resumes_the_substate() {
+        for (history, resumed) in [
+            (History::None, S::A1),
+            (History::Shallow, S::A21),
+            (History::Deep, S::A22),
+        ] {
+            let (mut hsm, _receiver) = started(history);
+            hsm.dispatch(&message(Next { allowed: true }));
+            hsm.dispatch(&message(Next { allowed: true }));
+            assert_eq!(hsm.current(), Some(S::A22));
+            hsm.dispatch(&message(Go));
+            hsm.dispatch(&message(Back));
+            assert_eq!(hsm.current(), Some(resumed), "{:?}", history);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timers_fire_only_while_their_state_is_active() {
+        let (mut hsm, mut receiver) = started(History::None);
+        hsm.dispatch(&message(Go));
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        assert_eq!(deliver_timers(&mut hsm, &mut receiver).await, 1);
+        assert_eq!(hsm.current(), Some(S::A1));
+
+        // B is left before its timer expires, the timer is ignored
+        hsm.dispatch(&message(Go));
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        hsm.dispatch(&message(Back));
+        hsm.dispatch(&message(Go));
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        assert_eq!(deliver_timers(&mut hsm, &mut receiver).await, 0);
+        assert_eq!(hsm.current(), Some(S::B));
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(deliver_timers(&mut hsm, &mut receiver).await, 1);
+        assert_eq!(hsm.current(), Some(S::A1));
+    }
+}
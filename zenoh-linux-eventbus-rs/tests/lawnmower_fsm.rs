@@ -0,0 +1,292 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use zenoh_linux_eventbus_rs::eventbus::{ActorImpl, ActorStart, Bus};
+use zenoh_linux_eventbus_rs::fsm_actor::{ESTOP_HOLD, FsmActor, MowerState, PAUSE_TIMEOUT};
+use zenoh_linux_eventbus_rs::hsm::HsmTimer;
+use zenoh_linux_eventbus_rs::limero::{
+    HoverboardCmd, LawnmowerAutoCmd, LawnmowerManualCmd, LawnmowerStatus,
+};
+
+type Receiver = UnboundedReceiver<Arc<dyn Any + Send + Sync>>;
+
+struct Mower {
+    actor: FsmActor,
+    receiver: Receiver,
+    statuses: Vec<LawnmowerStatus>,
+    motor_cmds: Vec<HoverboardCmd>,
+}
+
+impl Mower {
+    async fn start() -> Self {
+        let (sender, receiver) = unbounded_channel();
+        let bus = Bus::new(sender);
+        let mut mower = Mower {
+            actor: FsmActor::new(bus.clone()),
+            receiver,
+            statuses: vec![],
+            motor_cmds: vec![],
+        };
+        mower.send(ActorStart { bus }).await;
+        mower
+    }
+
+    /*
+       the actor handles the message, then everything it emitted is collected and the
+       expired timers are handed back, as the eventbus would
+    */
+    async fn send<M: Any + Send + Sync>(&mut self, msg: M) {
+        let msg: Arc<dyn Any + Send + Sync> = Arc::new(msg);
+        self.actor.handle(&msg).await;
+        self.drain().await;
+    }
+
+    async fn drain(&mut self) {
+        tokio::task::yield_now().await;
+        while let Ok(msg) = self.receiver.try_recv() {
+            if let Some(status) = msg.downcast_ref::<LawnmowerStatus>() {
+                self.statuses.push(status.clone());
+            } else if let Some(cmd) = msg.downcast_ref::<HoverboardCmd>() {
+                self.motor_cmds.push(cmd.clone());
+            } else if msg.is::<HsmTimer>() {
+                self.actor.handle(&msg).await;
+            }
+        }
+    }
+
+    async fn wait(&mut self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await;
+        self.drain().await;
+    }
+
+    async fn manual(&mut self, cmd: LawnmowerManualCmd) {
+        self.send(cmd).await;
+    }
+
+    async fn auto(&mut self, cmd: LawnmowerAutoCmd) {
+        self.send(cmd).await;
+    }
+
+    fn state(&self) -> MowerState {
+        self.actor.state().unwrap()
+    }
+
+    fn last_status(&self) -> &LawnmowerStatus {
+        self.statuses.last().unwrap()
+    }
+}
+
+fn emergency_stop() -> LawnmowerManualCmd {
+    LawnmowerManualCmd {
+        emergency_stop: Some(true),
+        ..Default::default()
+    }
+}
+
+fn start_manual() -> LawnmowerManualCmd {
+    LawnmowerManualCmd {
+        start_manual_control: Some(true),
+        ..Default::default()
+    }
+}
+
+fn drive(speed: f32, blade: bool) -> LawnmowerManualCmd {
+    LawnmowerManualCmd {
+        speed: Some(speed),
+        steer: Some(0.0),
+        blade: Some(blade),
+        ..Default::default()
+    }
+}
+
+fn auto_cmd(f: impl FnOnce(&mut LawnmowerAutoCmd)) -> LawnmowerAutoCmd {
+    let mut cmd = LawnmowerAutoCmd::default();
+    f(&mut cmd);
+    cmd
+}
+
+async fn mower_in(state: MowerState) -> Mower {
+    let mut mower = Mower::start().await;
+    match state {
+        MowerState::Manual => mower.manual(drive(0.5, true)).await,
+        MowerState::Cutting => mower.auto(auto_cmd(|c| c.start = Some(true))).await,
+        MowerState::Docking => {
+            mower.auto(auto_cmd(|c| c.start = Some(true))).await;
+            mower
+                .auto(auto_cmd(|c| c.mode = Some("dock".to_string())))
+                .await;
+        }
+        MowerState::Paused => {
+            mower.manual(drive(0.5, true)).await;
+            mower.auto(auto_cmd(|c| c.pause = Some(true))).await;
+        }
+        MowerState::EmergencyStop => mower.manual(emergency_stop()).await,
+        _ => unreachable!(),
+    }
+    assert_eq!(mower.state(), state);
+    mower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn emergency_stop_from_every_state() {
+        for state in [
+            MowerState::Manual,
+            MowerState::Cutting,
+            MowerState::Docking,
+            MowerState::Paused,
+            MowerState::EmergencyStop,
+        ] {
+            let mut mower = mower_in(state).await;
+            mower.manual(emergency_stop()).await;
+
+            assert_eq!(mower.state(), MowerState::EmergencyStop, "from {:?}", state);
+            let status = mower.last_status();
+            assert_eq!(status.current_mode.as_deref(), Some("EmergencyStop"));
+            assert_eq!(status.blade_status, Some(false));
+            assert_eq!(status.error_message.as_deref(), Some("emergency stop"));
+            let motors = mower.motor_cmds.last().unwrap();
+            assert_eq!((motors.speed, motors.steer), (Some(0), Some(0)));
+            assert_eq!(mower.actor.mower().speed, 0.0);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn emergency_stop_is_held_before_release() {
+        let mut mower = mower_in(MowerState::Cutting).await;
+        mower.manual(emergency_stop()).await;
+
+        // drive commands and an early release are ignored
+        mower.manual(drive(1.0, true)).await;
+        mower.manual(start_manual()).await;
+        assert_eq!(mower.state(), MowerState::EmergencyStop);
+        assert_eq!(mower.actor.mower().speed, 0.0);
+
+        // a second stop restarts the hold time
+        mower.wait(ESTOP_HOLD / 2).await;
+        mower.manual(emergency_stop()).await;
+        mower.wait(ESTOP_HOLD / 2).await;
+        mower.manual(start_manual()).await;
+        assert_eq!(mower.state(), MowerState::EmergencyStop);
+
+        mower.wait(ESTOP_HOLD).await;
+        mower.manual(start_manual()).await;
+        // back to Manual, never to the interrupted auto mode
+        assert_eq!(mower.state(), MowerState::Manual);
+        assert_eq!(mower.last_status().current_mode.as_deref(), Some("Manual"));
+        assert_eq!(mower.last_status().error_message, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn emergency_stop_wins_over_other_flags() {
+        let cases = [
+            (
+                MowerState::Manual,
+                LawnmowerManualCmd {
+                    start_auto_mode: Some(true),
+                    ..emergency_stop()
+                },
+            ),
+            (
+                MowerState::Manual,
+                LawnmowerManualCmd {
+                    emergency_stop: Some(true),
+                    ..drive(1.0, true)
+                },
+            ),
+            (
+                MowerState::Cutting,
+                LawnmowerManualCmd {
+                    start_manual_control: Some(true),
+                    ..emergency_stop()
+                },
+            ),
+            (
+                MowerState::Docking,
+                LawnmowerManualCmd {
+                    stop_auto_mode: Some(true),
+                    ..emergency_stop()
+                },
+            ),
+            (
+                MowerState::Paused,
+                LawnmowerManualCmd {
+                    start_manual_control: Some(true),
+                    ..emergency_stop()
+                },
+            ),
+        ];
+        for (state, cmd) in cases {
+            let mut mower = mower_in(state).await;
+            mower.manual(cmd).await;
+            assert_eq!(mower.state(), MowerState::EmergencyStop, "from {:?}", state);
+            assert_eq!(mower.actor.mower().speed, 0.0);
+            assert_eq!(mower.last_status().blade_status, Some(false));
+        }
+
+        // a release that still has the stop set is a new stop, not a release
+        let mut mower = mower_in(MowerState::EmergencyStop).await;
+        mower.wait(ESTOP_HOLD).await;
+        mower
+            .manual(LawnmowerManualCmd {
+                start_manual_control: Some(true),
+                ..emergency_stop()
+            })
+            .await;
+        assert_eq!(mower.state(), MowerState::EmergencyStop);
+        mower.manual(start_manual()).await;
+        assert_eq!(mower.state(), MowerState::EmergencyStop);
+        mower.wait(ESTOP_HOLD).await;
+        mower.manual(start_manual()).await;
+        assert_eq!(mower.state(), MowerState::Manual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn manual_commands_drive_the_motors() {
+        let mut mower = Mower::start().await;
+        assert_eq!(mower.last_status().current_mode.as_deref(), Some("Manual"));
+
+        mower.manual(drive(0.5, true)).await;
+        let motors = mower.motor_cmds.last().unwrap();
+        assert_eq!(motors.speed, Some(500));
+        assert_eq!(mower.last_status().blade_status, Some(true));
+
+        mower
+            .manual(LawnmowerManualCmd {
+                start_auto_mode: Some(true),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(mower.state(), MowerState::Cutting);
+        assert_eq!(mower.last_status().current_mode.as_deref(), Some("Auto"));
+
+        mower
+            .manual(LawnmowerManualCmd {
+                stop_auto_mode: Some(true),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(mower.state(), MowerState::Manual);
+        assert_eq!(mower.last_status().blade_status, Some(false));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_resumes_where_it_left_off() {
+        let mut mower = mower_in(MowerState::Docking).await;
+        mower.auto(auto_cmd(|c| c.pause = Some(true))).await;
+        assert_eq!(mower.state(), MowerState::Paused);
+        assert_eq!(mower.last_status().current_mode.as_deref(), Some("Paused"));
+
+        mower.auto(auto_cmd(|c| c.resume = Some(true))).await;
+        assert_eq!(mower.state(), MowerState::Docking);
+
+        // paused too long falls back to manual
+        mower.auto(auto_cmd(|c| c.pause = Some(true))).await;
+        mower.wait(PAUSE_TIMEOUT).await;
+        assert_eq!(mower.state(), MowerState::Manual);
+    }
+}
@@ -0,0 +1,459 @@
+use anyhow::{bail, Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::{self, Instant};
+
+use crate::image::Checks;
+use crate::upload::{self, OtaLink, UploadOptions, UploadReport};
+
+/// The devices of a campaign, read from a JSON file
+/// ```json
+/// { "concurrency": 4,
+///   "devices": [ { "key": "dst/esp1/ota/firmware", "firmware": "esp1.bin", "version": "1.2.0",
+///                  "sys_event": "src/esp1/sys/SysEvent", "chip": "esp32", "project": "esp1" } ] }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct Manifest {
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    pub devices: Vec<DeviceEntry>,
+}
+
+fn default_concurrency() -> usize {
+    4
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceEntry {
+    /// the key the device takes OtaMsg on
+    pub key: String,
+    /// relative to the manifest
+    pub firmware: PathBuf,
+    /// the version the firmware has, checked against its app descriptor
+    #[serde(default)]
+    pub version: Option<String>,
+    /// where the device publishes its SysEvent with the running version
+    #[serde(default)]
+    pub sys_event: Option<String>,
+    /// the image has to be built for this chip and project
+    #[serde(default)]
+    pub chip: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("manifest {:?}", path))?;
+        let mut manifest: Manifest =
+            serde_json::from_str(&text).with_context(|| format!("manifest {:?}", path))?;
+        let dir = path.parent().unwrap_or(Path::new("."));
+        for device in &mut manifest.devices {
+            device.firmware = dir.join(&device.firmware);
+        }
+        Ok(manifest)
+    }
+}
+
+/// How a campaign reaches the devices, over zenoh or simulated
+pub trait Fleet: Send + Sync + 'static {
+    type Link: OtaLink + Send;
+    /// a link to the device and the key its replies arrive on
+    fn connect(&self, device: &DeviceEntry) -> impl Future<Output = Result<(Self::Link, String)>> + Send;
+    /// the version or build date the device runs, None when it doesn't tell
+    fn version(&self, device: &DeviceEntry) -> impl Future<Output = Result<Option<String>>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Updated,
+    Skipped,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeviceReport {
+    pub key: String,
+    pub firmware: PathBuf,
+    pub version: Option<String>,
+    pub reported_version: Option<String>,
+    pub outcome: Outcome,
+    pub error: Option<String>,
+    pub bytes: usize,
+    pub resumed_from: usize,
+    pub retransmits: u32,
+    pub seconds: f64,
+}
+
+impl DeviceReport {
+    fn failed(device: &DeviceEntry, error: Option<String>) -> Self {
+        DeviceReport {
+            key: device.key.clone(),
+            firmware: device.firmware.clone(),
+            version: device.version.clone(),
+            reported_version: None,
+            outcome: Outcome::Failed,
+            error,
+            bytes: 0,
+            resumed_from: 0,
+            retransmits: 0,
+            seconds: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignReport {
+    pub started: String,
+    pub updated: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub devices: Vec<DeviceReport>,
+}
+
+/// Updates the devices, at most manifest.concurrency at a time, the report lists them in
+/// manifest order. The chip and project of checks apply to devices that don't name their own.
+pub async fn run<F: Fleet>(
+    fleet: Arc<F>,
+    manifest: &Manifest,
+    options: &UploadOptions,
+    checks: &Checks,
+) -> CampaignReport {
+    let started = chrono::Local::now().to_rfc3339();
+    let permits = Arc::new(Semaphore::new(manifest.concurrency.max(1)));
+    // key => (acked, size) for the progress log
+    let progress: Arc<Mutex<BTreeMap<String, (usize, usize)>>> = Arc::default();
+    let mut tasks = JoinSet::new();
+    // a task that panics only leaves its id to find the device by
+    let mut indexes = HashMap::new();
+    for (index, device) in manifest.devices.iter().cloned().enumerate() {
+        let (fleet, permits, progress, options) =
+            (fleet.clone(), permits.clone(), progress.clone(), options.clone());
+        let checks = Checks {
+            chip: device.chip.clone().or(checks.chip.clone()),
+            project: device.project.clone().or(checks.project.clone()),
+            force: checks.force,
+        };
+        let task = tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await.expect("semaphore is never closed");
+            (index, update(fleet.as_ref(), &device, &options, &checks, &progress).await)
+        });
+        indexes.insert(task.id(), index);
+    }
+
+    let mut reports: Vec<Option<DeviceReport>> = vec![None; manifest.devices.len()];
+    let mut ticker = time::interval(Duration::from_secs(2));
+    loop {
+        tokio::select! {
+            done = tasks.join_next() => match done {
+                Some(Ok((index, report))) => {
+                    info!("{} : {:?} {}", report.key, report.outcome, report.error.as_deref().unwrap_or(""));
+                    progress.lock().unwrap().remove(&report.key);
+                    reports[index] = Some(report);
+                }
+                Some(Err(e)) => {
+                    let index = indexes[&e.id()];
+                    let device = &manifest.devices[index];
+                    warn!("{} : update task failed: {}", device.key, e);
+                    progress.lock().unwrap().remove(&device.key);
+                    let error = format!("update task failed: {}", e);
+                    reports[index] = Some(DeviceReport::failed(device, Some(error)));
+                }
+                None => break,
+            },
+            _ = ticker.tick() => log_progress(&progress),
+        }
+    }
+
+    let devices: Vec<DeviceReport> = reports.into_iter().flatten().collect();
+    let count = |outcome| devices.iter().filter(|d| d.outcome == outcome).count();
+    CampaignReport {
+        started,
+        updated: count(Outcome::Updated),
+        skipped: count(Outcome::Skipped),
+        failed: count(Outcome::Failed),
+        devices,
+    }
+}
+
+fn log_progress(progress: &Mutex<BTreeMap<String, (usize, usize)>>) {
+    let progress = progress.lock().unwrap();
+    if progress.is_empty() {
+        return;
+    }
+    let line: Vec<String> = progress
+        .iter()
+        .map(|(key, (acked, size))| format!("{} {}%", key, acked * 100 / (*size).max(1)))
+        .collect();
+    info!("Progress: {}", line.join(", "));
+}
+
+async fn update<F: Fleet>(
+    fleet: &F,
+    device: &DeviceEntry,
+    options: &UploadOptions,
+    checks: &Checks,
+    progress: &Mutex<BTreeMap<String, (usize, usize)>>,
+) -> DeviceReport {
+    let started = Instant::now();
+    let mut report = DeviceReport::failed(device, None);
+    let result = async {
+        let image = fs::read(&device.firmware).with_context(|| format!("firmware {:?}", device.firmware))?;
+        let info = checks.inspect(&image)?;
+        if let (Some(info), Some(version)) = (&info, &device.version) {
+            if &info.version != version {
+                bail!("manifest says version {}, the image is {}", version, info.version);
+            }
+        }
+        if let Some(info) = &info {
+            report.version = Some(info.version.clone());
+        }
+
+        match fleet.version(device).await {
+            Ok(version) => report.reported_version = version,
+            Err(e) => warn!("{} : no version, updating anyway: {}", device.key, e),
+        }
+        let running = |reported: &str| match &info {
+            Some(info) => info.is_running(reported),
+            None => report.version.as_deref() == Some(reported),
+        };
+        if let Some(reported) = &report.reported_version {
+            if running(reported) && !checks.force {
+                info!("{} already runs {}", device.key, reported);
+                return Ok(None);
+            }
+        }
+
+        let (mut link, reply_to) = fleet.connect(device).await?;
+        let size = image.len();
+        let uploaded = upload::upload_with_progress(&mut link, &reply_to, &image, options, |acked| {
+            progress.lock().unwrap().insert(device.key.clone(), (acked, size));
+        })
+        .await;
+        match uploaded {
+            Ok(upload) => Ok(Some(upload)),
+            // the device restarts after OtaCommit, its reply can get lost on the way
+            Err(e) => match rebooted_into(fleet, device, report.reported_version.as_deref(), running).await {
+                Some(reported) => {
+                    warn!("{} : {:#}, but it runs {} now", device.key, e, reported);
+                    report.reported_version = Some(reported);
+                    Ok(Some(UploadReport { image_size: size, ..Default::default() }))
+                }
+                None => Err(e),
+            },
+        }
+    }
+    .await;
+    match result {
+        Ok(Some(upload)) => {
+            report.outcome = Outcome::Updated;
+            report.bytes = upload.image_size;
+            report.resumed_from = upload.resumed_from;
+            report.retransmits = upload.retransmits;
+        }
+        Ok(None) => report.outcome = Outcome::Skipped,
+        Err(e) => report.error = Some(format!("{:#}", e)),
+    }
+    report.seconds = started.elapsed().as_secs_f64();
+    report
+}
+
+/// The version the device reports after a failed upload when it is the one of the image and
+/// differs from what it ran before, so a device that already ran it doesn't count
+async fn rebooted_into<F: Fleet>(
+    fleet: &F,
+    device: &DeviceEntry,
+    before: Option<&str>,
+    running: impl Fn(&str) -> bool,
+) -> Option<String> {
+    match fleet.version(device).await {
+        Ok(Some(reported)) if running(&reported) && before != Some(reported.as_str()) => Some(reported),
+        Ok(_) => None,
+        Err(e) => {
+            warn!("{} : no version after the upload: {}", device.key, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::{SimulatedDevice, SimulatedFleet, SimulatedLink};
+    use crate::msg::{OtaMsg, OtaOperation};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// SimulatedFleet whose update task panics for one device
+    struct PanickingFleet {
+        fleet: SimulatedFleet,
+        panics_on: &'static str,
+    }
+
+    impl Fleet for PanickingFleet {
+        type Link = SimulatedLink;
+
+        async fn connect(&self, device: &DeviceEntry) -> Result<(SimulatedLink, String)> {
+            if device.key == self.panics_on {
+                panic!("simulated crash for {}", device.key);
+            }
+            self.fleet.connect(device).await
+        }
+
+        async fn version(&self, device: &DeviceEntry) -> Result<Option<String>> {
+            self.fleet.version(device).await
+        }
+    }
+
+    fn manifest(dir: &Path) -> Manifest {
+        fs::create_dir_all(dir).unwrap();
+        for (name, len) in [("esp1.bin", 6_000), ("esp2.bin", 9_000), ("esp3.bin", 3_000)] {
+            fs::write(dir.join(name), vec![0x5A; len]).unwrap();
+        }
+        fs::write(
+            dir.join("manifest.json"),
+            r#"{ "concurrency": 2, "devices": [
+                { "key": "dst/esp1/ota", "firmware": "esp1.bin" },
+                { "key": "dst/esp2/ota", "firmware": "esp2.bin", "version": "1.0.0" },
+                { "key": "dst/missing/ota", "firmware": "missing.bin" },
+                { "key": "dst/esp3/ota", "firmware": "esp3.bin" } ] }"#,
+        )
+        .unwrap();
+        Manifest::load(&dir.join("manifest.json")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn campaign_reports_every_device_in_manifest_order() {
+        let dir = std::env::temp_dir().join(format!("zenoh-ota-campaign-{}", std::process::id()));
+        let manifest = manifest(&dir);
+        let fleet = PanickingFleet {
+            fleet: SimulatedFleet {
+                drop_every: Some(5),
+                stall_at: None,
+            },
+            panics_on: "dst/esp3/ota",
+        };
+        let options = UploadOptions {
+            timeout: Duration::from_millis(20),
+            ..Default::default()
+        };
+        // the images are no ESP-IDF apps
+        let checks = Checks {
+            force: true,
+            ..Default::default()
+        };
+        let report = run(Arc::new(fleet), &manifest, &options, &checks).await;
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!((report.updated, report.skipped, report.failed), (2, 0, 2));
+        let outcomes: Vec<(&str, Outcome)> =
+            report.devices.iter().map(|d| (d.key.as_str(), d.outcome)).collect();
+        assert_eq!(
+            outcomes,
+            [
+                ("dst/esp1/ota", Outcome::Updated),
+                ("dst/esp2/ota", Outcome::Updated),
+                ("dst/missing/ota", Outcome::Failed),
+                ("dst/esp3/ota", Outcome::Failed),
+            ]
+        );
+        let esp2 = &report.devices[1];
+        assert_eq!((esp2.bytes, esp2.version.as_deref()), (9_000, Some("1.0.0")));
+        assert!(esp2.retransmits > 0);
+        assert!(report.devices[2].error.as_deref().unwrap().contains("missing.bin"));
+        assert!(report.devices[3].error.as_deref().unwrap().contains("panic"));
+    }
+
+    /// devices that restart before their OtaCommit reply leaves, the committed ones report 2.0.0
+    #[derive(Default)]
+    struct RebootingFleet {
+        committed: Arc<Mutex<BTreeMap<String, Arc<AtomicBool>>>>,
+    }
+
+    struct RebootingLink {
+        link: SimulatedLink,
+        committed: Arc<AtomicBool>,
+    }
+
+    impl OtaLink for RebootingLink {
+        async fn send(&mut self, msg: OtaMsg) -> Result<()> {
+            let commit = msg.operation == Some(OtaOperation::OtaCommit);
+            self.link.send(msg).await?;
+            if commit && !self.link.device.running.is_empty() {
+                self.committed.store(true, Ordering::SeqCst);
+                while self.link.recv(Duration::ZERO).await?.is_some() {}
+            }
+            Ok(())
+        }
+
+        async fn recv(&mut self, timeout: Duration) -> Result<Option<OtaMsg>> {
+            match self.committed.load(Ordering::SeqCst) {
+                true => {
+                    time::sleep(timeout).await;
+                    Ok(None)
+                }
+                false => self.link.recv(timeout).await,
+            }
+        }
+    }
+
+    impl Fleet for RebootingFleet {
+        type Link = RebootingLink;
+
+        async fn connect(&self, device: &DeviceEntry) -> Result<(RebootingLink, String)> {
+            let committed = Arc::new(AtomicBool::new(false));
+            self.committed.lock().unwrap().insert(device.key.clone(), committed.clone());
+            let link = SimulatedLink::new(SimulatedDevice::new(64 * 1024));
+            Ok((RebootingLink { link, committed }, format!("dst/simulator/{}", device.key)))
+        }
+
+        async fn version(&self, device: &DeviceEntry) -> Result<Option<String>> {
+            let committed = self.committed.lock().unwrap().get(&device.key).cloned();
+            Ok(Some(match committed {
+                Some(committed) if committed.load(Ordering::SeqCst) => "2.0.0".to_string(),
+                _ => "1.0.0".to_string(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn device_that_rebooted_into_the_image_counts_as_updated() {
+        let dir = std::env::temp_dir().join(format!("zenoh-ota-reboot-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("esp.bin"), vec![0xA5; 5_000]).unwrap();
+        fs::write(
+            dir.join("manifest.json"),
+            r#"{ "devices": [
+                { "key": "dst/new/ota", "firmware": "esp.bin", "version": "2.0.0" },
+                { "key": "dst/old/ota", "firmware": "esp.bin", "version": "1.0.0" } ] }"#,
+        )
+        .unwrap();
+        let manifest = Manifest::load(&dir.join("manifest.json")).unwrap();
+        let options = UploadOptions {
+            timeout: Duration::from_millis(20),
+            ..Default::default()
+        };
+        let checks = Checks {
+            force: true,
+            ..Default::default()
+        };
+        let report = run(Arc::new(RebootingFleet::default()), &manifest, &options, &checks).await;
+        fs::remove_dir_all(&dir).unwrap();
+
+        let new = &report.devices[0];
+        assert_eq!(new.outcome, Outcome::Updated, "{:?}", new.error);
+        assert_eq!((new.bytes, new.reported_version.as_deref()), (5_000, Some("2.0.0")));
+        // it reports 1.0.0 before and after, the lost commit reply can't be told from a failure
+        let old = &report.devices[1];
+        assert_eq!(old.outcome, Outcome::Failed);
+        assert!(old.error.as_deref().unwrap().contains("OtaCommit"), "{:?}", old.error);
+    }
+}
@@ -0,0 +1,226 @@
+use anyhow::Result;
+use log::{debug, info};
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time;
+
+use crate::msg::*;
+use crate::campaign::{DeviceEntry, Fleet};
+use crate::upload::OtaLink;
+
+/// Answers OtaMsg like the OtaActor on an ESP32, the image is kept in memory
+pub struct SimulatedDevice {
+    capacity: usize,
+    pub running: Vec<u8>,
+    previous: Option<Vec<u8>>,
+    upload: Option<Upload>,
+    /// drop every nth message to exercise retransmission
+    pub drop_every: Option<u32>,
+    /// stop answering once a write passes this offset until the next OtaBegin, like a lost link
+    pub stall_at: Option<u32>,
+    stalled: bool,
+    received: u32,
+}
+
+struct Upload {
+    sha256: Vec<u8>,
+    data: Vec<u8>,
+    written: Vec<bool>,
+    verified: bool,
+}
+
+impl Upload {
+    /// bytes received without a gap from the start, where an upload of the same image resumes
+    fn contiguous(&self) -> usize {
+        self.written.iter().position(|w| !w).unwrap_or(self.written.len())
+    }
+}
+
+impl SimulatedDevice {
+    pub fn new(capacity: usize) -> Self {
+        SimulatedDevice {
+            capacity,
+            running: Vec::new(),
+            previous: None,
+            upload: None,
+            drop_every: None,
+            stall_at: None,
+            stalled: false,
+            received: 0,
+        }
+    }
+
+    pub fn handle(&mut self, msg: OtaMsg) -> Option<OtaMsg> {
+        self.received += 1;
+        if self.drop_every.is_some_and(|n| n > 0 && self.received.is_multiple_of(n)) {
+            debug!("Simulated device drops {:?}", msg.operation);
+            return None;
+        }
+        let Some(operation) = msg.operation.clone() else {
+            return Some(OtaMsg::reply(&msg, -1, "operation missing"));
+        };
+        let reply = match operation {
+            OtaOperation::OtaBegin => self.begin(&msg),
+            OtaOperation::OtaWrite => {
+                if self.stalled {
+                    return None;
+                }
+                self.write(&msg)?
+            }
+            OtaOperation::OtaEnd => self.verify(&msg),
+            OtaOperation::OtaCommit => self.commit(&msg),
+            OtaOperation::OtaAbort => {
+                self.upload = None;
+                OtaMsg::reply(&msg, 0, "aborted")
+            }
+            OtaOperation::OtaRollback => match self.previous.take() {
+                Some(previous) => {
+                    self.running = previous;
+                    OtaMsg::reply(&msg, 0, "rolled back")
+                }
+                None => OtaMsg::reply(&msg, -1, "no previous image"),
+            },
+        };
+        Some(reply)
+    }
+
+    fn begin(&mut self, msg: &OtaMsg) -> OtaMsg {
+        let (Some(size), Some(sha256)) = (msg.image_size, msg.sha256.as_ref()) else {
+            return OtaMsg::reply(msg, -1, "image_size and sha256 required");
+        };
+        let size = size as usize;
+        if size > self.capacity {
+            return OtaMsg::reply(msg, -1, "image larger than partition");
+        }
+        self.stalled = false;
+        let same_image = self
+            .upload
+            .as_ref()
+            .is_some_and(|u| u.data.len() == size && &u.sha256 == sha256);
+        if !same_image {
+            self.upload = Some(Upload {
+                sha256: sha256.clone(),
+                data: vec![0; size],
+                written: vec![false; size],
+                verified: false,
+            });
+        }
+        let mut reply = OtaMsg::reply(msg, 0, "ready");
+        reply.offset = self.upload.as_ref().map(|u| u.contiguous() as u32);
+        reply
+    }
+
+    fn write(&mut self, msg: &OtaMsg) -> Option<OtaMsg> {
+        let Some(upload) = self.upload.as_mut() else {
+            return Some(OtaMsg::reply(msg, -1, "no OtaBegin"));
+        };
+        let (Some(offset), Some(image)) = (msg.offset, msg.image.as_ref()) else {
+            return Some(OtaMsg::reply(msg, -1, "offset and image required"));
+        };
+        let (offset, end) = (offset as usize, offset as usize + image.len());
+        if let Some(stall_at) = self.stall_at {
+            if end > stall_at as usize {
+                info!("Simulated device stalls at {}", offset);
+                self.stall_at = None;
+                self.stalled = true;
+                return None;
+            }
+        }
+        if end > upload.data.len() {
+            return Some(OtaMsg::reply(msg, -1, "write beyond image size"));
+        }
+        // like the OtaActor, which appends to flash, a chunk after a gap is not acked
+        if offset > upload.contiguous() {
+            debug!("Simulated device ignores chunk at {} after a gap", offset);
+            return None;
+        }
+        upload.data[offset..end].copy_from_slice(image);
+        upload.written[offset..end].fill(true);
+        upload.verified = false;
+        Some(OtaMsg::reply(msg, 0, "written"))
+    }
+
+    fn verify(&mut self, msg: &OtaMsg) -> OtaMsg {
+        let Some(upload) = self.upload.as_mut() else {
+            return OtaMsg::reply(msg, -1, "no OtaBegin");
+        };
+        if upload.contiguous() < upload.data.len() {
+            return OtaMsg::reply(msg, -1, "image incomplete");
+        }
+        if Sha256::digest(&upload.data).as_slice() != upload.sha256.as_slice() {
+            return OtaMsg::reply(msg, -1, "sha256 mismatch");
+        }
+        upload.verified = true;
+        OtaMsg::reply(msg, 0, "verified")
+    }
+
+    fn commit(&mut self, msg: &OtaMsg) -> OtaMsg {
+        match self.upload.take() {
+            Some(upload) if upload.verified => {
+                self.previous = Some(std::mem::replace(&mut self.running, upload.data));
+                OtaMsg::reply(msg, 0, "committed")
+            }
+            upload => {
+                self.upload = upload;
+                OtaMsg::reply(msg, -1, "image not verified")
+            }
+        }
+    }
+}
+
+/// Link to a SimulatedDevice in the same process, messages go through CBOR like on the wire
+pub struct SimulatedLink {
+    pub device: SimulatedDevice,
+    replies: VecDeque<OtaMsg>,
+}
+
+impl SimulatedLink {
+    pub fn new(device: SimulatedDevice) -> Self {
+        SimulatedLink {
+            device,
+            replies: VecDeque::new(),
+        }
+    }
+}
+
+impl OtaLink for SimulatedLink {
+    async fn send(&mut self, msg: OtaMsg) -> Result<()> {
+        let msg = minicbor::decode::<OtaMsg>(&minicbor::to_vec(&msg)?)?;
+        if let Some(reply) = self.device.handle(msg) {
+            self.replies.push_back(minicbor::decode(&minicbor::to_vec(&reply)?)?);
+        }
+        Ok(())
+    }
+
+    async fn recv(&mut self, timeout: Duration) -> Result<Option<OtaMsg>> {
+        match self.replies.pop_front() {
+            Some(reply) => Ok(Some(reply)),
+            None => {
+                time::sleep(timeout).await;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// A fresh SimulatedDevice per manifest entry, none of them reports a version
+pub struct SimulatedFleet {
+    pub drop_every: Option<u32>,
+    pub stall_at: Option<u32>,
+}
+
+impl Fleet for SimulatedFleet {
+    type Link = SimulatedLink;
+
+    async fn connect(&self, device: &DeviceEntry) -> Result<(SimulatedLink, String)> {
+        let mut simulated = SimulatedDevice::new(4 * 1024 * 1024);
+        simulated.drop_every = self.drop_every;
+        simulated.stall_at = self.stall_at;
+        Ok((SimulatedLink::new(simulated), format!("dst/simulator/{}", device.key)))
+    }
+
+    async fn version(&self, _device: &DeviceEntry) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,329 @@
+use anyhow::{bail, Result};
+use log::warn;
+use std::fmt;
+
+/// esp_image_header_t.magic
+pub const IMAGE_MAGIC: u8 = 0xE9;
+/// esp_app_desc_t.magic_word
+pub const APP_DESC_MAGIC: u32 = 0xABCD_5432;
+/// ESP_IMAGE_MAX_SEGMENTS
+const MAX_SEGMENTS: u8 = 16;
+/// esp_image_header_t
+const HEADER_SIZE: usize = 24;
+/// esp_image_segment_header_t, the app descriptor is at the start of the first segment
+const SEGMENT_HEADER_SIZE: usize = 8;
+const APP_DESC_OFFSET: usize = HEADER_SIZE + SEGMENT_HEADER_SIZE;
+/// esp_app_desc_t up to idf_ver
+const APP_DESC_SIZE: usize = 144;
+
+/// esp_chip_id_t
+const CHIPS: [(u16, &str); 10] = [
+    (0, "esp32"),
+    (2, "esp32s2"),
+    (5, "esp32c3"),
+    (9, "esp32s3"),
+    (12, "esp32c2"),
+    (13, "esp32c6"),
+    (16, "esp32h2"),
+    (18, "esp32p4"),
+    (20, "esp32c61"),
+    (23, "esp32c5"),
+];
+
+/// What an ESP-IDF app image says about itself in its header and app descriptor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub chip_id: u16,
+    pub segment_count: u8,
+    pub entry_addr: u32,
+    pub project_name: String,
+    pub version: String,
+    pub idf_version: String,
+    /// "Mmm dd yyyy hh:mm:ss", like __DATE__ " " __TIME__ in the SysEvent of the device
+    pub build_date: String,
+}
+
+impl ImageInfo {
+    pub fn parse(image: &[u8]) -> Result<Self> {
+        if image.len() < APP_DESC_OFFSET + APP_DESC_SIZE {
+            bail!("image of {} bytes is too small for an ESP-IDF app", image.len());
+        }
+        if image[0] != IMAGE_MAGIC {
+            bail!("no ESP-IDF image, magic is 0x{:02X} instead of 0x{:02X}", image[0], IMAGE_MAGIC);
+        }
+        let segment_count = image[1];
+        if segment_count == 0 || segment_count > MAX_SEGMENTS {
+            bail!("invalid segment count {}", segment_count);
+        }
+        let desc = &image[APP_DESC_OFFSET..APP_DESC_OFFSET + APP_DESC_SIZE];
+        let magic_word = u32::from_le_bytes(desc[0..4].try_into().unwrap());
+        if magic_word != APP_DESC_MAGIC {
+            bail!("no app descriptor, magic word is 0x{:08X}", magic_word);
+        }
+        Ok(ImageInfo {
+            chip_id: u16::from_le_bytes([image[12], image[13]]),
+            segment_count,
+            entry_addr: u32::from_le_bytes(image[4..8].try_into().unwrap()),
+            version: text(&desc[16..48]),
+            project_name: text(&desc[48..80]),
+            build_date: format!("{} {}", text(&desc[96..112]), text(&desc[80..96])),
+            idf_version: text(&desc[112..144]),
+        })
+    }
+
+    pub fn chip(&self) -> Option<&'static str> {
+        chip_name(self.chip_id)
+    }
+
+    /// Refuses an image built for another chip or project
+    pub fn check(&self, chip: Option<&str>, project: Option<&str>) -> Result<()> {
+        if let Some(chip) = chip {
+            let Some(chip_id) = chip_id(chip) else {
+                bail!("unknown chip {}", chip);
+            };
+            if chip_id != self.chip_id {
+                bail!("image is for {}, not {}", self.chip().unwrap_or("an unknown chip"), chip);
+            }
+        }
+        if let Some(project) = project {
+            if project != self.project_name {
+                bail!("image is project {}, not {}", self.project_name, project);
+            }
+        }
+        Ok(())
+    }
+
+    /// True when the version or build date the device reports is this image
+    pub fn is_running(&self, reported: &str) -> bool {
+        reported == self.version || reported == self.build_date
+    }
+}
+
+/// What an image is checked against before it is uploaded, force makes a refusal a warning
+#[derive(Debug, Clone, Default)]
+pub struct Checks {
+    pub chip: Option<String>,
+    pub project: Option<String>,
+    pub force: bool,
+}
+
+impl Checks {
+    /// The info of an acceptable image, None when a forced image has no ESP-IDF header
+    pub fn inspect(&self, image: &[u8]) -> Result<Option<ImageInfo>> {
+        let checked = ImageInfo::parse(image).and_then(|info| {
+            info.check(self.chip.as_deref(), self.project.as_deref())?;
+            Ok(info)
+        });
+        match checked {
+            Ok(info) => Ok(Some(info)),
+            Err(e) if self.force => {
+                warn!("{}, forced to upload anyway", e);
+                Ok(ImageInfo::parse(image).ok())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl fmt::Display for ImageInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} for {} built {} with IDF {}, {} segments, entry 0x{:08X}",
+            self.project_name,
+            self.version,
+            self.chip().unwrap_or("unknown chip"),
+            self.build_date,
+            self.idf_version,
+            self.segment_count,
+            self.entry_addr
+        )
+    }
+}
+
+pub fn chip_name(chip_id: u16) -> Option<&'static str> {
+    CHIPS.iter().find(|(id, _)| *id == chip_id).map(|(_, name)| *name)
+}
+
+/// accepts ESP32-S3 as well as esp32s3
+pub fn chip_id(name: &str) -> Option<u16> {
+    let name = name.to_lowercase().replace(['-', '_'], "");
+    CHIPS.iter().find(|(_, chip)| *chip == name).map(|(id, _)| *id)
+}
+
+/// The version a device reports in its SysEvent, its build date when it has no version
+pub fn reported_version(sys_event: &serde_json::Value) -> Option<String> {
+    ["version", "build_date"]
+        .iter()
+        .find_map(|field| sys_event.get(field)?.as_str())
+        .map(str::to_string)
+}
+
+/// The text fields at the top level of a CBOR SysEvent, as a JSON object for reported_version.
+/// zenoh-esp32 publishes its SysMsg as a CBOR map with text keys, of indefinite length.
+pub fn sys_event_from_cbor(payload: &[u8]) -> Result<serde_json::Value> {
+    use minicbor::data::Type;
+    let mut decoder = minicbor::Decoder::new(payload);
+    let mut remaining = decoder.map()?;
+    let mut fields = serde_json::Map::new();
+    loop {
+        match remaining {
+            Some(0) => break,
+            Some(n) => remaining = Some(n - 1),
+            None if decoder.datatype()? == Type::Break => break,
+            None => {}
+        }
+        let key = match decoder.datatype()? {
+            Type::String => Some(decoder.str()?.to_string()),
+            _ => {
+                decoder.skip()?;
+                None
+            }
+        };
+        match (key, decoder.datatype()?) {
+            (Some(key), Type::String) => {
+                fields.insert(key, decoder.str()?.into());
+            }
+            _ => decoder.skip()?,
+        }
+    }
+    Ok(serde_json::Value::Object(fields))
+}
+
+/// zero terminated char array
+fn text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(desc: &mut [u8], offset: usize, value: &str) {
+        desc[offset..offset + value.len()].copy_from_slice(value.as_bytes());
+    }
+
+    /// header, first segment header and app descriptor as esptool lays them out
+    fn synthetic_image(chip_id: u16, project: &str, version: &str) -> Vec<u8> {
+        let mut image = vec![0u8; 1024];
+        image[0] = IMAGE_MAGIC;
+        image[1] = 5;
+        image[4..8].copy_from_slice(&0x4008_1234u32.to_le_bytes());
+        image[12..14].copy_from_slice(&chip_id.to_le_bytes());
+        image[24..28].copy_from_slice(&0x3F40_0020u32.to_le_bytes());
+        image[28..32].copy_from_slice(&256u32.to_le_bytes());
+        let desc = &mut image[APP_DESC_OFFSET..APP_DESC_OFFSET + 256];
+        desc[0..4].copy_from_slice(&APP_DESC_MAGIC.to_le_bytes());
+        field(desc, 16, version);
+        field(desc, 48, project);
+        field(desc, 80, "12:34:56");
+        field(desc, 96, "Oct 18 2026");
+        field(desc, 112, "v5.3.1");
+        image
+    }
+
+    #[test]
+    fn parses_header_and_app_descriptor() {
+        let info = ImageInfo::parse(&synthetic_image(9, "zenoh-camera", "1.3.0")).unwrap();
+        assert_eq!(
+            info,
+            ImageInfo {
+                chip_id: 9,
+                segment_count: 5,
+                entry_addr: 0x4008_1234,
+                project_name: "zenoh-camera".to_string(),
+                version: "1.3.0".to_string(),
+                idf_version: "v5.3.1".to_string(),
+                build_date: "Oct 18 2026 12:34:56".to_string(),
+            }
+        );
+        assert_eq!(info.chip(), Some("esp32s3"));
+    }
+
+    #[test]
+    fn full_length_fields_have_no_terminator() {
+        let version = "v".repeat(32);
+        let info = ImageInfo::parse(&synthetic_image(0, "p", &version)).unwrap();
+        assert_eq!(info.version, version);
+    }
+
+    #[test]
+    fn rejects_what_is_no_app_image() {
+        let mut image = synthetic_image(0, "p", "1");
+        assert!(ImageInfo::parse(&image[..100]).is_err());
+        image[0] = 0xE8;
+        assert!(ImageInfo::parse(&image).is_err());
+        image[0] = IMAGE_MAGIC;
+        image[1] = 17;
+        assert!(ImageInfo::parse(&image).is_err());
+        image[1] = 1;
+        image[APP_DESC_OFFSET] = 0;
+        assert!(ImageInfo::parse(&image).is_err());
+    }
+
+    #[test]
+    fn refuses_wrong_chip_or_project() {
+        let info = ImageInfo::parse(&synthetic_image(5, "motor", "0.9.2")).unwrap();
+        assert!(info.check(None, None).is_ok());
+        assert!(info.check(Some("ESP32-C3"), Some("motor")).is_ok());
+        assert!(info.check(Some("esp32"), None).is_err());
+        assert!(info.check(Some("esp8266"), None).is_err());
+        assert!(info.check(None, Some("camera")).is_err());
+    }
+
+    #[test]
+    fn force_accepts_what_is_refused() {
+        let image = synthetic_image(0, "camera", "1.3.0");
+        let checks = Checks {
+            chip: Some("esp32s3".to_string()),
+            ..Default::default()
+        };
+        assert!(checks.inspect(&image).is_err());
+        assert!(checks.inspect(&[0u8; 64]).is_err());
+        let checks = Checks { force: true, ..checks };
+        assert_eq!(checks.inspect(&image).unwrap().unwrap().project_name, "camera");
+        assert_eq!(checks.inspect(&[0u8; 64]).unwrap(), None);
+    }
+
+    #[test]
+    fn compares_with_the_reported_version() {
+        let info = ImageInfo::parse(&synthetic_image(0, "p", "1.3.0")).unwrap();
+        let event = serde_json::json!({ "uptime": 1000, "version": "1.3.0" });
+        assert_eq!(reported_version(&event).as_deref(), Some("1.3.0"));
+        assert!(info.is_running("1.3.0"));
+        assert!(!info.is_running("1.2.0"));
+
+        // a device that only reports its build date
+        let event = serde_json::json!({ "build_date": "Oct 18 2026 12:34:56" });
+        let reported = reported_version(&event).unwrap();
+        assert!(info.is_running(&reported));
+        assert_eq!(reported_version(&serde_json::json!({ "uptime": 1 })), None);
+    }
+
+    #[test]
+    fn reads_the_text_fields_of_a_cbor_sys_event() {
+        // as the CborSerializer of zenoh-esp32 writes a SysMsg, with a version added
+        let mut payload = vec![];
+        let mut encoder = minicbor::Encoder::new(&mut payload);
+        encoder.begin_map().unwrap();
+        encoder.str("cpu").unwrap().str("esp32").unwrap();
+        encoder.str("up_time").unwrap().u64(123_456).unwrap();
+        encoder.u32(7).unwrap().str("integer key").unwrap();
+        encoder.str("version").unwrap().str("1.3.0").unwrap();
+        encoder.end().unwrap();
+        let event = sys_event_from_cbor(&payload).unwrap();
+        assert_eq!(event, serde_json::json!({ "cpu": "esp32", "version": "1.3.0" }));
+        assert_eq!(reported_version(&event).as_deref(), Some("1.3.0"));
+
+        // the SysMsg of zenoh-esp32 has neither version nor build date
+        let mut payload = vec![];
+        let mut encoder = minicbor::Encoder::new(&mut payload);
+        encoder.map(2).unwrap();
+        encoder.str("cpu").unwrap().str("esp32").unwrap();
+        encoder.str("free_heap").unwrap().u32(100_000).unwrap();
+        assert_eq!(reported_version(&sys_event_from_cbor(&payload).unwrap()), None);
+
+        assert!(sys_event_from_cbor(b"[1]").is_err());
+    }
+}
@@ -5,10 +5,13 @@
 #![allow(unused_variables)]
 
 use anyhow::{Context, Error, Result};
-use clap::{Arg, Command};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 use log::{debug, error, info};
 use minicbor::{to_vec, Encode};
+use std::num::NonZeroUsize;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use std::{fs, thread::Thread};
 use tokio::time;
 use walkdir::WalkDir;
@@ -26,6 +29,18 @@ use logger::init;
 mod msg;
 use msg::*;
 
+mod device;
+use device::{SimulatedDevice, SimulatedFleet, SimulatedLink};
+
+mod upload;
+use upload::{OtaLink, UploadOptions};
+
+mod campaign;
+use campaign::{DeviceEntry, Fleet, Manifest};
+
+mod image;
+use image::Checks;
+
 #[tokio::main]
 async fn main() -> Result<()> {
     zenoh::init_log_from_env_or("info");
@@ -34,6 +49,7 @@ async fn main() -> Result<()> {
     let r = do_ota().await;
     if r.is_err() {
         error!("Error: {:?}", r.err().unwrap().to_string());
+        std::process::exit(1);
     }
     Ok(())
 }
@@ -83,17 +99,202 @@ async fn do_ota() -> Result<()> {
                 .help("The path to the zenoh config file")
                 .required(false),
         )
+        .arg(
+            Arg::new("window")
+                .short('w')
+                .long("window")
+                .value_name("CHUNKS")
+                .default_value("4")
+                .value_parser(value_parser!(usize))
+                .help("Chunks sent before waiting for an ack"),
+        )
+        .arg(
+            Arg::new("chunk-size")
+                .long("chunk-size")
+                .value_name("BYTES")
+                .default_value("1024")
+                .value_parser(value_parser!(NonZeroUsize))
+                .help("Bytes per OtaWrite"),
+        )
+        .arg(
+            Arg::new("attempts")
+                .long("attempts")
+                .value_name("N")
+                .default_value("3")
+                .value_parser(value_parser!(u32))
+                .help("Times a failed transfer is resumed"),
+        )
+        .arg(
+            Arg::new("abort")
+                .long("abort")
+                .action(ArgAction::SetTrue)
+                .help("Discard the partial upload on the device"),
+        )
+        .arg(
+            Arg::new("rollback")
+                .long("rollback")
+                .action(ArgAction::SetTrue)
+                .help("Boot the image the device ran before the last update"),
+        )
+        .arg(
+            Arg::new("simulate")
+                .long("simulate")
+                .action(ArgAction::SetTrue)
+                .help("Upload to a simulated device in this process instead of over zenoh"),
+        )
+        .arg(
+            Arg::new("simulate-drop")
+                .long("simulate-drop")
+                .value_name("N")
+                .value_parser(value_parser!(u32))
+                .help("The simulated device drops every Nth message"),
+        )
+        .arg(
+            Arg::new("simulate-stall")
+                .long("simulate-stall")
+                .value_name("OFFSET")
+                .value_parser(value_parser!(u32))
+                .help("The simulated device stops answering at OFFSET until the upload resumes"),
+        )
+        .arg(
+            Arg::new("manifest")
+                .short('m')
+                .long("manifest")
+                .value_name("MANIFEST")
+                .help("Update the devices listed in this JSON campaign manifest"),
+        )
+        .arg(
+            Arg::new("report")
+                .short('r')
+                .long("report")
+                .value_name("REPORT")
+                .help("Write the JSON summary of the campaign to this file"),
+        )
+        .arg(
+            Arg::new("chip")
+                .long("chip")
+                .value_name("CHIP")
+                .help("Refuse images that are not built for this chip, like esp32 or esp32s3"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .value_name("PROJECT")
+                .help("Refuse images of another ESP-IDF project"),
+        )
+        .arg(
+            Arg::new("sys-event")
+                .long("sys-event")
+                .value_name("KEY")
+                .help("Skip the upload when the SysEvent on KEY reports the version of the image"),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .action(ArgAction::SetTrue)
+                .help("Upload refused images and images the device already runs"),
+        )
         .get_matches();
 
     // Get the Zenoh key and binary path from the arguments
     let key = matches.get_one::<String>("key").context("argument key")?;
+    let options = UploadOptions {
+        window: *matches.get_one::<usize>("window").unwrap(),
+        chunk_size: matches.get_one::<NonZeroUsize>("chunk-size").unwrap().get(),
+        attempts: *matches.get_one::<u32>("attempts").unwrap(),
+        ..Default::default()
+    };
+    let checks = Checks {
+        chip: matches.get_one::<String>("chip").cloned(),
+        project: matches.get_one::<String>("project").cloned(),
+        force: matches.get_flag("force"),
+    };
+    if let Some(manifest) = matches.get_one::<String>("manifest") {
+        return do_campaign(&matches, Path::new(manifest), &options, &checks).await;
+    }
+    let operation_only = matches.get_flag("abort") || matches.get_flag("rollback");
+
+    let mut image_info = None;
+    let firmware_data = if operation_only {
+        Vec::new()
+    } else {
+        let binary_path = match matches.get_one::<String>("binary") {
+            Some(binary_path) => binary_path.clone(),
+            None => find_default_file()?,
+        };
+        let binary_path = Path::new(&binary_path);
+        // Check if the firmware binary exists
+        if !binary_path.exists() {
+            return Err(anyhow::anyhow!(
+                "Firmware binary not found at: {:?}",
+                binary_path
+            ));
+        }
+
+        // Read the firmware binary into a byte vector
+        let firmware_data = fs::read(binary_path)?;
+        info!(
+            "Loaded firmware binary ({} bytes) from: {:?}",
+            firmware_data.len(),
+            binary_path
+        );
+        image_info = checks.inspect(&firmware_data)?;
+        if let Some(info) = &image_info {
+            info!("Image {}", info);
+        }
+        firmware_data
+    };
 
-    let binary_path = match matches.get_one::<String>("binary") {
-        Some(binary_path) => binary_path.clone(),
-        None => find_default_file()?,
+    if matches.get_flag("simulate") {
+        let mut device = SimulatedDevice::new(4 * 1024 * 1024);
+        device.drop_every = matches.get_one::<u32>("simulate-drop").copied();
+        device.stall_at = matches.get_one::<u32>("simulate-stall").copied();
+        let mut link = SimulatedLink::new(device);
+        return run(&mut link, "dst/simulator", &matches, &firmware_data, &options).await;
+    }
+
+    let session = open_session(&matches).await?;
+    if let (Some(info), Some(key), false) =
+        (&image_info, matches.get_one::<String>("sys-event"), checks.force)
+    {
+        let running = match reported_version(&session, key, Duration::from_secs(10)).await {
+            Ok(Some(reported)) => {
+                info!("Device reports {}", reported);
+                info.is_running(&reported)
+            }
+            Ok(None) => false,
+            Err(e) => {
+                info!("No version from device, uploading anyway: {}", e);
+                false
+            }
+        };
+        if running {
+            info!("Device already runs this image, use --force to upload it again");
+            session.close().await.map_err(|e| anyhow::anyhow!(e))?;
+            return Ok(());
+        }
+    }
+    let zid = session.info().zid().await;
+    let reply_topic = format!("dst/{}", zid);
+    let reply_topic = reply_topic.as_str();
+
+    let subscriber = session.declare_subscriber(reply_topic).await.unwrap();
+
+    info!("Declared subscriber for key: '{}'", reply_topic);
+
+    let mut link = ZenohLink {
+        session: session.clone(),
+        subscriber,
+        key: key.to_string(),
     };
-    let binary_path = Path::new(&binary_path);
+    let result = run(&mut link, reply_topic, &matches, &firmware_data, &options).await;
+
+    session.close().await.map_err(|e| anyhow::anyhow!(e))?;
 
+    result
+}
+
+async fn open_session(matches: &ArgMatches) -> Result<Session> {
     let config = match matches.get_one::<String>("config") {
         Some(c) => Config::from_file(c).map_err(|e| anyhow::anyhow!(e))?,
         None => {
@@ -112,27 +313,10 @@ async fn do_ota() -> Result<()> {
         }
     };
 
-    // Check if the firmware binary exists
-    if !binary_path.exists() {
-        return Err(anyhow::anyhow!(
-            "Firmware binary not found at: {:?}",
-            binary_path
-        ));
-    }
-
-    // Read the firmware binary into a byte vector
-    let firmware_data = fs::read(binary_path)?;
-    info!(
-        "Loaded firmware binary ({} bytes) from: {:?}",
-        firmware_data.len(),
-        binary_path
-    );
-
     info!("Opening session...");
     let session = zenoh::open(config).await.map_err(|e| anyhow::anyhow!(e))?; // Open a Zenoh session
 
-    let session_info = session.info();
-    let zid = session_info.zid().await;
+    let zid = session.info().zid().await;
     info!("Session ZID: {:?}", zid);
     let mut routers_zid = session.info().routers_zid().await;
     while let Some(router_zid) = routers_zid.next() {
@@ -143,125 +327,162 @@ async fn do_ota() -> Result<()> {
         info!("Peer ZID: {:?}", peer_zid);
     }
 
-    let reply_topic = format!("dst/{}", zid);
-    let reply_topic = reply_topic.as_str();
-
-    let subscriber = session.declare_subscriber(reply_topic).await.unwrap();
-
-    info!("Declared subscriber for key: '{}'", reply_topic);
-
-    let mut ota_msg = OtaMsg::default();
-    ota_msg.operation = Some(OtaOperation::OtaBegin);
-    ota_msg.reply_to = Some(reply_topic.to_string());
-
-    // Publish the OTA begin message to the specified Zenoh key
-
-    let reply = request(session.clone(), &subscriber, key.to_string(), ota_msg, 5).await?;
-
-    if reply.operation.unwrap() != OtaOperation::OtaBegin && reply.rc != Some(0) {
-        return Err(anyhow::anyhow!(
-            "Expected OTA begin ack, got: {:?}",
-            reply.message.unwrap()
-        ));
-    }
+    Ok(session)
+}
 
-    let block_size = 1024;
-    let blocks = firmware_data.len() / block_size;
+async fn do_campaign(
+    matches: &ArgMatches,
+    manifest: &Path,
+    options: &UploadOptions,
+    checks: &Checks,
+) -> Result<()> {
+    let manifest = Manifest::load(manifest)?;
     info!(
-        "Sending OTA data in {} blocks of {} bytes",
-        blocks, block_size
+        "Campaign for {} devices, {} at a time",
+        manifest.devices.len(),
+        manifest.concurrency
     );
+    let report = if matches.get_flag("simulate") {
+        let fleet = SimulatedFleet {
+            drop_every: matches.get_one::<u32>("simulate-drop").copied(),
+            stall_at: matches.get_one::<u32>("simulate-stall").copied(),
+        };
+        campaign::run(Arc::new(fleet), &manifest, options, checks).await
+    } else {
+        let session = open_session(matches).await?;
+        let fleet = ZenohFleet {
+            zid: session.info().zid().await.to_string(),
+            session: session.clone(),
+            version_timeout: Duration::from_secs(10),
+        };
+        let report = campaign::run(Arc::new(fleet), &manifest, options, checks).await;
+        session.close().await.map_err(|e| anyhow::anyhow!(e))?;
+        report
+    };
 
-    for i in 0..=blocks {
-        let begin = i * block_size;
-        let mut end = begin + block_size;
-        if end > firmware_data.len() {
-            end = firmware_data.len()
-        }
-        let slice = &firmware_data[begin..end];
-
-        info!(
-            "Sending OTA data chunk: {}..{} - [{}]",
-            begin,
-            end,
-            end - begin
-        );
-        let mut ota_msg = OtaMsg::default();
-        ota_msg.operation = Some(OtaOperation::OtaWrite);
-        ota_msg.offset = Some(begin as u32);
-        ota_msg.image = Some(slice.to_vec());
-        ota_msg.reply_to = Some(reply_topic.to_string());
-        let reply = request(session.clone(), &subscriber, key.to_string(), ota_msg, 3).await?;
-        if reply.operation != Some(OtaOperation::OtaWrite) && reply.rc != Some(0) {
-            return Err(anyhow::anyhow!(
-                "Expected OTA data ack, got: {:?}",
-                reply.message.unwrap()
-            ));
+    let json = serde_json::to_string_pretty(&report)?;
+    match matches.get_one::<String>("report") {
+        Some(path) => {
+            fs::write(path, &json)?;
+            info!("Campaign report written to {}", path);
         }
+        None => println!("{}", json),
     }
-
-    let mut ota_msg = OtaMsg::default();
-    ota_msg.operation = Some(OtaOperation::OtaEnd);
-    ota_msg.reply_to = Some(reply_topic.to_string());
-
-    // Publish the OTA begin message to the specified Zenoh key
-    info!(" Sending OtaEnd ");
-
-    let reply = request(session.clone(), &subscriber, key.to_string(), ota_msg, 1).await?;
-
-    if reply.operation != Some(OtaOperation::OtaEnd) && reply.rc != Some(0) {
-        return Err(anyhow::anyhow!(
-            "Expected OTA begin ack, got: {:?}",
-            reply.operation
-        ));
+    info!(
+        "Campaign done: {} updated, {} skipped, {} failed",
+        report.updated, report.skipped, report.failed
+    );
+    if report.failed > 0 {
+        return Err(anyhow::anyhow!("{} devices failed", report.failed));
     }
+    Ok(())
+}
 
-    session.close().await.map_err(|e| anyhow::anyhow!(e))?;
-
+async fn run<L: OtaLink>(
+    link: &mut L,
+    reply_to: &str,
+    matches: &ArgMatches,
+    firmware_data: &[u8],
+    options: &UploadOptions,
+) -> Result<()> {
+    if matches.get_flag("abort") {
+        return upload::abort(link, reply_to, options).await;
+    }
+    if matches.get_flag("rollback") {
+        return upload::rollback(link, reply_to, options).await;
+    }
+    let report = upload::upload(link, reply_to, firmware_data, options).await?;
+    info!(
+        "OTA done: {} bytes from offset {} in {} attempt(s), {} chunks sent, {} retransmitted",
+        report.image_size,
+        report.resumed_from,
+        report.attempts,
+        report.chunks_sent,
+        report.retransmits
+    );
     Ok(())
 }
 
-async fn request(
+/// OtaMsg are published on the device key, the replies arrive on our own dst/<zid>
+struct ZenohLink {
     session: Session,
-    subscriber: &Subscriber<FifoChannelHandler<Sample>>,
-    request_topic: String,
-    request: OtaMsg,
-    trials: u32,
-) -> Result<OtaMsg> {
-    debug!(
-        "Publishing OTA  message to key: '{}':{:?}",
-        request_topic, request.operation
-    );
+    subscriber: Subscriber<FifoChannelHandler<Sample>>,
+    key: String,
+}
 
-    for i in 0..trials {
-        let request_bytes = to_vec(&request)?;
-        info!("Request bytes: '{}' => [{}]",request_topic, request_bytes.len()); // {:02X?}
-        session
-            .put(request_topic.clone(), request_bytes)
+impl OtaLink for ZenohLink {
+    async fn send(&mut self, msg: OtaMsg) -> Result<()> {
+        let request_bytes = to_vec(&msg)?;
+        debug!("Request bytes: '{}' => [{}]", self.key, request_bytes.len());
+        self.session
+            .put(self.key.clone(), request_bytes)
             .congestion_control(CongestionControl::Block)
             .await
-            .map_err(|e| anyhow::anyhow!(e))?;
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 
+    async fn recv(&mut self, timeout: Duration) -> Result<Option<OtaMsg>> {
         tokio::select! {
-            _ = time::sleep(time::Duration::from_secs(1)) =>{
-
-            },
-            sample = subscriber.recv_async() =>  {
-                match sample {
-                Ok(sample) => {
-                    let bytes: Vec<u8> = sample.payload().slices().fold(Vec::new(), |mut b, x| {
-                        b.extend_from_slice(x);
-                        b
-                    });
-                    let reply = minicbor::decode::<OtaMsg>(bytes.as_slice())?;
-                    info!("Received OTA reply message: {:?}", reply);
-                    return Ok(reply)
-                }
-                Err(e) => {},
+            _ = time::sleep(timeout) => Ok(None),
+            sample = self.subscriber.recv_async() => {
+                let sample = sample.map_err(|e| anyhow::anyhow!(e))?;
+                let bytes: Vec<u8> = sample.payload().slices().fold(Vec::new(), |mut b, x| {
+                    b.extend_from_slice(x);
+                    b
+                });
+                Ok(Some(minicbor::decode::<OtaMsg>(bytes.as_slice())?))
             }
         }
+    }
+}
+
+/// Every device gets its own reply key dst/<zid>/<device key>
+struct ZenohFleet {
+    session: Session,
+    zid: String,
+    version_timeout: Duration,
+}
+
+impl Fleet for ZenohFleet {
+    type Link = ZenohLink;
+
+    async fn connect(&self, device: &DeviceEntry) -> Result<(ZenohLink, String)> {
+        let reply_topic = format!("dst/{}/{}", self.zid, device.key);
+        let subscriber = self
+            .session
+            .declare_subscriber(reply_topic.as_str())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let link = ZenohLink {
+            session: self.session.clone(),
+            subscriber,
+            key: device.key.clone(),
+        };
+        Ok((link, reply_topic))
+    }
+
+    async fn version(&self, device: &DeviceEntry) -> Result<Option<String>> {
+        match &device.sys_event {
+            Some(key) => reported_version(&self.session, key, self.version_timeout).await,
+            None => Ok(None),
         }
-        if  i+1 < trials { info!(" Retry ......................... {}",i+1);};
     }
-    Err(Error::msg("failed after retries"))
+}
+
+/// The version or build date in the next SysEvent the device publishes on key, in JSON or CBOR
+async fn reported_version(session: &Session, key: &str, timeout: Duration) -> Result<Option<String>> {
+    let subscriber = session
+        .declare_subscriber(key)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let sample = time::timeout(timeout, subscriber.recv_async())
+        .await
+        .context("no SysEvent")?
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let payload = sample.payload().to_bytes();
+    let event = match serde_json::from_slice::<serde_json::Value>(&payload) {
+        Ok(event) => event,
+        Err(_) => image::sys_event_from_cbor(&payload).context("SysEvent is neither JSON nor a CBOR map")?,
+    };
+    Ok(image::reported_version(&event))
 }
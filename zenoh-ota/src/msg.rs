@@ -81,12 +81,16 @@ struct OtaMsg : public Serializable
 };
 */
 
+/// OtaEnd verifies the SHA-256 of the received image, OtaCommit makes it the boot image
 #[derive(Decode,Encode,Debug, Clone,PartialEq)]
 #[cbor(index_only)]
 pub enum OtaOperation {
     #[n(0)] OtaBegin = 0,
     #[n(1)] OtaEnd,
     #[n(2)] OtaWrite,
+    #[n(3)] OtaCommit,
+    #[n(4)] OtaAbort,
+    #[n(5)] OtaRollback,
 }
 
 #[derive(Encode, Decode, Default, Debug, Clone)]
@@ -104,19 +108,32 @@ pub struct OtaMsg {
     pub message: Option<String>,
     #[n(5)]
     pub reply_to: Option<String>,
+    #[n(6)]
+    pub image_size: Option<u32>,
+    #[cbor(n(7), with = "minicbor::bytes")]
+    pub sha256: Option<Vec<u8>>,
 }
 
 impl OtaMsg {
 
-    fn begin(reply_to : String )->Self {
-        OtaMsg { operation: Some(OtaOperation::OtaBegin) , reply_to:Some(reply_to), ..Default::default() }
+    /// the reply to OtaBegin carries in offset how much of this image the device already has
+    pub fn begin(image_size: u32, sha256: Vec<u8>, reply_to: String) -> Self {
+        OtaMsg { operation: Some(OtaOperation::OtaBegin), image_size: Some(image_size), sha256: Some(sha256), reply_to: Some(reply_to), ..Default::default() }
     }
 
-    fn write(offset :u32, data : Vec<u8> , reply_to:String ) -> Self {
+    pub fn write(offset :u32, data : Vec<u8> , reply_to:String ) -> Self {
         OtaMsg { operation: Some(OtaOperation::OtaWrite) , offset : Some(offset), image:Some(data) ,reply_to:Some(reply_to), ..Default::default() }
     }
 
-    fn end(reply_to : String )->Self {
-        OtaMsg { operation: Some(OtaOperation::OtaEnd) , reply_to:Some(reply_to), ..Default::default() }
+    pub fn operation(operation: OtaOperation, reply_to: String) -> Self {
+        OtaMsg { operation: Some(operation), reply_to: Some(reply_to), ..Default::default() }
+    }
+
+    pub fn reply(request: &OtaMsg, rc: i32, message: &str) -> Self {
+        OtaMsg { operation: request.operation.clone(), offset: request.offset, rc: Some(rc), message: Some(message.to_string()), reply_to: request.reply_to.clone(), ..Default::default() }
     }
-}
\ No newline at end of file
+
+    pub fn is_ok(&self) -> bool {
+        self.rc == Some(0)
+    }
+}
@@ -0,0 +1,353 @@
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::msg::*;
+
+/// Carries OtaMsg between the uploader and a device, over zenoh or to a simulated device,
+/// the futures are Send so uploads to several devices can run as tasks
+pub trait OtaLink {
+    fn send(&mut self, msg: OtaMsg) -> impl Future<Output = Result<()>> + Send;
+    /// the next message from the device, None when nothing arrived within timeout
+    fn recv(&mut self, timeout: Duration) -> impl Future<Output = Result<Option<OtaMsg>>> + Send;
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadOptions {
+    pub chunk_size: usize,
+    /// chunks sent before waiting for an ack
+    pub window: usize,
+    /// wait for a reply before sending again
+    pub timeout: Duration,
+    pub retries: u32,
+    /// a failed transfer is resumed with a new OtaBegin this many times
+    pub attempts: u32,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        UploadOptions {
+            chunk_size: 1024,
+            window: 4,
+            timeout: Duration::from_secs(1),
+            retries: 3,
+            attempts: 3,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct UploadReport {
+    pub image_size: usize,
+    pub sha256: String,
+    /// offset the last attempt started at, not 0 when the device already had part of the image
+    pub resumed_from: usize,
+    pub attempts: u32,
+    pub chunks_sent: u32,
+    pub retransmits: u32,
+}
+
+/// Sends the image, resuming where the device stopped, then verifies and commits it
+pub async fn upload<L: OtaLink>(
+    link: &mut L,
+    reply_to: &str,
+    image: &[u8],
+    options: &UploadOptions,
+) -> Result<UploadReport> {
+    upload_with_progress(link, reply_to, image, options, |_| {}).await
+}
+
+/// As upload, progress is called with the number of bytes the device acked
+pub async fn upload_with_progress<L: OtaLink>(
+    link: &mut L,
+    reply_to: &str,
+    image: &[u8],
+    options: &UploadOptions,
+    mut progress: impl FnMut(usize) + Send,
+) -> Result<UploadReport> {
+    if options.chunk_size == 0 {
+        bail!("chunk_size must be at least 1 byte");
+    }
+    let sha256 = Sha256::digest(image).to_vec();
+    let mut report = UploadReport {
+        image_size: image.len(),
+        sha256: hex::encode(&sha256),
+        ..Default::default()
+    };
+    info!(
+        "Uploading {} bytes, sha256 {} in chunks of {} with a window of {}",
+        image.len(),
+        report.sha256,
+        options.chunk_size,
+        options.window
+    );
+
+    loop {
+        report.attempts += 1;
+        match send_image(link, reply_to, image, &sha256, options, &mut report, &mut progress).await {
+            Ok(()) => break,
+            Err(e) if report.attempts < options.attempts => {
+                warn!("Attempt {} failed: {}, resuming", report.attempts, e)
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let reply = request(link, OtaMsg::operation(OtaOperation::OtaEnd, reply_to.to_string()), options).await?;
+    if !reply.is_ok() {
+        // the received image is useless, the next upload starts from scratch
+        abort(link, reply_to, options).await?;
+        bail!("Device rejected the image: {}", reply.message.unwrap_or_default());
+    }
+    info!("Image verified by device");
+
+    let reply = request(link, OtaMsg::operation(OtaOperation::OtaCommit, reply_to.to_string()), options).await?;
+    if !reply.is_ok() {
+        bail!("Commit failed: {}", reply.message.unwrap_or_default());
+    }
+    info!("Image committed, device boots it next");
+    Ok(report)
+}
+
+/// Discards the partial upload on the device
+pub async fn abort<L: OtaLink>(link: &mut L, reply_to: &str, options: &UploadOptions) -> Result<()> {
+    operation(link, OtaOperation::OtaAbort, reply_to, options).await
+}
+
+/// Makes the device boot the image it ran before the last commit
+pub async fn rollback<L: OtaLink>(link: &mut L, reply_to: &str, options: &UploadOptions) -> Result<()> {
+    operation(link, OtaOperation::OtaRollback, reply_to, options).await
+}
+
+async fn operation<L: OtaLink>(
+    link: &mut L,
+    operation: OtaOperation,
+    reply_to: &str,
+    options: &UploadOptions,
+) -> Result<()> {
+    let reply = request(link, OtaMsg::operation(operation.clone(), reply_to.to_string()), options).await?;
+    if !reply.is_ok() {
+        bail!("{:?} failed: {}", operation, reply.message.unwrap_or_default());
+    }
+    info!("{:?} done", operation);
+    Ok(())
+}
+
+async fn send_image<L: OtaLink>(
+    link: &mut L,
+    reply_to: &str,
+    image: &[u8],
+    sha256: &[u8],
+    options: &UploadOptions,
+    report: &mut UploadReport,
+    progress: &mut (impl FnMut(usize) + Send),
+) -> Result<()> {
+    let begin = OtaMsg::begin(image.len() as u32, sha256.to_vec(), reply_to.to_string());
+    let reply = request(link, begin, options).await?;
+    if !reply.is_ok() {
+        bail!("Device refused OtaBegin: {}", reply.message.unwrap_or_default());
+    }
+    // chunks are resent from a chunk boundary
+    let chunk_size = options.chunk_size;
+    let resume = (reply.offset.unwrap_or(0) as usize).min(image.len()) / chunk_size * chunk_size;
+    if resume > 0 {
+        info!("Device has {} bytes of this image, resuming", resume);
+    }
+    report.resumed_from = resume;
+    let mut acked = resume;
+    progress(acked);
+
+    let mut next = resume;
+    // offset => (sent at, retries)
+    let mut in_flight: BTreeMap<usize, (Instant, u32)> = BTreeMap::new();
+    while next < image.len() || !in_flight.is_empty() {
+        while in_flight.len() < options.window.max(1) && next < image.len() {
+            send_chunk(link, reply_to, image, next, chunk_size).await?;
+            report.chunks_sent += 1;
+            in_flight.insert(next, (Instant::now(), 0));
+            next += chunk_size;
+        }
+
+        let (&oldest, &(sent, retries)) = in_flight
+            .iter()
+            .min_by_key(|(_, (sent, _))| *sent)
+            .expect("window is not empty");
+        let wait = (sent + options.timeout).saturating_duration_since(Instant::now());
+        match link.recv(wait).await? {
+            Some(reply) if reply.operation == Some(OtaOperation::OtaWrite) => {
+                let Some(offset) = reply.offset.map(|offset| offset as usize) else {
+                    continue;
+                };
+                if !reply.is_ok() {
+                    bail!("Write at {} failed: {}", offset, reply.message.unwrap_or_default());
+                }
+                if in_flight.remove(&offset).is_some() {
+                    acked += chunk_size.min(image.len().saturating_sub(offset));
+                    debug!("Ack {} , {} bytes to go", offset, image.len() - acked);
+                    progress(acked);
+                }
+            }
+            Some(reply) => debug!("Ignoring {:?} reply", reply.operation),
+            None => {
+                if retries >= options.retries {
+                    bail!("No ack for chunk at {} after {} retries", oldest, retries);
+                }
+                info!(" Retry ......................... chunk {} ({})", oldest, retries + 1);
+                send_chunk(link, reply_to, image, oldest, chunk_size).await?;
+                report.chunks_sent += 1;
+                report.retransmits += 1;
+                in_flight.insert(oldest, (Instant::now(), retries + 1));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn send_chunk<L: OtaLink>(
+    link: &mut L,
+    reply_to: &str,
+    image: &[u8],
+    offset: usize,
+    chunk_size: usize,
+) -> Result<()> {
+    let end = (offset + chunk_size).min(image.len());
+    debug!("Sending OTA data chunk: {}..{} - [{}]", offset, end, end - offset);
+    link.send(OtaMsg::write(offset as u32, image[offset..end].to_vec(), reply_to.to_string()))
+        .await
+}
+
+/// Sends the request until a reply for the same operation arrives
+async fn request<L: OtaLink>(link: &mut L, request: OtaMsg, options: &UploadOptions) -> Result<OtaMsg> {
+    for trial in 0..=options.retries {
+        link.send(request.clone()).await?;
+        let deadline = Instant::now() + options.timeout;
+        loop {
+            match link.recv(deadline.saturating_duration_since(Instant::now())).await? {
+                Some(reply) if reply.operation == request.operation => {
+                    debug!("Received OTA reply message: {:?}", reply);
+                    return Ok(reply);
+                }
+                Some(reply) => debug!("Ignoring late {:?} reply", reply.operation),
+                None => break,
+            }
+        }
+        if trial < options.retries {
+            info!(" Retry ......................... {:?} {}", request.operation, trial + 1);
+        }
+    }
+    bail!("No reply to {:?} after {} retries", request.operation, options.retries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::{SimulatedDevice, SimulatedLink};
+
+    const REPLY_TO: &str = "src/test/ota";
+
+    fn image(len: usize, seed: u8) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8 ^ seed).collect()
+    }
+
+    fn options() -> UploadOptions {
+        UploadOptions {
+            chunk_size: 512,
+            timeout: Duration::from_millis(20),
+            ..Default::default()
+        }
+    }
+
+    fn link(configure: impl FnOnce(&mut SimulatedDevice)) -> SimulatedLink {
+        let mut device = SimulatedDevice::new(64 * 1024);
+        configure(&mut device);
+        SimulatedLink::new(device)
+    }
+
+    /// flips a bit of the first chunk on its way to the device
+    struct CorruptingLink {
+        link: SimulatedLink,
+        corrupted: bool,
+    }
+
+    impl OtaLink for CorruptingLink {
+        async fn send(&mut self, mut msg: OtaMsg) -> Result<()> {
+            if !self.corrupted && msg.operation == Some(OtaOperation::OtaWrite) {
+                if let Some(image) = msg.image.as_mut() {
+                    image[0] ^= 1;
+                    self.corrupted = true;
+                }
+            }
+            self.link.send(msg).await
+        }
+
+        async fn recv(&mut self, timeout: Duration) -> Result<Option<OtaMsg>> {
+            self.link.recv(timeout).await
+        }
+    }
+
+    #[tokio::test]
+    async fn lost_messages_are_retransmitted() {
+        let image = image(20_000, 1);
+        let mut link = link(|device| device.drop_every = Some(7));
+        let report = upload(&mut link, REPLY_TO, &image, &options()).await.unwrap();
+        assert!(report.retransmits > 0);
+        assert_eq!(report.attempts, 1);
+        assert_eq!(link.device.running, image);
+    }
+
+    #[tokio::test]
+    async fn stalled_upload_resumes_where_the_device_stopped() {
+        let image = image(20_000, 2);
+        let mut link = link(|device| device.stall_at = Some(8_000));
+        let report = upload(&mut link, REPLY_TO, &image, &options()).await.unwrap();
+        assert_eq!(report.attempts, 2);
+        assert!(report.resumed_from > 0 && report.resumed_from <= 8_000);
+        assert_eq!(report.resumed_from % 512, 0);
+        assert_eq!(link.device.running, image);
+    }
+
+    #[tokio::test]
+    async fn zero_chunk_size_is_refused() {
+        let options = UploadOptions { chunk_size: 0, ..options() };
+        let error = upload(&mut link(|_| {}), REPLY_TO, &image(1_000, 4), &options).await.unwrap_err();
+        assert!(error.to_string().contains("chunk_size"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn sha256_mismatch_aborts_the_upload() {
+        let image = image(4_000, 3);
+        let mut corrupting = CorruptingLink {
+            link: link(|_| {}),
+            corrupted: false,
+        };
+        let error = upload(&mut corrupting, REPLY_TO, &image, &options()).await.unwrap_err();
+        assert!(error.to_string().contains("sha256 mismatch"), "{}", error);
+        assert!(corrupting.link.device.running.is_empty());
+
+        // aborted, so nothing of the corrupt image is resumed
+        let mut link = corrupting.link;
+        let report = upload(&mut link, REPLY_TO, &image, &options()).await.unwrap();
+        assert_eq!(report.resumed_from, 0);
+        assert_eq!(link.device.running, image);
+    }
+
+    #[tokio::test]
+    async fn commit_swaps_the_running_image_and_rollback_restores_it() {
+        let (first, second) = (image(3_000, 4), image(5_000, 5));
+        let mut link = link(|_| {});
+        upload(&mut link, REPLY_TO, &first, &options()).await.unwrap();
+        assert_eq!(link.device.running, first);
+        upload(&mut link, REPLY_TO, &second, &options()).await.unwrap();
+        assert_eq!(link.device.running, second);
+
+        rollback(&mut link, REPLY_TO, &options()).await.unwrap();
+        assert_eq!(link.device.running, first);
+        // only the image before the last commit is kept
+        assert!(rollback(&mut link, REPLY_TO, &options()).await.is_err());
+        assert_eq!(link.device.running, first);
+    }
+}
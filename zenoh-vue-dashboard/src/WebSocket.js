@@ -13,7 +13,9 @@ class WS {
         this.url = url;
         this.ws = null;
         this.connected = false;
-        this.subscriptions = [];
+        this.subscriptions = new Map(); // topic pattern => binary, replayed on every (re)connect
+        this.pending = {}; // "SaveReply:key" => queue of [resolve, reject, timer] of the commands waiting for it
+        this.timeout = 5000; // msec before a command without reply is rejected
         this.txd = txd;
         this.rxd = rxd;
         this.start_time = Date.now();
@@ -28,13 +30,20 @@ class WS {
             this.ws.close();
         }
         this.ws = new WebSocket(this.url);
+        this.ws.binaryType = "arraybuffer";
 
         this.ws.onopen = () => {
             this.connected = true;
             console.log("Connected to WebSocket server");
+            // the server forgets the subscriptions of a closed connection
+            for (const [topic_pattern, binary] of this.subscriptions) {
+                this.request({ type: "Subscribe", topic: topic_pattern, binary: binary }, "SubscribeReply", topic_pattern)
+                    .catch((rc) => console.warn("WS resubscribe failed:", topic_pattern, rc));
+            }
         };
         this.ws.onclose = () => {
             this.connected = false;
+            this.rejectPending({ code: 503, msg: "WebSocket disconnected." });
             console.log("Disconnected from WebSocket server");
             // Attempt to reconnect after a delay
             setTimeout(() => {
@@ -51,10 +60,30 @@ class WS {
             console.log("Message sent:", event.data);
         }
         this.ws.onmessage = (event) => {
+            if (event.data instanceof ArrayBuffer) {
+                // binary frame of a binary subscription : u16 topic length, topic, payload bytes
+                const view = new DataView(event.data);
+                const length = view.getUint16(0);
+                const topic = new TextDecoder().decode(new Uint8Array(event.data, 2, length));
+                if (this.rxd) this.rxd.publish(topic, new Uint8Array(event.data, 2 + length));
+                return;
+            }
             const message = JSON.parse(event.data);
             console.log("Message received:", message);
             if (message.type === "Publish" && this.rxd) {
                 this.rxd.publish(message.topic, message.payload);
+            } else if (message.rc) {
+                // replies for the same key come back in the order the commands were sent
+                const id = message.type + ":" + (message.key ?? message.topic ?? message.prefix);
+                const waiting = this.pending[id]?.shift();
+                if (this.pending[id]?.length === 0) delete this.pending[id];
+                if (message.rc.code !== 200) console.warn("WS command failed:", message);
+                if (waiting) {
+                    window.clearTimeout(waiting[2]);
+                    message.rc.code === 200 ? waiting[0](message) : waiting[1](message.rc);
+                }
+            } else if (message.type === "Error") {
+                console.warn("WS command rejected:", message.msg);
             }
         };
         this.connectionTimer = window.setInterval(() => {
@@ -95,13 +124,44 @@ class WS {
             alert('WebSocket is not connected.');
         }
     }
-    subscribe(topic_pattern) {
-        if (this.ws && this.ws.readyState === WebSocket.OPEN) {
-            var message = { type: "Subscribe", topic: topic_pattern };
+    // sends a command, the promise resolves with the reply or rejects with its ReturnCode
+    request(message, reply_type, id) {
+        return new Promise((resolve, reject) => {
+            if (!(this.ws && this.ws.readyState === WebSocket.OPEN)) {
+                reject({ code: 503, msg: "WebSocket is not connected." });
+                return;
+            }
+            const key = reply_type + ":" + id;
+            const queue = this.pending[key] ??= [];
+            const entry = [resolve, reject, window.setTimeout(() => {
+                const index = queue.indexOf(entry);
+                if (index >= 0) queue.splice(index, 1);
+                if (queue.length === 0 && this.pending[key] === queue) delete this.pending[key];
+                reject({ code: 504, msg: "No " + reply_type + " for " + id + " within " + this.timeout + " msec." });
+            }, this.timeout)];
+            queue.push(entry);
             this.ws.send(JSON.stringify(message));
-        } else {
-            console.log('WebSocket is not connected.');
+        });
+    }
+    rejectPending(rc) {
+        for (const queue of Object.values(this.pending)) {
+            for (const [, reject, timer] of queue) {
+                window.clearTimeout(timer);
+                reject(rc);
+            }
         }
+        this.pending = {};
+    }
+    // topic_pattern is a zenoh key expression, a new connection is subscribed to src/**
+    // with binary, raw and jpeg payloads arrive as Uint8Array instead of base64 strings
+    // the subscription is kept and sent again after a reconnect
+    subscribe(topic_pattern, binary = false) {
+        this.subscriptions.set(topic_pattern, binary);
+        return this.request({ type: "Subscribe", topic: topic_pattern, binary: binary }, "SubscribeReply", topic_pattern);
+    }
+    unsubscribe(topic_pattern) {
+        this.subscriptions.delete(topic_pattern);
+        return this.request({ type: "Unsubscribe", topic: topic_pattern }, "UnsubscribeReply", topic_pattern);
     }
     publish(topic, payload) {
         if (this.ws && this.ws.readyState === WebSocket.OPEN) {
@@ -113,20 +173,14 @@ class WS {
         }
     }
     save(key, payload) {
-        if (this.ws && this.ws.readyState === WebSocket.OPEN) {
-            var message = { type: "Save", key: key, payload: payload };
-            this.ws.send(JSON.stringify(message));
-        } else {
-            console.log('WebSocket is not connected.');
-        }
+        return this.request({ type: "Save", key: key, payload: payload }, "SaveReply", key);
     }
+    // resolves with the LoadReply, its payload is the saved value
     load(key) {
-        if (this.ws && this.ws.readyState === WebSocket.OPEN) {
-            var message = { type: "Load", key: key };
-            this.ws.send(JSON.stringify(message));
-        } else {
-            console.log('WebSocket is not connected.');
-        }
+        return this.request({ type: "Load", key: key }, "LoadReply", key);
+    }
+    list(prefix) {
+        return this.request({ type: "List", prefix: prefix }, "ListReply", prefix);
     }
 }
 
@@ -0,0 +1,285 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use zenoh::bytes::Encoding;
+use zenoh::key_expr::{keyexpr, OwnedKeyExpr};
+
+use crate::msg::{Publish, ReturnCode};
+
+// How the payload on a key is interpreted
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadEncoding {
+    Json,
+    Cbor,
+    Raw,
+    Jpeg,
+}
+
+impl PayloadEncoding {
+    pub fn zenoh_encoding(self) -> Encoding {
+        match self {
+            PayloadEncoding::Json => Encoding::APPLICATION_JSON,
+            PayloadEncoding::Cbor => Encoding::APPLICATION_CBOR,
+            PayloadEncoding::Raw => Encoding::ZENOH_BYTES,
+            PayloadEncoding::Jpeg => Encoding::IMAGE_JPEG,
+        }
+    }
+
+    // what the sender declared, None for plain bytes that have to be sniffed
+    fn from_zenoh(encoding: &Encoding) -> Option<Self> {
+        let encoding = encoding.to_string();
+        match encoding.split(';').next().unwrap_or_default() {
+            "application/json" | "text/json" => Some(PayloadEncoding::Json),
+            "application/cbor" => Some(PayloadEncoding::Cbor),
+            "image/jpeg" => Some(PayloadEncoding::Jpeg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct EncodingRule {
+    key: String,
+    encoding: PayloadEncoding,
+}
+
+// Encodings configured per key expression, the first rule matching a key wins
+// [ { "key": "src/cam1/**", "encoding": "jpeg" }, { "key": "src/*/motor/**", "encoding": "cbor" } ]
+#[derive(Default)]
+pub struct Encodings {
+    rules: Vec<(OwnedKeyExpr, PayloadEncoding)>,
+}
+
+impl Encodings {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let text = match fs::read_to_string(path.as_ref()) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        let rules: Vec<EncodingRule> = serde_json::from_str(&text)?;
+        let rules = rules
+            .into_iter()
+            .map(|rule| {
+                OwnedKeyExpr::try_from(rule.key.as_str())
+                    .map(|key_expr| (key_expr, rule.encoding))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        info!("🔤 {} encoding rules from {:?}", rules.len(), path.as_ref());
+        Ok(Self { rules })
+    }
+
+    pub fn configured(&self, key: &keyexpr) -> Option<PayloadEncoding> {
+        self.rules
+            .iter()
+            .find(|(key_expr, _)| key_expr.intersects(key))
+            .map(|(_, encoding)| *encoding)
+    }
+
+    // configured, else declared by the publisher, else guessed from the bytes
+    pub fn detect(&self, key: &keyexpr, encoding: &Encoding, payload: &[u8]) -> PayloadEncoding {
+        self.configured(key)
+            .or_else(|| PayloadEncoding::from_zenoh(encoding))
+            .unwrap_or_else(|| sniff(payload))
+    }
+
+    // the bytes to put on zenoh : base64 decoded, or the JSON transcoded to the encoding of the key
+    pub fn encode_payload(&self, pub_msg: &Publish) -> Result<(Vec<u8>, PayloadEncoding), ReturnCode> {
+        let configured = keyexpr::new(pub_msg.topic.as_str())
+            .map_err(|e| ReturnCode::error(400, format!("invalid key expression: {}", e)))
+            .map(|key| self.configured(key))?;
+        let text = pub_msg.payload.as_str();
+        if pub_msg.base64 {
+            let text = text.ok_or_else(|| ReturnCode::error(400, "base64 payload must be a string"))?;
+            let bytes = STANDARD
+                .decode(text)
+                .map_err(|e| ReturnCode::error(400, format!("invalid base64: {}", e)))?;
+            return Ok((bytes, pub_msg.encoding.or(configured).unwrap_or(PayloadEncoding::Raw)));
+        }
+        match pub_msg.encoding.or(configured).unwrap_or(PayloadEncoding::Json) {
+            PayloadEncoding::Json => Ok((pub_msg.payload.to_string().into_bytes(), PayloadEncoding::Json)),
+            PayloadEncoding::Cbor => Ok((json_to_cbor(&pub_msg.payload), PayloadEncoding::Cbor)),
+            encoding => match text {
+                Some(text) => Ok((text.as_bytes().to_vec(), encoding)),
+                None => Err(ReturnCode::error(400, "raw payload must be a string or base64")),
+            },
+        }
+    }
+}
+
+// only for keys without rule or declared encoding, almost any short byte string is a valid CBOR
+// scalar so only a map or an array at the top level counts as CBOR
+fn sniff(payload: &[u8]) -> PayloadEncoding {
+    if payload.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        PayloadEncoding::Jpeg
+    } else if serde_json::from_slice::<serde_json::Value>(payload).is_ok() {
+        PayloadEncoding::Json
+    } else if matches!(cbor_value(payload), Ok(ciborium::Value::Map(_) | ciborium::Value::Array(_))) {
+        PayloadEncoding::Cbor
+    } else {
+        PayloadEncoding::Raw
+    }
+}
+
+pub fn cbor_to_json(payload: &[u8]) -> Result<serde_json::Value, String> {
+    cbor_value(payload).map(cbor_value_to_json)
+}
+
+// The whole payload has to be one CBOR item
+fn cbor_value(payload: &[u8]) -> Result<ciborium::Value, String> {
+    let mut reader = payload;
+    let value: ciborium::Value = ciborium::from_reader(&mut reader).map_err(|e| e.to_string())?;
+    if !reader.is_empty() {
+        return Err(format!("{} bytes after the CBOR item", reader.len()));
+    }
+    Ok(value)
+}
+
+pub fn json_to_cbor(value: &serde_json::Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).expect("writing to a Vec doesn't fail");
+    bytes
+}
+
+// byte strings become base64, map keys that are no text become their JSON text, tags are dropped
+fn cbor_value_to_json(value: ciborium::Value) -> serde_json::Value {
+    use ciborium::Value as Cbor;
+    use serde_json::Value as Json;
+    match value {
+        Cbor::Integer(i) => {
+            let i = i128::from(i);
+            i64::try_from(i)
+                .map(Json::from)
+                .or_else(|_| u64::try_from(i).map(Json::from))
+                .unwrap_or_else(|_| Json::from(i as f64))
+        }
+        Cbor::Float(f) => serde_json::Number::from_f64(f).map(Json::Number).unwrap_or(Json::Null),
+        Cbor::Bytes(bytes) => Json::String(STANDARD.encode(bytes)),
+        Cbor::Text(text) => Json::String(text),
+        Cbor::Bool(b) => Json::Bool(b),
+        Cbor::Null => Json::Null,
+        Cbor::Tag(_, value) => cbor_value_to_json(*value),
+        Cbor::Array(items) => Json::Array(items.into_iter().map(cbor_value_to_json).collect()),
+        Cbor::Map(entries) => Json::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match cbor_value_to_json(key) {
+                        Json::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    (key, cbor_value_to_json(value))
+                })
+                .collect(),
+        ),
+        _ => Json::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ciborium::Value as Cbor;
+    use serde_json::json;
+
+    fn cbor(value: Cbor) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&value, &mut bytes).unwrap();
+        bytes
+    }
+
+    fn publish(topic: &str, payload: serde_json::Value, base64: bool, encoding: Option<PayloadEncoding>) -> Publish {
+        Publish { topic: topic.to_string(), payload, base64, encoding }
+    }
+
+    #[test]
+    fn cbor_byte_strings_become_base64() {
+        let payload = cbor(Cbor::Map(vec![(Cbor::Text("frame".into()), Cbor::Bytes(vec![0, 1, 2, 0xFF]))]));
+        assert_eq!(cbor_to_json(&payload).unwrap(), json!({ "frame": STANDARD.encode([0, 1, 2, 0xFF]) }));
+    }
+
+    #[test]
+    fn cbor_map_keys_that_are_no_text_become_their_json_text() {
+        let payload = cbor(Cbor::Map(vec![
+            (Cbor::Integer(7.into()), Cbor::Text("seven".into())),
+            (Cbor::Bool(true), Cbor::Null),
+            (Cbor::Array(vec![Cbor::Integer(1.into())]), Cbor::Integer(2.into())),
+        ]));
+        assert_eq!(cbor_to_json(&payload).unwrap(), json!({ "7": "seven", "true": null, "[1]": 2 }));
+    }
+
+    #[test]
+    fn cbor_integers_beyond_i64_keep_their_value() {
+        let payload = cbor(Cbor::Array(vec![Cbor::Integer(u64::MAX.into()), Cbor::Integer(i64::MIN.into())]));
+        assert_eq!(cbor_to_json(&payload).unwrap(), json!([u64::MAX, i64::MIN]));
+    }
+
+    #[test]
+    fn cbor_with_trailing_bytes_is_refused() {
+        let mut payload = cbor(Cbor::Array(vec![]));
+        payload.push(0);
+        assert!(cbor_to_json(&payload).is_err());
+    }
+
+    #[test]
+    fn sniff_takes_only_maps_and_arrays_for_cbor() {
+        assert_eq!(sniff(&cbor(Cbor::Map(vec![(Cbor::Text("a".into()), Cbor::Integer(1.into()))]))), PayloadEncoding::Cbor);
+        assert_eq!(sniff(&cbor(Cbor::Array(vec![Cbor::Bytes(vec![1])]))), PayloadEncoding::Cbor);
+        // a single byte below 0x18 is a valid CBOR integer, a short text a valid CBOR string
+        assert_eq!(sniff(&[0x05]), PayloadEncoding::Raw);
+        assert_eq!(sniff(&cbor(Cbor::Text("abc".into()))), PayloadEncoding::Raw);
+        assert_eq!(sniff(br#"{"a":1}"#), PayloadEncoding::Json);
+        assert_eq!(sniff(&[0xFF, 0xD8, 0xFF, 0xE0]), PayloadEncoding::Jpeg);
+    }
+
+    #[test]
+    fn detect_sniffs_only_without_rule_or_declared_encoding() {
+        let encodings = Encodings { rules: vec![(OwnedKeyExpr::try_from("src/cam/**").unwrap(), PayloadEncoding::Jpeg)] };
+        let map = cbor(Cbor::Map(vec![]));
+        let key = keyexpr::new("src/cam/image").unwrap();
+        assert_eq!(encodings.detect(key, &Encoding::ZENOH_BYTES, &map), PayloadEncoding::Jpeg);
+        let key = keyexpr::new("src/motor").unwrap();
+        assert_eq!(encodings.detect(key, &Encoding::APPLICATION_JSON, &map), PayloadEncoding::Json);
+        assert_eq!(encodings.detect(key, &Encoding::ZENOH_BYTES, &map), PayloadEncoding::Cbor);
+    }
+
+    #[test]
+    fn base64_publish_puts_the_decoded_bytes() {
+        let encodings = Encodings { rules: vec![(OwnedKeyExpr::try_from("src/cam/**").unwrap(), PayloadEncoding::Jpeg)] };
+        let bytes = vec![0xFF, 0xD8, 0xFF, 0x00, 0x80];
+        let payload = json!(STANDARD.encode(&bytes));
+        assert_eq!(
+            encodings.encode_payload(&publish("src/cam/image", payload.clone(), true, None)),
+            Ok((bytes.clone(), PayloadEncoding::Jpeg))
+        );
+        assert_eq!(
+            encodings.encode_payload(&publish("src/blob", payload.clone(), true, None)),
+            Ok((bytes.clone(), PayloadEncoding::Raw))
+        );
+        assert_eq!(
+            encodings.encode_payload(&publish("src/blob", payload, true, Some(PayloadEncoding::Cbor))),
+            Ok((bytes, PayloadEncoding::Cbor))
+        );
+        let rc = encodings.encode_payload(&publish("src/blob", json!("not base64!"), true, None)).unwrap_err();
+        assert_eq!(rc.code, 400);
+        let rc = encodings.encode_payload(&publish("src/blob", json!([1, 2]), true, None)).unwrap_err();
+        assert_eq!(rc.code, 400);
+    }
+
+    #[test]
+    fn json_publish_is_transcoded_to_the_encoding_of_the_key() {
+        let encodings = Encodings { rules: vec![(OwnedKeyExpr::try_from("dst/*/motor").unwrap(), PayloadEncoding::Cbor)] };
+        let payload = json!({ "rpm": 1200 });
+        let (bytes, encoding) = encodings.encode_payload(&publish("dst/m1/motor", payload.clone(), false, None)).unwrap();
+        assert_eq!(encoding, PayloadEncoding::Cbor);
+        assert_eq!(cbor_to_json(&bytes).unwrap(), payload);
+        let (bytes, encoding) = encodings.encode_payload(&publish("dst/m1/led", payload.clone(), false, None)).unwrap();
+        assert_eq!(encoding, PayloadEncoding::Json);
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&bytes).unwrap(), payload);
+    }
+}
@@ -4,20 +4,38 @@ use actix_files::Files;
 use actix_web::test;
 use actix_web::{web, App, Error, HttpRequest, HttpResponse, HttpServer};
 use actix_web_actors::ws;
-use futures::StreamExt;
 use log::debug;
 use log::info;
-use serde::Deserialize;
 use serde_json::json;
+use std::collections::HashMap;
 use std::result::Result;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::{broadcast, mpsc};
 // use zenoh::prelude::r#async::*;
 use zenoh::*;
 mod logger;
 use logger::init;
 mod msg;
-use msg::{Load, Message, Publish, Save, Subscribe};
+use msg::{
+    ListReply, LoadReply, Message, Publish, PublishReply, ReturnCode, SaveReply, SubscribeReply,
+    UnsubscribeReply,
+};
+mod store;
+use store::Store;
+mod encoding;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use encoding::{Encodings, PayloadEncoding};
+use zenoh::bytes::Encoding;
+use zenoh::key_expr::{keyexpr, OwnedKeyExpr};
+use zenoh::pubsub::Subscriber;
+use zenoh::sample::Sample;
+
+// a new connection gets what every client got before it could subscribe
+const DEFAULT_SUBSCRIPTION: &str = "src/**";
+const STORE_FILE: &str = "./store.json";
+const ENCODINGS_FILE: &str = "./encodings.json";
 
 use crate::msg::test_serialization;
 
@@ -25,52 +43,163 @@ use crate::msg::test_serialization;
 struct ZenohSample {
     key: String,
     value: Vec<u8>,
+    encoding: PayloadEncoding,
+    // the JSON or CBOR payload as JSON, decoded once for all clients
+    json: Option<serde_json::Value>,
+    // the zenoh subscriber that received it, a sample arrives once per intersecting subscriber
+    subscription: OwnedKeyExpr,
+}
+
+// zenoh subscribers follow the union of the client subscriptions, counted per key expression
+#[derive(Debug)]
+enum SubscriptionRequest {
+    Declare(OwnedKeyExpr),
+    Undeclare(OwnedKeyExpr),
 }
 
 #[derive(Clone)]
 struct AppState {
     tx_broadcast: broadcast::Sender<ZenohSample>,
     tx_publish: mpsc::Sender<PublishRequest>,
+    tx_subscription: mpsc::UnboundedSender<SubscriptionRequest>,
+    store: Arc<Mutex<Store>>,
+    encodings: Arc<Encodings>,
 }
 
 #[derive(Debug)]
 struct PublishRequest {
     key: String,
     payload: Vec<u8>,
-}
-
-#[derive(Deserialize)]
-struct WsPublishMessage {
-    topic: String,
-    payload: String,
-    #[serde(default)]
-    base64: bool,
+    encoding: Encoding,
 }
 
 // === WebSocket Actor ===
 
 struct WsActor {
     tx_publish: mpsc::Sender<PublishRequest>,
+    tx_subscription: mpsc::UnboundedSender<SubscriptionRequest>,
     rx_broadcast: broadcast::Receiver<ZenohSample>,
+    store: Arc<Mutex<Store>>,
+    encodings: Arc<Encodings>,
+    // only samples on keys intersecting one of these reach the client, with the binary flag
+    subscriptions: Vec<(OwnedKeyExpr, bool)>,
     hb: Instant,
 }
 
 impl WsActor {
     fn new(
         tx_publish: mpsc::Sender<PublishRequest>,
+        tx_subscription: mpsc::UnboundedSender<SubscriptionRequest>,
         rx_broadcast: broadcast::Receiver<ZenohSample>,
+        store: Arc<Mutex<Store>>,
+        encodings: Arc<Encodings>,
     ) -> Self {
+        let default = OwnedKeyExpr::try_from(DEFAULT_SUBSCRIPTION).unwrap();
+        let _ = tx_subscription.send(SubscriptionRequest::Declare(default.clone()));
         Self {
             tx_publish,
+            tx_subscription,
             rx_broadcast,
+            store,
+            encodings,
+            subscriptions: vec![(default, false)],
             hb: Instant::now(),
         }
     }
 
+    fn reply(ctx: &mut ws::WebsocketContext<Self>, msg: Message) {
+        match msg::serialize_message(&msg) {
+            Ok(txt) => ctx.text(txt),
+            Err(e) => info!("Failed to serialize reply {:?}: {}", msg, e),
+        }
+    }
+
+    fn handle_command(&mut self, cmd: Message, ctx: &mut ws::WebsocketContext<Self>) {
+        let reply = match cmd {
+            Message::Publish(pub_msg) => {
+                info!("Processing Publish command for topic {} with payload {}", pub_msg.topic, pub_msg.payload);
+                let rc = match self.encodings.encode_payload(&pub_msg) {
+                    Ok((payload, encoding)) => self.publish(&pub_msg.topic, payload, encoding),
+                    Err(rc) => rc,
+                };
+                Message::PublishReply(PublishReply { topic: pub_msg.topic, rc })
+            }
+            Message::Subscribe(sub) => {
+                let rc = match OwnedKeyExpr::try_from(sub.topic.as_str()) {
+                    Ok(key_expr) => {
+                        let count = self.subscriptions.len();
+                        self.subscriptions.retain(|(subscribed, _)| *subscribed != key_expr);
+                        if self.subscriptions.len() == count {
+                            let _ = self.tx_subscription.send(SubscriptionRequest::Declare(key_expr.clone()));
+                        }
+                        self.subscriptions.push((key_expr, sub.binary));
+                        ReturnCode::ok()
+                    }
+                    Err(e) => ReturnCode::error(400, format!("invalid key expression: {}", e)),
+                };
+                Message::SubscribeReply(SubscribeReply { topic: sub.topic, rc })
+            }
+            Message::Unsubscribe(unsub) => {
+                let rc = if let Some(index) = self.subscriptions.iter().position(|(key_expr, _)| key_expr.as_str() == unsub.topic) {
+                    let (key_expr, _) = self.subscriptions.remove(index);
+                    let _ = self.tx_subscription.send(SubscriptionRequest::Undeclare(key_expr));
+                    ReturnCode::ok()
+                } else {
+                    ReturnCode::error(404, "not subscribed")
+                };
+                Message::UnsubscribeReply(UnsubscribeReply { topic: unsub.topic, rc })
+            }
+            Message::Save(save) => {
+                let rc = if save.key.is_empty() {
+                    ReturnCode::error(400, "empty key")
+                } else {
+                    match self.store.lock().unwrap().set(&save.key, save.payload) {
+                        Ok(()) => ReturnCode::ok(),
+                        Err(e) => ReturnCode::error(500, e.to_string()),
+                    }
+                };
+                Message::SaveReply(SaveReply { key: save.key, rc })
+            }
+            Message::Load(load) => {
+                let (payload, rc) = match self.store.lock().unwrap().get(&load.key) {
+                    Some(payload) => (payload.clone(), ReturnCode::ok()),
+                    None => (serde_json::Value::Null, ReturnCode::error(404, "no such key")),
+                };
+                Message::LoadReply(LoadReply { key: load.key, payload, rc })
+            }
+            Message::List(list) => {
+                let keys = self.store.lock().unwrap().keys(&list.prefix);
+                Message::ListReply(ListReply { prefix: list.prefix, keys, rc: ReturnCode::ok() })
+            }
+            reply => {
+                info!("Unsupported command type {:?}", reply);
+                Message::Error(ReturnCode::error(400, "not a command"))
+            }
+        };
+        Self::reply(ctx, reply);
+    }
+
+    fn publish(&self, key: &str, payload: Vec<u8>, encoding: PayloadEncoding) -> ReturnCode {
+        match self.tx_publish.try_send(PublishRequest {
+            key: key.to_string(),
+            payload,
+            encoding: encoding.zenoh_encoding(),
+        }) {
+            Ok(()) => ReturnCode::ok(),
+            Err(mpsc::error::TrySendError::Full(_)) => ReturnCode::error(503, "publish queue full"),
+            Err(mpsc::error::TrySendError::Closed(_)) => ReturnCode::error(500, "zenoh is not running"),
+        }
+    }
+
     fn start_broadcast(addr: actix::Addr<Self>, mut rx: broadcast::Receiver<ZenohSample>) {
         actix_rt::spawn(async move {
-            while let Ok(sample) = rx.recv().await {
-                addr.do_send(BroadcastMsg(sample));
+            loop {
+                match rx.recv().await {
+                    Ok(sample) => addr.do_send(BroadcastMsg(sample)),
+                    // a slow client loses the oldest samples, not its feed
+                    Err(RecvError::Lagged(skipped)) => info!("WebSocket client lagging, {} samples dropped", skipped),
+                    Err(RecvError::Closed) => break,
+                }
             }
         });
     }
@@ -97,24 +226,46 @@ impl actix::Actor for WsActor {
         Self::start_broadcast(ctx.address(), self.rx_broadcast.resubscribe());
         self.start_heartbeat(ctx);
     }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        for (key_expr, _) in self.subscriptions.drain(..) {
+            let _ = self.tx_subscription.send(SubscriptionRequest::Undeclare(key_expr));
+        }
+    }
 }
 
 impl actix::Handler<BroadcastMsg> for WsActor {
     type Result = ();
 
     fn handle(&mut self, msg: BroadcastMsg, ctx: &mut Self::Context) {
-        let str = String::from_utf8(msg.0.value.clone()).unwrap_or_default();
-        let js: serde_json::Value = serde_json::from_str(&str).unwrap_or_default();
-        debug!("Broadcasting Zenoh message to WebSocket client {:?}", msg.0.key);
-
-        let json = serde_json::json!({
-            "type": "Publish",
-            "topic": msg.0.key,
-            "payload": js,
-        });
-        if let Ok(txt) = serde_json::to_string(&json) {
-            ctx.text(txt);
+        let Ok(key) = keyexpr::new(msg.0.key.as_str()) else {
+            return;
+        };
+        let matching: Vec<_> = self.subscriptions.iter().filter(|(key_expr, _)| key_expr.intersects(key)).collect();
+        // overlapping subscriptions each get the sample from zenoh, forward only the copy of the first
+        let first = matching.iter().map(|(key_expr, _)| key_expr).min_by(|a, b| a.as_str().cmp(b.as_str()));
+        if first != Some(&msg.0.subscription) {
+            return;
         }
+        let binary = matching.iter().any(|(_, binary)| *binary);
+        let sample = msg.0;
+        debug!("Broadcasting Zenoh message to WebSocket client {:?} as {:?}", sample.key, sample.encoding);
+
+        // JSON and CBOR as JSON, the rest as binary frames or base64
+        let publish = match sample.json {
+            Some(payload) => Publish { topic: sample.key, payload, base64: false, encoding: Some(sample.encoding) },
+            None if binary => {
+                ctx.binary(msg::binary_frame(&sample.key, &sample.value));
+                return;
+            }
+            None => Publish {
+                topic: sample.key,
+                payload: serde_json::Value::String(STANDARD.encode(&sample.value)),
+                base64: true,
+                encoding: Some(sample.encoding),
+            },
+        };
+        Self::reply(ctx, Message::Publish(publish));
     }
 }
 
@@ -126,30 +277,32 @@ impl actix::StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsActor {
         });
         match msg {
             Ok(ws::Message::Text(txt)) => {
-                if let Ok(cmd) = serde_json::from_str::<msg::Message>(&txt) {
-                    info!("Parsed command: {:?}", cmd);
-                    match cmd {
-                        msg::Message::Publish(pub_msg) => {
-                            info!("Processing Publish command for topic {} with payload {}", pub_msg.topic, pub_msg.payload);
-
-                            let tx = self.tx_publish.clone();
-                            actix_rt::spawn(async move {
-                                let _ = tx
-                                    .send(PublishRequest {
-                                        key: pub_msg.topic,
-                                        payload:pub_msg.payload.to_string().into_bytes(),
-                                    })
-                                    .await;
-                            });
-                        }
-                        _ => {
-                            info!("Unsupported command type");
-                        }
+                match msg::deserialize_message(&txt) {
+                    Ok(cmd) => {
+                        info!("Parsed command: {:?}", cmd);
+                        self.handle_command(cmd, ctx);
+                    }
+                    Err(e) => {
+                        info!("Failed to parse WebSocket message as Command");
+                        Self::reply(ctx, Message::Error(ReturnCode::error(400, e.to_string())));
                     }
-                } else {
-                    info!("Failed to parse WebSocket message as Command");
                 }
             }
+            Ok(ws::Message::Binary(frame)) => {
+                let rc = match msg::parse_binary_frame(&frame) {
+                    Some((topic, payload)) => {
+                        let encoding = keyexpr::new(topic)
+                            .ok()
+                            .and_then(|key| self.encodings.configured(key))
+                            .unwrap_or(PayloadEncoding::Raw);
+                        let rc = self.publish(topic, payload.to_vec(), encoding);
+                        Self::reply(ctx, Message::PublishReply(PublishReply { topic: topic.to_string(), rc }));
+                        return;
+                    }
+                    None => ReturnCode::error(400, "binary frame without topic"),
+                };
+                Self::reply(ctx, Message::Error(rc));
+            }
             Ok(ws::Message::Ping(p)) => ctx.pong(&p),
             Ok(ws::Message::Pong(_)) => self.hb = Instant::now(),
             Ok(ws::Message::Close(_)) => ctx.stop(),
@@ -163,7 +316,13 @@ async fn ws_handler(
     stream: web::Payload,
     state: web::Data<AppState>,
 ) -> Result<HttpResponse, Error> {
-    let actor = WsActor::new(state.tx_publish.clone(), state.tx_broadcast.subscribe());
+    let actor = WsActor::new(
+        state.tx_publish.clone(),
+        state.tx_subscription.clone(),
+        state.tx_broadcast.subscribe(),
+        state.store.clone(),
+        state.encodings.clone(),
+    );
     info!("🛰️ New WebSocket connection established {:?}",req);
 
     ws::start(actor, &req, stream)
@@ -178,14 +337,20 @@ async fn main() -> std::io::Result<()> {
     // Channels
     let (tx_broadcast, _rx_broadcast) = broadcast::channel(128);
     let (tx_publish, rx_publish) = mpsc::channel(128);
+    let (tx_subscription, rx_subscription) = mpsc::unbounded_channel();
 
     info!("🚀 Starting server...");
     // Spawn Zenoh async worker
-    tokio::spawn(zenoh_worker(tx_broadcast.clone(), rx_publish));
+    let encodings = Arc::new(Encodings::load(ENCODINGS_FILE)?);
+    tokio::spawn(zenoh_worker(tx_broadcast.clone(), rx_publish, rx_subscription, encodings.clone()));
 
+    let store = Arc::new(Mutex::new(Store::open(STORE_FILE)?));
     let state = web::Data::new(AppState {
         tx_broadcast,
         tx_publish,
+        tx_subscription,
+        store,
+        encodings,
     });
 
     info!("🌐 Serving on http://localhost:8080");
@@ -204,33 +369,75 @@ async fn main() -> std::io::Result<()> {
 async fn zenoh_worker(
     tx_broadcast: broadcast::Sender<ZenohSample>,
     mut rx_publish: mpsc::Receiver<PublishRequest>,
+    mut rx_subscription: mpsc::UnboundedReceiver<SubscriptionRequest>,
+    encodings: Arc<Encodings>,
 ) {
     let mut config = zenoh::Config::default();
     config.insert_json5("mode", &json!("router").to_string()).unwrap();
     let session = zenoh::open(config).await.unwrap();
     info!("✅ Connected to Zenoh");
 
-    // Subscribe to everything under "demo/**"
-    let sub = session.declare_subscriber("src/**").await.unwrap();
-
-    let tx_broadcast_clone = tx_broadcast.clone();
-
-    // Zenoh subscription loop
-    tokio::spawn(async move {
-        let mut stream = sub.stream();
-        while let Some(sample) = stream.next().await {
-            debug!("Received Zenoh sample on key {}:{}", sample.key_expr(),sample.payload().try_to_string().unwrap());
-            let data = ZenohSample {
-                key: sample.key_expr().to_string(),
-                value: sample.payload().to_bytes().to_vec(),
-            };
-            let _ = tx_broadcast_clone.send(data);
+    // only what some client subscribed to leaves the router, dropping a subscriber undeclares it
+    let mut subscribers: HashMap<OwnedKeyExpr, (usize, Subscriber<()>)> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            Some(req) = rx_publish.recv() => {
+                info!("📤 Publishing to Zenoh key {}", req.key);
+                let _ = session.put(&req.key, req.payload).encoding(req.encoding).await;
+            }
+            Some(req) = rx_subscription.recv() => match req {
+                SubscriptionRequest::Declare(key_expr) => {
+                    if let Some((count, _)) = subscribers.get_mut(&key_expr) {
+                        *count += 1;
+                        continue;
+                    }
+                    let tx_broadcast = tx_broadcast.clone();
+                    let encodings = encodings.clone();
+                    let subscription = key_expr.clone();
+                    match session
+                        .declare_subscriber(key_expr.clone())
+                        .callback(move |sample| {
+                            let _ = tx_broadcast.send(to_zenoh_sample(&sample, &encodings, &subscription));
+                        })
+                        .await
+                    {
+                        Ok(subscriber) => {
+                            info!("Declared Zenoh subscriber on {}", key_expr);
+                            subscribers.insert(key_expr, (1, subscriber));
+                        }
+                        Err(e) => info!("Failed to declare Zenoh subscriber on {}: {}", key_expr, e),
+                    }
+                }
+                SubscriptionRequest::Undeclare(key_expr) => {
+                    if let Some((count, _)) = subscribers.get_mut(&key_expr) {
+                        *count -= 1;
+                        if *count == 0 {
+                            subscribers.remove(&key_expr);
+                            info!("Undeclared Zenoh subscriber on {}", key_expr);
+                        }
+                    }
+                }
+            },
+            else => break,
         }
-    });
+    }
+}
 
-    // Publishing loop
-    while let Some(req) = rx_publish.recv().await {
-        info!("📤 Publishing to Zenoh key {}", req.key);
-        let _ = session.put(&req.key, req.payload).await;
+fn to_zenoh_sample(sample: &Sample, encodings: &Encodings, subscription: &OwnedKeyExpr) -> ZenohSample {
+    let value = sample.payload().to_bytes().to_vec();
+    let encoding = encodings.detect(sample.key_expr(), sample.encoding(), &value);
+    debug!("Received Zenoh sample on key {}: {:?} [{}]", sample.key_expr(), encoding, value.len());
+    let json = match encoding {
+        PayloadEncoding::Json => serde_json::from_slice(&value).ok(),
+        PayloadEncoding::Cbor => encoding::cbor_to_json(&value).ok(),
+        PayloadEncoding::Raw | PayloadEncoding::Jpeg => None,
+    };
+    ZenohSample {
+        key: sample.key_expr().to_string(),
+        value,
+        encoding,
+        json,
+        subscription: subscription.clone(),
     }
 }
@@ -1,24 +1,68 @@
 use serde::{Deserialize, Serialize};
 
+use crate::encoding::PayloadEncoding;
 
 
-#[derive(Serialize, Deserialize, Debug)]
+
+// HTTP like codes : 200 ok, 400 bad request, 404 not found, 500 server error, 503 busy
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct ReturnCode {
     pub code: u16,
     pub msg : String,
 }
 
-// Define the Publish structure
+impl ReturnCode {
+    pub fn ok() -> Self {
+        ReturnCode { code: 200, msg: "OK".to_string() }
+    }
+
+    pub fn error(code: u16, msg: impl Into<String>) -> Self {
+        ReturnCode { code, msg: msg.into() }
+    }
+}
+
+// Define the Publish structure, with base64 the payload is a string with the bytes
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Publish {
     pub topic: String,
     pub payload: serde_json::Value,
+    #[serde(default)]
+    pub base64: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
 }
 
-// Define the Subscribe structure
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublishReply {
+    pub topic: String,
+    pub rc : ReturnCode,
+}
+
+// Define the Subscribe structure, topic is a zenoh key expression like src/esp1/**
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Subscribe {
     pub topic: String,
+    // raw and jpeg payloads as binary frames instead of base64 in a Publish
+    #[serde(default)]
+    pub binary: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubscribeReply {
+    pub topic: String,
+    pub rc : ReturnCode,
+}
+
+// Define the Unsubscribe structure, topic as given in Subscribe
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Unsubscribe {
+    pub topic: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnsubscribeReply {
+    pub topic: String,
+    pub rc : ReturnCode,
 }
 
 // Define the Save structure
@@ -66,13 +110,19 @@ pub struct ListReply {
 #[serde(tag = "type")] // Use "type" field to differentiate message types
 pub enum Message {
     Publish(Publish),
+    PublishReply(PublishReply),
     Subscribe(Subscribe),
+    SubscribeReply(SubscribeReply),
+    Unsubscribe(Unsubscribe),
+    UnsubscribeReply(UnsubscribeReply),
     Save(Save),
     SaveReply(SaveReply),
     Load(Load),
     LoadReply(LoadReply),
     List(List),
     ListReply(ListReply),
+    // reply to a command that could not be parsed
+    Error(ReturnCode),
 }
 
 pub fn serialize_message(msg: &Message) -> Result<String, serde_json::Error> {
@@ -87,6 +137,8 @@ pub fn test_serialization() {
     let publish_msg = Message::Publish(Publish {
         topic: "example/topic".to_string(),
         payload: serde_json::json!({"key": "value"}),
+        base64: false,
+        encoding: None,
     });
 
     let serialized = serialize_message(&publish_msg).unwrap();
@@ -94,4 +146,39 @@ pub fn test_serialization() {
 
     let deserialized: Message = deserialize_message(&serialized).unwrap();
     println!("Deserialized Publish Message: {:?}", deserialized);
-}
\ No newline at end of file
+}
+// Binary WebSocket frame : topic length as u16 big endian, the topic and the payload bytes
+pub fn binary_frame(topic: &str, payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(2 + topic.len() + payload.len());
+    frame.extend_from_slice(&(topic.len() as u16).to_be_bytes());
+    frame.extend_from_slice(topic.as_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+pub fn parse_binary_frame(frame: &[u8]) -> Option<(&str, &[u8])> {
+    let length = u16::from_be_bytes([*frame.first()?, *frame.get(1)?]) as usize;
+    let topic = std::str::from_utf8(frame.get(2..2 + length)?).ok()?;
+    Some((topic, &frame[2 + length..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_frame_round_trip() {
+        let payload = [0xFF, 0xD8, 0xFF, 0x00, 0x0A];
+        let frame = binary_frame("src/cam1/image", &payload);
+        assert_eq!(&frame[..2], &[0, 14]);
+        assert_eq!(parse_binary_frame(&frame), Some(("src/cam1/image", &payload[..])));
+        assert_eq!(parse_binary_frame(&binary_frame("src/empty", &[])), Some(("src/empty", &[][..])));
+    }
+
+    #[test]
+    fn truncated_binary_frame_is_refused() {
+        let frame = binary_frame("src/cam1/image", b"jpeg");
+        assert_eq!(parse_binary_frame(&frame[..1]), None);
+        assert_eq!(parse_binary_frame(&frame[..10]), None);
+    }
+}
@@ -0,0 +1,56 @@
+use log::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// Named layouts and values saved by the clients, kept in one JSON file
+pub struct Store {
+    path: PathBuf,
+    values: BTreeMap<String, serde_json::Value>,
+}
+
+impl Store {
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let values = match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        info!("💾 Store {:?} has {} keys", path, values.len());
+        Ok(Self { path, values })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.values.get(key)
+    }
+
+    // written to a temporary file first so a crash never leaves half a store
+    pub fn set(&mut self, key: &str, value: serde_json::Value) -> io::Result<()> {
+        let previous = self.values.insert(key.to_string(), value);
+        let result = self.write();
+        if result.is_err() {
+            match previous {
+                Some(previous) => self.values.insert(key.to_string(), previous),
+                None => self.values.remove(key),
+            };
+        }
+        result
+    }
+
+    pub fn keys(&self, prefix: &str) -> Vec<String> {
+        self.values
+            .range(prefix.to_string()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn write(&self) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&self.values)?)?;
+        fs::rename(tmp, &self.path)
+    }
+}